@@ -17,6 +17,7 @@ pub use crate::{
         SpringConfig,
     },
     stream_engine::{
+        decimal::SpringDecimal,
         time::{SpringEventDuration, SpringTimestamp},
         SpringValue,
     },
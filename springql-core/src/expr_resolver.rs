@@ -93,12 +93,9 @@ impl ExprResolver {
             })
     }
 
-    /// TODO use in HAVING clause
-    ///
     /// # Failures
     ///
     /// - `SpringError::Sql` if alias is not in select_list.
-    #[allow(dead_code)]
     pub fn resolve_aggr_alias(&self, aggr_alias: AggrAlias) -> Result<AggrExprLabel> {
         self.aggr_aliased_labels
             .get(&aggr_alias)
@@ -4,21 +4,29 @@
 
 mod boolean_expression;
 mod function_call;
+mod navigation_function_call;
 mod operator;
 
 pub use boolean_expression::{BinaryExpr, ComparisonFunction, LogicalFunction, NumericalFunction};
-pub use function_call::FunctionCall;
+pub use function_call::{parse_utc_offset, FunctionCall, GeoPolygon, JsonPath, TimestampFormat};
+pub use navigation_function_call::{NavigationFunction, NavigationFunctionCall};
 pub use operator::{BinaryOperator, UnaryOperator};
 
 use anyhow::anyhow;
+use ordered_float::OrderedFloat;
 
 use crate::{
     api::error::{Result, SpringError},
-    pipeline::{AggregateFunctionParameter, ColumnReference},
+    expression::function_call::{
+        geohash, get_bits, haversine_distance, hex, initial_bearing, ByteOrder,
+    },
+    pipeline::{AggregateFunctionParameter, ColumnName, ColumnReference, SqlType},
     stream_engine::{
-        time::{SpringDuration, SpringEventDuration},
+        decimal::MAX_DECIMAL_PRECISION,
+        time::{SpringDuration, SpringEventDuration, SpringTimestamp, SystemTimestamp},
         NnSqlValue, SqlCompareResult, SqlValue, Tuple,
     },
+    time::Duration,
 };
 
 pub trait ValueExprType {}
@@ -33,12 +41,287 @@ pub enum ValueExpr {
     UnaryOperator(UnaryOperator, Box<Self>),
     BinaryExpr(BinaryExpr<Self>),
     FunctionCall(FunctionCall<Self>),
+    NavigationFunctionCall(NavigationFunctionCall),
+    /// `CHANGELOG_OP`: 'INSERT' or 'UPDATE' of the row emitted from aggregation without window.
+    ChangelogOp,
+    /// `RESAMPLE_TIME`: start of the interval of the row emitted from aggregation with RESAMPLE window.
+    ResampleTime,
 
     ColumnReference(ColumnReference),
 }
 impl ValueExprType for ValueExpr {}
 
 impl ValueExpr {
+    /// Replaces navigation function calls (LAG, LEAD, EWMA, ...) in this expression with column references returned from `replace`.
+    ///
+    /// # Failures
+    ///
+    /// Error from `replace`.
+    pub fn replace_navigation_function_calls<F>(self, replace: &mut F) -> Result<Self>
+    where
+        F: FnMut(NavigationFunctionCall) -> Result<ColumnReference>,
+    {
+        let mut replace_child = |child: Box<Self>| -> Result<Box<Self>> {
+            Ok(Box::new(child.replace_navigation_function_calls(replace)?))
+        };
+
+        match self {
+            Self::Constant(_)
+            | Self::ColumnReference(_)
+            | Self::ChangelogOp
+            | Self::ResampleTime => Ok(self),
+            Self::NavigationFunctionCall(call) => Ok(Self::ColumnReference(replace(call)?)),
+            Self::UnaryOperator(op, child) => Ok(Self::UnaryOperator(op, replace_child(child)?)),
+            Self::FunctionCall(function_call) => {
+                let function_call = match function_call {
+                    FunctionCall::DurationMillis { duration_millis } => {
+                        FunctionCall::DurationMillis {
+                            duration_millis: replace_child(duration_millis)?,
+                        }
+                    }
+                    FunctionCall::DurationSecs { duration_secs } => FunctionCall::DurationSecs {
+                        duration_secs: replace_child(duration_secs)?,
+                    },
+                    FunctionCall::DurationMicros { duration_micros } => {
+                        FunctionCall::DurationMicros {
+                            duration_micros: replace_child(duration_micros)?,
+                        }
+                    }
+                    FunctionCall::DurationMinutes { duration_minutes } => {
+                        FunctionCall::DurationMinutes {
+                            duration_minutes: replace_child(duration_minutes)?,
+                        }
+                    }
+                    FunctionCall::DurationHours { duration_hours } => FunctionCall::DurationHours {
+                        duration_hours: replace_child(duration_hours)?,
+                    },
+                    FunctionCall::DurationToMillis { duration } => FunctionCall::DurationToMillis {
+                        duration: replace_child(duration)?,
+                    },
+                    FunctionCall::FloorTime { target, resolution } => FunctionCall::FloorTime {
+                        target: replace_child(target)?,
+                        resolution: replace_child(resolution)?,
+                    },
+                    FunctionCall::Extract { field, target } => FunctionCall::Extract {
+                        field,
+                        target: replace_child(target)?,
+                    },
+                    FunctionCall::DateTrunc { field, target } => FunctionCall::DateTrunc {
+                        field,
+                        target: replace_child(target)?,
+                    },
+                    FunctionCall::ToTimestamp { text, format } => FunctionCall::ToTimestamp {
+                        text: replace_child(text)?,
+                        format,
+                    },
+                    FunctionCall::FormatTimestamp { target, format } => {
+                        FunctionCall::FormatTimestamp {
+                            target: replace_child(target)?,
+                            format,
+                        }
+                    }
+                    FunctionCall::CurrentTimestamp => FunctionCall::CurrentTimestamp,
+                    FunctionCall::AtTimeZone {
+                        target,
+                        offset_secs,
+                    } => FunctionCall::AtTimeZone {
+                        target: replace_child(target)?,
+                        offset_secs,
+                    },
+                    FunctionCall::StDistance {
+                        lat1,
+                        lon1,
+                        lat2,
+                        lon2,
+                    } => FunctionCall::StDistance {
+                        lat1: replace_child(lat1)?,
+                        lon1: replace_child(lon1)?,
+                        lat2: replace_child(lat2)?,
+                        lon2: replace_child(lon2)?,
+                    },
+                    FunctionCall::StBearing {
+                        lat1,
+                        lon1,
+                        lat2,
+                        lon2,
+                    } => FunctionCall::StBearing {
+                        lat1: replace_child(lat1)?,
+                        lon1: replace_child(lon1)?,
+                        lat2: replace_child(lat2)?,
+                        lon2: replace_child(lon2)?,
+                    },
+                    FunctionCall::StWithin { lat, lon, polygon } => FunctionCall::StWithin {
+                        lat: replace_child(lat)?,
+                        lon: replace_child(lon)?,
+                        polygon,
+                    },
+                    FunctionCall::StWithinWkt {
+                        lat,
+                        lon,
+                        polygon_wkt,
+                    } => FunctionCall::StWithinWkt {
+                        lat: replace_child(lat)?,
+                        lon: replace_child(lon)?,
+                        polygon_wkt: replace_child(polygon_wkt)?,
+                    },
+                    FunctionCall::Geohash {
+                        lat,
+                        lon,
+                        precision,
+                    } => FunctionCall::Geohash {
+                        lat: replace_child(lat)?,
+                        lon: replace_child(lon)?,
+                        precision: replace_child(precision)?,
+                    },
+                    FunctionCall::GetBits {
+                        blob,
+                        start_bit,
+                        length,
+                        byte_order,
+                        signed,
+                    } => FunctionCall::GetBits {
+                        blob: replace_child(blob)?,
+                        start_bit: replace_child(start_bit)?,
+                        length: replace_child(length)?,
+                        byte_order: replace_child(byte_order)?,
+                        signed: replace_child(signed)?,
+                    },
+                    FunctionCall::GetByte { blob, index } => FunctionCall::GetByte {
+                        blob: replace_child(blob)?,
+                        index: replace_child(index)?,
+                    },
+                    FunctionCall::BlobLength { blob } => FunctionCall::BlobLength {
+                        blob: replace_child(blob)?,
+                    },
+                    FunctionCall::Hex { blob } => FunctionCall::Hex {
+                        blob: replace_child(blob)?,
+                    },
+                    FunctionCall::Round { value, scale } => FunctionCall::Round {
+                        value: replace_child(value)?,
+                        scale: replace_child(scale)?,
+                    },
+                    FunctionCall::ElementAt { container, key } => FunctionCall::ElementAt {
+                        container: replace_child(container)?,
+                        key: replace_child(key)?,
+                    },
+                    FunctionCall::FieldOf { row, field } => FunctionCall::FieldOf {
+                        row: replace_child(row)?,
+                        field,
+                    },
+                    FunctionCall::Cardinality { container } => FunctionCall::Cardinality {
+                        container: replace_child(container)?,
+                    },
+                    FunctionCall::JsonValue { json, path } => FunctionCall::JsonValue {
+                        json: replace_child(json)?,
+                        path,
+                    },
+                    FunctionCall::JsonQuery { json, path } => FunctionCall::JsonQuery {
+                        json: replace_child(json)?,
+                        path,
+                    },
+                    FunctionCall::JsonExists { json, path } => FunctionCall::JsonExists {
+                        json: replace_child(json)?,
+                        path,
+                    },
+                    FunctionCall::JsonObject { entries } => FunctionCall::JsonObject {
+                        entries: entries
+                            .into_iter()
+                            .map(|(key, v)| Ok((key, replace_child(v)?)))
+                            .collect::<Result<_>>()?,
+                    },
+                };
+                Ok(Self::FunctionCall(function_call))
+            }
+            Self::BinaryExpr(binary_expr) => {
+                let binary_expr = match binary_expr {
+                    BinaryExpr::LogicalFunctionVariant(LogicalFunction::AndVariant {
+                        left,
+                        right,
+                    }) => BinaryExpr::LogicalFunctionVariant(LogicalFunction::AndVariant {
+                        left: replace_child(left)?,
+                        right: replace_child(right)?,
+                    }),
+                    BinaryExpr::ComparisonFunctionVariant(ComparisonFunction::EqualVariant {
+                        left,
+                        right,
+                    }) => BinaryExpr::ComparisonFunctionVariant(ComparisonFunction::EqualVariant {
+                        left: replace_child(left)?,
+                        right: replace_child(right)?,
+                    }),
+                    BinaryExpr::NumericalFunctionVariant(NumericalFunction::AddVariant {
+                        left,
+                        right,
+                    }) => BinaryExpr::NumericalFunctionVariant(NumericalFunction::AddVariant {
+                        left: replace_child(left)?,
+                        right: replace_child(right)?,
+                    }),
+                    BinaryExpr::NumericalFunctionVariant(NumericalFunction::SubVariant {
+                        left,
+                        right,
+                    }) => BinaryExpr::NumericalFunctionVariant(NumericalFunction::SubVariant {
+                        left: replace_child(left)?,
+                        right: replace_child(right)?,
+                    }),
+                    BinaryExpr::NumericalFunctionVariant(NumericalFunction::MulVariant {
+                        left,
+                        right,
+                    }) => BinaryExpr::NumericalFunctionVariant(NumericalFunction::MulVariant {
+                        left: replace_child(left)?,
+                        right: replace_child(right)?,
+                    }),
+                    BinaryExpr::NumericalFunctionVariant(NumericalFunction::DivVariant {
+                        left,
+                        right,
+                    }) => BinaryExpr::NumericalFunctionVariant(NumericalFunction::DivVariant {
+                        left: replace_child(left)?,
+                        right: replace_child(right)?,
+                    }),
+                    BinaryExpr::NumericalFunctionVariant(NumericalFunction::BitAndVariant {
+                        left,
+                        right,
+                    }) => BinaryExpr::NumericalFunctionVariant(NumericalFunction::BitAndVariant {
+                        left: replace_child(left)?,
+                        right: replace_child(right)?,
+                    }),
+                    BinaryExpr::NumericalFunctionVariant(NumericalFunction::BitOrVariant {
+                        left,
+                        right,
+                    }) => BinaryExpr::NumericalFunctionVariant(NumericalFunction::BitOrVariant {
+                        left: replace_child(left)?,
+                        right: replace_child(right)?,
+                    }),
+                    BinaryExpr::NumericalFunctionVariant(NumericalFunction::ShlVariant {
+                        left,
+                        right,
+                    }) => BinaryExpr::NumericalFunctionVariant(NumericalFunction::ShlVariant {
+                        left: replace_child(left)?,
+                        right: replace_child(right)?,
+                    }),
+                    BinaryExpr::NumericalFunctionVariant(NumericalFunction::ShrVariant {
+                        left,
+                        right,
+                    }) => BinaryExpr::NumericalFunctionVariant(NumericalFunction::ShrVariant {
+                        left: replace_child(left)?,
+                        right: replace_child(right)?,
+                    }),
+                };
+                Ok(Self::BinaryExpr(binary_expr))
+            }
+        }
+    }
+
+    /// Whether this expression contains navigation function calls (LAG, LEAD, EWMA, ...).
+    pub fn has_navigation_function_call(&self) -> bool {
+        let mut found = false;
+        let _ = self.clone().replace_navigation_function_calls(&mut |call| {
+            found = true;
+            Ok(ColumnReference::Derived {
+                field_name: ColumnName::new(call.func.name().to_string()),
+            })
+        });
+        found
+    }
+
     pub fn resolve_colref(self, tuple: &Tuple) -> Result<ValueExprPh2> {
         match self {
             Self::Constant(value) => Ok(ValueExprPh2::Constant(value)),
@@ -48,6 +331,16 @@ impl ValueExpr {
                 Ok(ValueExprPh2::Constant(value))
             }
 
+            Self::NavigationFunctionCall(_) => Err(SpringError::Sql(anyhow!(
+                "navigation functions (LAG, LEAD, EWMA, ZSCORE, ...) are only supported in select list and WHERE clause"
+            ))),
+            Self::ChangelogOp => Err(SpringError::Sql(anyhow!(
+                "CHANGELOG_OP is only supported in select list of aggregation without window"
+            ))),
+            Self::ResampleTime => Err(SpringError::Sql(anyhow!(
+                "RESAMPLE_TIME is only supported in select list of aggregation with RESAMPLE window"
+            ))),
+
             Self::FunctionCall(function_call) => match function_call {
                 FunctionCall::DurationMillis { duration_millis } => {
                     let duration_millis_ph2 = duration_millis.resolve_colref(tuple)?;
@@ -61,6 +354,30 @@ impl ValueExpr {
                         duration_secs: Box::new(duration_secs_ph2),
                     }))
                 }
+                FunctionCall::DurationMicros { duration_micros } => {
+                    let duration_micros_ph2 = duration_micros.resolve_colref(tuple)?;
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::DurationMicros {
+                        duration_micros: Box::new(duration_micros_ph2),
+                    }))
+                }
+                FunctionCall::DurationMinutes { duration_minutes } => {
+                    let duration_minutes_ph2 = duration_minutes.resolve_colref(tuple)?;
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::DurationMinutes {
+                        duration_minutes: Box::new(duration_minutes_ph2),
+                    }))
+                }
+                FunctionCall::DurationHours { duration_hours } => {
+                    let duration_hours_ph2 = duration_hours.resolve_colref(tuple)?;
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::DurationHours {
+                        duration_hours: Box::new(duration_hours_ph2),
+                    }))
+                }
+                FunctionCall::DurationToMillis { duration } => {
+                    let duration_ph2 = duration.resolve_colref(tuple)?;
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::DurationToMillis {
+                        duration: Box::new(duration_ph2),
+                    }))
+                }
                 FunctionCall::FloorTime { target, resolution } => {
                     let target_ph2 = target.resolve_colref(tuple)?;
                     let resolution_ph2 = resolution.resolve_colref(tuple)?;
@@ -69,6 +386,163 @@ impl ValueExpr {
                         resolution: Box::new(resolution_ph2),
                     }))
                 }
+                FunctionCall::Extract { field, target } => {
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::Extract {
+                        field,
+                        target: Box::new(target.resolve_colref(tuple)?),
+                    }))
+                }
+                FunctionCall::DateTrunc { field, target } => {
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::DateTrunc {
+                        field,
+                        target: Box::new(target.resolve_colref(tuple)?),
+                    }))
+                }
+                FunctionCall::ToTimestamp { text, format } => {
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::ToTimestamp {
+                        text: Box::new(text.resolve_colref(tuple)?),
+                        format,
+                    }))
+                }
+                FunctionCall::FormatTimestamp { target, format } => {
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::FormatTimestamp {
+                        target: Box::new(target.resolve_colref(tuple)?),
+                        format,
+                    }))
+                }
+                FunctionCall::CurrentTimestamp => {
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::CurrentTimestamp))
+                }
+                FunctionCall::AtTimeZone {
+                    target,
+                    offset_secs,
+                } => Ok(ValueExprPh2::FunctionCall(FunctionCall::AtTimeZone {
+                    target: Box::new(target.resolve_colref(tuple)?),
+                    offset_secs,
+                })),
+                FunctionCall::StDistance {
+                    lat1,
+                    lon1,
+                    lat2,
+                    lon2,
+                } => Ok(ValueExprPh2::FunctionCall(FunctionCall::StDistance {
+                    lat1: Box::new(lat1.resolve_colref(tuple)?),
+                    lon1: Box::new(lon1.resolve_colref(tuple)?),
+                    lat2: Box::new(lat2.resolve_colref(tuple)?),
+                    lon2: Box::new(lon2.resolve_colref(tuple)?),
+                })),
+                FunctionCall::StBearing {
+                    lat1,
+                    lon1,
+                    lat2,
+                    lon2,
+                } => Ok(ValueExprPh2::FunctionCall(FunctionCall::StBearing {
+                    lat1: Box::new(lat1.resolve_colref(tuple)?),
+                    lon1: Box::new(lon1.resolve_colref(tuple)?),
+                    lat2: Box::new(lat2.resolve_colref(tuple)?),
+                    lon2: Box::new(lon2.resolve_colref(tuple)?),
+                })),
+                FunctionCall::StWithin { lat, lon, polygon } => {
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::StWithin {
+                        lat: Box::new(lat.resolve_colref(tuple)?),
+                        lon: Box::new(lon.resolve_colref(tuple)?),
+                        polygon,
+                    }))
+                }
+                FunctionCall::StWithinWkt {
+                    lat,
+                    lon,
+                    polygon_wkt,
+                } => Ok(ValueExprPh2::FunctionCall(FunctionCall::StWithinWkt {
+                    lat: Box::new(lat.resolve_colref(tuple)?),
+                    lon: Box::new(lon.resolve_colref(tuple)?),
+                    polygon_wkt: Box::new(polygon_wkt.resolve_colref(tuple)?),
+                })),
+                FunctionCall::Geohash {
+                    lat,
+                    lon,
+                    precision,
+                } => Ok(ValueExprPh2::FunctionCall(FunctionCall::Geohash {
+                    lat: Box::new(lat.resolve_colref(tuple)?),
+                    lon: Box::new(lon.resolve_colref(tuple)?),
+                    precision: Box::new(precision.resolve_colref(tuple)?),
+                })),
+                FunctionCall::GetBits {
+                    blob,
+                    start_bit,
+                    length,
+                    byte_order,
+                    signed,
+                } => Ok(ValueExprPh2::FunctionCall(FunctionCall::GetBits {
+                    blob: Box::new(blob.resolve_colref(tuple)?),
+                    start_bit: Box::new(start_bit.resolve_colref(tuple)?),
+                    length: Box::new(length.resolve_colref(tuple)?),
+                    byte_order: Box::new(byte_order.resolve_colref(tuple)?),
+                    signed: Box::new(signed.resolve_colref(tuple)?),
+                })),
+                FunctionCall::GetByte { blob, index } => {
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::GetByte {
+                        blob: Box::new(blob.resolve_colref(tuple)?),
+                        index: Box::new(index.resolve_colref(tuple)?),
+                    }))
+                }
+                FunctionCall::BlobLength { blob } => {
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::BlobLength {
+                        blob: Box::new(blob.resolve_colref(tuple)?),
+                    }))
+                }
+                FunctionCall::Hex { blob } => Ok(ValueExprPh2::FunctionCall(FunctionCall::Hex {
+                    blob: Box::new(blob.resolve_colref(tuple)?),
+                })),
+                FunctionCall::Round { value, scale } => {
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::Round {
+                        value: Box::new(value.resolve_colref(tuple)?),
+                        scale: Box::new(scale.resolve_colref(tuple)?),
+                    }))
+                }
+                FunctionCall::ElementAt { container, key } => {
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::ElementAt {
+                        container: Box::new(container.resolve_colref(tuple)?),
+                        key: Box::new(key.resolve_colref(tuple)?),
+                    }))
+                }
+                FunctionCall::FieldOf { row, field } => {
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::FieldOf {
+                        row: Box::new(row.resolve_colref(tuple)?),
+                        field,
+                    }))
+                }
+                FunctionCall::Cardinality { container } => {
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::Cardinality {
+                        container: Box::new(container.resolve_colref(tuple)?),
+                    }))
+                }
+                FunctionCall::JsonValue { json, path } => {
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::JsonValue {
+                        json: Box::new(json.resolve_colref(tuple)?),
+                        path,
+                    }))
+                }
+                FunctionCall::JsonQuery { json, path } => {
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::JsonQuery {
+                        json: Box::new(json.resolve_colref(tuple)?),
+                        path,
+                    }))
+                }
+                FunctionCall::JsonExists { json, path } => {
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::JsonExists {
+                        json: Box::new(json.resolve_colref(tuple)?),
+                        path,
+                    }))
+                }
+                FunctionCall::JsonObject { entries } => {
+                    Ok(ValueExprPh2::FunctionCall(FunctionCall::JsonObject {
+                        entries: entries
+                            .into_iter()
+                            .map(|(key, v)| Ok((key, Box::new(v.resolve_colref(tuple)?))))
+                            .collect::<Result<_>>()?,
+                    }))
+                }
             },
             Self::UnaryOperator(op, expr_ph1) => {
                 let expr_ph2 = expr_ph1.resolve_colref(tuple)?;
@@ -117,6 +591,18 @@ impl ValueExpr {
                                 ),
                             ))
                         }
+                        NumericalFunction::SubVariant { left, right } => {
+                            let left_ph2 = left.resolve_colref(tuple)?;
+                            let right_ph2 = right.resolve_colref(tuple)?;
+                            Ok(ValueExprPh2::BinaryExpr(
+                                BinaryExpr::NumericalFunctionVariant(
+                                    NumericalFunction::SubVariant {
+                                        left: Box::new(left_ph2),
+                                        right: Box::new(right_ph2),
+                                    },
+                                ),
+                            ))
+                        }
                         NumericalFunction::MulVariant { left, right } => {
                             let left_ph2 = left.resolve_colref(tuple)?;
                             let right_ph2 = right.resolve_colref(tuple)?;
@@ -129,6 +615,66 @@ impl ValueExpr {
                                 ),
                             ))
                         }
+                        NumericalFunction::DivVariant { left, right } => {
+                            let left_ph2 = left.resolve_colref(tuple)?;
+                            let right_ph2 = right.resolve_colref(tuple)?;
+                            Ok(ValueExprPh2::BinaryExpr(
+                                BinaryExpr::NumericalFunctionVariant(
+                                    NumericalFunction::DivVariant {
+                                        left: Box::new(left_ph2),
+                                        right: Box::new(right_ph2),
+                                    },
+                                ),
+                            ))
+                        }
+                        NumericalFunction::BitAndVariant { left, right } => {
+                            let left_ph2 = left.resolve_colref(tuple)?;
+                            let right_ph2 = right.resolve_colref(tuple)?;
+                            Ok(ValueExprPh2::BinaryExpr(
+                                BinaryExpr::NumericalFunctionVariant(
+                                    NumericalFunction::BitAndVariant {
+                                        left: Box::new(left_ph2),
+                                        right: Box::new(right_ph2),
+                                    },
+                                ),
+                            ))
+                        }
+                        NumericalFunction::BitOrVariant { left, right } => {
+                            let left_ph2 = left.resolve_colref(tuple)?;
+                            let right_ph2 = right.resolve_colref(tuple)?;
+                            Ok(ValueExprPh2::BinaryExpr(
+                                BinaryExpr::NumericalFunctionVariant(
+                                    NumericalFunction::BitOrVariant {
+                                        left: Box::new(left_ph2),
+                                        right: Box::new(right_ph2),
+                                    },
+                                ),
+                            ))
+                        }
+                        NumericalFunction::ShlVariant { left, right } => {
+                            let left_ph2 = left.resolve_colref(tuple)?;
+                            let right_ph2 = right.resolve_colref(tuple)?;
+                            Ok(ValueExprPh2::BinaryExpr(
+                                BinaryExpr::NumericalFunctionVariant(
+                                    NumericalFunction::ShlVariant {
+                                        left: Box::new(left_ph2),
+                                        right: Box::new(right_ph2),
+                                    },
+                                ),
+                            ))
+                        }
+                        NumericalFunction::ShrVariant { left, right } => {
+                            let left_ph2 = left.resolve_colref(tuple)?;
+                            let right_ph2 = right.resolve_colref(tuple)?;
+                            Ok(ValueExprPh2::BinaryExpr(
+                                BinaryExpr::NumericalFunctionVariant(
+                                    NumericalFunction::ShrVariant {
+                                        left: Box::new(left_ph2),
+                                        right: Box::new(right_ph2),
+                                    },
+                                ),
+                            ))
+                        }
                     }
                 }
             },
@@ -201,11 +747,41 @@ impl ValueExprPh2 {
                 let right_sql_value = right.eval()?;
                 left_sql_value + right_sql_value
             }
+            NumericalFunction::SubVariant { left, right } => {
+                let left_sql_value = left.eval()?;
+                let right_sql_value = right.eval()?;
+                left_sql_value - right_sql_value
+            }
             NumericalFunction::MulVariant { left, right } => {
                 let left_sql_value = left.eval()?;
                 let right_sql_value = right.eval()?;
                 left_sql_value * right_sql_value
             }
+            NumericalFunction::DivVariant { left, right } => {
+                let left_sql_value = left.eval()?;
+                let right_sql_value = right.eval()?;
+                left_sql_value / right_sql_value
+            }
+            NumericalFunction::BitAndVariant { left, right } => {
+                let left_sql_value = left.eval()?;
+                let right_sql_value = right.eval()?;
+                left_sql_value & right_sql_value
+            }
+            NumericalFunction::BitOrVariant { left, right } => {
+                let left_sql_value = left.eval()?;
+                let right_sql_value = right.eval()?;
+                left_sql_value | right_sql_value
+            }
+            NumericalFunction::ShlVariant { left, right } => {
+                let left_sql_value = left.eval()?;
+                let right_sql_value = right.eval()?;
+                left_sql_value << right_sql_value
+            }
+            NumericalFunction::ShrVariant { left, right } => {
+                let left_sql_value = left.eval()?;
+                let right_sql_value = right.eval()?;
+                left_sql_value >> right_sql_value
+            }
         }
     }
 
@@ -214,12 +790,147 @@ impl ValueExprPh2 {
             FunctionCall::FloorTime { target, resolution } => {
                 Self::eval_function_floor_time(*target, *resolution)
             }
+            FunctionCall::Extract { field, target } => {
+                Self::eval_function_timestamp(*target, "EXTRACT", |ts, _| {
+                    Ok(NnSqlValue::Integer(ts.extract(field)))
+                })
+            }
+            FunctionCall::DateTrunc { field, target } => {
+                Self::eval_function_timestamp(*target, "DATE_TRUNC", |ts, with_tz| {
+                    let ts = ts.trunc(field)?;
+                    Ok(if with_tz {
+                        NnSqlValue::TimestampTz(ts)
+                    } else {
+                        NnSqlValue::Timestamp(ts)
+                    })
+                })
+            }
+            FunctionCall::ToTimestamp { text, format } => {
+                Self::eval_function_to_timestamp(*text, &format)
+            }
+            FunctionCall::FormatTimestamp { target, format } => {
+                Self::eval_function_timestamp(*target, "FORMAT_TIMESTAMP", |ts, _| {
+                    format.format_timestamp(&ts).map(NnSqlValue::Text)
+                })
+            }
+            FunctionCall::CurrentTimestamp => Ok(SqlValue::NotNull(NnSqlValue::TimestampTz(
+                SystemTimestamp::now(),
+            ))),
+            FunctionCall::AtTimeZone {
+                target,
+                offset_secs,
+            } => Self::eval_function_timestamp(*target, "AT TIME ZONE", |ts, with_tz| {
+                let offset = Duration::seconds(offset_secs as i64);
+                Ok(if with_tz {
+                    NnSqlValue::Timestamp(ts + offset)
+                } else {
+                    NnSqlValue::TimestampTz(ts - offset)
+                })
+            }),
             FunctionCall::DurationMillis { duration_millis } => {
-                Self::eval_function_duration_millis(*duration_millis)
+                Self::eval_function_duration(*duration_millis, "DURATION_MILLIS", |millis| {
+                    Some(SpringEventDuration::from_millis(millis))
+                })
             }
             FunctionCall::DurationSecs { duration_secs } => {
-                Self::eval_function_duration_secs(*duration_secs)
+                Self::eval_function_duration(*duration_secs, "DURATION_SECS", |secs| {
+                    Some(SpringEventDuration::from_secs(secs))
+                })
+            }
+            FunctionCall::DurationMicros { duration_micros } => {
+                Self::eval_function_duration(*duration_micros, "DURATION_MICROS", |micros| {
+                    Some(SpringEventDuration::from_micros(micros))
+                })
+            }
+            FunctionCall::DurationMinutes { duration_minutes } => {
+                Self::eval_function_duration(*duration_minutes, "DURATION_MINUTES", |minutes| {
+                    minutes.checked_mul(60).map(SpringEventDuration::from_secs)
+                })
+            }
+            FunctionCall::DurationHours { duration_hours } => {
+                Self::eval_function_duration(*duration_hours, "DURATION_HOURS", |hours| {
+                    hours
+                        .checked_mul(60 * 60)
+                        .map(SpringEventDuration::from_secs)
+                })
+            }
+            FunctionCall::DurationToMillis { duration } => match duration.eval()? {
+                SqlValue::Null => Ok(SqlValue::Null),
+                SqlValue::NotNull(NnSqlValue::Duration(d)) => Ok(SqlValue::NotNull(
+                    NnSqlValue::BigInt((d.as_nanos() / 1_000_000) as i64),
+                )),
+                SqlValue::NotNull(v) => Err(SpringError::Sql(anyhow!(
+                    "DURATION_TO_MILLIS takes DURATION but got `{}`",
+                    v
+                ))),
+            },
+            FunctionCall::StDistance {
+                lat1,
+                lon1,
+                lat2,
+                lon2,
+            } => Self::eval_function_two_points(*lat1, *lon1, *lat2, *lon2, haversine_distance),
+            FunctionCall::StBearing {
+                lat1,
+                lon1,
+                lat2,
+                lon2,
+            } => Self::eval_function_two_points(*lat1, *lon1, *lat2, *lon2, initial_bearing),
+            FunctionCall::StWithin { lat, lon, polygon } => {
+                Self::eval_function_st_within(*lat, *lon, &polygon)
+            }
+            FunctionCall::StWithinWkt {
+                lat,
+                lon,
+                polygon_wkt,
+            } => Self::eval_function_st_within_wkt(*lat, *lon, *polygon_wkt),
+            FunctionCall::Geohash {
+                lat,
+                lon,
+                precision,
+            } => Self::eval_function_geohash(*lat, *lon, *precision),
+            FunctionCall::GetBits {
+                blob,
+                start_bit,
+                length,
+                byte_order,
+                signed,
+            } => Self::eval_function_get_bits(*blob, *start_bit, *length, *byte_order, *signed),
+            FunctionCall::GetByte { blob, index } => Self::eval_function_get_byte(*blob, *index),
+            FunctionCall::BlobLength { blob } => {
+                Self::eval_function_blob(*blob, "BLOB_LENGTH", |data| {
+                    NnSqlValue::Integer(data.len() as i32)
+                })
+            }
+            FunctionCall::Hex { blob } => {
+                Self::eval_function_blob(*blob, "HEX", |data| NnSqlValue::Text(hex(data)))
+            }
+            FunctionCall::Round { value, scale } => Self::eval_function_round(*value, *scale),
+            FunctionCall::ElementAt { container, key } => {
+                Self::eval_function_element_at(*container, *key)
+            }
+            FunctionCall::FieldOf { row, field } => Self::eval_function_field_of(*row, &field),
+            FunctionCall::Cardinality { container } => Self::eval_function_cardinality(*container),
+            FunctionCall::JsonValue { json, path } => {
+                Self::eval_function_json_path(*json, &path, "JSON_VALUE", |found| match found {
+                    Some(v) if !(v.is_array() || v.is_object()) => SqlValue::try_from(v),
+                    _ => Ok(SqlValue::Null),
+                })
+            }
+            FunctionCall::JsonQuery { json, path } => {
+                Self::eval_function_json_path(*json, &path, "JSON_QUERY", |found| match found {
+                    Some(v) if v.is_array() || v.is_object() => {
+                        Ok(SqlValue::NotNull(NnSqlValue::Json(v.clone())))
+                    }
+                    _ => Ok(SqlValue::Null),
+                })
+            }
+            FunctionCall::JsonExists { json, path } => {
+                Self::eval_function_json_path(*json, &path, "JSON_EXISTS", |found| {
+                    Ok(SqlValue::NotNull(NnSqlValue::Boolean(found.is_some())))
+                })
             }
+            FunctionCall::JsonObject { entries } => Self::eval_function_json_object(entries),
         }
     }
 
@@ -235,6 +946,13 @@ impl ValueExprPh2 {
                 let ts_floor = ts.floor(resolution.to_duration())?;
                 Ok(SqlValue::NotNull(NnSqlValue::Timestamp(ts_floor)))
             }
+            (
+                SqlValue::NotNull(NnSqlValue::TimestampTz(ts)),
+                SqlValue::NotNull(NnSqlValue::Duration(resolution)),
+            ) => {
+                let ts_floor = ts.floor(resolution.to_duration())?;
+                Ok(SqlValue::NotNull(NnSqlValue::TimestampTz(ts_floor)))
+            }
             _ => Err(SpringError::Sql(anyhow!(
                 "invalid parameter to FLOOR_TIME: `({}, {})`",
                 target_value,
@@ -243,29 +961,363 @@ impl ValueExprPh2 {
         }
     }
 
-    fn eval_function_duration_millis(duration_millis: Self) -> Result<SqlValue> {
-        let duration_value = duration_millis.eval()?;
-        let duration_millis = duration_value.to_i64()?;
-        if duration_millis >= 0 {
-            let duration = SpringEventDuration::from_millis(duration_millis as u64);
-            Ok(SqlValue::NotNull(NnSqlValue::Duration(duration)))
-        } else {
-            Err(SpringError::Sql(anyhow!(
-                "DURATION_MILLIS should take positive integer but got `{}`",
-                duration_millis
-            )))
+    /// NULL if `target` is NULL. `f` takes the timestamp in UTC and whether it is a TIMESTAMP WITH TIME ZONE.
+    fn eval_function_timestamp<F>(target: Self, function_name: &str, f: F) -> Result<SqlValue>
+    where
+        F: FnOnce(SpringTimestamp, bool) -> Result<NnSqlValue>,
+    {
+        match target.eval()? {
+            SqlValue::Null => Ok(SqlValue::Null),
+            SqlValue::NotNull(NnSqlValue::Timestamp(ts)) => f(ts, false).map(SqlValue::NotNull),
+            SqlValue::NotNull(NnSqlValue::TimestampTz(ts)) => f(ts, true).map(SqlValue::NotNull),
+            SqlValue::NotNull(v) => Err(SpringError::Sql(anyhow!(
+                "{} takes TIMESTAMP but got `{}`",
+                function_name,
+                v
+            ))),
+        }
+    }
+
+    fn eval_function_to_timestamp(text: Self, format: &TimestampFormat) -> Result<SqlValue> {
+        match text.eval()? {
+            SqlValue::Null => Ok(SqlValue::Null),
+            SqlValue::NotNull(NnSqlValue::Text(s)) => format
+                .parse_timestamp(&s)
+                .map(|ts| SqlValue::NotNull(NnSqlValue::TimestampTz(ts))),
+            SqlValue::NotNull(v) => Err(SpringError::Sql(anyhow!(
+                "TO_TIMESTAMP takes TEXT but got `{}`",
+                v
+            ))),
+        }
+    }
+
+    /// None for NULL.
+    fn eval_as_f64(expr: Self) -> Result<Option<f64>> {
+        match expr.eval()? {
+            SqlValue::Null => Ok(None),
+            SqlValue::NotNull(nn_sql_value) => Ok(Some(nn_sql_value.unpack::<f64>()?)),
+        }
+    }
+
+    /// NULL if any of the coordinates is NULL.
+    fn eval_function_two_points(
+        lat1: Self,
+        lon1: Self,
+        lat2: Self,
+        lon2: Self,
+        f: fn(f64, f64, f64, f64) -> f64,
+    ) -> Result<SqlValue> {
+        match (
+            Self::eval_as_f64(lat1)?,
+            Self::eval_as_f64(lon1)?,
+            Self::eval_as_f64(lat2)?,
+            Self::eval_as_f64(lon2)?,
+        ) {
+            (Some(lat1), Some(lon1), Some(lat2), Some(lon2)) => Ok(SqlValue::NotNull(
+                NnSqlValue::Float(OrderedFloat(f(lat1, lon1, lat2, lon2) as f32)),
+            )),
+            _ => Ok(SqlValue::Null),
+        }
+    }
+
+    fn eval_function_st_within(lat: Self, lon: Self, polygon: &GeoPolygon) -> Result<SqlValue> {
+        match (Self::eval_as_f64(lat)?, Self::eval_as_f64(lon)?) {
+            (Some(lat), Some(lon)) => Ok(SqlValue::NotNull(NnSqlValue::Boolean(
+                polygon.contains(lat, lon),
+            ))),
+            _ => Ok(SqlValue::Null),
+        }
+    }
+
+    /// # Failures
+    ///
+    /// `SpringError::Sql` when `polygon_wkt` is not a TEXT of polygon WKT.
+    fn eval_function_st_within_wkt(lat: Self, lon: Self, polygon_wkt: Self) -> Result<SqlValue> {
+        match polygon_wkt.eval()? {
+            SqlValue::Null => Ok(SqlValue::Null),
+            SqlValue::NotNull(polygon_wkt) => {
+                let polygon = GeoPolygon::from_wkt(&polygon_wkt.unpack::<String>()?)?;
+                Self::eval_function_st_within(lat, lon, &polygon)
+            }
+        }
+    }
+
+    fn eval_function_geohash(lat: Self, lon: Self, precision: Self) -> Result<SqlValue> {
+        let precision = precision.eval()?;
+        match (Self::eval_as_f64(lat)?, Self::eval_as_f64(lon)?, precision) {
+            (Some(lat), Some(lon), SqlValue::NotNull(precision)) => {
+                let precision = precision.unpack::<i64>()?;
+                Ok(SqlValue::NotNull(NnSqlValue::Text(geohash(
+                    lat, lon, precision,
+                )?)))
+            }
+            _ => Ok(SqlValue::Null),
+        }
+    }
+
+    /// None for NULL.
+    ///
+    /// # Failures
+    ///
+    /// `SpringError::Sql` when `expr` is not a BLOB.
+    fn eval_as_blob(expr: Self, function_name: &str) -> Result<Option<Vec<u8>>> {
+        match expr.eval()? {
+            SqlValue::Null => Ok(None),
+            SqlValue::NotNull(NnSqlValue::Blob(data)) => Ok(Some(data)),
+            SqlValue::NotNull(nn_sql_value) => Err(SpringError::Sql(anyhow!(
+                "{} takes BLOB but got `{}`",
+                function_name,
+                nn_sql_value
+            ))),
+        }
+    }
+
+    fn eval_function_get_bits(
+        blob: Self,
+        start_bit: Self,
+        length: Self,
+        byte_order: Self,
+        signed: Self,
+    ) -> Result<SqlValue> {
+        let data = Self::eval_as_blob(blob, "GET_BITS")?;
+        match (
+            data,
+            start_bit.eval()?,
+            length.eval()?,
+            byte_order.eval()?,
+            signed.eval()?,
+        ) {
+            (
+                Some(data),
+                SqlValue::NotNull(start_bit),
+                SqlValue::NotNull(length),
+                SqlValue::NotNull(byte_order),
+                SqlValue::NotNull(signed),
+            ) => {
+                let bits = get_bits(
+                    &data,
+                    start_bit.unpack::<i64>()?,
+                    length.unpack::<i64>()?,
+                    ByteOrder::from_sql(&byte_order.unpack::<String>()?)?,
+                    signed.unpack::<bool>()?,
+                )?;
+                Ok(SqlValue::NotNull(NnSqlValue::BigInt(bits)))
+            }
+            _ => Ok(SqlValue::Null),
+        }
+    }
+
+    fn eval_function_get_byte(blob: Self, index: Self) -> Result<SqlValue> {
+        let data = Self::eval_as_blob(blob, "GET_BYTE")?;
+        match (data, index.eval()?) {
+            (Some(data), SqlValue::NotNull(index)) => {
+                let index = index.unpack::<i64>()?;
+                let byte = usize::try_from(index)
+                    .ok()
+                    .and_then(|index| data.get(index))
+                    .ok_or_else(|| {
+                        SpringError::Sql(anyhow!(
+                            "GET_BYTE reads byte {} of BLOB with {} bytes",
+                            index,
+                            data.len()
+                        ))
+                    })?;
+                Ok(SqlValue::NotNull(NnSqlValue::Integer(*byte as i32)))
+            }
+            _ => Ok(SqlValue::Null),
+        }
+    }
+
+    /// For functions taking only a BLOB.
+    fn eval_function_blob(
+        blob: Self,
+        function_name: &str,
+        f: fn(&[u8]) -> NnSqlValue,
+    ) -> Result<SqlValue> {
+        Ok(Self::eval_as_blob(blob, function_name)?
+            .map_or(SqlValue::Null, |data| SqlValue::NotNull(f(&data))))
+    }
+
+    fn eval_function_round(value: Self, scale: Self) -> Result<SqlValue> {
+        match (value.eval()?, scale.eval()?) {
+            (SqlValue::NotNull(value), SqlValue::NotNull(scale)) => {
+                let scale = scale.unpack::<i64>()?;
+                let scale = u32::try_from(scale)
+                    .ok()
+                    .filter(|scale| *scale <= MAX_DECIMAL_PRECISION)
+                    .ok_or_else(|| {
+                        SpringError::Sql(anyhow!(
+                            "ROUND takes scale from 0 to {} but got {}",
+                            MAX_DECIMAL_PRECISION,
+                            scale
+                        ))
+                    })?;
+                let round_float = |v: f64| {
+                    let factor = 10f64.powi(scale as i32);
+                    (v * factor).round() / factor
+                };
+
+                let rounded = match value {
+                    NnSqlValue::Decimal(d) => NnSqlValue::Decimal(d.round(scale)?),
+                    NnSqlValue::Float(f) => {
+                        NnSqlValue::Float(OrderedFloat(round_float(f.into_inner() as f64) as f32))
+                    }
+                    NnSqlValue::Double(f) => {
+                        NnSqlValue::Double(OrderedFloat(round_float(f.into_inner())))
+                    }
+                    NnSqlValue::SmallInt(_)
+                    | NnSqlValue::Integer(_)
+                    | NnSqlValue::BigInt(_)
+                    | NnSqlValue::UnsignedInteger(_)
+                    | NnSqlValue::UnsignedBigInt(_) => value,
+                    _ => {
+                        return Err(SpringError::Sql(anyhow!(
+                            "ROUND takes a numeric value but got {}",
+                            value
+                        )))
+                    }
+                };
+                Ok(SqlValue::NotNull(rounded))
+            }
+            _ => Ok(SqlValue::Null),
+        }
+    }
+
+    fn eval_function_element_at(container: Self, key: Self) -> Result<SqlValue> {
+        match (container.eval()?, key.eval()?) {
+            (SqlValue::NotNull(NnSqlValue::Array(elements)), SqlValue::NotNull(index)) => {
+                let index = index.unpack::<i64>()?;
+                let element = index
+                    .checked_sub(1)
+                    .and_then(|i| usize::try_from(i).ok())
+                    .and_then(|i| elements.get(i))
+                    .cloned();
+                Ok(element.unwrap_or(SqlValue::Null))
+            }
+            (SqlValue::NotNull(NnSqlValue::Map(entries)), SqlValue::NotNull(key)) => {
+                let key = key.unpack::<String>()?;
+                Ok(entries.get(&key).cloned().unwrap_or(SqlValue::Null))
+            }
+            (SqlValue::Null, _) | (_, SqlValue::Null) => Ok(SqlValue::Null),
+            (SqlValue::NotNull(container), _) => Err(SpringError::Sql(anyhow!(
+                "`[]` is applicable to ARRAY or MAP but got `{}`",
+                container
+            ))),
+        }
+    }
+
+    fn eval_function_field_of(row: Self, field: &str) -> Result<SqlValue> {
+        match row.eval()? {
+            SqlValue::Null => Ok(SqlValue::Null),
+            SqlValue::NotNull(NnSqlValue::Map(entries)) => {
+                Ok(entries.get(field).cloned().unwrap_or(SqlValue::Null))
+            }
+            SqlValue::NotNull(NnSqlValue::Row(fields)) => fields
+                .into_iter()
+                .find_map(|(name, v)| (name == field).then_some(v))
+                .ok_or_else(|| SpringError::Sql(anyhow!("ROW does not have field `{}`", field))),
+            SqlValue::NotNull(v) => Err(SpringError::Sql(anyhow!(
+                "`.{}` is applicable to ROW or MAP but got `{}`",
+                field,
+                v
+            ))),
+        }
+    }
+
+    fn eval_function_cardinality(container: Self) -> Result<SqlValue> {
+        let cardinality = match container.eval()? {
+            SqlValue::Null => return Ok(SqlValue::Null),
+            SqlValue::NotNull(NnSqlValue::Array(elements)) => elements.len(),
+            SqlValue::NotNull(NnSqlValue::Map(entries)) => entries.len(),
+            SqlValue::NotNull(v) => {
+                return Err(SpringError::Sql(anyhow!(
+                    "CARDINALITY takes ARRAY or MAP but got `{}`",
+                    v
+                )))
+            }
+        };
+        Ok(SqlValue::NotNull(NnSqlValue::Integer(cardinality as i32)))
+    }
+
+    /// None for NULL.
+    ///
+    /// # Failures
+    ///
+    /// `SpringError::Sql` when `expr` is neither a JSON nor a TEXT in JSON.
+    fn eval_as_json(expr: Self, function_name: &str) -> Result<Option<serde_json::Value>> {
+        match expr.eval()? {
+            SqlValue::Null => Ok(None),
+            SqlValue::NotNull(NnSqlValue::Json(v)) => Ok(Some(v)),
+            SqlValue::NotNull(NnSqlValue::Text(s)) => {
+                serde_json::from_str(&s).map(Some).map_err(|e| {
+                    SpringError::Sql(anyhow!(
+                        "{} takes TEXT in JSON but got `{}`: {}",
+                        function_name,
+                        s,
+                        e
+                    ))
+                })
+            }
+            SqlValue::NotNull(nn_sql_value) => Err(SpringError::Sql(anyhow!(
+                "{} takes JSON but got `{}`",
+                function_name,
+                nn_sql_value
+            ))),
         }
     }
-    fn eval_function_duration_secs(duration_secs: Self) -> Result<SqlValue> {
-        let duration_value = duration_secs.eval()?;
-        let duration_secs = duration_value.to_i64()?;
-        if duration_secs >= 0 {
-            let duration = SpringEventDuration::from_secs(duration_secs as u64);
+
+    /// NULL if `json` is NULL. `f` takes the value at `path` (None if not found).
+    fn eval_function_json_path(
+        json: Self,
+        path: &JsonPath,
+        function_name: &str,
+        f: fn(Option<&serde_json::Value>) -> Result<SqlValue>,
+    ) -> Result<SqlValue> {
+        match Self::eval_as_json(json, function_name)? {
+            Some(json) => f(path.query(&json)),
+            None => Ok(SqlValue::Null),
+        }
+    }
+
+    fn eval_function_json_object(entries: Vec<(String, Box<Self>)>) -> Result<SqlValue> {
+        let object = entries
+            .into_iter()
+            .map(|(key, v)| {
+                let json_v = match v.eval()? {
+                    SqlValue::Null => serde_json::Value::Null,
+                    SqlValue::NotNull(nn_sql_value) => {
+                        nn_sql_value.try_convert(&SqlType::json())?.unpack()?
+                    }
+                };
+                Ok((key, json_v))
+            })
+            .collect::<Result<serde_json::Map<_, _>>>()?;
+        Ok(SqlValue::NotNull(NnSqlValue::Json(
+            serde_json::Value::Object(object),
+        )))
+    }
+
+    /// `to_duration` returns None if the duration is too large.
+    fn eval_function_duration<F>(
+        count: Self,
+        function_name: &str,
+        to_duration: F,
+    ) -> Result<SqlValue>
+    where
+        F: FnOnce(u64) -> Option<SpringEventDuration>,
+    {
+        let count_value = count.eval()?;
+        let count = count_value.to_i64()?;
+        if count >= 0 {
+            let duration = to_duration(count as u64).ok_or_else(|| {
+                SpringError::Sql(anyhow!("{}({}) is too large", function_name, count))
+            })?;
             Ok(SqlValue::NotNull(NnSqlValue::Duration(duration)))
         } else {
             Err(SpringError::Sql(anyhow!(
-                "DURATION_SECS should take positive integer but got `{}`",
-                duration_secs
+                "{} should take positive integer but got `{}`",
+                function_name,
+                count
             )))
         }
     }
@@ -277,3 +1329,67 @@ pub struct AggrExpr {
     pub func: AggregateFunctionParameter,
     pub aggregated: ValueExpr,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_element_at_out_of_range() -> Result<()> {
+        let element_at = |index: i64| {
+            let array = NnSqlValue::Array(vec![
+                SqlValue::NotNull(NnSqlValue::Integer(10)),
+                SqlValue::NotNull(NnSqlValue::Integer(20)),
+            ]);
+            ValueExprPh2::FunctionCall(FunctionCall::ElementAt {
+                container: Box::new(ValueExprPh2::Constant(SqlValue::NotNull(array))),
+                key: Box::new(ValueExprPh2::Constant(SqlValue::NotNull(
+                    NnSqlValue::BigInt(index),
+                ))),
+            })
+            .eval()
+        };
+
+        assert_eq!(element_at(2)?, SqlValue::NotNull(NnSqlValue::Integer(20)));
+        for index in [0, 3, -1, i64::MIN, i64::MAX] {
+            assert!(matches!(element_at(index)?, SqlValue::Null));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_st_within_wkt() -> Result<()> {
+        let constant = |v: NnSqlValue| Box::new(ValueExprPh2::Constant(SqlValue::NotNull(v)));
+        let st_within_wkt = |lat: f64, polygon_wkt: Box<ValueExprPh2>| {
+            ValueExprPh2::FunctionCall(FunctionCall::StWithinWkt {
+                lat: constant(NnSqlValue::Double(OrderedFloat(lat))),
+                lon: constant(NnSqlValue::Double(OrderedFloat(5.0))),
+                polygon_wkt,
+            })
+            .eval()
+        };
+        let square = || {
+            constant(NnSqlValue::Text(
+                "POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))".to_string(),
+            ))
+        };
+
+        assert_eq!(
+            st_within_wkt(5.0, square())?,
+            SqlValue::NotNull(NnSqlValue::Boolean(true))
+        );
+        assert_eq!(
+            st_within_wkt(15.0, square())?,
+            SqlValue::NotNull(NnSqlValue::Boolean(false))
+        );
+        assert!(matches!(
+            st_within_wkt(5.0, Box::new(ValueExprPh2::Constant(SqlValue::Null)))?,
+            SqlValue::Null
+        ));
+        assert!(matches!(
+            st_within_wkt(5.0, constant(NnSqlValue::Text("POINT(5 5)".to_string()))),
+            Err(SpringError::Sql(_))
+        ));
+        Ok(())
+    }
+}
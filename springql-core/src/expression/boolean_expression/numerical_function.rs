@@ -2,6 +2,7 @@
 
 use crate::expression::ValueExprType;
 
+#[allow(clippy::enum_variant_names)]
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub enum NumericalFunction<E>
 where
@@ -10,6 +11,24 @@ where
     /// `+` operation
     AddVariant { left: Box<E>, right: Box<E> },
 
+    /// `-` operation
+    SubVariant { left: Box<E>, right: Box<E> },
+
     /// `*` operation
     MulVariant { left: Box<E>, right: Box<E> },
+
+    /// `/` operation
+    DivVariant { left: Box<E>, right: Box<E> },
+
+    /// `&` operation
+    BitAndVariant { left: Box<E>, right: Box<E> },
+
+    /// `|` operation
+    BitOrVariant { left: Box<E>, right: Box<E> },
+
+    /// `<<` operation
+    ShlVariant { left: Box<E>, right: Box<E> },
+
+    /// `>>` operation
+    ShrVariant { left: Box<E>, right: Box<E> },
 }
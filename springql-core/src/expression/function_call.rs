@@ -1,6 +1,16 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
-use crate::expression::ValueExprType;
+mod bits;
+mod date_time;
+mod geo;
+mod json_path;
+
+pub use bits::{get_bits, hex, ByteOrder};
+pub use date_time::{parse_utc_offset, TimestampFormat};
+pub use geo::{geohash, haversine_distance, initial_bearing, GeoPolygon};
+pub use json_path::JsonPath;
+
+use crate::{expression::ValueExprType, stream_engine::time::TimestampField};
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub enum FunctionCall<E>
@@ -17,8 +27,230 @@ where
     /// ```
     DurationSecs { duration_secs: Box<E> },
 
+    /// ```text
+    /// DURATION_MICROS(1) -> EventDuration::from_micros(1)
+    /// ```
+    DurationMicros { duration_micros: Box<E> },
+
+    /// ```text
+    /// DURATION_MINUTES(1) -> EventDuration::from_secs(60)
+    /// ```
+    DurationMinutes { duration_minutes: Box<E> },
+
+    /// ```text
+    /// DURATION_HOURS(1) -> EventDuration::from_secs(60 * 60)
+    /// ```
+    DurationHours { duration_hours: Box<E> },
+
+    /// Whole milliseconds of a DURATION as BIGINT.
+    ///
+    /// ```text
+    /// DURATION_TO_MILLIS(DURATION_MICROS(1500)) -> 1
+    /// ```
+    DurationToMillis { duration: Box<E> },
+
     /// ```text
     /// FLOOR_TIME("2020-01-01 01:11:11.000000000", DURATION_SECS(10 * 60)) -> "2020-01-01 01:10:00.000000000"
     /// ```
     FloorTime { target: Box<E>, resolution: Box<E> },
+
+    /// Field of a timestamp as INTEGER. A TIMESTAMP WITH TIME ZONE is in UTC.
+    ///
+    /// ```text
+    /// EXTRACT(HOUR FROM "2020-01-01 01:11:11.000000000") -> 1
+    /// EXTRACT(DOW FROM "2020-01-01 01:11:11.000000000") -> 3  -- 0 for Sunday
+    /// ```
+    Extract {
+        field: TimestampField,
+        target: Box<E>,
+    },
+
+    /// Truncates the fields smaller than `field`. The unit must be a constant.
+    ///
+    /// ```text
+    /// DATE_TRUNC('day', "2020-01-01 01:11:11.000000000") -> "2020-01-01 00:00:00.000000000"
+    /// ```
+    DateTrunc {
+        field: TimestampField,
+        target: Box<E>,
+    },
+
+    /// TIMESTAMP WITH TIME ZONE parsed from TEXT. The format must be a constant and is validated on planning.
+    ///
+    /// ```text
+    /// TO_TIMESTAMP('2020/01/01 09:00+09:00', '[year]/[month]/[day] [hour]:[minute][offset_hour sign:mandatory]:[offset_minute]') -> "2020-01-01T00:00:00.000000000Z"
+    /// ```
+    ToTimestamp {
+        text: Box<E>,
+        format: TimestampFormat,
+    },
+
+    /// TEXT formatted from a timestamp. The format must be a constant and is validated on planning.
+    ///
+    /// ```text
+    /// FORMAT_TIMESTAMP("2020-01-01 01:11:11.000000000", '[hour]:[minute]') -> "01:11"
+    /// ```
+    FormatTimestamp {
+        target: Box<E>,
+        format: TimestampFormat,
+    },
+
+    /// Wall-clock time as TIMESTAMP WITH TIME ZONE, evaluated for each row.
+    ///
+    /// ```text
+    /// NOW() -> "2020-01-01T00:00:00.000000000Z"
+    /// CURRENT_TIMESTAMP -> "2020-01-01T00:00:00.000000000Z"
+    /// ```
+    CurrentTimestamp,
+
+    /// Conversion between TIMESTAMP WITH TIME ZONE and the local TIMESTAMP at the UTC offset.
+    ///
+    /// ```text
+    /// "2020-01-01T00:00:00.000000000Z" AT TIME ZONE '+09:00' -> "2020-01-01 09:00:00.000000000"
+    /// "2020-01-01 09:00:00.000000000" AT TIME ZONE '+09:00' -> "2020-01-01T00:00:00.000000000Z"
+    /// ```
+    AtTimeZone { target: Box<E>, offset_secs: i32 },
+
+    /// Great-circle distance in meters (haversine).
+    ///
+    /// ```text
+    /// ST_DISTANCE(0.0, 0.0, 1.0, 0.0) -> 111195.08
+    /// ```
+    StDistance {
+        lat1: Box<E>,
+        lon1: Box<E>,
+        lat2: Box<E>,
+        lon2: Box<E>,
+    },
+
+    /// Initial bearing in degrees, clockwise from north.
+    ///
+    /// ```text
+    /// ST_BEARING(0.0, 0.0, 0.0, 1.0) -> 90.0
+    /// ```
+    StBearing {
+        lat1: Box<E>,
+        lon1: Box<E>,
+        lat2: Box<E>,
+        lon2: Box<E>,
+    },
+
+    /// Whether a point is inside a polygon. A constant WKT is parsed on planning.
+    ///
+    /// ```text
+    /// ST_WITHIN(5.0, 5.0, 'POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))') -> TRUE
+    /// ```
+    StWithin {
+        lat: Box<E>,
+        lon: Box<E>,
+        polygon: GeoPolygon,
+    },
+
+    /// `StWithin` with a non-constant polygon WKT, which is parsed for each row.
+    ///
+    /// ```text
+    /// ST_WITHIN(s.lat, s.lon, s.area_wkt)
+    /// ```
+    StWithinWkt {
+        lat: Box<E>,
+        lon: Box<E>,
+        polygon_wkt: Box<E>,
+    },
+
+    /// ```text
+    /// GEOHASH(57.64911, 10.40744, 5) -> "u4pru"
+    /// ```
+    Geohash {
+        lat: Box<E>,
+        lon: Box<E>,
+        precision: Box<E>,
+    },
+
+    /// Signal of `length` bits from `start_bit`, in `'LE'` or `'BE'` byte order.
+    ///
+    /// ```text
+    /// GET_BITS(blob, 4, 8, 'LE', FALSE) -> 0x23  -- blob: [0x34, 0x12]
+    /// ```
+    GetBits {
+        blob: Box<E>,
+        start_bit: Box<E>,
+        length: Box<E>,
+        byte_order: Box<E>,
+        signed: Box<E>,
+    },
+
+    /// `index`-th byte (0-origin).
+    ///
+    /// ```text
+    /// GET_BYTE(blob, 1) -> 0x12  -- blob: [0x34, 0x12]
+    /// ```
+    GetByte { blob: Box<E>, index: Box<E> },
+
+    /// ```text
+    /// BLOB_LENGTH(blob) -> 2  -- blob: [0x34, 0x12]
+    /// ```
+    BlobLength { blob: Box<E> },
+
+    /// ```text
+    /// HEX(blob) -> "3412"  -- blob: [0x34, 0x12]
+    /// ```
+    Hex { blob: Box<E> },
+
+    /// Rounds half away from zero to `scale` fractional digits. Integers are kept as-is.
+    ///
+    /// ```text
+    /// ROUND(price, 2) -> 12.35  -- price: 12.345 (DECIMAL)
+    /// ```
+    Round { value: Box<E>, scale: Box<E> },
+
+    /// Element of ARRAY (1-origin index) or MAP (key). NULL if not found.
+    ///
+    /// ```text
+    /// arr[1] -> 10  -- arr: [10, 20]
+    /// m['k'] -> 1  -- m: {"k": 1}
+    /// ```
+    ElementAt { container: Box<E>, key: Box<E> },
+
+    /// Field of ROW (or entry of MAP).
+    ///
+    /// ```text
+    /// r.x -> 1.5  -- r: ROW(x: 1.5, y: 2.0)
+    /// ```
+    FieldOf { row: Box<E>, field: String },
+
+    /// Number of elements in ARRAY or MAP.
+    ///
+    /// ```text
+    /// CARDINALITY(arr) -> 2  -- arr: [10, 20]
+    /// ```
+    Cardinality { container: Box<E> },
+
+    /// Scalar at the path in JSON (or TEXT parsed as JSON). NULL if not found or not a scalar.
+    /// The path must be a constant and is parsed on planning.
+    ///
+    /// ```text
+    /// JSON_VALUE(payload, '$.engine.rpm') -> 2400  -- payload: {"engine": {"rpm": 2400}}
+    /// ```
+    JsonValue { json: Box<E>, path: JsonPath },
+
+    /// Object or array at the path in JSON, as JSON. NULL if not found or not an object nor an array.
+    ///
+    /// ```text
+    /// JSON_QUERY(payload, '$.engine') -> {"rpm": 2400}  -- payload: {"engine": {"rpm": 2400}}
+    /// ```
+    JsonQuery { json: Box<E>, path: JsonPath },
+
+    /// Whether the path exists in JSON.
+    ///
+    /// ```text
+    /// JSON_EXISTS(payload, '$.engine.temp') -> FALSE  -- payload: {"engine": {"rpm": 2400}}
+    /// ```
+    JsonExists { json: Box<E>, path: JsonPath },
+
+    /// JSON object built from key-value pairs. NULL values are written as JSON null.
+    ///
+    /// ```text
+    /// JSON_OBJECT('id' VALUE 1, 'tags' VALUE tags) -> {"id": 1, "tags": ["a"]}  -- tags: ['a'] (ARRAY<TEXT>)
+    /// ```
+    JsonObject { entries: Vec<(String, Box<E>)> },
 }
@@ -0,0 +1,157 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+//! Bit and byte extraction from BLOB for `GET_BITS`, `GET_BYTE` and `HEX` functions.
+
+use anyhow::anyhow;
+
+use crate::api::error::{Result, SpringError};
+
+/// Byte order of a signal in `GET_BITS`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum ByteOrder {
+    /// `'LE'` (Intel): `start_bit` is the least significant bit and the signal goes to more significant bits.
+    LittleEndian,
+    /// `'BE'` (Motorola): `start_bit` is the most significant bit and the signal goes to less significant bits,
+    /// from bit 0 of a byte to bit 7 of the next byte.
+    BigEndian,
+}
+
+impl ByteOrder {
+    /// # Failures
+    ///
+    /// `SpringError::Sql` when `s` is neither `'LE'` nor `'BE'`.
+    pub fn from_sql(s: &str) -> Result<Self> {
+        match s.to_uppercase().as_str() {
+            "LE" => Ok(Self::LittleEndian),
+            "BE" => Ok(Self::BigEndian),
+            _ => Err(SpringError::Sql(anyhow!(
+                "byte order of GET_BITS must be 'LE' or 'BE' but '{}'",
+                s
+            ))),
+        }
+    }
+}
+
+/// Extracts a signal of `length` bits from `data`.
+///
+/// Bits are numbered from the least significant bit of the first byte: bit `i` is bit `i % 8` of byte `i / 8`.
+///
+/// # Failures
+///
+/// `SpringError::Sql` when:
+///   - `length` is not in 1..=64 (1..=63 if not `signed`).
+///   - The signal exceeds `data` (or bit positions overflow).
+pub fn get_bits(
+    data: &[u8],
+    start_bit: i64,
+    length: i64,
+    byte_order: ByteOrder,
+    signed: bool,
+) -> Result<i64> {
+    let max_length = if signed { 64 } else { 63 };
+    if !(1..=max_length).contains(&length) {
+        return Err(SpringError::Sql(anyhow!(
+            "length of GET_BITS must be in 1..={} but {}",
+            max_length,
+            length
+        )));
+    }
+
+    let bit_at = |pos: i64| -> Result<u64> {
+        usize::try_from(pos)
+            .ok()
+            .and_then(|pos| {
+                data.get(pos / 8)
+                    .map(|byte| ((byte >> (pos % 8)) & 1) as u64)
+            })
+            .ok_or_else(|| {
+                SpringError::Sql(anyhow!(
+                    "GET_BITS reads bit {} of BLOB with {} bytes",
+                    pos,
+                    data.len()
+                ))
+            })
+    };
+
+    let overflow = || {
+        SpringError::Sql(anyhow!(
+            "bit position of GET_BITS overflows from start bit {}",
+            start_bit
+        ))
+    };
+
+    let mut value = 0u64;
+    match byte_order {
+        ByteOrder::LittleEndian => {
+            for i in 0..length {
+                let pos = start_bit.checked_add(i).ok_or_else(overflow)?;
+                value |= bit_at(pos)? << i;
+            }
+        }
+        ByteOrder::BigEndian => {
+            let mut pos = start_bit;
+            for _ in 0..length {
+                value = (value << 1) | bit_at(pos)?;
+                pos = if pos % 8 == 0 {
+                    pos.checked_add(15)
+                } else {
+                    pos.checked_sub(1)
+                }
+                .ok_or_else(overflow)?;
+            }
+        }
+    }
+
+    if signed {
+        let shift = 64 - length;
+        Ok(((value << shift) as i64) >> shift)
+    } else {
+        Ok(value as i64)
+    }
+}
+
+/// Upper-case hexadecimal string of `data`.
+pub fn hex(data: &[u8]) -> String {
+    data.iter().map(|byte| format!("{:02X}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_bits_little_endian() -> Result<()> {
+        let data = [0x34, 0x12, 0xF0];
+        assert_eq!(
+            get_bits(&data, 0, 16, ByteOrder::LittleEndian, false)?,
+            0x1234
+        );
+        assert_eq!(get_bits(&data, 4, 8, ByteOrder::LittleEndian, false)?, 0x23);
+        assert_eq!(get_bits(&data, 20, 4, ByteOrder::LittleEndian, false)?, 0xF);
+        assert_eq!(get_bits(&data, 20, 4, ByteOrder::LittleEndian, true)?, -1);
+        assert!(get_bits(&data, 20, 5, ByteOrder::LittleEndian, false).is_err());
+        assert!(get_bits(&data, 0, 0, ByteOrder::LittleEndian, false).is_err());
+        assert!(get_bits(&data, i64::MAX, 8, ByteOrder::LittleEndian, false).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_bits_big_endian() -> Result<()> {
+        let data = [0x12, 0x34, 0xF0];
+        // MSB is bit 7 of byte 0
+        assert_eq!(get_bits(&data, 7, 16, ByteOrder::BigEndian, false)?, 0x1234);
+        // bit 3..0 of byte 0 and bit 7..4 of byte 1
+        assert_eq!(get_bits(&data, 3, 8, ByteOrder::BigEndian, false)?, 0x23);
+        assert_eq!(get_bits(&data, 23, 4, ByteOrder::BigEndian, true)?, -1);
+        assert!(get_bits(&data, 16, 2, ByteOrder::BigEndian, false).is_err());
+        assert!(get_bits(&data, i64::MAX, 8, ByteOrder::BigEndian, false).is_err());
+        assert!(get_bits(&data, i64::MIN, 8, ByteOrder::BigEndian, false).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(hex(&[0x00, 0xAB, 0x1F]), "00AB1F");
+        assert_eq!(hex(&[]), "");
+    }
+}
@@ -0,0 +1,142 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+//! Time zones and formats for `AT TIME ZONE`, `TO_TIMESTAMP` and `FORMAT_TIMESTAMP`.
+
+use std::hash::{Hash, Hasher};
+
+use anyhow::anyhow;
+
+use crate::{
+    api::error::{Result, SpringError},
+    stream_engine::time::SpringTimestamp,
+    time::FormatDescription,
+};
+
+const MAX_UTC_OFFSET_SECS: i32 = 24 * 60 * 60;
+
+/// Format of timestamps in the syntax of the `time` crate, parsed on planning.
+///
+/// ```text
+/// [year]-[month]-[day] [hour]:[minute]:[second][offset_hour sign:mandatory]:[offset_minute]
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TimestampFormat {
+    format: String,
+    description: FormatDescription,
+}
+
+impl Hash for TimestampFormat {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.format.hash(state);
+    }
+}
+
+impl TimestampFormat {
+    /// # Failures
+    ///
+    /// `SpringError::Sql` when `format` is not in the syntax of the `time` crate.
+    pub fn parse(format: &str) -> Result<Self> {
+        let description = SpringTimestamp::parse_format(format).map_err(|e| {
+            SpringError::Sql(anyhow!("invalid timestamp format `{}`: {}", format, e))
+        })?;
+        Ok(Self {
+            format: format.to_string(),
+            description,
+        })
+    }
+
+    /// Timestamp in UTC. A text without offset is regarded as in UTC.
+    pub fn parse_timestamp(&self, s: &str) -> Result<SpringTimestamp> {
+        SpringTimestamp::parse_with_description(s, &self.description)
+    }
+
+    pub fn format_timestamp(&self, ts: &SpringTimestamp) -> Result<String> {
+        ts.format_with_description(&self.description)
+    }
+}
+
+/// UTC offset in seconds from a time zone: `UTC`, `Z` or `[+-]HH[:MM]`.
+///
+/// # Failures
+///
+/// `SpringError::Sql` when `zone` is not in the supported syntax.
+pub fn parse_utc_offset(zone: &str) -> Result<i32> {
+    let err = || {
+        SpringError::Sql(anyhow!(
+            "invalid time zone `{}`: must be `UTC`, `Z` or `[+-]HH[:MM]`",
+            zone
+        ))
+    };
+
+    let zone = zone.trim();
+    if zone.eq_ignore_ascii_case("UTC") || zone.eq_ignore_ascii_case("Z") {
+        return Ok(0);
+    }
+
+    let (sign, hhmm) = if let Some(hhmm) = zone.strip_prefix('+') {
+        (1, hhmm)
+    } else if let Some(hhmm) = zone.strip_prefix('-') {
+        (-1, hhmm)
+    } else {
+        return Err(err());
+    };
+    let (hh, mm) = match hhmm.split_once(':') {
+        Some((hh, mm)) => (hh, mm),
+        None if hhmm.len() == 4 => hhmm.split_at(2),
+        None => (hhmm, "00"),
+    };
+    if hh.len() != 2 || mm.len() != 2 {
+        return Err(err());
+    }
+    let (hours, minutes) = (
+        hh.parse::<i32>().map_err(|_| err())?,
+        mm.parse::<i32>().map_err(|_| err())?,
+    );
+
+    let offset_secs = hours * 60 * 60 + minutes * 60;
+    if minutes < 60 && offset_secs < MAX_UTC_OFFSET_SECS {
+        Ok(sign * offset_secs)
+    } else {
+        Err(err())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_utc_offset() {
+        assert_eq!(parse_utc_offset("UTC").unwrap(), 0);
+        assert_eq!(parse_utc_offset("z").unwrap(), 0);
+        assert_eq!(parse_utc_offset("+09:00").unwrap(), 9 * 3600);
+        assert_eq!(parse_utc_offset("+0930").unwrap(), 9 * 3600 + 30 * 60);
+        assert_eq!(parse_utc_offset("-05").unwrap(), -5 * 3600);
+
+        assert!(parse_utc_offset("Asia/Tokyo").is_err());
+        assert!(parse_utc_offset("+9:00").is_err());
+        assert!(parse_utc_offset("+24:00").is_err());
+        assert!(parse_utc_offset("+09:60").is_err());
+    }
+
+    #[test]
+    fn test_timestamp_format() {
+        let format = TimestampFormat::parse(
+            "[year]/[month]/[day] [hour]:[minute][offset_hour sign:mandatory]:[offset_minute]",
+        )
+        .unwrap();
+        let ts = format.parse_timestamp("2020/01/02 09:30+09:00").unwrap();
+        assert_eq!(ts.to_string(), "2020-01-02 00:30:00.000000000");
+        assert_eq!(
+            format.format_timestamp(&ts).unwrap(),
+            "2020/01/02 00:30+00:00"
+        );
+
+        let date_format = TimestampFormat::parse("[year]-[month]-[day]").unwrap();
+        let ts = date_format.parse_timestamp("2020-01-02").unwrap();
+        assert_eq!(ts.to_string(), "2020-01-02 00:00:00.000000000");
+
+        assert!(TimestampFormat::parse("[year").is_err());
+        assert!(date_format.parse_timestamp("2020/01/02").is_err());
+    }
+}
@@ -0,0 +1,239 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+//! Geospatial computations for `ST_*` and `GEOHASH` functions.
+//!
+//! Latitudes and longitudes are in degrees on WGS 84 and the earth is regarded as a sphere.
+
+use anyhow::anyhow;
+use ordered_float::OrderedFloat;
+
+use crate::api::error::{Result, SpringError};
+
+/// Mean radius of the earth.
+const EARTH_RADIUS_METERS: f64 = 6_371_008.8;
+
+const GEOHASH_BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+const GEOHASH_MAX_PRECISION: i64 = 12;
+
+/// Great-circle distance in meters by haversine formula.
+pub fn haversine_distance(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let (phi1, phi2) = (lat1.to_radians(), lat2.to_radians());
+    let d_phi = (lat2 - lat1).to_radians();
+    let d_lambda = (lon2 - lon1).to_radians();
+
+    let a = (d_phi / 2.0).sin().powi(2) + phi1.cos() * phi2.cos() * (d_lambda / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_METERS * a.sqrt().min(1.0).asin()
+}
+
+/// Initial bearing from point 1 to point 2 in degrees, clockwise from north in `[0, 360)`.
+pub fn initial_bearing(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let (phi1, phi2) = (lat1.to_radians(), lat2.to_radians());
+    let d_lambda = (lon2 - lon1).to_radians();
+
+    let y = d_lambda.sin() * phi2.cos();
+    let x = phi1.cos() * phi2.sin() - phi1.sin() * phi2.cos() * d_lambda.cos();
+    y.atan2(x).to_degrees().rem_euclid(360.0)
+}
+
+/// # Failures
+///
+/// `SpringError::Sql` when:
+///   - `precision` is not in 1..=12.
+///   - `lat` or `lon` is out of range.
+pub fn geohash(lat: f64, lon: f64, precision: i64) -> Result<String> {
+    if !(1..=GEOHASH_MAX_PRECISION).contains(&precision) {
+        return Err(SpringError::Sql(anyhow!(
+            "precision of GEOHASH must be in 1..={} but {}",
+            GEOHASH_MAX_PRECISION,
+            precision
+        )));
+    }
+    if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lon) {
+        return Err(SpringError::Sql(anyhow!(
+            "invalid point to GEOHASH: ({}, {})",
+            lat,
+            lon
+        )));
+    }
+
+    let precision = precision as usize;
+    let mut lat_range = (-90.0, 90.0);
+    let mut lon_range = (-180.0, 180.0);
+
+    let mut hash = String::with_capacity(precision);
+    let mut is_lon = true;
+    let (mut bits, mut idx) = (0, 0);
+    while hash.len() < precision {
+        let (range, value) = if is_lon {
+            (&mut lon_range, lon)
+        } else {
+            (&mut lat_range, lat)
+        };
+        let mid = (range.0 + range.1) / 2.0;
+        idx <<= 1;
+        if value >= mid {
+            idx |= 1;
+            range.0 = mid;
+        } else {
+            range.1 = mid;
+        }
+
+        is_lon = !is_lon;
+        bits += 1;
+        if bits == 5 {
+            hash.push(GEOHASH_BASE32[idx] as char);
+            bits = 0;
+            idx = 0;
+        }
+    }
+    Ok(hash)
+}
+
+/// Polygon given in WKT: `POLYGON((lon lat, lon lat, ...), (lon lat, ...))`.
+///
+/// The first ring is the exterior and the others are holes. Edges are straight lines on longitude-latitude plane.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GeoPolygon {
+    /// (longitude, latitude) of vertices
+    rings: Vec<Vec<(OrderedFloat<f64>, OrderedFloat<f64>)>>,
+}
+
+impl GeoPolygon {
+    /// # Failures
+    ///
+    /// `SpringError::Sql` when `wkt` is not a POLYGON or a ring has less than 3 vertices.
+    pub fn from_wkt(wkt: &str) -> Result<Self> {
+        let err = || SpringError::Sql(anyhow!("invalid polygon WKT: `{}`", wkt));
+
+        let wkt_trimmed = wkt.trim();
+        let body = wkt_trimmed
+            .get(..7)
+            .filter(|tag| tag.eq_ignore_ascii_case("POLYGON"))
+            .map(|_| wkt_trimmed[7..].trim())
+            .and_then(|body| body.strip_prefix('('))
+            .and_then(|body| body.strip_suffix(')'))
+            .ok_or_else(err)?;
+
+        let rings = body
+            .split(')')
+            .map(|ring| ring.trim().trim_start_matches(',').trim())
+            .filter(|ring| !ring.is_empty())
+            .map(|ring| {
+                let vertices = ring
+                    .strip_prefix('(')
+                    .ok_or_else(err)?
+                    .split(',')
+                    .map(|vertex| {
+                        let coords = vertex
+                            .split_whitespace()
+                            .map(|coord| coord.parse::<f64>().ok().filter(|c| c.is_finite()))
+                            .collect::<Option<Vec<_>>>()
+                            .ok_or_else(err)?;
+                        match coords.as_slice() {
+                            [lon, lat] => Ok((OrderedFloat(*lon), OrderedFloat(*lat))),
+                            _ => Err(err()),
+                        }
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                if vertices.len() < 3 {
+                    return Err(err());
+                }
+                Ok(vertices)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if rings.is_empty() {
+            Err(err())
+        } else {
+            Ok(Self { rings })
+        }
+    }
+
+    /// Whether a point is inside the polygon (and outside of its holes), by even-odd rule.
+    /// A point just on an edge may be either inside or outside.
+    pub fn contains(&self, lat: f64, lon: f64) -> bool {
+        let mut inside = false;
+        for ring in &self.rings {
+            let mut prev = ring.last().expect("ring has 3 or more vertices");
+            for vertex in ring {
+                let (x1, y1) = (vertex.0 .0, vertex.1 .0);
+                let (x2, y2) = (prev.0 .0, prev.1 .0);
+                if (y1 > lat) != (y2 > lat) && lon < (x2 - x1) * (lat - y1) / (y2 - y1) + x1 {
+                    inside = !inside;
+                }
+                prev = vertex;
+            }
+        }
+        inside
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use float_cmp::approx_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_haversine_distance() {
+        // 1 degree of latitude
+        assert!(approx_eq!(
+            f64,
+            haversine_distance(0.0, 0.0, 1.0, 0.0),
+            111_195.08,
+            epsilon = 0.01
+        ));
+        assert!(approx_eq!(
+            f64,
+            haversine_distance(35.0, 139.0, 35.0, 139.0),
+            0.0
+        ));
+    }
+
+    #[test]
+    fn test_initial_bearing() {
+        assert!(approx_eq!(
+            f64,
+            initial_bearing(0.0, 0.0, 1.0, 0.0),
+            0.0,
+            epsilon = 1e-9
+        ));
+        assert!(approx_eq!(
+            f64,
+            initial_bearing(0.0, 0.0, 0.0, 1.0),
+            90.0,
+            epsilon = 1e-9
+        ));
+        assert!(approx_eq!(
+            f64,
+            initial_bearing(0.0, 0.0, 0.0, -1.0),
+            270.0,
+            epsilon = 1e-9
+        ));
+    }
+
+    #[test]
+    fn test_geohash() -> Result<()> {
+        assert_eq!(geohash(57.64911, 10.40744, 11)?, "u4pruydqqvj");
+        assert_eq!(geohash(57.64911, 10.40744, 5)?, "u4pru");
+        assert!(geohash(57.64911, 10.40744, 13).is_err());
+        assert!(geohash(90.1, 0.0, 5).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_polygon_contains() -> Result<()> {
+        let polygon = GeoPolygon::from_wkt(
+            "POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0), (4 4, 6 4, 6 6, 4 6, 4 4))",
+        )?;
+        assert!(polygon.contains(2.0, 2.0));
+        assert!(!polygon.contains(5.0, 5.0)); // in the hole
+        assert!(!polygon.contains(2.0, 12.0));
+        assert!(!polygon.contains(-1.0, 5.0));
+
+        assert!(GeoPolygon::from_wkt("POINT (1 2)").is_err());
+        assert!(GeoPolygon::from_wkt("POLYGON ((0 0, 1 1))").is_err());
+        assert!(GeoPolygon::from_wkt("POLYGON ((0 0, 1 x, 1 0))").is_err());
+        Ok(())
+    }
+}
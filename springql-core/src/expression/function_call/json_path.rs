@@ -0,0 +1,113 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+//! JSON path for `JSON_VALUE`, `JSON_QUERY` and `JSON_EXISTS` functions.
+
+use anyhow::anyhow;
+
+use crate::api::error::{Result, SpringError};
+
+/// Subset of SQL/JSON path: `$` followed by member accessors (`.key`, `."key"` or `["key"]`) and array accessors (`[0]`, 0-origin).
+///
+/// ```text
+/// $.vehicle.tires[0]."pressure kPa"
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct JsonPath {
+    steps: Vec<JsonPathStep>,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+enum JsonPathStep {
+    Key(String),
+    Index(usize),
+}
+
+impl JsonPath {
+    /// # Failures
+    ///
+    /// `SpringError::Sql` when `path` is not in the supported syntax.
+    pub fn parse(path: &str) -> Result<Self> {
+        let err =
+            |reason: &str| SpringError::Sql(anyhow!("invalid JSON path `{}`: {}", path, reason));
+
+        let mut rest = path
+            .trim()
+            .strip_prefix('$')
+            .ok_or_else(|| err("must start with `$`"))?;
+        let mut steps = Vec::new();
+
+        while !rest.is_empty() {
+            if let Some(member) = rest.strip_prefix('.') {
+                let (key, remaining) = if let Some(quoted) = member.strip_prefix('"') {
+                    let end = quoted.find('"').ok_or_else(|| err("unclosed `\"`"))?;
+                    (&quoted[..end], &quoted[end + 1..])
+                } else {
+                    let end = member.find(['.', '[']).unwrap_or(member.len());
+                    (&member[..end], &member[end..])
+                };
+                if key.is_empty() {
+                    return Err(err("empty key"));
+                }
+                steps.push(JsonPathStep::Key(key.to_string()));
+                rest = remaining;
+            } else if let Some(subscript) = rest.strip_prefix('[') {
+                let end = subscript.find(']').ok_or_else(|| err("unclosed `[`"))?;
+                let inner = subscript[..end].trim();
+                let step = match inner
+                    .strip_prefix('"')
+                    .and_then(|quoted| quoted.strip_suffix('"'))
+                {
+                    Some(key) => JsonPathStep::Key(key.to_string()),
+                    None => JsonPathStep::Index(
+                        inner
+                            .parse()
+                            .map_err(|_| err("array index must be a non-negative integer"))?,
+                    ),
+                };
+                steps.push(step);
+                rest = &subscript[end + 1..];
+            } else {
+                return Err(err("expected `.` or `[`"));
+            }
+        }
+
+        Ok(Self { steps })
+    }
+
+    /// None if the path does not exist in `json`.
+    pub fn query<'a>(&self, json: &'a serde_json::Value) -> Option<&'a serde_json::Value> {
+        self.steps.iter().try_fold(json, |v, step| match step {
+            JsonPathStep::Key(key) => v.get(key.as_str()),
+            JsonPathStep::Index(index) => v.get(*index),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_json_path_query() -> Result<()> {
+        let json = json!({"a": {"b": [10, {"c d": true}]}, "e": null});
+
+        assert_eq!(JsonPath::parse("$")?.query(&json), Some(&json));
+        assert_eq!(JsonPath::parse("$.a.b[0]")?.query(&json), Some(&json!(10)));
+        assert_eq!(
+            JsonPath::parse(r#"$.a["b"][1]."c d""#)?.query(&json),
+            Some(&json!(true))
+        );
+        assert_eq!(JsonPath::parse("$.e")?.query(&json), Some(&json!(null)));
+        assert_eq!(JsonPath::parse("$.a.b[2]")?.query(&json), None);
+        assert_eq!(JsonPath::parse("$.a.x")?.query(&json), None);
+        assert_eq!(JsonPath::parse("$.a[0]")?.query(&json), None);
+
+        assert!(JsonPath::parse("a.b").is_err());
+        assert!(JsonPath::parse("$.").is_err());
+        assert!(JsonPath::parse("$[-1]").is_err());
+        assert!(JsonPath::parse("$[0").is_err());
+        Ok(())
+    }
+}
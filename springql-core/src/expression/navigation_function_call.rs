@@ -0,0 +1,67 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use ordered_float::OrderedFloat;
+
+use crate::{expression::ValueExpr, stream_engine::time::SpringEventDuration};
+
+/// Functions evaluated with earlier (or later) tuples in the same partition.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum NavigationFunction {
+    /// Value of an earlier tuple in the same partition.
+    Lag,
+    /// Value of a later tuple in the same partition.
+    /// A tuple waits for the value only within `window` (in ROWTIME) if `window` is supplied, and gets NULL after that.
+    Lead { window: Option<SpringEventDuration> },
+    /// Exponentially weighted moving average: `alpha * value + (1 - alpha) * previous average`.
+    Ewma { alpha: OrderedFloat<f64> },
+    /// Increase of a counter per second from the previous tuple. A decrease is taken as a counter reset.
+    Rate,
+    /// Change of a value per second from the previous tuple.
+    Derivative,
+    /// Running integral of a value over seconds by trapezoidal rule.
+    Integral,
+    /// Standard score of a value against the mean and standard deviation of the preceding values.
+    /// Only the values within `window` before the tuple are used if `window` is supplied.
+    ZScore { window: Option<SpringEventDuration> },
+    /// Whether the absolute value of ZSCORE exceeds `k_sigma`.
+    IsOutlier {
+        k_sigma: OrderedFloat<f64>,
+        window: Option<SpringEventDuration>,
+    },
+}
+
+impl NavigationFunction {
+    pub fn name(&self) -> &'static str {
+        match self {
+            NavigationFunction::Lag => "LAG",
+            NavigationFunction::Lead { .. } => "LEAD",
+            NavigationFunction::Ewma { .. } => "EWMA",
+            NavigationFunction::Rate => "RATE",
+            NavigationFunction::Derivative => "DERIVATIVE",
+            NavigationFunction::Integral => "INTEGRAL",
+            NavigationFunction::ZScore { .. } => "ZSCORE",
+            NavigationFunction::IsOutlier { .. } => "IS_OUTLIER",
+        }
+    }
+}
+
+/// ```text
+/// LAG(expr [, offset]) OVER ([PARTITION BY key1, key2, ...])
+/// LEAD(expr [, offset]) OVER ([PARTITION BY key1, key2, ...] [WINDOW duration])
+/// EWMA(expr, alpha) OVER ([PARTITION BY key1, key2, ...])
+/// RATE(expr) OVER ([PARTITION BY key1, key2, ...])
+/// ZSCORE(expr) OVER ([PARTITION BY key1, key2, ...] [WINDOW duration])
+/// IS_OUTLIER(expr, k_sigma) OVER ([PARTITION BY key1, key2, ...] [WINDOW duration])
+/// ```
+///
+/// Unlike other functions, a navigation function cannot be evaluated with a single tuple.
+/// The query planner replaces it with a reference to a field derived by navigation operation.
+#[derive(Clone, PartialEq, Hash, Debug)]
+pub struct NavigationFunctionCall {
+    pub func: NavigationFunction,
+    pub expr: Box<ValueExpr>,
+    /// 1 or larger. Always 1 for functions other than LAG and LEAD.
+    pub offset: usize,
+    /// Empty when PARTITION BY is not supplied.
+    pub partition_by: Vec<ValueExpr>,
+}
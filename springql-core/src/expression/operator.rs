@@ -8,7 +8,7 @@ pub enum UnaryOperator {
 }
 
 /// binary operator for an expression
-#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum BinaryOperator {
     /// =
     Equal,
@@ -16,9 +16,43 @@ pub enum BinaryOperator {
     /// +
     Add,
 
+    /// -
+    Sub,
+
     /// *
     Mul,
 
+    /// /
+    Div,
+
     /// AND
     And,
+
+    /// &
+    BitAnd,
+
+    /// |
+    BitOr,
+
+    /// <<
+    Shl,
+
+    /// >>
+    Shr,
+}
+
+impl BinaryOperator {
+    /// Higher binds tighter. Operators of the same precedence are left-associative.
+    pub fn precedence(&self) -> u8 {
+        match self {
+            BinaryOperator::Mul | BinaryOperator::Div => 5,
+            BinaryOperator::Add | BinaryOperator::Sub => 4,
+            BinaryOperator::BitAnd
+            | BinaryOperator::BitOr
+            | BinaryOperator::Shl
+            | BinaryOperator::Shr => 3,
+            BinaryOperator::Equal => 2,
+            BinaryOperator::And => 1,
+        }
+    }
 }
@@ -22,18 +22,23 @@ pub use name::{
     StreamName, ValueAlias,
 };
 pub use option::{
-    CANOptions, Http1ClientOptions, HttpMethod, InMemoryQueueOptions, NetClientOptions,
-    NetProtocol, NetServerOptions, Options, OptionsBuilder,
+    CANOptions, ColumnPathOptions, DecimalFormat, Http1ClientOptions, HttpMethod,
+    InMemoryQueueOptions, NetClientOptions, NetProtocol, NetServerOptions, Options, OptionsBuilder,
+    TimestampFormatOptions, WatermarkOptions,
 };
 pub use pipeline_graph::{Edge, PipelineGraph};
 pub use pipeline_version::PipelineVersion;
 pub use pump_model::{
-    AggregateFunctionParameter, AggregateParameter, GroupByLabels, JoinParameter, JoinType,
-    PumpInputType, PumpModel, WindowOperationParameter, WindowParameter,
+    AggregateFunctionParameter, AggregateParameter, ChangesOnlyParameter, DeduplicateParameter,
+    DistinctParameter, GroupByLabels, IntervalJoinParameter, JoinParameter, JoinType,
+    MatchRecognizeParameter, NavigationParameter, PatternElement, PatternQuantifier, PumpInputType,
+    PumpModel, ResampleFill, SilenceAlertParameter, SortOrder, TopNParameter, UnnestParameter,
+    WindowOperationParameter, WindowParameter, WindowTime,
 };
 pub use relation::{
-    ColumnConstraint, ColumnDataType, ColumnDefinition, F32LooseType, I64LooseType,
-    NumericComparableType, SqlType, StringComparableLoseType, U64LooseType,
+    ColumnConstraint, ColumnDataType, ColumnDefinition, DecimalLooseType, F32LooseType,
+    F64LooseType, I64LooseType, NumericComparableType, SqlType, StringComparableLoseType,
+    U64LooseType,
 };
 pub use sink_writer_model::{SinkWriterModel, SinkWriterType};
 pub use source_reader_model::{SourceReaderModel, SourceReaderType};
@@ -101,8 +106,15 @@ impl Pipeline {
 
     /// # Failure
     ///
-    /// TODO
+    /// - `SpringError::InvalidOption` when:
+    ///   - Watermark options of the source reader are invalid
+    ///   - Column path options of the source reader are invalid
+    ///   - Timestamp format options of the source reader are invalid
     pub fn add_source_reader(&mut self, source_reader: SourceReaderModel) -> Result<()> {
+        let _ = WatermarkOptions::try_from(source_reader.options())?;
+        let _ = ColumnPathOptions::try_from(source_reader.options())?;
+        let _ = TimestampFormatOptions::try_from(source_reader.options())?;
+
         self.update_version();
         self.graph.add_source_reader(source_reader)
     }
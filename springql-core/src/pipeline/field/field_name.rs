@@ -17,6 +17,8 @@ pub enum ColumnReference {
     },
     /// Processing time
     PTime { stream_name: StreamName },
+    /// Field derived by an operation in a pump (e.g. result of LAG / LEAD), not from any stream
+    Derived { field_name: ColumnName },
 }
 
 impl MemSize for ColumnReference {
@@ -27,6 +29,7 @@ impl MemSize for ColumnReference {
                 column_name,
             } => stream_name.mem_size() + column_name.mem_size(),
             Self::PTime { stream_name } => stream_name.mem_size(),
+            Self::Derived { field_name } => field_name.mem_size(),
         }
     }
 }
@@ -1,20 +1,26 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
 mod can_options;
+mod column_path_options;
 mod http1_client_options;
 mod in_memory_queue_options;
 mod net_client_options;
 mod net_protocol;
 mod net_server_options;
 mod options_builder;
+mod timestamp_format_options;
+mod watermark_options;
 
 pub use can_options::CANOptions;
+pub use column_path_options::ColumnPathOptions;
 pub use http1_client_options::{Http1ClientOptions, HttpMethod};
 pub use in_memory_queue_options::InMemoryQueueOptions;
-pub use net_client_options::NetClientOptions;
+pub use net_client_options::{DecimalFormat, NetClientOptions};
 pub use net_protocol::NetProtocol;
 pub use net_server_options::NetServerOptions;
 pub use options_builder::OptionsBuilder;
+pub use timestamp_format_options::TimestampFormatOptions;
+pub use watermark_options::WatermarkOptions;
 
 use std::collections::HashMap;
 
@@ -46,6 +52,30 @@ impl Options {
             })
     }
 
+    /// # Returns
+    ///
+    /// `None` when key is not found in this Options.
+    ///
+    /// # Failure
+    ///
+    /// - `SpringError::InvalidOption` when:
+    ///   - value of the key is invalid.
+    pub fn get_optional<V, F>(&self, key: &str, value_parser: F) -> Result<Option<V>>
+    where
+        F: FnOnce(&String) -> std::result::Result<V, anyhow::Error>,
+    {
+        self.0
+            .get(key)
+            .map(|value| {
+                value_parser(value).map_err(|e| SpringError::InvalidOption {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                    source: e,
+                })
+            })
+            .transpose()
+    }
+
     pub fn as_key_values(&self) -> Vec<(&str, &str)> {
         self.0
             .iter()
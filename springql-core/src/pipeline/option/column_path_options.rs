@@ -0,0 +1,106 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::collections::HashMap;
+
+use crate::{
+    api::error::{Result, SpringError},
+    expression::JsonPath,
+    pipeline::{option::Options, ColumnName},
+};
+
+/// Where to find a column value in a JSON message from a foreign source.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum ColumnPath {
+    /// `$.vehicle.speed`
+    JsonPath(JsonPath),
+    /// `/vehicle/speed` (RFC 6901)
+    JsonPointer(String),
+}
+
+impl ColumnPath {
+    /// # Failure
+    ///
+    /// When `path` is neither a JSON pointer nor a JSON path.
+    fn parse(path: &str) -> anyhow::Result<Self> {
+        if path.is_empty() || path.starts_with('/') {
+            Ok(Self::JsonPointer(path.to_string()))
+        } else {
+            JsonPath::parse(path)
+                .map(Self::JsonPath)
+                .map_err(|e| match e {
+                    SpringError::Sql(e) => e,
+                    e => e.into(),
+                })
+        }
+    }
+
+    /// None if the path does not exist in `json`.
+    pub fn query<'a>(&self, json: &'a serde_json::Value) -> Option<&'a serde_json::Value> {
+        match self {
+            Self::JsonPath(json_path) => json_path.query(json),
+            Self::JsonPointer(pointer) => json.pointer(pointer),
+        }
+    }
+}
+
+/// Options of a source reader to map values nested in JSON messages to columns.
+///
+/// `COLUMN_PATH_<column name> '<JSON path or JSON pointer>'` for each mapped column. Other columns are taken from top-level keys.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct ColumnPathOptions {
+    pub column_paths: HashMap<ColumnName, ColumnPath>,
+}
+
+impl TryFrom<&Options> for ColumnPathOptions {
+    type Error = SpringError;
+
+    fn try_from(options: &Options) -> Result<Self> {
+        let mut column_paths = HashMap::new();
+
+        for (key, value) in options.as_key_values() {
+            if let Some(column) = key.strip_prefix("COLUMN_PATH_") {
+                let column_path =
+                    ColumnPath::parse(value).map_err(|e| SpringError::InvalidOption {
+                        key: key.to_string(),
+                        value: value.to_string(),
+                        source: e,
+                    })?;
+                column_paths.insert(ColumnName::new(column.to_string()), column_path);
+            }
+        }
+        Ok(Self { column_paths })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::pipeline::OptionsBuilder;
+
+    use super::*;
+
+    #[test]
+    fn test_column_paths() {
+        let options = OptionsBuilder::default()
+            .add("PROTOCOL", "TCP")
+            .add("COLUMN_PATH_speed", "$.vehicle.speed")
+            .add("COLUMN_PATH_tire", "/vehicle/tires/1")
+            .build();
+        let column_paths = ColumnPathOptions::try_from(&options).unwrap().column_paths;
+        assert_eq!(column_paths.len(), 2);
+
+        let json = json!({"vehicle": {"speed": 42, "tires": [230, 240]}});
+        let query = |column: &str| column_paths[&ColumnName::new(column.to_string())].query(&json);
+        assert_eq!(query("speed"), Some(&json!(42)));
+        assert_eq!(query("tire"), Some(&json!(240)));
+
+        let options = OptionsBuilder::default()
+            .add("COLUMN_PATH_speed", "vehicle.speed")
+            .build();
+        assert!(matches!(
+            ColumnPathOptions::try_from(&options).unwrap_err(),
+            SpringError::InvalidOption { .. }
+        ));
+    }
+}
@@ -1,6 +1,6 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
-use std::net::IpAddr;
+use std::{net::IpAddr, str::FromStr};
 
 use anyhow::Context;
 
@@ -14,6 +14,32 @@ pub struct NetClientOptions {
     pub protocol: NetProtocol,
     pub remote_host: IpAddr,
     pub remote_port: u16,
+    pub decimal_format: DecimalFormat,
+}
+
+/// How a sink writer writes DECIMAL values into JSON.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub enum DecimalFormat {
+    /// JSON number with the exact digits, e.g. `12.30`.
+    #[default]
+    Number,
+    /// JSON string without rounding, e.g. `"12.30"`.
+    String,
+}
+impl FromStr for DecimalFormat {
+    type Err = SpringError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "NUMBER" => Ok(Self::Number),
+            "STRING" => Ok(Self::String),
+            _ => Err(SpringError::InvalidOption {
+                key: "DECIMAL_FORMAT".to_string(),
+                value: s.to_string(),
+                source: anyhow::anyhow!("unsupported decimal format {}", s),
+            }),
+        }
+    }
 }
 
 impl TryFrom<&Options> for NetClientOptions {
@@ -32,6 +58,11 @@ impl TryFrom<&Options> for NetClientOptions {
             remote_port: options.get("REMOTE_PORT", |remote_port_str| {
                 remote_port_str.parse().context("invalid remote port")
             })?,
+            decimal_format: options
+                .get_optional("DECIMAL_FORMAT", |decimal_format_str| {
+                    decimal_format_str.parse().context("invalid decimal format")
+                })?
+                .unwrap_or_default(),
         })
     }
 }
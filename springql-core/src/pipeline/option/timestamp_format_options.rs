@@ -0,0 +1,250 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Context};
+
+use crate::{
+    api::error::{Result, SpringError},
+    pipeline::{option::Options, ColumnName},
+    stream_engine::time::SpringTimestamp,
+    time::FormatDescription,
+};
+
+/// Format of timestamps in JSON messages from a foreign source.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum SourceTimestampFormat {
+    /// Seconds since the Unix epoch (integer or float).
+    EpochSecs,
+    /// Milliseconds since the Unix epoch (integer or float).
+    EpochMillis,
+    /// Microseconds since the Unix epoch (integer or float).
+    EpochMicros,
+    /// Nanoseconds since the Unix epoch (integer or float).
+    EpochNanos,
+    /// RFC 3339 or ISO 8601. A timestamp without offset is in UTC.
+    Iso8601,
+    /// strftime-like format, parsed into a format description of the `time` crate.
+    Custom(FormatDescription),
+}
+
+impl SourceTimestampFormat {
+    /// `EPOCH_SECS`, `EPOCH_MILLIS`, `EPOCH_MICROS`, `EPOCH_NANOS`, `RFC3339`, `ISO8601` or a strftime-like format.
+    ///
+    /// # Failure
+    ///
+    /// When `format` has an unsupported conversion specification.
+    fn parse(format: &str) -> anyhow::Result<Self> {
+        match format.to_uppercase().as_str() {
+            "EPOCH_SECS" => Ok(Self::EpochSecs),
+            "EPOCH_MILLIS" => Ok(Self::EpochMillis),
+            "EPOCH_MICROS" => Ok(Self::EpochMicros),
+            "EPOCH_NANOS" => Ok(Self::EpochNanos),
+            "RFC3339" | "ISO8601" => Ok(Self::Iso8601),
+            _ => {
+                let description = strftime_to_format_description(format)?;
+                Ok(Self::Custom(SpringTimestamp::parse_format(&description)?))
+            }
+        }
+    }
+
+    /// Nanoseconds per unit of epoch formats.
+    fn epoch_unit_nanos(&self) -> Option<i128> {
+        match self {
+            Self::EpochSecs => Some(1_000_000_000),
+            Self::EpochMillis => Some(1_000_000),
+            Self::EpochMicros => Some(1_000),
+            Self::EpochNanos => Some(1),
+            Self::Iso8601 | Self::Custom(_) => None,
+        }
+    }
+
+    /// Epoch formats take JSON numbers (or numeric strings), and others take JSON strings.
+    ///
+    /// # Failure
+    ///
+    /// - `SpringError::InvalidFormat` when:
+    ///   - `json_value` is not in this format.
+    pub fn parse_json(&self, json_value: &serde_json::Value) -> Result<SpringTimestamp> {
+        let err = |e: anyhow::Error| SpringError::InvalidFormat {
+            s: json_value.to_string(),
+            source: e,
+        };
+
+        match (self.epoch_unit_nanos(), json_value) {
+            (Some(unit_nanos), serde_json::Value::Number(n)) => {
+                let nanos = if let Some(i) = n.as_i64() {
+                    Some(i as i128 * unit_nanos)
+                } else if let Some(u) = n.as_u64() {
+                    Some(u as i128 * unit_nanos)
+                } else {
+                    n.as_f64().map(|f| (f * unit_nanos as f64) as i128)
+                };
+                let nanos = nanos.context("not an epoch time").map_err(err)?;
+                SpringTimestamp::from_unix_nanos(nanos)
+            }
+            (Some(unit_nanos), serde_json::Value::String(s)) => {
+                let nanos = s
+                    .parse::<i64>()
+                    .map(|i| i as i128 * unit_nanos)
+                    .or_else(|_| s.parse::<f64>().map(|f| (f * unit_nanos as f64) as i128))
+                    .context("not an epoch time")
+                    .map_err(err)?;
+                SpringTimestamp::from_unix_nanos(nanos)
+            }
+            (None, serde_json::Value::String(s)) => match self {
+                Self::Custom(description) => {
+                    SpringTimestamp::parse_with_description(s, description)
+                }
+                _ => SpringTimestamp::parse_iso8601(s),
+            },
+            _ => Err(err(anyhow!("unexpected JSON type for {:?}", self))),
+        }
+    }
+}
+
+/// Converts strftime conversion specifications into a format description of the `time` crate.
+///
+/// Supports `%Y %m %d %e %H %I %p %M %S %f %j %b %h %B %a %A %z %:z %F %T %%`.
+fn strftime_to_format_description(format: &str) -> anyhow::Result<String> {
+    let mut description = String::new();
+    let mut chars = format.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '%' => {
+                let spec = match chars.next() {
+                    Some(':') => format!(":{}", chars.next().unwrap_or_default()),
+                    Some(c) => c.to_string(),
+                    None => String::new(),
+                };
+                let item = match spec.as_str() {
+                    "Y" => "[year]",
+                    "m" => "[month]",
+                    "d" => "[day]",
+                    "e" => "[day padding:space]",
+                    "H" => "[hour]",
+                    "I" => "[hour repr:12]",
+                    "p" => "[period]",
+                    "M" => "[minute]",
+                    "S" => "[second]",
+                    "f" => "[subsecond]",
+                    "j" => "[ordinal]",
+                    "b" | "h" => "[month repr:short]",
+                    "B" => "[month repr:long]",
+                    "a" => "[weekday repr:short]",
+                    "A" => "[weekday]",
+                    "z" => "[offset_hour sign:mandatory][offset_minute]",
+                    ":z" => "[offset_hour sign:mandatory]:[offset_minute]",
+                    "F" => "[year]-[month]-[day]",
+                    "T" => "[hour]:[minute]:[second]",
+                    "%" => "%",
+                    _ => return Err(anyhow!("unsupported conversion specification `%{}`", spec)),
+                };
+                description.push_str(item);
+            }
+            '[' => description.push_str("[["),
+            c => description.push(c),
+        }
+    }
+    Ok(description)
+}
+
+/// Options of a source reader to parse timestamps in JSON messages.
+///
+/// `TIMESTAMP_FORMAT_<column name> '<format>'` for each column, typically for ROWTIME. See `SourceTimestampFormat` for formats.
+/// Other columns are parsed as RFC 3339 or `"%Y-%m-%d %H:%M:%S%.9f"` on conversion into TIMESTAMP.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct TimestampFormatOptions {
+    pub timestamp_formats: HashMap<ColumnName, SourceTimestampFormat>,
+}
+
+impl TryFrom<&Options> for TimestampFormatOptions {
+    type Error = SpringError;
+
+    fn try_from(options: &Options) -> Result<Self> {
+        let mut timestamp_formats = HashMap::new();
+
+        for (key, value) in options.as_key_values() {
+            if let Some(column) = key.strip_prefix("TIMESTAMP_FORMAT_") {
+                let timestamp_format = SourceTimestampFormat::parse(value).map_err(|e| {
+                    SpringError::InvalidOption {
+                        key: key.to_string(),
+                        value: value.to_string(),
+                        source: e,
+                    }
+                })?;
+                timestamp_formats.insert(ColumnName::new(column.to_string()), timestamp_format);
+            }
+        }
+        Ok(Self { timestamp_formats })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::pipeline::OptionsBuilder;
+
+    use super::*;
+
+    #[test]
+    fn test_timestamp_formats() {
+        let options = OptionsBuilder::default()
+            .add("PROTOCOL", "TCP")
+            .add("TIMESTAMP_FORMAT_secs", "EPOCH_SECS")
+            .add("TIMESTAMP_FORMAT_millis", "epoch_millis")
+            .add("TIMESTAMP_FORMAT_nanos", "EPOCH_NANOS")
+            .add("TIMESTAMP_FORMAT_iso", "ISO8601")
+            .add("TIMESTAMP_FORMAT_custom", "%d/%b/%Y:%H:%M:%S %z")
+            .build();
+        let timestamp_formats = TimestampFormatOptions::try_from(&options)
+            .unwrap()
+            .timestamp_formats;
+        assert_eq!(timestamp_formats.len(), 5);
+
+        let parse = |column: &str, v: serde_json::Value| {
+            timestamp_formats[&ColumnName::new(column.to_string())]
+                .parse_json(&v)
+                .map(|ts| ts.to_string())
+        };
+        let expected = "2020-01-01 00:00:00.000000000";
+        assert_eq!(parse("secs", json!(1577836800)).unwrap(), expected);
+        assert_eq!(
+            parse("secs", json!(1577836800.5)).unwrap(),
+            "2020-01-01 00:00:00.500000000"
+        );
+        assert_eq!(parse("millis", json!(1577836800000_i64)).unwrap(), expected);
+        assert_eq!(parse("millis", json!("1577836800000")).unwrap(), expected);
+        assert_eq!(
+            parse("nanos", json!(1577836800000000000_i64)).unwrap(),
+            expected
+        );
+        assert_eq!(
+            parse("iso", json!("2020-01-01T09:00:00+09:00")).unwrap(),
+            expected
+        );
+        assert_eq!(
+            parse("iso", json!("2020-01-01T00:00:00Z")).unwrap(),
+            expected
+        );
+        assert_eq!(parse("iso", json!("20200101T000000Z")).unwrap(), expected);
+        assert_eq!(
+            parse("custom", json!("01/Jan/2020:09:00:00 +0900")).unwrap(),
+            expected
+        );
+
+        assert!(parse("secs", json!(true)).is_err());
+        assert!(parse("iso", json!(1577836800)).is_err());
+        assert!(parse("custom", json!("2020-01-01")).is_err());
+
+        let options = OptionsBuilder::default()
+            .add("TIMESTAMP_FORMAT_ts", "%Y-%m-%d %Q")
+            .build();
+        assert!(matches!(
+            TimestampFormatOptions::try_from(&options).unwrap_err(),
+            SpringError::InvalidOption { .. }
+        ));
+    }
+}
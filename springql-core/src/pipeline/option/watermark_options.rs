@@ -0,0 +1,65 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use anyhow::Context;
+
+use crate::{
+    api::error::{Result, SpringError},
+    pipeline::option::Options,
+    stream_engine::time::{SpringDuration, SpringEventDuration},
+};
+
+/// Options of a source reader affecting watermarks of windows reading the source stream.
+///
+/// Available for any type of source reader.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct WatermarkOptions {
+    /// Used only when a window clause does not have `IDLE TIMEOUT`.
+    pub idle_timeout: Option<SpringEventDuration>,
+}
+
+impl TryFrom<&Options> for WatermarkOptions {
+    type Error = SpringError;
+
+    fn try_from(options: &Options) -> Result<Self> {
+        Ok(Self {
+            idle_timeout: options.get_optional("IDLE_TIMEOUT_MSEC", |idle_timeout_str| {
+                idle_timeout_str
+                    .parse()
+                    .map(SpringEventDuration::from_millis)
+                    .context("invalid idle timeout")
+            })?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pipeline::OptionsBuilder;
+
+    use super::*;
+
+    #[test]
+    fn test_idle_timeout() {
+        let options = OptionsBuilder::default()
+            .add("IDLE_TIMEOUT_MSEC", "500")
+            .build();
+        assert_eq!(
+            WatermarkOptions::try_from(&options).unwrap().idle_timeout,
+            Some(SpringEventDuration::from_millis(500))
+        );
+
+        let options = OptionsBuilder::default().build();
+        assert_eq!(
+            WatermarkOptions::try_from(&options).unwrap().idle_timeout,
+            None
+        );
+
+        let options = OptionsBuilder::default()
+            .add("IDLE_TIMEOUT_MSEC", "-1")
+            .build();
+        assert!(matches!(
+            WatermarkOptions::try_from(&options).unwrap_err(),
+            SpringError::InvalidOption { .. }
+        ));
+    }
+}
@@ -4,7 +4,7 @@ use std::sync::Arc;
 
 use crate::pipeline::{name::StreamName, stream_model::StreamModel};
 
-#[derive(Clone, Eq, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug)]
 pub enum StreamNode {
     Stream(Arc<StreamModel>),
     VirtualRoot,
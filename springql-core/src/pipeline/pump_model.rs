@@ -1,15 +1,29 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
+mod changes_only_parameter;
+mod deduplicate_parameter;
+mod interval_join_parameter;
+mod match_recognize_parameter;
+mod navigation_parameter;
 mod pump_input_type;
+mod silence_alert_parameter;
+mod unnest_parameter;
 mod window_operation_parameter;
 mod window_parameter;
 
+pub use changes_only_parameter::ChangesOnlyParameter;
+pub use deduplicate_parameter::DeduplicateParameter;
+pub use interval_join_parameter::IntervalJoinParameter;
+pub use match_recognize_parameter::{MatchRecognizeParameter, PatternElement, PatternQuantifier};
+pub use navigation_parameter::NavigationParameter;
 pub use pump_input_type::PumpInputType;
+pub use silence_alert_parameter::SilenceAlertParameter;
+pub use unnest_parameter::UnnestParameter;
 pub use window_operation_parameter::{
-    AggregateFunctionParameter, AggregateParameter, GroupByLabels, JoinParameter, JoinType,
-    WindowOperationParameter,
+    AggregateFunctionParameter, AggregateParameter, DistinctParameter, GroupByLabels,
+    JoinParameter, JoinType, SortOrder, TopNParameter, WindowOperationParameter,
 };
-pub use window_parameter::WindowParameter;
+pub use window_parameter::{ResampleFill, WindowParameter, WindowTime};
 
 use crate::{
     pipeline::name::{PumpName, StreamName},
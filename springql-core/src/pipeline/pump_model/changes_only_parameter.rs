@@ -0,0 +1,16 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::expr_resolver::ValueExprLabel;
+
+/// A tuple is dropped if `columns` have the same values as the last tuple in its partition.
+///
+/// ```sql
+/// SELECT STREAM can.ts, can.can_id, can.speed
+///   FROM can
+///   CHANGES ONLY ON (can.speed) PARTITION BY can.can_id;
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, new)]
+pub struct ChangesOnlyParameter {
+    pub columns: Vec<ValueExprLabel>,
+    pub partition_by: Vec<ValueExprLabel>,
+}
@@ -0,0 +1,16 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::{expr_resolver::ValueExprLabel, stream_engine::time::SpringEventDuration};
+
+/// A tuple is dropped if another tuple with the same keys has been emitted within `within` (in ROWTIME).
+///
+/// ```sql
+/// SELECT STREAM frame.ts, frame.payload
+///   FROM frame
+///   DEDUPLICATE ON (frame.gateway_id, frame.seq) WITHIN DURATION_SECS(10);
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, new)]
+pub struct DeduplicateParameter {
+    pub keys: Vec<ValueExprLabel>,
+    pub within: SpringEventDuration,
+}
@@ -0,0 +1,24 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::stream_engine::time::SpringEventDuration;
+
+/// Bounds of an interval join, relative to the left tuple's ROWTIME.
+///
+/// A left tuple `l` and a right tuple `r` are joined only if:
+///
+/// ```text
+/// l.ROWTIME - lower_bound <= r.ROWTIME <= l.ROWTIME + upper_bound
+/// ```
+///
+/// ```sql
+/// SELECT brake.ts, speed.speed
+///   FROM brake
+///   LEFT OUTER JOIN speed
+///   ON brake.vehicle_id = speed.vehicle_id
+///     AND speed.ts BETWEEN brake.ts - DURATION_SECS(5) AND brake.ts + DURATION_SECS(5);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug, new)]
+pub struct IntervalJoinParameter {
+    pub lower_bound: SpringEventDuration,
+    pub upper_bound: SpringEventDuration,
+}
@@ -0,0 +1,74 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::{
+    expr_resolver::ValueExprLabel,
+    pipeline::{ColumnReference, StreamName},
+    stream_engine::time::SpringEventDuration,
+};
+
+/// Row pattern recognition.
+///
+/// Rows in a partition are matched to `pattern` in arrival order. A match must be completed within `within` (in ROWTIME) from its first row.
+/// One tuple is emitted per match: the last row of the match with `measures` appended.
+///
+/// ```sql
+/// SELECT STREAM vehicle.id, vehicle.brake_at, vehicle.airbag_at
+///   FROM vehicle
+///   MATCH_RECOGNIZE (
+///     PARTITION BY vehicle.id
+///     ORDER BY vehicle.ts
+///     MEASURES A.ts AS brake_at, B.ts AS airbag_at
+///     PATTERN (A B)
+///     WITHIN DURATION_SECS(2)
+///     DEFINE A AS A.decel > 8, B AS B.airbag = TRUE
+///   );
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, new)]
+pub struct MatchRecognizeParameter {
+    pub partition_by: Vec<ValueExprLabel>,
+    /// (measure expression, field of output tuple)
+    pub measures: Vec<(ValueExprLabel, ColumnReference)>,
+    pub pattern: Vec<PatternElement>,
+    pub within: SpringEventDuration,
+}
+
+/// A pattern variable with a quantifier.
+///
+/// A pattern variable is used as a correlation name in DEFINE conditions and MEASURES (e.g. `A.decel`)
+/// to refer to the last row mapped to the variable.
+#[derive(Clone, PartialEq, Eq, Debug, new)]
+pub struct PatternElement {
+    pub variable: StreamName,
+    pub quantifier: PatternQuantifier,
+    /// DEFINE condition. Any row is mapped to a variable without condition.
+    pub condition: Option<ValueExprLabel>,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum PatternQuantifier {
+    /// `A`
+    One,
+    /// `A?`
+    ZeroOrOne,
+    /// `A*`
+    ZeroOrMore,
+    /// `A+`
+    OneOrMore,
+}
+
+impl PatternQuantifier {
+    pub fn min(&self) -> usize {
+        match self {
+            PatternQuantifier::One | PatternQuantifier::OneOrMore => 1,
+            PatternQuantifier::ZeroOrOne | PatternQuantifier::ZeroOrMore => 0,
+        }
+    }
+
+    /// None for unbounded.
+    pub fn max(&self) -> Option<usize> {
+        match self {
+            PatternQuantifier::One | PatternQuantifier::ZeroOrOne => Some(1),
+            PatternQuantifier::ZeroOrMore | PatternQuantifier::OneOrMore => None,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::{
+    expr_resolver::ValueExprLabel, expression::NavigationFunction, pipeline::ColumnReference,
+};
+
+/// A navigation function call (LAG, LEAD, EWMA, RATE, DERIVATIVE, INTEGRAL, ZSCORE, IS_OUTLIER) in select_list or WHERE clause.
+///
+/// Navigation operation evaluates `expr` for each tuple and keeps the values per partition.
+/// The result is added to a tuple as a `derived` field, which replaces the function call in select_list.
+///
+/// ```sql
+/// SELECT STREAM
+///     battery.vehicle_id,
+///     battery.level + -LAG(battery.level) OVER (PARTITION BY battery.vehicle_id) AS delta
+///   FROM battery;
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, new)]
+pub struct NavigationParameter {
+    pub func: NavigationFunction,
+    pub expr: ValueExprLabel,
+    pub offset: usize,
+    pub partition_by: Vec<ValueExprLabel>,
+    pub derived: ColumnReference,
+}
@@ -0,0 +1,20 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::{
+    expr_resolver::ValueExprLabel, pipeline::WindowTime, stream_engine::time::SpringEventDuration,
+};
+
+/// The last tuple of a partition is emitted as an alert when no tuple comes into the partition for `silent_for`.
+///
+/// ```sql
+/// SELECT STREAM heartbeat.ecu_id, heartbeat.ts
+///   FROM heartbeat
+///   ALERT IF SILENT FOR DURATION_SECS(3) PARTITION BY heartbeat.ecu_id;
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, new)]
+pub struct SilenceAlertParameter {
+    pub silent_for: SpringEventDuration,
+    /// ROWTIME (driven by watermark) or PTIME (driven by wall clock)
+    pub time: WindowTime,
+    pub partition_by: Vec<ValueExprLabel>,
+}
@@ -0,0 +1,18 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::{expr_resolver::ValueExprLabel, pipeline::ColumnReference};
+
+/// A tuple is expanded into a tuple per element of `array`, with the element as `element` field.
+///
+/// An empty or NULL array emits no tuple.
+///
+/// ```sql
+/// SELECT STREAM batch.ts, t.sample
+///   FROM batch
+///   CROSS JOIN UNNEST(batch.samples) AS t(sample);
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, new)]
+pub struct UnnestParameter {
+    pub array: ValueExprLabel,
+    pub element: ColumnReference,
+}
@@ -1,14 +1,19 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
 mod aggregate;
+mod distinct_parameter;
 mod join_parameter;
+mod top_n_parameter;
 
 pub use aggregate::{AggregateFunctionParameter, AggregateParameter, GroupByLabels};
+pub use distinct_parameter::DistinctParameter;
 pub use join_parameter::{JoinParameter, JoinType};
+pub use top_n_parameter::{SortOrder, TopNParameter};
 
 /// Window operation parameters
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum WindowOperationParameter {
     Aggregate(AggregateParameter),
     Join(JoinParameter),
+    Distinct(DistinctParameter),
 }
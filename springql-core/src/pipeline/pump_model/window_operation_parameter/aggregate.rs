@@ -1,6 +1,9 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
-use crate::expr_resolver::{AggrExprLabel, ValueExprLabel};
+use crate::{
+    expr_resolver::{AggrExprLabel, ValueExprLabel},
+    pipeline::TopNParameter,
+};
 
 /// [GROUP BY c1, c2, c3...]
 #[derive(Clone, PartialEq, Eq, Debug, Default, new)]
@@ -20,7 +23,8 @@ impl GroupByLabels {
 /// SELECT group_by, aggr_expr.func(aggr_expr.aggregated)
 ///   FROM s
 ///   [GROUP BY group_by]
-///   SLIDING WINDOW ...;
+///   SLIDING WINDOW ...
+///   [ORDER BY ... LIMIT n];
 /// ```
 #[derive(Clone, PartialEq, Eq, Debug, new)]
 pub struct AggregateParameter {
@@ -28,6 +32,9 @@ pub struct AggregateParameter {
     pub aggr_func: AggregateFunctionParameter,
     pub aggr_expr: AggrExprLabel,
     pub group_by: GroupByLabels,
+
+    /// Some if ORDER BY ... LIMIT is supplied.
+    pub top_n: Option<TopNParameter>,
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
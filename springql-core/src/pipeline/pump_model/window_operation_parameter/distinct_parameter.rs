@@ -0,0 +1,18 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::expr_resolver::ValueExprLabel;
+
+/// Parameter for `SELECT DISTINCT STREAM` with a window clause.
+///
+/// A pane emits the first tuple of each distinct `keys` on close.
+///
+/// ```sql
+/// SELECT DISTINCT STREAM frame.gateway_id, frame.seq
+///   FROM frame
+///   FIXED WINDOW DURATION_SECS(10), DURATION_SECS(0);
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, new)]
+pub struct DistinctParameter {
+    /// Expressions in select_list.
+    pub keys: Vec<ValueExprLabel>,
+}
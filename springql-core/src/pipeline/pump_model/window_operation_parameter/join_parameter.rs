@@ -1,6 +1,9 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
-use crate::{expr_resolver::ValueExprLabel, pipeline::field::ColumnReference};
+use crate::{
+    expr_resolver::ValueExprLabel,
+    pipeline::{field::ColumnReference, TopNParameter},
+};
 
 /// TODO `support complex expression with aggregations`
 ///
@@ -20,6 +23,9 @@ pub struct JoinParameter {
     pub right_colrefs: Vec<ColumnReference>,
 
     pub on_expr: ValueExprLabel,
+
+    /// Some if ORDER BY ... LIMIT is supplied (only for JOIN window without aggregation).
+    pub top_n: Option<TopNParameter>,
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
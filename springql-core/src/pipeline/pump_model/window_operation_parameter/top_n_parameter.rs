@@ -0,0 +1,27 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::expr_resolver::ExprLabel;
+
+/// Emits only the top `limit` rows (or groups) of each pane when it closes.
+///
+/// ```sql
+/// SELECT s.c1, AVG(s.c2) AS a
+///   FROM s
+///   GROUP BY s.c1
+///   FIXED WINDOW ...
+///   ORDER BY a DESC LIMIT 5;
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, new)]
+pub struct TopNParameter {
+    /// - `ExprLabel::Aggr`: aggregated value.
+    /// - `ExprLabel::Value`: GROUP BY value for aggregation, or value evaluated from a joined tuple.
+    pub sort_key: ExprLabel,
+    pub order: SortOrder,
+    pub limit: usize,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
@@ -2,7 +2,33 @@
 
 use crate::stream_engine::time::SpringEventDuration;
 
+/// Timestamp which window panes are keyed by.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum WindowTime {
+    /// ROWTIME of tuples (event-time if the stream has ROWTIME column, otherwise processing time).
+    Rowtime,
+
+    /// Wall-clock time when tuples are dispatched to a window (`ON PTIME`).
+    ///
+    /// Panes are closed by wall clock so allowed delay and idle timeout are meaningless.
+    PTime,
+}
+
+/// How `RESAMPLE EVERY` window fills intervals without any tuple of a group.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum ResampleFill {
+    /// `FILL NULL` (default): aggregated value is NULL.
+    Null,
+    /// `FILL PREVIOUS`: the last aggregated value of the group is carried forward.
+    Previous,
+    /// `FILL LINEAR`: aggregated value is linearly interpolated between the last and the next aggregated values of the group.
+    ///
+    /// Rows for empty intervals are emitted when the next value comes.
+    Linear,
+}
+
 /// Window parameters
+#[allow(clippy::enum_variant_names)]
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub enum WindowParameter {
     /// Time-based sliding window
@@ -21,6 +47,8 @@ pub enum WindowParameter {
         length: SpringEventDuration,
         period: SpringEventDuration,
         allowed_delay: SpringEventDuration,
+        idle_timeout: Option<SpringEventDuration>,
+        window_time: WindowTime,
     },
 
     /// Time-based fixed window
@@ -38,6 +66,30 @@ pub enum WindowParameter {
     TimedFixedWindow {
         length: SpringEventDuration,
         allowed_delay: SpringEventDuration,
+        idle_timeout: Option<SpringEventDuration>,
+        window_time: WindowTime,
+    },
+
+    /// Time-based fixed window which emits a row for every interval and every group seen so far,
+    /// even if the interval does not have any tuple of the group.
+    ///
+    /// ```text
+    /// every = 10sec, allowed_delay = 0;
+    ///
+    /// group A  o         (fill)     o
+    /// group B  o         o          (fill)
+    ///
+    /// -----------------------------------> t
+    ///      :00       :10        :20
+    /// ```
+    TimedResampleWindow {
+        every: SpringEventDuration,
+        fill: ResampleFill,
+        /// A group without tuples for this duration after its last interval is evicted. Kept forever if None.
+        within: Option<SpringEventDuration>,
+        allowed_delay: SpringEventDuration,
+        idle_timeout: Option<SpringEventDuration>,
+        window_time: WindowTime,
     },
 }
 
@@ -46,6 +98,7 @@ impl WindowParameter {
         match self {
             WindowParameter::TimedSlidingWindow { length, .. } => *length,
             WindowParameter::TimedFixedWindow { length, .. } => *length,
+            WindowParameter::TimedResampleWindow { every, .. } => *every,
         }
     }
 
@@ -53,6 +106,7 @@ impl WindowParameter {
         match self {
             WindowParameter::TimedSlidingWindow { period, .. } => *period,
             WindowParameter::TimedFixedWindow { length, .. } => *length,
+            WindowParameter::TimedResampleWindow { every, .. } => *every,
         }
     }
 
@@ -60,6 +114,40 @@ impl WindowParameter {
         match self {
             WindowParameter::TimedSlidingWindow { allowed_delay, .. } => *allowed_delay,
             WindowParameter::TimedFixedWindow { allowed_delay, .. } => *allowed_delay,
+            WindowParameter::TimedResampleWindow { allowed_delay, .. } => *allowed_delay,
+        }
+    }
+
+    /// Wall-clock time without any input after which the watermark advances by processing time.
+    pub fn idle_timeout(&self) -> Option<SpringEventDuration> {
+        match self {
+            WindowParameter::TimedSlidingWindow { idle_timeout, .. } => *idle_timeout,
+            WindowParameter::TimedFixedWindow { idle_timeout, .. } => *idle_timeout,
+            WindowParameter::TimedResampleWindow { idle_timeout, .. } => *idle_timeout,
+        }
+    }
+
+    pub fn window_time(&self) -> WindowTime {
+        match self {
+            WindowParameter::TimedSlidingWindow { window_time, .. } => *window_time,
+            WindowParameter::TimedFixedWindow { window_time, .. } => *window_time,
+            WindowParameter::TimedResampleWindow { window_time, .. } => *window_time,
+        }
+    }
+
+    /// `Some` only for `RESAMPLE EVERY` window.
+    pub fn resample_fill(&self) -> Option<ResampleFill> {
+        match self {
+            WindowParameter::TimedResampleWindow { fill, .. } => Some(*fill),
+            _ => None,
+        }
+    }
+
+    /// `Some` only for `RESAMPLE EVERY ... WITHIN` window.
+    pub fn resample_within(&self) -> Option<SpringEventDuration> {
+        match self {
+            WindowParameter::TimedResampleWindow { within, .. } => *within,
+            _ => None,
         }
     }
 }
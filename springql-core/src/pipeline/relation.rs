@@ -5,6 +5,6 @@ mod sql_type;
 
 pub use column::{ColumnConstraint, ColumnDataType, ColumnDefinition};
 pub use sql_type::{
-    F32LooseType, I64LooseType, NumericComparableType, SqlType, StringComparableLoseType,
-    U64LooseType,
+    DecimalLooseType, F32LooseType, F64LooseType, I64LooseType, NumericComparableType, SqlType,
+    StringComparableLoseType, U64LooseType,
 };
@@ -1,7 +1,13 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
+use crate::{expression::ValueExpr, stream_engine::SqlValue};
+
 /// Column with data type.
-#[derive(Clone, Eq, PartialEq, Hash, Debug, new)]
+#[derive(Clone, PartialEq, Debug)]
 pub enum ColumnConstraint {
     Rowtime,
+    /// `DEFAULT constant` for a source row without the column (or with NULL).
+    Default(SqlValue),
+    /// `GENERATED ALWAYS AS (expr)` evaluated from other columns of a source row.
+    Generated(ValueExpr),
 }
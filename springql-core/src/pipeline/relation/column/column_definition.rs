@@ -1,13 +1,13 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
-use crate::pipeline::name::ColumnName;
+use crate::{expression::ValueExpr, pipeline::name::ColumnName, stream_engine::SqlValue};
 
 use crate::pipeline::relation::column::{
     column_constraint::ColumnConstraint, column_data_type::ColumnDataType,
 };
 
 /// Column definition used in DDL.
-#[derive(Clone, Eq, PartialEq, Debug, new)]
+#[derive(Clone, PartialEq, Debug, new)]
 pub struct ColumnDefinition {
     column_data_type: ColumnDataType,
     column_constraints: Vec<ColumnConstraint>,
@@ -25,4 +25,24 @@ impl ColumnDefinition {
     pub fn column_constraints(&self) -> &[ColumnConstraint] {
         &self.column_constraints
     }
+
+    /// Value for a source row without this column (or with NULL).
+    pub fn default_value(&self) -> Option<&SqlValue> {
+        self.column_constraints
+            .iter()
+            .find_map(|constraint| match constraint {
+                ColumnConstraint::Default(value) => Some(value),
+                _ => None,
+            })
+    }
+
+    /// Expression to evaluate this column from other columns of a source row.
+    pub fn generated_expr(&self) -> Option<&ValueExpr> {
+        self.column_constraints
+            .iter()
+            .find_map(|constraint| match constraint {
+                ColumnConstraint::Generated(expr) => Some(expr),
+                _ => None,
+            })
+    }
 }
@@ -7,6 +7,7 @@
 /// - Comparable types: two values are **comparable** (, and some types are also **ordered**).
 ///   - Loose types: values can be typed as 1 specific Rust type.
 ///     - SQL types: corresponds to an SQL type.
+/// - Structured types: hold other SQL values as their elements. Compared element by element.
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 pub enum SqlType {
     /// Numeric types
@@ -24,8 +25,23 @@ pub enum SqlType {
     /// Timestamp types
     TimestampComparable,
 
+    /// Timestamp with time zone types
+    TimestampTzComparable,
+
     /// Duration types
     DurationComparable,
+
+    /// `ARRAY<element type>`
+    Array(Box<SqlType>),
+
+    /// `MAP<TEXT, value type>`
+    Map(Box<SqlType>),
+
+    /// `ROW(field name field type, ...)`
+    Row(Vec<(String, SqlType)>),
+
+    /// Semi-structured JSON value
+    Json,
 }
 
 impl SqlType {
@@ -59,6 +75,17 @@ impl SqlType {
     pub fn float() -> SqlType {
         SqlType::NumericComparable(NumericComparableType::F32Loose(F32LooseType::Float))
     }
+    /// Constructor of Double
+    pub fn double() -> SqlType {
+        SqlType::NumericComparable(NumericComparableType::F64Loose(F64LooseType::Double))
+    }
+
+    /// Constructor of Decimal
+    pub fn decimal(precision: u32, scale: u32) -> SqlType {
+        SqlType::NumericComparable(NumericComparableType::DecimalLoose(
+            DecimalLooseType::Decimal { precision, scale },
+        ))
+    }
 
     /// Constructor of Text
     pub fn text() -> SqlType {
@@ -80,10 +107,40 @@ impl SqlType {
         SqlType::TimestampComparable
     }
 
+    /// Constructor of Timestamp with time zone
+    pub fn timestamp_tz() -> SqlType {
+        SqlType::TimestampTzComparable
+    }
+
     /// Constructor of Duration
     pub fn duration() -> SqlType {
         SqlType::DurationComparable
     }
+
+    /// Constructor of Array
+    pub fn array(element_type: SqlType) -> SqlType {
+        SqlType::Array(Box::new(element_type))
+    }
+
+    /// Constructor of Map (keys are TEXT)
+    pub fn map(value_type: SqlType) -> SqlType {
+        SqlType::Map(Box::new(value_type))
+    }
+
+    /// Constructor of Row
+    pub fn row(fields: Vec<(String, SqlType)>) -> SqlType {
+        SqlType::Row(fields)
+    }
+
+    /// Constructor of Json
+    pub fn json() -> SqlType {
+        SqlType::Json
+    }
+
+    /// Whether this type holds other values as its elements.
+    pub fn is_structured(&self) -> bool {
+        matches!(self, SqlType::Array(_) | SqlType::Map(_) | SqlType::Row(_))
+    }
 }
 
 /// Numeric types (comparable).
@@ -98,6 +155,12 @@ pub enum NumericComparableType {
 
     /// Loosely typed as f32
     F32Loose(F32LooseType),
+
+    /// Loosely typed as f64
+    F64Loose(F64LooseType),
+
+    /// Loosely typed as SpringDecimal
+    DecimalLoose(DecimalLooseType),
 }
 
 /// Integer types (loosely typed as i64).
@@ -123,13 +186,27 @@ pub enum U64LooseType {
     UnsignedBigInt,
 }
 
-/// Float types (loosely typed as f64).
+/// Float types (loosely typed as f32).
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 pub enum F32LooseType {
     /// fp32
     Float,
 }
 
+/// Double precision float types (loosely typed as f64).
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum F64LooseType {
+    /// fp64
+    Double,
+}
+
+/// Fixed-point decimal types (loosely typed as SpringDecimal).
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum DecimalLooseType {
+    /// Up to `precision` digits in total, and `scale` digits of them are after the decimal point.
+    Decimal { precision: u32, scale: u32 },
+}
+
 /// Text types (comparable, loosely typed as String).
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 pub enum StringComparableLoseType {
@@ -6,7 +6,7 @@ pub use stream_shape::StreamShape;
 
 use crate::pipeline::{field::ColumnReference, name::StreamName};
 
-#[derive(Clone, Eq, PartialEq, Debug, new)]
+#[derive(Clone, PartialEq, Debug, new)]
 pub struct StreamModel {
     name: StreamName,
     shape: StreamShape,
@@ -8,7 +8,7 @@ use crate::{
     pipeline::relation::{ColumnConstraint, ColumnDefinition, SqlType},
 };
 
-#[derive(Clone, Eq, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug)]
 pub struct StreamShape {
     cols: Vec<ColumnDefinition>,
     event_time_col: Option<ColumnName>,
@@ -89,7 +89,9 @@ impl StreamShape {
             })
             .map_err(SpringError::Sql)?;
 
-        if let SqlType::TimestampComparable = rowtime_coldef.column_data_type().sql_type() {
+        if let SqlType::TimestampComparable | SqlType::TimestampTzComparable =
+            rowtime_coldef.column_data_type().sql_type()
+        {
             Ok(())
         } else {
             Err(SpringError::Sql(anyhow!(
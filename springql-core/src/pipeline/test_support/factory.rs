@@ -44,6 +44,24 @@ impl ValueExpr {
         Self::BinaryExpr(BinaryExpr::factory_add(left, right))
     }
 
+    pub fn factory_sub(left: ValueExpr, right: ValueExpr) -> Self {
+        Self::BinaryExpr(BinaryExpr::NumericalFunctionVariant(
+            NumericalFunction::SubVariant {
+                left: Box::new(left),
+                right: Box::new(right),
+            },
+        ))
+    }
+
+    pub fn factory_mul(left: ValueExpr, right: ValueExpr) -> Self {
+        Self::BinaryExpr(BinaryExpr::NumericalFunctionVariant(
+            NumericalFunction::MulVariant {
+                left: Box::new(left),
+                right: Box::new(right),
+            },
+        ))
+    }
+
     pub fn factory_and(left: ValueExpr, right: ValueExpr) -> Self {
         Self::BinaryExpr(BinaryExpr::LogicalFunctionVariant(
             LogicalFunction::AndVariant {
@@ -57,18 +57,29 @@
 
 mod select_syntax_analyzer;
 
+use anyhow::anyhow;
+
 use crate::{
-    api::error::Result,
-    expr_resolver::{ExprLabel, ExprResolver},
+    api::error::{Result, SpringError},
+    expr_resolver::{ExprLabel, ExprResolver, ValueExprLabel},
+    expression::{AggrExpr, BinaryExpr, LogicalFunction, NavigationFunctionCall, ValueExpr},
     pipeline::{
-        AggregateParameter, GroupByLabels, Pipeline, WindowOperationParameter, WindowParameter,
+        AggrAlias, AggregateParameter, ChangesOnlyParameter, ColumnName, ColumnReference,
+        DeduplicateParameter, DistinctParameter, GroupByLabels, NavigationParameter, Pipeline,
+        SilenceAlertParameter, StreamName, TopNParameter, UnnestParameter, ValueAlias,
+        WindowOperationParameter, WindowParameter,
     },
     sql_processor::{
         query_planner::select_syntax_analyzer::SelectSyntaxAnalyzer,
-        sql_parser::{GroupingElementSyntax, SelectStreamSyntax},
+        sql_parser::{
+            GroupingElementSyntax, OrderBySyntax, SelectFieldSyntax, SelectStreamSyntax,
+            SortKeySyntax,
+        },
     },
     stream_engine::command::{
-        GroupAggregateWindowOp, JoinOp, LowerOps, ProjectionOp, QueryPlan, UpperOps,
+        ChangesOnlyOp, ContinuousAggregateOp, DeduplicateOp, DistinctWindowOp,
+        GroupAggregateWindowOp, JoinOp, LowerOps, MatchRecognizeOp, NavigationOp, ProjectionOp,
+        QueryPlan, SelectionOp, SilenceAlertOp, UnnestOp, UpperOps,
     },
 };
 
@@ -85,43 +96,264 @@ impl QueryPlanner {
     }
 
     pub fn plan(self, pipeline: &Pipeline) -> Result<QueryPlan> {
-        let (mut expr_resolver, labels_select_list) =
-            ExprResolver::new(self.analyzer.select_list().to_vec());
+        let (where_condition, navigation_where_condition) =
+            Self::split_where_condition(self.analyzer.where_condition().cloned());
+        let (select_list, navigation_where_condition, navigation_calls) =
+            Self::replace_navigation_function_calls(
+                self.analyzer.select_list().to_vec(),
+                navigation_where_condition,
+            )?;
+
+        let (mut expr_resolver, labels_select_list) = ExprResolver::new(select_list);
         let projection = ProjectionOp {
             expr_labels: labels_select_list,
         };
 
-        let group_aggr_window =
-            self.create_group_aggr_window_op(&projection, &mut expr_resolver)?;
+        let (mut group_aggr_window, continuous_aggr) =
+            self.create_group_aggr_ops(&projection, &mut expr_resolver)?;
+
+        let mut join = self.create_join_op(&mut expr_resolver, pipeline)?;
+
+        if let Some(order_by) = self.analyzer.order_by() {
+            self.set_top_n_param(
+                order_by,
+                &mut group_aggr_window,
+                &mut join,
+                &mut expr_resolver,
+            )?;
+        }
+
+        let distinct_window =
+            self.create_distinct_window_op(&projection, &group_aggr_window, &join)?;
+        let unnest = self.create_unnest_op(&mut expr_resolver);
+        let selection = where_condition.map(|condition| SelectionOp {
+            condition: expr_resolver.register_value_expr(condition),
+        });
+        let match_recognize =
+            self.create_match_recognize_op(&group_aggr_window, &mut expr_resolver, pipeline)?;
+        let deduplicate = self.create_deduplicate_op(&mut expr_resolver)?;
+        let changes_only = self.create_changes_only_op(&mut expr_resolver);
+        let silence_alert = self.create_silence_alert_op(&mut expr_resolver);
+        let navigation = Self::create_navigation_op(navigation_calls, &mut expr_resolver);
+        let navigation_selection = navigation_where_condition.map(|condition| SelectionOp {
+            condition: expr_resolver.register_value_expr(condition),
+        });
 
         let upper_ops = UpperOps {
             projection,
             group_aggr_window,
+            distinct_window,
+            continuous_aggr,
+            navigation,
+            navigation_selection,
+        };
+        let lower_ops = LowerOps {
+            join,
+            unnest,
+            selection,
+            match_recognize,
+            deduplicate,
+            changes_only,
+            silence_alert,
         };
-
-        let join = self.create_join_op(&mut expr_resolver, pipeline)?;
-        let lower_ops = LowerOps { join };
 
         Ok(QueryPlan::new(upper_ops, lower_ops, expr_resolver))
     }
 
-    fn create_group_aggr_window_op(
+    /// Splits AND-conjuncts of WHERE condition into ones without navigation functions, applied before stateful operations,
+    /// and ones with navigation functions, applied after navigation operation.
+    ///
+    /// # Returns
+    ///
+    /// `(condition without navigation functions, condition with navigation functions)`
+    fn split_where_condition(
+        where_condition: Option<ValueExpr>,
+    ) -> (Option<ValueExpr>, Option<ValueExpr>) {
+        fn conjuncts(condition: ValueExpr, acc: &mut Vec<ValueExpr>) {
+            match condition {
+                ValueExpr::BinaryExpr(BinaryExpr::LogicalFunctionVariant(
+                    LogicalFunction::AndVariant { left, right },
+                )) => {
+                    conjuncts(*left, acc);
+                    conjuncts(*right, acc);
+                }
+                _ => acc.push(condition),
+            }
+        }
+        fn and(conditions: Vec<ValueExpr>) -> Option<ValueExpr> {
+            conditions.into_iter().reduce(|left, right| {
+                ValueExpr::BinaryExpr(BinaryExpr::LogicalFunctionVariant(
+                    LogicalFunction::AndVariant {
+                        left: Box::new(left),
+                        right: Box::new(right),
+                    },
+                ))
+            })
+        }
+
+        let mut all = Vec::new();
+        if let Some(condition) = where_condition {
+            conjuncts(condition, &mut all);
+        }
+        let (navigation, plain): (Vec<_>, Vec<_>) = all
+            .into_iter()
+            .partition(ValueExpr::has_navigation_function_call);
+        (and(plain), and(navigation))
+    }
+
+    /// Replaces navigation functions (LAG, LEAD, EWMA, ...) in select_list and WHERE condition with references to derived fields.
+    ///
+    /// # Returns
+    ///
+    /// `(new select_list, new WHERE condition, [(navigation function call, derived field)])`
+    #[allow(clippy::type_complexity)]
+    fn replace_navigation_function_calls(
+        select_list: Vec<SelectFieldSyntax>,
+        where_condition: Option<ValueExpr>,
+    ) -> Result<(
+        Vec<SelectFieldSyntax>,
+        Option<ValueExpr>,
+        Vec<(NavigationFunctionCall, ColumnReference)>,
+    )> {
+        let mut navigation_calls = Vec::new();
+        let mut replace = |call: NavigationFunctionCall| {
+            let field_name =
+                ColumnName::new(format!("{}#{}", call.func.name(), navigation_calls.len()));
+            let derived = ColumnReference::Derived { field_name };
+            navigation_calls.push((call, derived.clone()));
+            Ok(derived)
+        };
+
+        let select_list = select_list
+            .into_iter()
+            .map(|select_field| match select_field {
+                SelectFieldSyntax::ValueExpr { value_expr, alias } => {
+                    Ok(SelectFieldSyntax::ValueExpr {
+                        value_expr: value_expr.replace_navigation_function_calls(&mut replace)?,
+                        alias,
+                    })
+                }
+                SelectFieldSyntax::AggrExpr { aggr_expr, alias } => {
+                    Ok(SelectFieldSyntax::AggrExpr {
+                        aggr_expr: AggrExpr {
+                            func: aggr_expr.func,
+                            aggregated: aggr_expr
+                                .aggregated
+                                .replace_navigation_function_calls(&mut replace)?,
+                        },
+                        alias,
+                    })
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let where_condition = where_condition
+            .map(|condition| condition.replace_navigation_function_calls(&mut replace))
+            .transpose()?;
+
+        Ok((select_list, where_condition, navigation_calls))
+    }
+
+    fn create_navigation_op(
+        navigation_calls: Vec<(NavigationFunctionCall, ColumnReference)>,
+        expr_resolver: &mut ExprResolver,
+    ) -> Option<NavigationOp> {
+        let params = navigation_calls
+            .into_iter()
+            .map(|(call, derived)| {
+                let expr = expr_resolver.register_value_expr(*call.expr);
+                let partition_by = call
+                    .partition_by
+                    .into_iter()
+                    .map(|key| expr_resolver.register_value_expr(key))
+                    .collect();
+                NavigationParameter::new(call.func, expr, call.offset, partition_by, derived)
+            })
+            .collect::<Vec<_>>();
+
+        (!params.is_empty()).then_some(NavigationOp { params })
+    }
+
+    /// Aggregation with a window clause makes `GroupAggregateWindowOp` and aggregation without it makes `ContinuousAggregateOp`.
+    ///
+    /// # Failures
+    ///
+    /// `SpringError::Sql` when:
+    ///   - CHANGELOG_OP is used in SELECT without aggregation or with a window clause.
+    ///   - RESAMPLE_TIME is used in SELECT without RESAMPLE window.
+    ///   - RESAMPLE window is used in SELECT without aggregation.
+    fn create_group_aggr_ops(
         &self,
         projection_op: &ProjectionOp,
         expr_resolver: &mut ExprResolver,
-    ) -> Result<Option<GroupAggregateWindowOp>> {
+    ) -> Result<(
+        Option<GroupAggregateWindowOp>,
+        Option<ContinuousAggregateOp>,
+    )> {
         let window_param = self.create_window_param();
         let group_aggr_param = self.create_group_aggr_param(expr_resolver, projection_op)?;
+        let changelog_op = self.select_list_label(projection_op, &ValueExpr::ChangelogOp);
+        let resample_time = self.select_list_label(projection_op, &ValueExpr::ResampleTime);
+
+        let is_resample = window_param
+            .as_ref()
+            .map_or(false, |window_param| window_param.resample_fill().is_some());
+        if resample_time.is_some() && !is_resample {
+            return Err(SpringError::Sql(anyhow!(
+                "RESAMPLE_TIME is only supported in SELECT with RESAMPLE window"
+            )));
+        }
 
-        match (window_param, group_aggr_param) {
-            (Some(window_param), Some(group_aggr_param)) => Ok(Some(GroupAggregateWindowOp {
-                window_param,
-                op_param: WindowOperationParameter::Aggregate(group_aggr_param),
-            })),
-            _ => Ok(None),
+        match (window_param, group_aggr_param, changelog_op) {
+            (Some(_), _, Some(_)) => Err(SpringError::Sql(anyhow!(
+                "CHANGELOG_OP is not supported in SELECT with window clause"
+            ))),
+            (Some(window_param), Some(group_aggr_param), None) => Ok((
+                Some(GroupAggregateWindowOp {
+                    window_param,
+                    op_param: WindowOperationParameter::Aggregate(group_aggr_param),
+                    resample_time,
+                }),
+                None,
+            )),
+            (None, Some(aggr_param), changelog_op) => Ok((
+                None,
+                Some(ContinuousAggregateOp {
+                    aggr_param,
+                    changelog_op,
+                }),
+            )),
+            (None, None, Some(_)) => Err(SpringError::Sql(anyhow!(
+                "CHANGELOG_OP is only supported in SELECT with aggregation"
+            ))),
+            (Some(WindowParameter::TimedResampleWindow { .. }), None, None) => {
+                Err(SpringError::Sql(anyhow!(
+                    "RESAMPLE window is only supported in SELECT with aggregation"
+                )))
+            }
+            (_, None, None) => Ok((None, None)),
         }
     }
 
+    /// Label of a pseudo column (`CHANGELOG_OP`, `RESAMPLE_TIME`) in select list.
+    fn select_list_label(
+        &self,
+        projection_op: &ProjectionOp,
+        pseudo_column: &ValueExpr,
+    ) -> Option<ValueExprLabel> {
+        self.analyzer
+            .select_list()
+            .iter()
+            .zip(&projection_op.expr_labels)
+            .find_map(|(field, label)| match (field, label) {
+                (SelectFieldSyntax::ValueExpr { value_expr, .. }, ExprLabel::Value(label))
+                    if value_expr == pseudo_column =>
+                {
+                    Some(*label)
+                }
+                _ => None,
+            })
+    }
+
     fn create_window_param(&self) -> Option<WindowParameter> {
         self.analyzer.window_parameter()
     }
@@ -165,6 +397,7 @@ impl QueryPlanner {
                     aggr_func,
                     *aggr_label,
                     GroupByLabels::new(group_by_labels),
+                    None,
                 )))
             }
             0 => Ok(None),
@@ -172,6 +405,254 @@ impl QueryPlanner {
         }
     }
 
+    /// # Failures
+    ///
+    /// `SpringError::Sql` when SELECT DISTINCT STREAM:
+    ///   - does not have a window clause.
+    ///   - has aggregation or JOIN.
+    fn create_distinct_window_op(
+        &self,
+        projection_op: &ProjectionOp,
+        group_aggr_window: &Option<GroupAggregateWindowOp>,
+        join: &JoinOp,
+    ) -> Result<Option<DistinctWindowOp>> {
+        if !self.analyzer.distinct() {
+            return Ok(None);
+        }
+
+        let window_param = self.create_window_param().ok_or_else(|| {
+            SpringError::Sql(anyhow!(
+                "SELECT DISTINCT STREAM requires a window clause (use DEDUPLICATE ON ... WITHIN for unbounded streams)"
+            ))
+        })?;
+        if group_aggr_window.is_some() {
+            return Err(SpringError::Sql(anyhow!(
+                "SELECT DISTINCT STREAM with aggregation is not supported"
+            )));
+        }
+        if !matches!(join, JoinOp::Collect(_)) {
+            return Err(SpringError::Sql(anyhow!(
+                "SELECT DISTINCT STREAM with JOIN is not supported"
+            )));
+        }
+
+        let keys = projection_op
+            .expr_labels
+            .iter()
+            .map(|label| match label {
+                ExprLabel::Value(value_label) => Ok(*value_label),
+                ExprLabel::Aggr(_) => Err(SpringError::Sql(anyhow!(
+                    "SELECT DISTINCT STREAM with aggregation is not supported"
+                ))),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Some(DistinctWindowOp {
+            window_param,
+            op_param: WindowOperationParameter::Distinct(DistinctParameter::new(keys)),
+        }))
+    }
+
+    /// # Failures
+    ///
+    /// `SpringError::Sql` when MATCH_RECOGNIZE is used with a window clause or DISTINCT, or MATCH_RECOGNIZE itself is invalid.
+    fn create_match_recognize_op(
+        &self,
+        group_aggr_window: &Option<GroupAggregateWindowOp>,
+        expr_resolver: &mut ExprResolver,
+        pipeline: &Pipeline,
+    ) -> Result<Option<MatchRecognizeOp>> {
+        let match_recognize = match self.analyzer.match_recognize() {
+            Some(match_recognize) => match_recognize,
+            None => return Ok(None),
+        };
+
+        if group_aggr_window.is_some() || self.create_window_param().is_some() {
+            return Err(SpringError::Sql(anyhow!(
+                "MATCH_RECOGNIZE with a window clause is not supported"
+            )));
+        }
+        if self.analyzer.distinct() {
+            return Err(SpringError::Sql(anyhow!(
+                "MATCH_RECOGNIZE with SELECT DISTINCT STREAM is not supported"
+            )));
+        }
+
+        let match_param =
+            self.analyzer
+                .match_recognize_parameter(match_recognize, expr_resolver, pipeline)?;
+        Ok(Some(MatchRecognizeOp { match_param }))
+    }
+
+    fn create_unnest_op(&self, expr_resolver: &mut ExprResolver) -> Option<UnnestOp> {
+        self.analyzer.unnest().map(|unnest| {
+            let array = expr_resolver.register_value_expr(unnest.array);
+            let element = ColumnReference::Column {
+                stream_name: StreamName::new(unnest.alias.to_string()),
+                column_name: unnest.column_name,
+            };
+            UnnestOp {
+                unnest_param: UnnestParameter::new(array, element),
+            }
+        })
+    }
+
+    fn create_deduplicate_op(
+        &self,
+        expr_resolver: &mut ExprResolver,
+    ) -> Result<Option<DeduplicateOp>> {
+        Ok(self.analyzer.deduplicate().map(|dedup| {
+            let keys = dedup
+                .keys
+                .into_iter()
+                .map(|key| expr_resolver.register_value_expr(key))
+                .collect();
+            DeduplicateOp {
+                dedup_param: DeduplicateParameter::new(keys, dedup.within),
+            }
+        }))
+    }
+
+    fn create_changes_only_op(&self, expr_resolver: &mut ExprResolver) -> Option<ChangesOnlyOp> {
+        self.analyzer.changes_only().map(|changes_only| {
+            let columns = changes_only
+                .columns
+                .into_iter()
+                .map(|column| expr_resolver.register_value_expr(column))
+                .collect();
+            let partition_by = changes_only
+                .partition_by
+                .into_iter()
+                .map(|key| expr_resolver.register_value_expr(key))
+                .collect();
+            ChangesOnlyOp {
+                changes_only_param: ChangesOnlyParameter::new(columns, partition_by),
+            }
+        })
+    }
+
+    fn create_silence_alert_op(&self, expr_resolver: &mut ExprResolver) -> Option<SilenceAlertOp> {
+        self.analyzer.silence_alert().map(|silence_alert| {
+            let partition_by = silence_alert
+                .partition_by
+                .into_iter()
+                .map(|key| expr_resolver.register_value_expr(key))
+                .collect();
+            SilenceAlertOp {
+                silence_alert_param: SilenceAlertParameter::new(
+                    silence_alert.silent_for,
+                    silence_alert.time,
+                    partition_by,
+                ),
+            }
+        })
+    }
+
+    /// Top-N is applied to the output of the last window: aggregation window if exists, otherwise JOIN window.
+    ///
+    /// # Failures
+    ///
+    /// `SpringError::Sql` when:
+    ///   - SELECT does not have any window.
+    ///   - SELECT has RESAMPLE window.
+    ///   - sort key is not an aggregate expression nor a GROUP BY element for aggregation.
+    fn set_top_n_param(
+        &self,
+        order_by: OrderBySyntax,
+        group_aggr_window: &mut Option<GroupAggregateWindowOp>,
+        join: &mut JoinOp,
+        expr_resolver: &mut ExprResolver,
+    ) -> Result<()> {
+        match (group_aggr_window, join) {
+            (Some(group_aggr_window), _) => {
+                if group_aggr_window.window_param.resample_fill().is_some() {
+                    return Err(SpringError::Sql(anyhow!(
+                        "ORDER BY ... LIMIT with RESAMPLE window is not supported"
+                    )));
+                }
+                let aggr_param = match &mut group_aggr_window.op_param {
+                    WindowOperationParameter::Aggregate(aggr_param) => aggr_param,
+                    WindowOperationParameter::Join(_) | WindowOperationParameter::Distinct(_) => {
+                        unreachable!()
+                    }
+                };
+                let sort_key = self.aggr_sort_key(&order_by.sort_key, aggr_param, expr_resolver)?;
+                aggr_param.top_n =
+                    Some(TopNParameter::new(sort_key, order_by.order, order_by.limit));
+                Ok(())
+            }
+            (None, JoinOp::JoinWindow(join_window_op)) => {
+                let sort_key = match order_by.sort_key {
+                    SortKeySyntax::ValueExpr(value_expr) => {
+                        expr_resolver.register_value_expr(value_expr)
+                    }
+                    SortKeySyntax::Alias(alias) => {
+                        expr_resolver.resolve_value_alias(ValueAlias::new(alias))?
+                    }
+                    SortKeySyntax::AggrExpr(_) => {
+                        return Err(SpringError::Sql(anyhow!(
+                            "ORDER BY aggregate expression requires aggregation"
+                        )))
+                    }
+                };
+                join_window_op.join_param.top_n = Some(TopNParameter::new(
+                    ExprLabel::Value(sort_key),
+                    order_by.order,
+                    order_by.limit,
+                ));
+                Ok(())
+            }
+            (None, _) => Err(SpringError::Sql(anyhow!(
+                "ORDER BY ... LIMIT is only supported in SELECT with window clause"
+            ))),
+        }
+    }
+
+    fn aggr_sort_key(
+        &self,
+        sort_key: &SortKeySyntax,
+        aggr_param: &AggregateParameter,
+        expr_resolver: &ExprResolver,
+    ) -> Result<ExprLabel> {
+        let group_by_labels = aggr_param.group_by.as_labels();
+
+        let label = match sort_key {
+            SortKeySyntax::AggrExpr(aggr_expr) => {
+                let selected_aggr_expr = expr_resolver.resolve_aggr_expr(aggr_param.aggr_expr);
+                (&selected_aggr_expr == aggr_expr).then_some(ExprLabel::Aggr(aggr_param.aggr_expr))
+            }
+            SortKeySyntax::ValueExpr(value_expr) => self
+                .analyzer
+                .grouping_elements()
+                .iter()
+                .zip(group_by_labels)
+                .find_map(|(grouping_elem, label)| match grouping_elem {
+                    GroupingElementSyntax::ValueExpr(expr) if expr == value_expr => {
+                        Some(ExprLabel::Value(*label))
+                    }
+                    _ => None,
+                }),
+            SortKeySyntax::Alias(alias) => {
+                if let Ok(label) = expr_resolver.resolve_value_alias(ValueAlias::new(alias.clone()))
+                {
+                    group_by_labels
+                        .contains(&label)
+                        .then_some(ExprLabel::Value(label))
+                } else {
+                    let label = expr_resolver.resolve_aggr_alias(AggrAlias::new(alias.clone()))?;
+                    (label == aggr_param.aggr_expr).then_some(ExprLabel::Aggr(label))
+                }
+            }
+        };
+
+        label.ok_or_else(|| {
+            SpringError::Sql(anyhow!(
+                "ORDER BY {:?} must be an aggregate expression or a GROUP BY element",
+                sort_key
+            ))
+        })
+    }
+
     fn create_join_op(
         &self,
         expr_resolver: &mut ExprResolver,
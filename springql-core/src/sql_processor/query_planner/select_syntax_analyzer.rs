@@ -1,8 +1,11 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
+mod distinct;
 mod field;
 mod from_item;
 mod group_aggregate;
+mod match_recognize;
+mod order_by;
 mod window;
 
 use crate::sql_processor::sql_parser::SelectStreamSyntax;
@@ -0,0 +1,20 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::sql_processor::{
+    query_planner::SelectSyntaxAnalyzer,
+    sql_parser::{ChangesOnlySyntax, DeduplicateSyntax},
+};
+
+impl SelectSyntaxAnalyzer {
+    pub fn distinct(&self) -> bool {
+        self.select_syntax.distinct
+    }
+
+    pub fn deduplicate(&self) -> Option<DeduplicateSyntax> {
+        self.select_syntax.deduplicate.clone()
+    }
+
+    pub fn changes_only(&self) -> Option<ChangesOnlySyntax> {
+        self.select_syntax.changes_only.clone()
+    }
+}
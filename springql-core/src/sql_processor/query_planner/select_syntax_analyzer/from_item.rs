@@ -1,17 +1,29 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
+use anyhow::anyhow;
+
 use crate::{
-    api::error::Result,
+    api::error::{Result, SpringError},
     expr_resolver::ExprResolver,
-    pipeline::{JoinParameter, Pipeline},
+    pipeline::{ColumnReference, IntervalJoinParameter, JoinParameter, Pipeline, StreamName},
     sql_processor::{
         query_planner::SelectSyntaxAnalyzer,
-        sql_parser::{FromItemSyntax, SubFromItemSyntax},
+        sql_parser::{
+            FromItemSyntax, IntervalBoundSyntax, IntervalConditionSyntax, IntervalOffsetSyntax,
+            SubFromItemSyntax, UnnestSyntax,
+        },
+    },
+    stream_engine::{
+        command::{CollectOp, IntervalJoinOp, JoinOp, JoinWindowOp},
+        time::{SpringDuration, SpringEventDuration},
     },
-    stream_engine::command::{CollectOp, JoinOp, JoinWindowOp},
 };
 
 impl SelectSyntaxAnalyzer {
+    pub fn unnest(&self) -> Option<UnnestSyntax> {
+        self.select_syntax.unnest.clone()
+    }
+
     pub fn join_op(&self, expr_resolver: &mut ExprResolver, pipeline: &Pipeline) -> Result<JoinOp> {
         match self.select_syntax.from_item.clone() {
             FromItemSyntax::StreamVariant(sub_from_item) => {
@@ -23,6 +35,7 @@ impl SelectSyntaxAnalyzer {
                 right,
                 join_type,
                 on_expr,
+                interval_condition,
             } => {
                 let right_sub = match right.as_ref() {
                     FromItemSyntax::StreamVariant(sub_from_item) => sub_from_item,
@@ -42,19 +55,137 @@ impl SelectSyntaxAnalyzer {
                 let on_expr_label = expr_resolver.register_value_expr(on_expr);
 
                 let join_param =
-                    JoinParameter::new(join_type, left_colrefs, right_colrefs, on_expr_label);
-
-                let window_param = self
-                    .window_parameter()
-                    .expect("JOIN must take window clause");
-
-                Ok(JoinOp::JoinWindow(JoinWindowOp {
-                    left: left_collect_op,
-                    right: right_collect_op,
-                    window_param,
-                    join_param,
-                }))
+                    JoinParameter::new(join_type, left_colrefs, right_colrefs, on_expr_label, None);
+
+                if let Some(interval_condition) = interval_condition {
+                    let interval_param = Self::interval_join_parameter(
+                        &interval_condition,
+                        &left_sub.stream_name,
+                        &right_sub.stream_name,
+                        pipeline,
+                    )?;
+
+                    Ok(JoinOp::IntervalJoin(IntervalJoinOp {
+                        left: left_collect_op,
+                        right: right_collect_op,
+                        interval_param,
+                        join_param,
+                    }))
+                } else {
+                    let window_param = self
+                        .window_parameter()
+                        .expect("JOIN must take window clause");
+                    if window_param.resample_fill().is_some() {
+                        return Err(SpringError::Sql(anyhow!(
+                            "JOIN with RESAMPLE window is not supported"
+                        )));
+                    }
+
+                    Ok(JoinOp::JoinWindow(JoinWindowOp {
+                        left: left_collect_op,
+                        right: right_collect_op,
+                        window_param,
+                        join_param,
+                    }))
+                }
+            }
+        }
+    }
+
+    /// Normalizes `x.ts BETWEEN y.ts - lower AND y.ts + upper` into bounds of right ROWTIME relative to left ROWTIME.
+    ///
+    /// # Failures
+    ///
+    /// `SpringError::Sql` when:
+    ///   - a column in the condition is not the ROWTIME column of the left or right stream.
+    ///   - both sides of BETWEEN refer to the same stream.
+    ///   - the lower bound is not `y.ts [- duration]` or the upper bound is not `y.ts [+ duration]`.
+    fn interval_join_parameter(
+        interval_condition: &IntervalConditionSyntax,
+        left_stream: &StreamName,
+        right_stream: &StreamName,
+        pipeline: &Pipeline,
+    ) -> Result<IntervalJoinParameter> {
+        let subject_stream = Self::rowtime_stream(
+            &interval_condition.column_reference,
+            left_stream,
+            right_stream,
+            pipeline,
+        )?;
+        let lower_stream = Self::rowtime_stream(
+            &interval_condition.lower.column_reference,
+            left_stream,
+            right_stream,
+            pipeline,
+        )?;
+        let upper_stream = Self::rowtime_stream(
+            &interval_condition.upper.column_reference,
+            left_stream,
+            right_stream,
+            pipeline,
+        )?;
+        if subject_stream == lower_stream || subject_stream == upper_stream {
+            return Err(SpringError::Sql(anyhow!(
+                "interval join condition must compare ROWTIME of a stream with ROWTIME of the other stream"
+            )));
+        }
+
+        let lower = Self::interval_offset(&interval_condition.lower, false)?;
+        let upper = Self::interval_offset(&interval_condition.upper, true)?;
+
+        // subject BETWEEN other - lower AND other + upper
+        if subject_stream == right_stream {
+            Ok(IntervalJoinParameter::new(lower, upper))
+        } else {
+            Ok(IntervalJoinParameter::new(upper, lower))
+        }
+    }
+
+    /// Returns the stream whose ROWTIME column is `colref`.
+    fn rowtime_stream<'a>(
+        colref: &ColumnReference,
+        left_stream: &'a StreamName,
+        right_stream: &'a StreamName,
+        pipeline: &Pipeline,
+    ) -> Result<&'a StreamName> {
+        let err = || {
+            SpringError::Sql(anyhow!(
+                "`{:?}` in interval join condition must be ROWTIME column of joined streams",
+                colref
+            ))
+        };
+
+        match colref {
+            ColumnReference::Column {
+                stream_name,
+                column_name,
+            } => {
+                let stream = [left_stream, right_stream]
+                    .into_iter()
+                    .find(|s| *s == stream_name)
+                    .ok_or_else(err)?;
+                let event_time = pipeline.get_stream(stream)?.shape().event_time().cloned();
+                if event_time.as_ref() == Some(column_name) {
+                    Ok(stream)
+                } else {
+                    Err(err())
+                }
             }
+            ColumnReference::PTime { .. } | ColumnReference::Derived { .. } => Err(err()),
+        }
+    }
+
+    fn interval_offset(bound: &IntervalBoundSyntax, is_upper: bool) -> Result<SpringEventDuration> {
+        match (bound.offset, is_upper) {
+            (None, _) => Ok(SpringEventDuration::from_secs(0)),
+            (Some(IntervalOffsetSyntax::Minus(d)), false)
+            | (Some(IntervalOffsetSyntax::Plus(d)), true) => Ok(d),
+            (Some(_), false) => Err(SpringError::Sql(anyhow!(
+                "lower bound of interval join must be `ROWTIME - duration`"
+            ))),
+            (Some(_), true) => Err(SpringError::Sql(anyhow!(
+                "upper bound of interval join must be `ROWTIME + duration`"
+            ))),
         }
     }
 
@@ -0,0 +1,150 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use anyhow::anyhow;
+
+use crate::{
+    api::error::{Result, SpringError},
+    expr_resolver::ExprResolver,
+    pipeline::{ColumnReference, MatchRecognizeParameter, PatternElement, Pipeline, StreamModel},
+    sql_processor::{
+        query_planner::SelectSyntaxAnalyzer,
+        sql_parser::{FromItemSyntax, MatchRecognizeSyntax},
+    },
+};
+
+impl SelectSyntaxAnalyzer {
+    pub fn match_recognize(&self) -> Option<MatchRecognizeSyntax> {
+        self.select_syntax.match_recognize.clone()
+    }
+
+    /// # Failures
+    ///
+    /// `SpringError::Sql` when:
+    ///   - FROM clause has JOIN.
+    ///   - ORDER BY is not the ROWTIME column (or PTIME for streams without ROWTIME column).
+    ///   - a variable in DEFINE does not appear in PATTERN or is defined twice.
+    ///   - a pattern variable has the same name as the stream.
+    ///   - a measure has the same name as a column of the stream.
+    pub fn match_recognize_parameter(
+        &self,
+        match_recognize: MatchRecognizeSyntax,
+        expr_resolver: &mut ExprResolver,
+        pipeline: &Pipeline,
+    ) -> Result<MatchRecognizeParameter> {
+        let stream_name = match &self.select_syntax.from_item {
+            FromItemSyntax::StreamVariant(sub_from_item) => &sub_from_item.stream_name,
+            FromItemSyntax::JoinVariant { .. } => {
+                return Err(SpringError::Sql(anyhow!(
+                    "MATCH_RECOGNIZE with JOIN is not supported"
+                )))
+            }
+        };
+        let stream = pipeline.get_stream(stream_name)?;
+
+        Self::validate_match_recognize_order_by(&match_recognize.order_by, &stream)?;
+
+        for (variable, _) in &match_recognize.defines {
+            if !match_recognize.pattern.iter().any(|(v, _)| v == variable) {
+                return Err(SpringError::Sql(anyhow!(
+                    "pattern variable `{}` in DEFINE does not appear in PATTERN",
+                    variable
+                )));
+            }
+            if match_recognize
+                .defines
+                .iter()
+                .filter(|(v, _)| v == variable)
+                .count()
+                > 1
+            {
+                return Err(SpringError::Sql(anyhow!(
+                    "pattern variable `{}` is defined twice",
+                    variable
+                )));
+            }
+        }
+        if let Some((variable, _)) = match_recognize
+            .pattern
+            .iter()
+            .find(|(v, _)| v == stream_name)
+        {
+            return Err(SpringError::Sql(anyhow!(
+                "pattern variable `{}` must not be the same as the stream name",
+                variable
+            )));
+        }
+
+        let column_names = stream.shape().column_names();
+        let measures = match_recognize
+            .measures
+            .into_iter()
+            .map(|(value_expr, column_name)| {
+                if column_names.contains(&column_name) {
+                    return Err(SpringError::Sql(anyhow!(
+                        "measure `{}` conflicts with a column of stream `{}`",
+                        column_name,
+                        stream_name
+                    )));
+                }
+                let label = expr_resolver.register_value_expr(value_expr);
+                let colref = ColumnReference::Column {
+                    stream_name: stream_name.clone(),
+                    column_name,
+                };
+                Ok((label, colref))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let partition_by = match_recognize
+            .partition_by
+            .into_iter()
+            .map(|key| expr_resolver.register_value_expr(key))
+            .collect();
+
+        let mut defines = match_recognize.defines;
+        let pattern = match_recognize
+            .pattern
+            .into_iter()
+            .map(|(variable, quantifier)| {
+                let condition = defines
+                    .iter()
+                    .position(|(v, _)| v == &variable)
+                    .map(|idx| expr_resolver.register_value_expr(defines.remove(idx).1));
+                PatternElement::new(variable, quantifier, condition)
+            })
+            .collect::<Vec<_>>();
+
+        Ok(MatchRecognizeParameter::new(
+            partition_by,
+            measures,
+            pattern,
+            match_recognize.within,
+        ))
+    }
+
+    fn validate_match_recognize_order_by(
+        order_by: &ColumnReference,
+        stream: &StreamModel,
+    ) -> Result<()> {
+        let is_rowtime = match (order_by, stream.shape().event_time()) {
+            (
+                ColumnReference::Column {
+                    stream_name,
+                    column_name,
+                },
+                Some(event_time),
+            ) => stream_name == stream.name() && column_name == event_time,
+            (ColumnReference::PTime { stream_name }, None) => stream_name == stream.name(),
+            _ => false,
+        };
+
+        if is_rowtime {
+            Ok(())
+        } else {
+            Err(SpringError::Sql(anyhow!(
+                "ORDER BY in MATCH_RECOGNIZE must be the ROWTIME column of stream `{}`",
+                stream.name()
+            )))
+        }
+    }
+}
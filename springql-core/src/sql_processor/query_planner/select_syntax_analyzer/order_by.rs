@@ -0,0 +1,9 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::sql_processor::{query_planner::SelectSyntaxAnalyzer, sql_parser::OrderBySyntax};
+
+impl SelectSyntaxAnalyzer {
+    pub fn order_by(&self) -> Option<OrderBySyntax> {
+        self.select_syntax.order_by.clone()
+    }
+}
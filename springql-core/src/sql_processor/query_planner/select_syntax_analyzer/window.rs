@@ -1,9 +1,16 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
-use crate::{pipeline::WindowParameter, sql_processor::query_planner::SelectSyntaxAnalyzer};
+use crate::{
+    pipeline::WindowParameter,
+    sql_processor::{query_planner::SelectSyntaxAnalyzer, sql_parser::SilenceAlertSyntax},
+};
 
 impl SelectSyntaxAnalyzer {
     pub fn window_parameter(&self) -> Option<WindowParameter> {
         self.select_syntax.window_clause.clone()
     }
+
+    pub fn silence_alert(&self) -> Option<SilenceAlertSyntax> {
+        self.select_syntax.silence_alert.clone()
+    }
 }
@@ -12,15 +12,16 @@ use pest::{iterators::Pairs, Parser};
 use crate::{
     api::error::{Result, SpringError},
     expression::{
-        AggrExpr, BinaryExpr, BinaryOperator, ComparisonFunction, FunctionCall, LogicalFunction,
-        NumericalFunction, UnaryOperator, ValueExpr,
+        parse_utc_offset, AggrExpr, BinaryExpr, BinaryOperator, ComparisonFunction, FunctionCall,
+        GeoPolygon, JsonPath, LogicalFunction, NavigationFunction, NavigationFunctionCall,
+        NumericalFunction, TimestampFormat, UnaryOperator, ValueExpr,
     },
     pipeline::{
         AggrAlias, AggregateFunctionParameter, ColumnConstraint, ColumnDataType, ColumnDefinition,
-        ColumnName, ColumnReference, CorrelationAlias, JoinType, OptionsBuilder, PumpName,
-        SinkWriterModel, SinkWriterName, SinkWriterType, SourceReaderModel, SourceReaderName,
-        SourceReaderType, SqlType, StreamModel, StreamName, StreamShape, ValueAlias,
-        WindowParameter,
+        ColumnName, ColumnReference, CorrelationAlias, JoinType, OptionsBuilder, PatternQuantifier,
+        PumpName, ResampleFill, SinkWriterModel, SinkWriterName, SinkWriterType, SortOrder,
+        SourceReaderModel, SourceReaderName, SourceReaderType, SqlType, StreamModel, StreamName,
+        StreamShape, ValueAlias, WindowParameter, WindowTime,
     },
     sql_processor::sql_parser::{
         parse_success::{CreatePump, ParseSuccess},
@@ -29,13 +30,17 @@ use crate::{
             helper::{parse_child, parse_child_seq, self_as_str, try_parse_child, FnParseParams},
         },
         syntax::{
-            ColumnConstraintSyntax, DurationFunction, FromItemSyntax, GroupingElementSyntax,
-            OptionSyntax, SelectFieldSyntax, SelectStreamSyntax, SubFromItemSyntax,
+            ChangesOnlySyntax, ColumnConstraintSyntax, DeduplicateSyntax, DurationFunction,
+            ElementAccessorSyntax, FromItemSyntax, GroupingElementSyntax, IntervalBoundSyntax,
+            IntervalConditionSyntax, IntervalOffsetSyntax, MatchRecognizeSyntax, OptionSyntax,
+            OrderBySyntax, SelectFieldSyntax, SelectStreamSyntax, SilenceAlertSyntax,
+            SortKeySyntax, SubFromItemSyntax, UnnestSyntax,
         },
     },
     stream_engine::{
         command::InsertPlan,
-        time::{SpringDuration, SpringEventDuration},
+        decimal::MAX_DECIMAL_PRECISION,
+        time::{SpringDuration, SpringEventDuration, TimestampField},
         NnSqlValue, SqlValue,
     },
 };
@@ -201,22 +206,39 @@ impl PestParserImpl {
         )?;
 
         let event_duration = match duration_function {
+            DurationFunction::Micros => Ok(SpringEventDuration::from_micros(
+                integer_constant.to_i64()? as u64,
+            )),
             DurationFunction::Millis => Ok(SpringEventDuration::from_millis(
                 integer_constant.to_i64()? as u64,
             )),
             DurationFunction::Secs => Ok(SpringEventDuration::from_secs(
                 integer_constant.to_i64()? as u64,
             )),
+            DurationFunction::Minutes => Self::duration_secs_of(&integer_constant, 60),
+            DurationFunction::Hours => Self::duration_secs_of(&integer_constant, 60 * 60),
         }?;
 
         Ok(SqlValue::NotNull(NnSqlValue::Duration(event_duration)))
     }
 
+    /// DURATION of `count` units of `unit_secs` seconds.
+    fn duration_secs_of(count: &SqlValue, unit_secs: u64) -> Result<SpringEventDuration> {
+        let count = count.to_i64()?;
+        (count as u64)
+            .checked_mul(unit_secs)
+            .map(SpringEventDuration::from_secs)
+            .ok_or_else(|| SpringError::Sql(anyhow!("duration `{}` is too large", count)))
+    }
+
     fn parse_duration_function(mut params: FnParseParams) -> Result<DurationFunction> {
         let s = self_as_str(&mut params);
         match s.to_lowercase().as_ref() {
+            "duration_micros" => Ok(DurationFunction::Micros),
             "duration_millis" => Ok(DurationFunction::Millis),
             "duration_secs" => Ok(DurationFunction::Secs),
+            "duration_minutes" => Ok(DurationFunction::Minutes),
+            "duration_hours" => Ok(DurationFunction::Hours),
             _ => Err(SpringError::Sql(anyhow!(
                 "duration function `{}` is invalid",
                 s
@@ -245,8 +267,14 @@ impl PestParserImpl {
         match s.to_lowercase().as_str() {
             "=" => Ok(BinaryOperator::Equal),
             "+" => Ok(BinaryOperator::Add),
+            "-" => Ok(BinaryOperator::Sub),
             "*" => Ok(BinaryOperator::Mul),
+            "/" => Ok(BinaryOperator::Div),
             "and" => Ok(BinaryOperator::And),
+            "&" => Ok(BinaryOperator::BitAnd),
+            "|" => Ok(BinaryOperator::BitOr),
+            "<<" => Ok(BinaryOperator::Shl),
+            ">>" => Ok(BinaryOperator::Shr),
             _ => Err(SpringError::Sql(anyhow!(
                 "Does not match any child rule of binary_operator.",
             ))),
@@ -528,6 +556,7 @@ impl PestParserImpl {
      */
 
     fn parse_select_stream(mut params: FnParseParams) -> Result<SelectStreamSyntax> {
+        let distinct = try_parse_child(&mut params, Rule::distinct, |_| Ok(()), identity)?;
         let fields = parse_child_seq(
             &mut params,
             Rule::select_field,
@@ -540,6 +569,42 @@ impl PestParserImpl {
             Self::parse_from_item,
             identity,
         )?;
+        let unnest = try_parse_child(
+            &mut params,
+            Rule::unnest_item,
+            Self::parse_unnest_item,
+            identity,
+        )?;
+        let where_condition = try_parse_child(
+            &mut params,
+            Rule::where_clause,
+            Self::parse_where_clause,
+            identity,
+        )?;
+        let match_recognize = try_parse_child(
+            &mut params,
+            Rule::match_recognize_clause,
+            Self::parse_match_recognize_clause,
+            identity,
+        )?;
+        let deduplicate = try_parse_child(
+            &mut params,
+            Rule::deduplicate_clause,
+            Self::parse_deduplicate_clause,
+            identity,
+        )?;
+        let changes_only = try_parse_child(
+            &mut params,
+            Rule::changes_only_clause,
+            Self::parse_changes_only_clause,
+            identity,
+        )?;
+        let silence_alert = try_parse_child(
+            &mut params,
+            Rule::silence_alert_clause,
+            Self::parse_silence_alert_clause,
+            identity,
+        )?;
         let grouping_elements = try_parse_child(
             &mut params,
             Rule::group_by_clause,
@@ -552,12 +617,26 @@ impl PestParserImpl {
             Self::parse_window_clause,
             identity,
         )?;
+        let order_by = try_parse_child(
+            &mut params,
+            Rule::order_by_clause,
+            Self::parse_order_by_clause,
+            identity,
+        )?;
 
         Ok(SelectStreamSyntax {
+            distinct: distinct.is_some(),
             fields,
             from_item,
+            unnest,
+            match_recognize,
+            deduplicate,
+            changes_only,
+            silence_alert,
+            where_condition,
             grouping_elements: grouping_elements.unwrap_or_default(),
             window_clause,
+            order_by,
         })
     }
 
@@ -626,18 +705,32 @@ impl PestParserImpl {
                 Self::parse_from_item,
                 identity,
             )?;
-            let on_expr = parse_child(
+            let (on_expr, interval_condition) = match try_parse_child(
                 &mut params,
-                Rule::condition,
-                Self::parse_condition,
+                Rule::interval_join_condition,
+                Self::parse_interval_join_condition,
                 identity,
-            )?;
+            )? {
+                Some((on_expr, interval_condition)) => {
+                    (on_expr, Some(Box::new(interval_condition)))
+                }
+                None => {
+                    let on_expr = parse_child(
+                        &mut params,
+                        Rule::condition,
+                        Self::parse_condition,
+                        identity,
+                    )?;
+                    (on_expr, None)
+                }
+            };
 
             Ok(FromItemSyntax::JoinVariant {
                 left: sub_from_item.clone(),
                 right: Box::new(right_from_item),
                 join_type,
                 on_expr,
+                interval_condition,
             })
         });
 
@@ -660,6 +753,32 @@ impl PestParserImpl {
         Ok(SubFromItemSyntax { stream_name, alias })
     }
 
+    fn parse_unnest_item(mut params: FnParseParams) -> Result<UnnestSyntax> {
+        let array = parse_child(
+            &mut params,
+            Rule::value_expr,
+            Self::parse_value_expr,
+            identity,
+        )?;
+        let alias = parse_child(
+            &mut params,
+            Rule::correlation_alias,
+            Self::parse_correlation_alias,
+            identity,
+        )?;
+        let column_name = parse_child(
+            &mut params,
+            Rule::column_name,
+            Self::parse_column_name,
+            identity,
+        )?;
+        Ok(UnnestSyntax {
+            array,
+            alias,
+            column_name,
+        })
+    }
+
     fn parse_join_type(mut params: FnParseParams) -> Result<JoinType> {
         let s = self_as_str(&mut params);
         match s.to_lowercase().as_str() {
@@ -671,232 +790,883 @@ impl PestParserImpl {
         }
     }
 
-    fn parse_group_by_clause(mut params: FnParseParams) -> Result<Vec<GroupingElementSyntax>> {
-        parse_child_seq(
+    /// Key equalities are merged into an ON expression (TRUE if no key equality is given).
+    fn parse_interval_join_condition(
+        mut params: FnParseParams,
+    ) -> Result<(ValueExpr, IntervalConditionSyntax)> {
+        let key_equalities = parse_child_seq(
             &mut params,
-            Rule::grouping_element,
-            &Self::parse_grouping_element,
+            Rule::join_key_equality,
+            &Self::parse_join_key_equality,
             &identity,
-        )
+        )?;
+        let interval_condition = parse_child(
+            &mut params,
+            Rule::interval_condition,
+            Self::parse_interval_condition,
+            identity,
+        )?;
+
+        let on_expr = key_equalities
+            .into_iter()
+            .reduce(|left, right| {
+                ValueExpr::BinaryExpr(BinaryExpr::LogicalFunctionVariant(
+                    LogicalFunction::AndVariant {
+                        left: Box::new(left),
+                        right: Box::new(right),
+                    },
+                ))
+            })
+            .unwrap_or(ValueExpr::Constant(SqlValue::NotNull(NnSqlValue::Boolean(
+                true,
+            ))));
+
+        Ok((on_expr, interval_condition))
     }
 
-    fn parse_grouping_element(mut params: FnParseParams) -> Result<GroupingElementSyntax> {
-        try_parse_child(
+    fn parse_join_key_equality(mut params: FnParseParams) -> Result<ValueExpr> {
+        let left = parse_child(
             &mut params,
-            Rule::value_expr,
-            Self::parse_value_expr,
-            GroupingElementSyntax::ValueExpr,
-        )?
-        .or(try_parse_child(
+            Rule::column_reference,
+            Self::parse_column_reference,
+            ValueExpr::ColumnReference,
+        )?;
+        let right = parse_child(
             &mut params,
-            Rule::value_alias,
-            Self::parse_value_alias,
-            GroupingElementSyntax::ValueAlias,
-        )?)
-        .ok_or_else(|| {
-            SpringError::Sql(anyhow!("Failed to parse grouping element: {}", params.sql))
-        })
+            Rule::column_reference,
+            Self::parse_column_reference,
+            ValueExpr::ColumnReference,
+        )?;
+        Ok(ValueExpr::BinaryExpr(
+            BinaryExpr::ComparisonFunctionVariant(ComparisonFunction::EqualVariant {
+                left: Box::new(left),
+                right: Box::new(right),
+            }),
+        ))
     }
 
-    fn parse_window_clause(mut params: FnParseParams) -> Result<WindowParameter> {
-        try_parse_child(
-            &mut params,
-            Rule::fixed_window_clause,
-            Self::parse_fixed_window_clause,
-            identity,
-        )?
-        .or(try_parse_child(
+    fn parse_interval_condition(mut params: FnParseParams) -> Result<IntervalConditionSyntax> {
+        let column_reference = parse_child(
             &mut params,
-            Rule::sliding_window_clause,
-            Self::parse_sliding_window_clause,
+            Rule::column_reference,
+            Self::parse_column_reference,
             identity,
-        )?)
-        .ok_or_else(|| SpringError::Sql(anyhow!("Failed to parse window clause: {}", params.sql)))
-    }
-
-    fn parse_fixed_window_clause(mut params: FnParseParams) -> Result<WindowParameter> {
-        let length = parse_child(
+        )?;
+        let lower = parse_child(
             &mut params,
-            Rule::window_length,
-            Self::parse_window_length,
+            Rule::interval_bound,
+            Self::parse_interval_bound,
             identity,
         )?;
-        let length = length.to_event_duration()?;
-
-        let allowed_delay = parse_child(
+        let upper = parse_child(
             &mut params,
-            Rule::allowed_delay,
-            Self::parse_allowed_delay,
+            Rule::interval_bound,
+            Self::parse_interval_bound,
             identity,
         )?;
-        let allowed_delay = allowed_delay.to_event_duration()?;
-
-        Ok(WindowParameter::TimedFixedWindow {
-            length,
-            allowed_delay,
+        Ok(IntervalConditionSyntax {
+            column_reference,
+            lower,
+            upper,
         })
     }
 
-    fn parse_sliding_window_clause(mut params: FnParseParams) -> Result<WindowParameter> {
-        let length = parse_child(
+    fn parse_interval_bound(mut params: FnParseParams) -> Result<IntervalBoundSyntax> {
+        let column_reference = parse_child(
             &mut params,
-            Rule::window_length,
-            Self::parse_window_length,
+            Rule::column_reference,
+            Self::parse_column_reference,
             identity,
         )?;
-        let length = length.to_event_duration()?;
 
-        let period = parse_child(
+        let offset = try_parse_child(
             &mut params,
-            Rule::window_period,
-            Self::parse_window_period,
+            Rule::interval_offset_operator,
+            |mut params| Ok(self_as_str(&mut params) == "+"),
             identity,
-        )?;
-        let period = period.to_event_duration()?;
+        )?
+        .map(|is_plus| {
+            let duration = parse_child(
+                &mut params,
+                Rule::duration_constant,
+                Self::parse_duration_constant,
+                identity,
+            )?
+            .to_event_duration()?;
+            Ok(if is_plus {
+                IntervalOffsetSyntax::Plus(duration)
+            } else {
+                IntervalOffsetSyntax::Minus(duration)
+            })
+        })
+        .transpose()?;
 
-        let allowed_delay = parse_child(
+        Ok(IntervalBoundSyntax {
+            column_reference,
+            offset,
+        })
+    }
+
+    fn parse_order_by_clause(mut params: FnParseParams) -> Result<OrderBySyntax> {
+        let sort_key = parse_child(&mut params, Rule::sort_key, Self::parse_sort_key, identity)?;
+        let order = try_parse_child(
             &mut params,
-            Rule::allowed_delay,
-            Self::parse_allowed_delay,
+            Rule::sort_order,
+            Self::parse_sort_order,
+            identity,
+        )?
+        .unwrap_or(SortOrder::Asc);
+        let limit = parse_child(
+            &mut params,
+            Rule::integer_constant,
+            Self::parse_integer_constant,
             identity,
         )?;
-        let allowed_delay = allowed_delay.to_event_duration()?;
+        let limit = usize::try_from(limit.to_i64()?)
+            .map_err(|_| SpringError::Sql(anyhow!("LIMIT must not be negative")))?;
 
-        Ok(WindowParameter::TimedSlidingWindow {
-            length,
-            period,
-            allowed_delay,
+        Ok(OrderBySyntax {
+            sort_key,
+            order,
+            limit,
         })
     }
 
-    fn parse_window_length(mut params: FnParseParams) -> Result<SqlValue> {
-        parse_child(
+    fn parse_sort_key(mut params: FnParseParams) -> Result<SortKeySyntax> {
+        try_parse_child(
             &mut params,
-            Rule::duration_constant,
-            Self::parse_duration_constant,
-            identity,
-        )
+            Rule::aggr_expr,
+            Self::parse_aggr_expr,
+            SortKeySyntax::AggrExpr,
+        )?
+        .or(try_parse_child(
+            &mut params,
+            Rule::value_expr,
+            Self::parse_value_expr,
+            SortKeySyntax::ValueExpr,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::sort_key_alias,
+            Self::parse_sort_key_alias,
+            SortKeySyntax::Alias,
+        )?)
+        .ok_or_else(|| SpringError::Sql(anyhow!("Does not match any child rule of sort_key.",)))
     }
-    fn parse_window_period(mut params: FnParseParams) -> Result<SqlValue> {
+
+    fn parse_sort_key_alias(mut params: FnParseParams) -> Result<String> {
         parse_child(
             &mut params,
-            Rule::duration_constant,
-            Self::parse_duration_constant,
+            Rule::identifier,
+            Self::parse_identifier,
             identity,
         )
     }
-    fn parse_allowed_delay(mut params: FnParseParams) -> Result<SqlValue> {
-        parse_child(
+
+    fn parse_sort_order(mut params: FnParseParams) -> Result<SortOrder> {
+        let s = self_as_str(&mut params);
+        match s.to_lowercase().as_str() {
+            "asc" => Ok(SortOrder::Asc),
+            "desc" => Ok(SortOrder::Desc),
+            _ => Err(SpringError::Sql(anyhow!(
+                "unknown sort order {}",
+                s.to_lowercase()
+            ))),
+        }
+    }
+
+    fn parse_match_recognize_clause(mut params: FnParseParams) -> Result<MatchRecognizeSyntax> {
+        let partition_by = parse_child_seq(
+            &mut params,
+            Rule::value_expr,
+            &Self::parse_value_expr,
+            &identity,
+        )?;
+        let order_by = parse_child(
+            &mut params,
+            Rule::column_reference,
+            Self::parse_column_reference,
+            identity,
+        )?;
+        let measures =
+            parse_child_seq(&mut params, Rule::measure, &Self::parse_measure, &identity)?;
+        let pattern = parse_child_seq(
+            &mut params,
+            Rule::pattern_element,
+            &Self::parse_pattern_element,
+            &identity,
+        )?;
+        let within = parse_child(
             &mut params,
             Rule::duration_constant,
             Self::parse_duration_constant,
             identity,
-        )
-    }
+        )?;
+        let within = within.to_event_duration()?;
+        let defines = parse_child_seq(
+            &mut params,
+            Rule::pattern_definition,
+            &Self::parse_pattern_definition,
+            &identity,
+        )?;
 
-    /*
-     * ================================================================================================
-     * Value Expressions:
-     * ================================================================================================
-     */
+        Ok(MatchRecognizeSyntax {
+            partition_by,
+            order_by,
+            measures,
+            pattern,
+            within,
+            defines,
+        })
+    }
 
-    fn parse_condition(mut params: FnParseParams) -> Result<ValueExpr> {
-        parse_child(
+    fn parse_measure(mut params: FnParseParams) -> Result<(ValueExpr, ColumnName)> {
+        let value_expr = parse_child(
             &mut params,
             Rule::value_expr,
             Self::parse_value_expr,
             identity,
-        )
+        )?;
+        let column_name = parse_child(
+            &mut params,
+            Rule::column_name,
+            Self::parse_column_name,
+            identity,
+        )?;
+        Ok((value_expr, column_name))
     }
 
-    fn parse_value_expr(mut params: FnParseParams) -> Result<ValueExpr> {
-        let expr = parse_child(
+    fn parse_pattern_element(mut params: FnParseParams) -> Result<(StreamName, PatternQuantifier)> {
+        let variable = parse_child(
             &mut params,
-            Rule::sub_value_expr,
-            Self::parse_sub_value_expr,
+            Rule::pattern_variable,
+            Self::parse_pattern_variable,
             identity,
         )?;
-
-        if let Some(bin_op) = try_parse_child(
+        let quantifier = try_parse_child(
             &mut params,
-            Rule::binary_operator,
-            Self::parse_binary_operator,
+            Rule::pattern_quantifier,
+            Self::parse_pattern_quantifier,
             identity,
-        )? {
-            let right_expr = parse_child(
-                &mut params,
-                Rule::value_expr,
-                Self::parse_value_expr,
-                identity,
-            )?;
+        )?
+        .unwrap_or(PatternQuantifier::One);
+        Ok((variable, quantifier))
+    }
 
-            match bin_op {
-                BinaryOperator::Equal => Ok(ValueExpr::BinaryExpr(
-                    BinaryExpr::ComparisonFunctionVariant(ComparisonFunction::EqualVariant {
-                        left: Box::new(expr),
-                        right: Box::new(right_expr),
-                    }),
-                )),
-                BinaryOperator::Add => Ok(ValueExpr::BinaryExpr(
-                    BinaryExpr::NumericalFunctionVariant(NumericalFunction::AddVariant {
-                        left: Box::new(expr),
-                        right: Box::new(right_expr),
-                    }),
-                )),
-                BinaryOperator::Mul => Ok(ValueExpr::BinaryExpr(
-                    BinaryExpr::NumericalFunctionVariant(NumericalFunction::MulVariant {
-                        left: Box::new(expr),
-                        right: Box::new(right_expr),
-                    }),
-                )),
-                BinaryOperator::And => Ok(ValueExpr::BinaryExpr(
-                    BinaryExpr::LogicalFunctionVariant(LogicalFunction::AndVariant {
-                        left: Box::new(expr),
-                        right: Box::new(right_expr),
-                    }),
-                )),
-            }
-        } else {
-            Ok(expr)
+    fn parse_pattern_quantifier(mut params: FnParseParams) -> Result<PatternQuantifier> {
+        let s = self_as_str(&mut params);
+        match s {
+            "?" => Ok(PatternQuantifier::ZeroOrOne),
+            "*" => Ok(PatternQuantifier::ZeroOrMore),
+            "+" => Ok(PatternQuantifier::OneOrMore),
+            _ => Err(SpringError::Sql(anyhow!(
+                "unknown pattern quantifier {}",
+                s
+            ))),
         }
     }
 
-    fn parse_sub_value_expr(mut params: FnParseParams) -> Result<ValueExpr> {
-        try_parse_child(
+    fn parse_pattern_definition(mut params: FnParseParams) -> Result<(StreamName, ValueExpr)> {
+        let variable = parse_child(
             &mut params,
-            Rule::constant,
-            Self::parse_constant,
-            ValueExpr::Constant,
-        )?
-        .or(try_parse_child(
+            Rule::pattern_variable,
+            Self::parse_pattern_variable,
+            identity,
+        )?;
+        let condition = parse_child(
             &mut params,
-            Rule::column_reference,
-            Self::parse_column_reference,
-            ValueExpr::ColumnReference,
-        )?)
-        .or({
-            if let Some(uni_op) = try_parse_child(
-                &mut params,
+            Rule::value_expr,
+            Self::parse_value_expr,
+            identity,
+        )?;
+        Ok((variable, condition))
+    }
+
+    fn parse_deduplicate_clause(mut params: FnParseParams) -> Result<DeduplicateSyntax> {
+        let keys = parse_child_seq(
+            &mut params,
+            Rule::value_expr,
+            &Self::parse_value_expr,
+            &identity,
+        )?;
+        let within = parse_child(
+            &mut params,
+            Rule::duration_constant,
+            Self::parse_duration_constant,
+            identity,
+        )?;
+        let within = within.to_event_duration()?;
+
+        Ok(DeduplicateSyntax { keys, within })
+    }
+
+    fn parse_changes_only_clause(mut params: FnParseParams) -> Result<ChangesOnlySyntax> {
+        let columns = parse_child_seq(
+            &mut params,
+            Rule::value_expr,
+            &Self::parse_value_expr,
+            &identity,
+        )?;
+        let partition_by = parse_child_seq(
+            &mut params,
+            Rule::partition_key,
+            &Self::parse_partition_key,
+            &identity,
+        )?;
+
+        Ok(ChangesOnlySyntax {
+            columns,
+            partition_by,
+        })
+    }
+
+    fn parse_silence_alert_clause(mut params: FnParseParams) -> Result<SilenceAlertSyntax> {
+        let silent_for = parse_child(
+            &mut params,
+            Rule::duration_constant,
+            Self::parse_duration_constant,
+            identity,
+        )?;
+        let silent_for = silent_for.to_event_duration()?;
+        let on_ptime = try_parse_child(&mut params, Rule::on_ptime, |_| Ok(()), identity)?;
+        let time = if on_ptime.is_some() {
+            WindowTime::PTime
+        } else {
+            WindowTime::Rowtime
+        };
+        let partition_by = parse_child_seq(
+            &mut params,
+            Rule::partition_key,
+            &Self::parse_partition_key,
+            &identity,
+        )?;
+
+        Ok(SilenceAlertSyntax {
+            silent_for,
+            time,
+            partition_by,
+        })
+    }
+
+    fn parse_where_clause(mut params: FnParseParams) -> Result<ValueExpr> {
+        parse_child(
+            &mut params,
+            Rule::condition,
+            Self::parse_condition,
+            identity,
+        )
+    }
+
+    fn parse_partition_key(mut params: FnParseParams) -> Result<ValueExpr> {
+        parse_child(
+            &mut params,
+            Rule::value_expr,
+            Self::parse_value_expr,
+            identity,
+        )
+    }
+
+    fn parse_group_by_clause(mut params: FnParseParams) -> Result<Vec<GroupingElementSyntax>> {
+        parse_child_seq(
+            &mut params,
+            Rule::grouping_element,
+            &Self::parse_grouping_element,
+            &identity,
+        )
+    }
+
+    fn parse_grouping_element(mut params: FnParseParams) -> Result<GroupingElementSyntax> {
+        try_parse_child(
+            &mut params,
+            Rule::value_expr,
+            Self::parse_value_expr,
+            GroupingElementSyntax::ValueExpr,
+        )?
+        .or(try_parse_child(
+            &mut params,
+            Rule::value_alias,
+            Self::parse_value_alias,
+            GroupingElementSyntax::ValueAlias,
+        )?)
+        .ok_or_else(|| {
+            SpringError::Sql(anyhow!("Failed to parse grouping element: {}", params.sql))
+        })
+    }
+
+    fn parse_window_clause(mut params: FnParseParams) -> Result<WindowParameter> {
+        try_parse_child(
+            &mut params,
+            Rule::fixed_window_clause,
+            Self::parse_fixed_window_clause,
+            identity,
+        )?
+        .or(try_parse_child(
+            &mut params,
+            Rule::sliding_window_clause,
+            Self::parse_sliding_window_clause,
+            identity,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::resample_window_clause,
+            Self::parse_resample_window_clause,
+            identity,
+        )?)
+        .ok_or_else(|| SpringError::Sql(anyhow!("Failed to parse window clause: {}", params.sql)))
+    }
+
+    fn parse_fixed_window_clause(mut params: FnParseParams) -> Result<WindowParameter> {
+        let length = parse_child(
+            &mut params,
+            Rule::window_length,
+            Self::parse_window_length,
+            identity,
+        )?;
+        let length = length.to_event_duration()?;
+
+        let (allowed_delay, idle_timeout, window_time) = Self::parse_window_time(&mut params)?;
+
+        Ok(WindowParameter::TimedFixedWindow {
+            length,
+            allowed_delay,
+            idle_timeout,
+            window_time,
+        })
+    }
+
+    fn parse_sliding_window_clause(mut params: FnParseParams) -> Result<WindowParameter> {
+        let length = parse_child(
+            &mut params,
+            Rule::window_length,
+            Self::parse_window_length,
+            identity,
+        )?;
+        let length = length.to_event_duration()?;
+
+        let period = parse_child(
+            &mut params,
+            Rule::window_period,
+            Self::parse_window_period,
+            identity,
+        )?;
+        let period = period.to_event_duration()?;
+
+        let (allowed_delay, idle_timeout, window_time) = Self::parse_window_time(&mut params)?;
+
+        Ok(WindowParameter::TimedSlidingWindow {
+            length,
+            period,
+            allowed_delay,
+            idle_timeout,
+            window_time,
+        })
+    }
+
+    fn parse_resample_window_clause(mut params: FnParseParams) -> Result<WindowParameter> {
+        let every = parse_child(
+            &mut params,
+            Rule::window_length,
+            Self::parse_window_length,
+            identity,
+        )?;
+        let every = every.to_event_duration()?;
+
+        let (allowed_delay, idle_timeout, window_time) = Self::parse_window_time(&mut params)?;
+
+        let fill = try_parse_child(
+            &mut params,
+            Rule::resample_fill,
+            Self::parse_resample_fill,
+            identity,
+        )?
+        .unwrap_or(ResampleFill::Null);
+
+        let within = try_parse_child(
+            &mut params,
+            Rule::resample_within,
+            Self::parse_resample_within,
+            identity,
+        )?;
+
+        Ok(WindowParameter::TimedResampleWindow {
+            every,
+            fill,
+            within,
+            allowed_delay,
+            idle_timeout,
+            window_time,
+        })
+    }
+
+    fn parse_resample_fill(mut params: FnParseParams) -> Result<ResampleFill> {
+        parse_child(
+            &mut params,
+            Rule::resample_fill_mode,
+            Self::parse_resample_fill_mode,
+            identity,
+        )
+    }
+
+    fn parse_resample_within(mut params: FnParseParams) -> Result<SpringEventDuration> {
+        let within = parse_child(
+            &mut params,
+            Rule::duration_constant,
+            Self::parse_duration_constant,
+            identity,
+        )?;
+        within.to_event_duration()
+    }
+
+    fn parse_resample_fill_mode(mut params: FnParseParams) -> Result<ResampleFill> {
+        let s = self_as_str(&mut params);
+        match s.to_lowercase().as_ref() {
+            "null" => Ok(ResampleFill::Null),
+            "previous" => Ok(ResampleFill::Previous),
+            "linear" => Ok(ResampleFill::Linear),
+            _ => Err(SpringError::Sql(anyhow!("fill mode `{}` is invalid", s))),
+        }
+    }
+
+    /// Parses either `ON PTIME` or `, allowed_delay [IDLE TIMEOUT idle_timeout]`.
+    fn parse_window_time(
+        params: &mut FnParseParams,
+    ) -> Result<(SpringEventDuration, Option<SpringEventDuration>, WindowTime)> {
+        let on_ptime = try_parse_child(params, Rule::on_ptime, |_| Ok(()), identity)?;
+        if on_ptime.is_some() {
+            return Ok((SpringEventDuration::from_secs(0), None, WindowTime::PTime));
+        }
+
+        let allowed_delay = parse_child(
+            params,
+            Rule::allowed_delay,
+            Self::parse_allowed_delay,
+            identity,
+        )?;
+        let allowed_delay = allowed_delay.to_event_duration()?;
+
+        let idle_timeout = try_parse_child(
+            params,
+            Rule::idle_timeout,
+            Self::parse_idle_timeout,
+            identity,
+        )?;
+        let idle_timeout = idle_timeout
+            .map(|idle_timeout| idle_timeout.to_event_duration())
+            .transpose()?;
+
+        Ok((allowed_delay, idle_timeout, WindowTime::Rowtime))
+    }
+
+    fn parse_window_length(mut params: FnParseParams) -> Result<SqlValue> {
+        parse_child(
+            &mut params,
+            Rule::duration_constant,
+            Self::parse_duration_constant,
+            identity,
+        )
+    }
+    fn parse_window_period(mut params: FnParseParams) -> Result<SqlValue> {
+        parse_child(
+            &mut params,
+            Rule::duration_constant,
+            Self::parse_duration_constant,
+            identity,
+        )
+    }
+    fn parse_allowed_delay(mut params: FnParseParams) -> Result<SqlValue> {
+        parse_child(
+            &mut params,
+            Rule::duration_constant,
+            Self::parse_duration_constant,
+            identity,
+        )
+    }
+
+    fn parse_idle_timeout(mut params: FnParseParams) -> Result<SqlValue> {
+        parse_child(
+            &mut params,
+            Rule::duration_constant,
+            Self::parse_duration_constant,
+            identity,
+        )
+    }
+
+    /*
+     * ================================================================================================
+     * Value Expressions:
+     * ================================================================================================
+     */
+
+    fn parse_condition(mut params: FnParseParams) -> Result<ValueExpr> {
+        parse_child(
+            &mut params,
+            Rule::value_expr,
+            Self::parse_value_expr,
+            identity,
+        )
+    }
+
+    /// Operands and binary operators are folded by precedence climbing. See `BinaryOperator::precedence()`.
+    fn parse_value_expr(mut params: FnParseParams) -> Result<ValueExpr> {
+        let mut operands = vec![parse_child(
+            &mut params,
+            Rule::value_operand,
+            Self::parse_value_operand,
+            identity,
+        )?];
+        let mut operators: Vec<BinaryOperator> = Vec::new();
+
+        while let Some(bin_op) = try_parse_child(
+            &mut params,
+            Rule::binary_operator,
+            Self::parse_binary_operator,
+            identity,
+        )? {
+            // left-associative: reduces operators of the same or higher precedence first
+            while operators
+                .last()
+                .map_or(false, |top| top.precedence() >= bin_op.precedence())
+            {
+                Self::reduce_binary_expr(&mut operands, &mut operators);
+            }
+            operators.push(bin_op);
+            operands.push(parse_child(
+                &mut params,
+                Rule::value_operand,
+                Self::parse_value_operand,
+                identity,
+            )?);
+        }
+        while !operators.is_empty() {
+            Self::reduce_binary_expr(&mut operands, &mut operators);
+        }
+
+        Ok(operands.pop().expect("an operand is left"))
+    }
+
+    /// Replaces the last 2 operands with a binary expression by the last operator.
+    fn reduce_binary_expr(operands: &mut Vec<ValueExpr>, operators: &mut Vec<BinaryOperator>) {
+        let bin_op = operators.pop().expect("operator exists");
+        let right_expr = operands.pop().expect("right operand exists");
+        let expr = operands.pop().expect("left operand exists");
+
+        let bin_expr = match bin_op {
+            BinaryOperator::Equal => ValueExpr::BinaryExpr(BinaryExpr::ComparisonFunctionVariant(
+                ComparisonFunction::EqualVariant {
+                    left: Box::new(expr),
+                    right: Box::new(right_expr),
+                },
+            )),
+            BinaryOperator::Add => ValueExpr::BinaryExpr(BinaryExpr::NumericalFunctionVariant(
+                NumericalFunction::AddVariant {
+                    left: Box::new(expr),
+                    right: Box::new(right_expr),
+                },
+            )),
+            BinaryOperator::Sub => ValueExpr::BinaryExpr(BinaryExpr::NumericalFunctionVariant(
+                NumericalFunction::SubVariant {
+                    left: Box::new(expr),
+                    right: Box::new(right_expr),
+                },
+            )),
+            BinaryOperator::Mul => ValueExpr::BinaryExpr(BinaryExpr::NumericalFunctionVariant(
+                NumericalFunction::MulVariant {
+                    left: Box::new(expr),
+                    right: Box::new(right_expr),
+                },
+            )),
+            BinaryOperator::Div => ValueExpr::BinaryExpr(BinaryExpr::NumericalFunctionVariant(
+                NumericalFunction::DivVariant {
+                    left: Box::new(expr),
+                    right: Box::new(right_expr),
+                },
+            )),
+            BinaryOperator::And => ValueExpr::BinaryExpr(BinaryExpr::LogicalFunctionVariant(
+                LogicalFunction::AndVariant {
+                    left: Box::new(expr),
+                    right: Box::new(right_expr),
+                },
+            )),
+            BinaryOperator::BitAnd => ValueExpr::BinaryExpr(BinaryExpr::NumericalFunctionVariant(
+                NumericalFunction::BitAndVariant {
+                    left: Box::new(expr),
+                    right: Box::new(right_expr),
+                },
+            )),
+            BinaryOperator::BitOr => ValueExpr::BinaryExpr(BinaryExpr::NumericalFunctionVariant(
+                NumericalFunction::BitOrVariant {
+                    left: Box::new(expr),
+                    right: Box::new(right_expr),
+                },
+            )),
+            BinaryOperator::Shl => ValueExpr::BinaryExpr(BinaryExpr::NumericalFunctionVariant(
+                NumericalFunction::ShlVariant {
+                    left: Box::new(expr),
+                    right: Box::new(right_expr),
+                },
+            )),
+            BinaryOperator::Shr => ValueExpr::BinaryExpr(BinaryExpr::NumericalFunctionVariant(
+                NumericalFunction::ShrVariant {
+                    left: Box::new(expr),
+                    right: Box::new(right_expr),
+                },
+            )),
+        };
+        operands.push(bin_expr);
+    }
+
+    fn parse_value_operand(mut params: FnParseParams) -> Result<ValueExpr> {
+        let expr = parse_child(
+            &mut params,
+            Rule::sub_value_expr,
+            Self::parse_sub_value_expr,
+            identity,
+        )?;
+        let expr = parse_child_seq(
+            &mut params,
+            Rule::element_accessor,
+            &Self::parse_element_accessor,
+            &identity,
+        )?
+        .into_iter()
+        .fold(expr, |expr, accessor| match accessor {
+            ElementAccessorSyntax::Key(key) => ValueExpr::FunctionCall(FunctionCall::ElementAt {
+                container: Box::new(expr),
+                key: Box::new(key),
+            }),
+            ElementAccessorSyntax::Field(field) => ValueExpr::FunctionCall(FunctionCall::FieldOf {
+                row: Box::new(expr),
+                field,
+            }),
+        });
+        let expr = parse_child_seq(
+            &mut params,
+            Rule::at_time_zone,
+            &Self::parse_at_time_zone,
+            &identity,
+        )?
+        .into_iter()
+        .fold(expr, |expr, offset_secs| {
+            ValueExpr::FunctionCall(FunctionCall::AtTimeZone {
+                target: Box::new(expr),
+                offset_secs,
+            })
+        });
+
+        Ok(expr)
+    }
+
+    /// UTC offset in seconds
+    fn parse_at_time_zone(mut params: FnParseParams) -> Result<i32> {
+        match parse_child(
+            &mut params,
+            Rule::string_constant,
+            Self::parse_string_constant,
+            identity,
+        )? {
+            SqlValue::NotNull(NnSqlValue::Text(zone)) => parse_utc_offset(&zone),
+            _ => unreachable!("string constant must be TEXT"),
+        }
+    }
+
+    fn parse_element_accessor(mut params: FnParseParams) -> Result<ElementAccessorSyntax> {
+        try_parse_child(
+            &mut params,
+            Rule::value_expr,
+            Self::parse_value_expr,
+            ElementAccessorSyntax::Key,
+        )?
+        .or(try_parse_child(
+            &mut params,
+            Rule::identifier,
+            Self::parse_identifier,
+            ElementAccessorSyntax::Field,
+        )?)
+        .ok_or_else(|| {
+            SpringError::Sql(anyhow!(
+                "Does not match any child rule of element_accessor.",
+            ))
+        })
+    }
+
+    fn parse_sub_value_expr(mut params: FnParseParams) -> Result<ValueExpr> {
+        try_parse_child(
+            &mut params,
+            Rule::constant,
+            Self::parse_constant,
+            ValueExpr::Constant,
+        )?
+        .or(try_parse_child(
+            &mut params,
+            Rule::column_reference,
+            Self::parse_column_reference,
+            ValueExpr::ColumnReference,
+        )?)
+        .or({
+            if let Some(uni_op) = try_parse_child(
+                &mut params,
                 Rule::unary_operator,
                 Self::parse_unary_operator,
                 identity,
             )? {
                 Some(parse_child(
                     &mut params,
-                    Rule::value_expr,
-                    Self::parse_value_expr,
+                    Rule::value_operand,
+                    Self::parse_value_operand,
                     |expr| ValueExpr::UnaryOperator(uni_op.clone(), Box::new(expr)),
                 )?)
             } else {
                 None
             }
         })
+        .or(try_parse_child(
+            &mut params,
+            Rule::navigation_function_call,
+            Self::parse_navigation_function_call,
+            ValueExpr::NavigationFunctionCall,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::json_object_constructor,
+            Self::parse_json_object_constructor,
+            ValueExpr::FunctionCall,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::extract_call,
+            Self::parse_extract_call,
+            ValueExpr::FunctionCall,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::current_timestamp,
+            |_| Ok(()),
+            |_| ValueExpr::FunctionCall(FunctionCall::CurrentTimestamp),
+        )?)
         .or(try_parse_child(
             &mut params,
             Rule::function_call,
             Self::parse_function_call,
             ValueExpr::FunctionCall,
         )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::changelog_op,
+            |_| Ok(()),
+            |_| ValueExpr::ChangelogOp,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::resample_time,
+            |_| Ok(()),
+            |_| ValueExpr::ResampleTime,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::value_expr,
+            Self::parse_value_expr,
+            identity,
+        )?)
         .ok_or_else(|| {
             SpringError::Sql(anyhow!("Does not match any child rule of sub_value_expr.",))
         })
@@ -934,69 +1704,492 @@ impl PestParserImpl {
         }
     }
 
-    /*
-     * ----------------------------------------------------------------------------
-     * Function
-     * ----------------------------------------------------------------------------
-     */
-
-    fn parse_function_call(mut params: FnParseParams) -> Result<FunctionCall<ValueExpr>> {
-        let function_name = parse_child(
+    /*
+     * ----------------------------------------------------------------------------
+     * Function
+     * ----------------------------------------------------------------------------
+     */
+
+    fn parse_function_call(mut params: FnParseParams) -> Result<FunctionCall<ValueExpr>> {
+        let function_name = parse_child(
+            &mut params,
+            Rule::function_name,
+            Self::parse_function_name,
+            identity,
+        )?;
+        let parameters = parse_child_seq(
+            &mut params,
+            Rule::value_expr,
+            &Self::parse_value_expr,
+            &identity,
+        )?;
+
+        match function_name.to_lowercase().as_str() {
+            "duration_millis" => {
+                if parameters.len() == 1 {
+                    Ok(FunctionCall::DurationMillis {
+                        duration_millis: Box::new(parameters[0].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "duration_millis() takes exactly one parameter (duration_millis)."
+                    )))
+                }
+            }
+            "duration_secs" => {
+                if parameters.len() == 1 {
+                    Ok(FunctionCall::DurationSecs {
+                        duration_secs: Box::new(parameters[0].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "duration_secs() takes exactly one parameter (duration_secs)."
+                    )))
+                }
+            }
+            "duration_micros" => {
+                if parameters.len() == 1 {
+                    Ok(FunctionCall::DurationMicros {
+                        duration_micros: Box::new(parameters[0].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "duration_micros() takes exactly one parameter (duration_micros)."
+                    )))
+                }
+            }
+            "duration_minutes" => {
+                if parameters.len() == 1 {
+                    Ok(FunctionCall::DurationMinutes {
+                        duration_minutes: Box::new(parameters[0].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "duration_minutes() takes exactly one parameter (duration_minutes)."
+                    )))
+                }
+            }
+            "duration_hours" => {
+                if parameters.len() == 1 {
+                    Ok(FunctionCall::DurationHours {
+                        duration_hours: Box::new(parameters[0].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "duration_hours() takes exactly one parameter (duration_hours)."
+                    )))
+                }
+            }
+            "duration_to_millis" => {
+                if parameters.len() == 1 {
+                    Ok(FunctionCall::DurationToMillis {
+                        duration: Box::new(parameters[0].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "duration_to_millis() takes exactly one parameter (duration)."
+                    )))
+                }
+            }
+            "floor_time" => {
+                if parameters.len() == 2 {
+                    Ok(FunctionCall::FloorTime {
+                        target: Box::new(parameters[0].clone()),
+                        resolution: Box::new(parameters[1].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "floor_time() takes exactly two parameters (target, resolution)."
+                    )))
+                }
+            }
+            "st_distance" => {
+                if parameters.len() == 4 {
+                    Ok(FunctionCall::StDistance {
+                        lat1: Box::new(parameters[0].clone()),
+                        lon1: Box::new(parameters[1].clone()),
+                        lat2: Box::new(parameters[2].clone()),
+                        lon2: Box::new(parameters[3].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "st_distance() takes exactly four parameters (lat1, lon1, lat2, lon2)."
+                    )))
+                }
+            }
+            "st_bearing" => {
+                if parameters.len() == 4 {
+                    Ok(FunctionCall::StBearing {
+                        lat1: Box::new(parameters[0].clone()),
+                        lon1: Box::new(parameters[1].clone()),
+                        lat2: Box::new(parameters[2].clone()),
+                        lon2: Box::new(parameters[3].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "st_bearing() takes exactly four parameters (lat1, lon1, lat2, lon2)."
+                    )))
+                }
+            }
+            "st_within" => match parameters.as_slice() {
+                [lat, lon, ValueExpr::Constant(SqlValue::NotNull(NnSqlValue::Text(wkt)))] => {
+                    Ok(FunctionCall::StWithin {
+                        lat: Box::new(lat.clone()),
+                        lon: Box::new(lon.clone()),
+                        polygon: GeoPolygon::from_wkt(wkt)?,
+                    })
+                }
+                [lat, lon, polygon_wkt] => Ok(FunctionCall::StWithinWkt {
+                    lat: Box::new(lat.clone()),
+                    lon: Box::new(lon.clone()),
+                    polygon_wkt: Box::new(polygon_wkt.clone()),
+                }),
+                _ => Err(SpringError::Sql(anyhow!(
+                    "st_within() takes exactly three parameters (lat, lon, polygon_wkt)."
+                ))),
+            },
+            "get_bits" => {
+                if parameters.len() == 5 {
+                    Ok(FunctionCall::GetBits {
+                        blob: Box::new(parameters[0].clone()),
+                        start_bit: Box::new(parameters[1].clone()),
+                        length: Box::new(parameters[2].clone()),
+                        byte_order: Box::new(parameters[3].clone()),
+                        signed: Box::new(parameters[4].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "get_bits() takes exactly five parameters (blob, start_bit, length, byte_order, signed)."
+                    )))
+                }
+            }
+            "get_byte" => {
+                if parameters.len() == 2 {
+                    Ok(FunctionCall::GetByte {
+                        blob: Box::new(parameters[0].clone()),
+                        index: Box::new(parameters[1].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "get_byte() takes exactly two parameters (blob, index)."
+                    )))
+                }
+            }
+            "blob_length" => {
+                if parameters.len() == 1 {
+                    Ok(FunctionCall::BlobLength {
+                        blob: Box::new(parameters[0].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "blob_length() takes exactly one parameter (blob)."
+                    )))
+                }
+            }
+            "hex" => {
+                if parameters.len() == 1 {
+                    Ok(FunctionCall::Hex {
+                        blob: Box::new(parameters[0].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "hex() takes exactly one parameter (blob)."
+                    )))
+                }
+            }
+            "geohash" => {
+                if parameters.len() == 3 {
+                    Ok(FunctionCall::Geohash {
+                        lat: Box::new(parameters[0].clone()),
+                        lon: Box::new(parameters[1].clone()),
+                        precision: Box::new(parameters[2].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "geohash() takes exactly three parameters (lat, lon, precision)."
+                    )))
+                }
+            }
+            "round" => match parameters.len() {
+                1 | 2 => Ok(FunctionCall::Round {
+                    value: Box::new(parameters[0].clone()),
+                    scale: Box::new(parameters.get(1).cloned().unwrap_or(ValueExpr::Constant(
+                        SqlValue::NotNull(NnSqlValue::Integer(0)),
+                    ))),
+                }),
+                _ => Err(SpringError::Sql(anyhow!(
+                    "round() takes one or two parameters (value[, scale])."
+                ))),
+            },
+            "json_value" | "json_query" | "json_exists" => match parameters.as_slice() {
+                [json, ValueExpr::Constant(SqlValue::NotNull(NnSqlValue::Text(path)))] => {
+                    let (json, path) = (Box::new(json.clone()), JsonPath::parse(path)?);
+                    Ok(match function_name.to_lowercase().as_str() {
+                        "json_value" => FunctionCall::JsonValue { json, path },
+                        "json_query" => FunctionCall::JsonQuery { json, path },
+                        _ => FunctionCall::JsonExists { json, path },
+                    })
+                }
+                [_, _] => Err(SpringError::Sql(anyhow!(
+                    "{}() takes a constant JSON path as the second parameter.",
+                    function_name.to_lowercase()
+                ))),
+                _ => Err(SpringError::Sql(anyhow!(
+                    "{}() takes exactly two parameters (json, path).",
+                    function_name.to_lowercase()
+                ))),
+            },
+            "cardinality" => {
+                if parameters.len() == 1 {
+                    Ok(FunctionCall::Cardinality {
+                        container: Box::new(parameters[0].clone()),
+                    })
+                } else {
+                    Err(SpringError::Sql(anyhow!(
+                        "cardinality() takes exactly one parameter (array or map)."
+                    )))
+                }
+            }
+            "date_trunc" => match parameters.as_slice() {
+                [ValueExpr::Constant(SqlValue::NotNull(NnSqlValue::Text(unit))), target] => {
+                    match unit.parse::<TimestampField>()? {
+                        TimestampField::DayOfWeek => Err(SpringError::Sql(anyhow!(
+                            "date_trunc() cannot truncate into `{}`.",
+                            unit
+                        ))),
+                        field => Ok(FunctionCall::DateTrunc {
+                            field,
+                            target: Box::new(target.clone()),
+                        }),
+                    }
+                }
+                [_, _] => Err(SpringError::Sql(anyhow!(
+                    "date_trunc() takes a constant unit as the first parameter."
+                ))),
+                _ => Err(SpringError::Sql(anyhow!(
+                    "date_trunc() takes exactly two parameters (unit, timestamp)."
+                ))),
+            },
+            "to_timestamp" | "format_timestamp" => match parameters.as_slice() {
+                [expr, ValueExpr::Constant(SqlValue::NotNull(NnSqlValue::Text(format)))] => {
+                    let (expr, format) = (Box::new(expr.clone()), TimestampFormat::parse(format)?);
+                    Ok(match function_name.to_lowercase().as_str() {
+                        "to_timestamp" => FunctionCall::ToTimestamp { text: expr, format },
+                        _ => FunctionCall::FormatTimestamp {
+                            target: expr,
+                            format,
+                        },
+                    })
+                }
+                [_, _] => Err(SpringError::Sql(anyhow!(
+                    "{}() takes a constant format as the second parameter.",
+                    function_name.to_lowercase()
+                ))),
+                _ => Err(SpringError::Sql(anyhow!(
+                    "{}() takes exactly two parameters (value, format).",
+                    function_name.to_lowercase()
+                ))),
+            },
+            "floor" => unimplemented!(),
+            _ => Err(SpringError::Sql(anyhow!(
+                "unknown function {}",
+                function_name.to_lowercase()
+            ))),
+        }
+    }
+
+    fn parse_extract_call(mut params: FnParseParams) -> Result<FunctionCall<ValueExpr>> {
+        let field = parse_child(
+            &mut params,
+            Rule::timestamp_field,
+            |mut params| self_as_str(&mut params).parse::<TimestampField>(),
+            identity,
+        )?;
+        let target = parse_child(
+            &mut params,
+            Rule::value_expr,
+            Self::parse_value_expr,
+            identity,
+        )?;
+        Ok(FunctionCall::Extract {
+            field,
+            target: Box::new(target),
+        })
+    }
+
+    fn parse_json_object_constructor(mut params: FnParseParams) -> Result<FunctionCall<ValueExpr>> {
+        let entries = parse_child_seq(
+            &mut params,
+            Rule::json_object_entry,
+            &Self::parse_json_object_entry,
+            &identity,
+        )?;
+        Ok(FunctionCall::JsonObject { entries })
+    }
+
+    fn parse_json_object_entry(mut params: FnParseParams) -> Result<(String, Box<ValueExpr>)> {
+        let key = match parse_child(
+            &mut params,
+            Rule::string_constant,
+            Self::parse_string_constant,
+            identity,
+        )? {
+            SqlValue::NotNull(NnSqlValue::Text(key)) => key,
+            _ => unreachable!("string constant must be TEXT"),
+        };
+        let value = parse_child(
+            &mut params,
+            Rule::value_expr,
+            Self::parse_value_expr,
+            identity,
+        )?;
+        Ok((key, Box::new(value)))
+    }
+
+    fn parse_navigation_function_call(mut params: FnParseParams) -> Result<NavigationFunctionCall> {
+        let func = parse_child(
             &mut params,
-            Rule::function_name,
-            Self::parse_function_name,
+            Rule::navigation_function_name,
+            Self::parse_navigation_function_name,
             identity,
         )?;
-        let parameters = parse_child_seq(
+        let expr = parse_child(
             &mut params,
             Rule::value_expr,
-            &Self::parse_value_expr,
-            &identity,
+            Self::parse_value_expr,
+            identity,
         )?;
-
-        match function_name.to_lowercase().as_str() {
-            "duration_millis" => {
-                if parameters.len() == 1 {
-                    Ok(FunctionCall::DurationMillis {
-                        duration_millis: Box::new(parameters[0].clone()),
-                    })
-                } else {
-                    Err(SpringError::Sql(anyhow!(
-                        "duration_millis() takes exactly one parameter (duration_millis)."
-                    )))
-                }
+        let arg = try_parse_child(
+            &mut params,
+            Rule::numeric_constant,
+            Self::parse_numeric_constant,
+            identity,
+        )?;
+        let (func, offset) = match (func, arg) {
+            (NavigationFunction::Lag | NavigationFunction::Lead { .. }, arg) => {
+                let offset = arg.map(|offset| offset.to_i64()).transpose()?.unwrap_or(1);
+                let offset = usize::try_from(offset)
+                    .ok()
+                    .filter(|offset| *offset > 0)
+                    .ok_or_else(|| {
+                        SpringError::Sql(anyhow!("offset of LAG / LEAD must be positive"))
+                    })?;
+                (func, offset)
             }
-            "duration_secs" => {
-                if parameters.len() == 1 {
-                    Ok(FunctionCall::DurationSecs {
-                        duration_secs: Box::new(parameters[0].clone()),
-                    })
-                } else {
-                    Err(SpringError::Sql(anyhow!(
-                        "duration_secs() takes exactly one parameter (duration_secs)."
-                    )))
+            (NavigationFunction::Ewma { .. }, Some(SqlValue::NotNull(alpha))) => {
+                let alpha = alpha.unpack::<f64>()?;
+                if !(alpha > 0.0 && alpha <= 1.0) {
+                    return Err(SpringError::Sql(anyhow!(
+                        "alpha of EWMA must be in (0, 1] but {}",
+                        alpha
+                    )));
                 }
+                (
+                    NavigationFunction::Ewma {
+                        alpha: OrderedFloat(alpha),
+                    },
+                    1,
+                )
             }
-            "floor_time" => {
-                if parameters.len() == 2 {
-                    Ok(FunctionCall::FloorTime {
-                        target: Box::new(parameters[0].clone()),
-                        resolution: Box::new(parameters[1].clone()),
-                    })
-                } else {
-                    Err(SpringError::Sql(anyhow!(
-                        "floor_time() takes exactly two parameters (target, resolution)."
-                    )))
+            (NavigationFunction::Ewma { .. }, _) => {
+                return Err(SpringError::Sql(anyhow!("EWMA requires alpha")))
+            }
+            (NavigationFunction::IsOutlier { .. }, Some(SqlValue::NotNull(k_sigma))) => {
+                let k_sigma = k_sigma.unpack::<f64>()?;
+                if k_sigma <= 0.0 {
+                    return Err(SpringError::Sql(anyhow!(
+                        "k_sigma of IS_OUTLIER must be positive but {}",
+                        k_sigma
+                    )));
                 }
+                (
+                    NavigationFunction::IsOutlier {
+                        k_sigma: OrderedFloat(k_sigma),
+                        window: None,
+                    },
+                    1,
+                )
             }
-            "floor" => unimplemented!(),
-            _ => Err(SpringError::Sql(anyhow!(
-                "unknown function {}",
-                function_name.to_lowercase()
-            ))),
+            (NavigationFunction::IsOutlier { .. }, _) => {
+                return Err(SpringError::Sql(anyhow!("IS_OUTLIER requires k_sigma")))
+            }
+            (_, None) => (func, 1),
+            (_, Some(_)) => {
+                return Err(SpringError::Sql(anyhow!(
+                    "{} does not take the second argument",
+                    func.name()
+                )))
+            }
+        };
+        let partition_by = parse_child_seq(
+            &mut params,
+            Rule::value_expr,
+            &Self::parse_value_expr,
+            &identity,
+        )?;
+        let window = try_parse_child(
+            &mut params,
+            Rule::navigation_window,
+            Self::parse_navigation_window,
+            identity,
+        )?;
+        let func = match (func, window) {
+            (_, None) => func,
+            (NavigationFunction::Lead { .. }, window) => NavigationFunction::Lead { window },
+            (NavigationFunction::ZScore { .. }, window) => NavigationFunction::ZScore { window },
+            (NavigationFunction::IsOutlier { k_sigma, .. }, window) => {
+                NavigationFunction::IsOutlier { k_sigma, window }
+            }
+            (_, Some(_)) => {
+                return Err(SpringError::Sql(anyhow!(
+                    "{} does not take WINDOW in OVER clause",
+                    func.name()
+                )))
+            }
+        };
+
+        Ok(NavigationFunctionCall {
+            func,
+            expr: Box::new(expr),
+            offset,
+            partition_by,
+        })
+    }
+
+    fn parse_navigation_function_name(mut params: FnParseParams) -> Result<NavigationFunction> {
+        let s = self_as_str(&mut params);
+        match s.to_lowercase().as_str() {
+            "lag" => Ok(NavigationFunction::Lag),
+            // window is set by OVER clause
+            "lead" => Ok(NavigationFunction::Lead { window: None }),
+            // alpha is set by the second argument
+            "ewma" => Ok(NavigationFunction::Ewma {
+                alpha: OrderedFloat(1.0),
+            }),
+            "rate" => Ok(NavigationFunction::Rate),
+            "derivative" => Ok(NavigationFunction::Derivative),
+            "integral" => Ok(NavigationFunction::Integral),
+            // window is set by OVER clause
+            "zscore" => Ok(NavigationFunction::ZScore { window: None }),
+            // k_sigma is set by the second argument
+            "is_outlier" => Ok(NavigationFunction::IsOutlier {
+                k_sigma: OrderedFloat(1.0),
+                window: None,
+            }),
+            _ => unreachable!(),
         }
     }
 
+    fn parse_navigation_window(mut params: FnParseParams) -> Result<SpringEventDuration> {
+        let window = parse_child(
+            &mut params,
+            Rule::duration_constant,
+            Self::parse_duration_constant,
+            identity,
+        )?;
+        window.to_event_duration()
+    }
+
     fn parse_function_name(mut params: FnParseParams) -> Result<String> {
         Ok(self_as_str(&mut params).to_string())
     }
@@ -1064,6 +2257,12 @@ impl PestParserImpl {
             Self::parse_float_type,
             identity,
         )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::decimal_type,
+            Self::parse_decimal_type,
+            identity,
+        )?)
         .or(try_parse_child(
             &mut params,
             Rule::boolean_type,
@@ -1088,6 +2287,30 @@ impl PestParserImpl {
             Self::parse_timestamp_type,
             identity,
         )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::array_type,
+            Self::parse_array_type,
+            identity,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::map_type,
+            Self::parse_map_type,
+            identity,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::row_type,
+            Self::parse_row_type,
+            identity,
+        )?)
+        .or(try_parse_child(
+            &mut params,
+            Rule::json_type,
+            Self::parse_json_type,
+            identity,
+        )?)
         .ok_or_else(|| {
             SpringError::Sql(anyhow!(
                 "Does not match any child rule of data type: {}",
@@ -1105,8 +2328,14 @@ impl PestParserImpl {
     fn parse_integer_type(mut params: FnParseParams) -> Result<SqlType> {
         let s = self_as_str(&mut params);
         match s.to_ascii_uppercase().as_str() {
+            "SMALLINT" => Ok(SqlType::small_int()),
             "INTEGER" => Ok(SqlType::integer()),
+            "BIGINT" => Ok(SqlType::big_int()),
             "UNSIGNED INTEGER" => Ok(SqlType::unsigned_integer()),
+            "UNSIGNED BIGINT" => Ok(SqlType::unsigned_big_int()),
+            "UNSIGNED SMALLINT" => Err(SpringError::Sql(anyhow!(
+                "UNSIGNED SMALLINT is not supported"
+            ))),
             x => {
                 eprintln!("Unexpected data type parsed: {}", x);
                 unreachable!();
@@ -1118,6 +2347,7 @@ impl PestParserImpl {
         let s = self_as_str(&mut params);
         match s.to_ascii_uppercase().as_str() {
             "FLOAT" => Ok(SqlType::float()),
+            "DOUBLE" | "DOUBLE PRECISION" => Ok(SqlType::double()),
             x => {
                 eprintln!("Unexpected data type parsed: {}", x);
                 unreachable!();
@@ -1125,6 +2355,44 @@ impl PestParserImpl {
         }
     }
 
+    /*
+     * ----------------------------------------------------------------------------
+     * Decimal Types
+     * ----------------------------------------------------------------------------
+     */
+
+    fn parse_decimal_type(mut params: FnParseParams) -> Result<SqlType> {
+        let precision = parse_child(
+            &mut params,
+            Rule::integer_constant,
+            Self::parse_integer_constant,
+            identity,
+        )?
+        .to_i64()?;
+        let scale = try_parse_child(
+            &mut params,
+            Rule::integer_constant,
+            Self::parse_integer_constant,
+            identity,
+        )?
+        .map(|scale| scale.to_i64())
+        .transpose()?
+        .unwrap_or(0);
+
+        if (1..=MAX_DECIMAL_PRECISION as i64).contains(&precision)
+            && (0..=precision).contains(&scale)
+        {
+            Ok(SqlType::decimal(precision as u32, scale as u32))
+        } else {
+            Err(SpringError::Sql(anyhow!(
+                "DECIMAL({}, {}) is invalid: precision must be 1 to {}, and scale must be 0 to precision",
+                precision,
+                scale,
+                MAX_DECIMAL_PRECISION
+            )))
+        }
+    }
+
     fn parse_boolean_type(mut params: FnParseParams) -> Result<SqlType> {
         let s = self_as_str(&mut params);
         match s.to_ascii_uppercase().as_str() {
@@ -1160,8 +2428,26 @@ impl PestParserImpl {
 
     fn parse_timestamp_type(mut params: FnParseParams) -> Result<SqlType> {
         let s = self_as_str(&mut params);
-        match s.to_ascii_uppercase().as_str() {
+        match s
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_ascii_uppercase()
+            .as_str()
+        {
             "TIMESTAMP" => Ok(SqlType::timestamp()),
+            "TIMESTAMP WITH TIME ZONE" => Ok(SqlType::timestamp_tz()),
+            x => {
+                eprintln!("Unexpected data type parsed: {}", x);
+                unreachable!();
+            }
+        }
+    }
+
+    fn parse_json_type(mut params: FnParseParams) -> Result<SqlType> {
+        let s = self_as_str(&mut params);
+        match s.to_ascii_uppercase().as_str() {
+            "JSON" => Ok(SqlType::json()),
             x => {
                 eprintln!("Unexpected data type parsed: {}", x);
                 unreachable!();
@@ -1169,6 +2455,60 @@ impl PestParserImpl {
         }
     }
 
+    fn parse_array_type(mut params: FnParseParams) -> Result<SqlType> {
+        parse_child(
+            &mut params,
+            Rule::data_type,
+            Self::parse_data_type,
+            SqlType::array,
+        )
+    }
+
+    fn parse_map_type(mut params: FnParseParams) -> Result<SqlType> {
+        parse_child(
+            &mut params,
+            Rule::data_type,
+            Self::parse_data_type,
+            SqlType::map,
+        )
+    }
+
+    fn parse_row_type(mut params: FnParseParams) -> Result<SqlType> {
+        let fields = parse_child_seq(
+            &mut params,
+            Rule::row_field,
+            &Self::parse_row_field,
+            &identity,
+        )?;
+        if let Some((name, _)) = fields
+            .iter()
+            .enumerate()
+            .find_map(|(i, (name, _))| fields[..i].iter().find(|(prev, _)| prev == name))
+        {
+            return Err(SpringError::Sql(anyhow!(
+                "ROW has duplicate field `{}`",
+                name
+            )));
+        }
+        Ok(SqlType::row(fields))
+    }
+
+    fn parse_row_field(mut params: FnParseParams) -> Result<(String, SqlType)> {
+        let name = parse_child(
+            &mut params,
+            Rule::identifier,
+            Self::parse_identifier,
+            identity,
+        )?;
+        let typ = parse_child(
+            &mut params,
+            Rule::data_type,
+            Self::parse_data_type,
+            identity,
+        )?;
+        Ok((name, typ))
+    }
+
     /*
      * ================================================================================================
      * Misc:
@@ -1288,6 +2628,15 @@ impl PestParserImpl {
         )
     }
 
+    fn parse_pattern_variable(mut params: FnParseParams) -> Result<StreamName> {
+        parse_child(
+            &mut params,
+            Rule::identifier,
+            Self::parse_identifier,
+            StreamName::new,
+        )
+    }
+
     fn parse_correlation_alias(mut params: FnParseParams) -> Result<CorrelationAlias> {
         parse_child(
             &mut params,
@@ -1318,6 +2667,23 @@ impl PestParserImpl {
      */
 
     fn parse_column_constraint(mut params: FnParseParams) -> Result<ColumnConstraintSyntax> {
+        if let Some(default) = try_parse_child(
+            &mut params,
+            Rule::default_constraint,
+            Self::parse_default_constraint,
+            ColumnConstraintSyntax::Default,
+        )? {
+            return Ok(default);
+        }
+        if let Some(generated) = try_parse_child(
+            &mut params,
+            Rule::generated_constraint,
+            Self::parse_generated_constraint,
+            ColumnConstraintSyntax::Generated,
+        )? {
+            return Ok(generated);
+        }
+
         let s = self_as_str(&mut params);
         match s.to_lowercase().as_str() {
             "not null" => Ok(ColumnConstraintSyntax::NotNull),
@@ -1329,6 +2695,31 @@ impl PestParserImpl {
         }
     }
 
+    fn parse_default_constraint(mut params: FnParseParams) -> Result<SqlValue> {
+        let minus = try_parse_child(
+            &mut params,
+            Rule::unary_operator,
+            Self::parse_unary_operator,
+            identity,
+        )?;
+        let constant = parse_child(&mut params, Rule::constant, Self::parse_constant, identity)?;
+        match (minus, constant) {
+            (Some(UnaryOperator::Minus), SqlValue::NotNull(v)) => {
+                Ok(SqlValue::NotNull(v.negate()?))
+            }
+            (_, constant) => Ok(constant),
+        }
+    }
+
+    fn parse_generated_constraint(mut params: FnParseParams) -> Result<ValueExpr> {
+        parse_child(
+            &mut params,
+            Rule::value_expr,
+            Self::parse_value_expr,
+            identity,
+        )
+    }
+
     /*
      * ----------------------------------------------------------------------------
      * Column Definitions
@@ -1363,14 +2754,49 @@ impl PestParserImpl {
         let column_constraints = column_constraints_syntax
             .into_iter()
             .filter_map(|constraint_syntax| match constraint_syntax {
-                ColumnConstraintSyntax::Rowtime => Some(ColumnConstraint::Rowtime),
+                ColumnConstraintSyntax::Rowtime => Some(Ok(ColumnConstraint::Rowtime)),
+                ColumnConstraintSyntax::Default(value) => Some(
+                    Self::default_value(&column_data_type, value).map(ColumnConstraint::Default),
+                ),
+                ColumnConstraintSyntax::Generated(expr) => {
+                    Some(Ok(ColumnConstraint::Generated(expr)))
+                }
                 ColumnConstraintSyntax::NotNull => None,
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>>>()?;
+
+        let n_value_constraints = column_constraints
+            .iter()
+            .filter(|constraint| {
+                matches!(
+                    constraint,
+                    ColumnConstraint::Default(_) | ColumnConstraint::Generated(_)
+                )
+            })
+            .count();
+        if n_value_constraints > 1 {
+            return Err(SpringError::Sql(anyhow!(
+                r#"column "{}" can have only one of DEFAULT or GENERATED ALWAYS AS"#,
+                column_data_type.column_name()
+            )));
+        }
 
         Ok(ColumnDefinition::new(column_data_type, column_constraints))
     }
 
+    /// # Failures
+    ///
+    /// `SpringError::Sql` when `value` cannot be a value of the column.
+    fn default_value(column_data_type: &ColumnDataType, value: SqlValue) -> Result<SqlValue> {
+        match value.try_convert(column_data_type.sql_type())? {
+            SqlValue::Null if !column_data_type.nullable() => Err(SpringError::Sql(anyhow!(
+                r#"DEFAULT of NOT NULL column "{}" cannot be NULL"#,
+                column_data_type.column_name()
+            ))),
+            value => Ok(value),
+        }
+    }
+
     /*
      * ----------------------------------------------------------------------------
      * Option Specifications
@@ -1406,3 +2832,152 @@ impl PestParserImpl {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_value_expr(sql: &str) -> ValueExpr {
+        let pairs = GeneratedParser::parse(Rule::value_expr, sql).unwrap();
+        let mut params = FnParseParams {
+            sql,
+            children_pairs: pairs.collect(),
+            self_string: sql.to_string(),
+        };
+        parse_child(
+            &mut params,
+            Rule::value_expr,
+            PestParserImpl::parse_value_expr,
+            identity,
+        )
+        .unwrap()
+    }
+
+    fn colref(column_name: &str) -> ValueExpr {
+        ValueExpr::factory_colref("s", column_name)
+    }
+
+    #[test]
+    fn test_left_associativity() {
+        assert_eq!(
+            parse_value_expr("s.a - s.b - s.c"),
+            ValueExpr::factory_sub(
+                ValueExpr::factory_sub(colref("a"), colref("b")),
+                colref("c")
+            )
+        );
+    }
+
+    #[test]
+    fn test_precedence() {
+        assert_eq!(
+            parse_value_expr("s.a + s.b * s.c"),
+            ValueExpr::factory_add(
+                colref("a"),
+                ValueExpr::factory_mul(colref("b"), colref("c"))
+            )
+        );
+        assert_eq!(
+            parse_value_expr("s.a * s.b + s.c"),
+            ValueExpr::factory_add(
+                ValueExpr::factory_mul(colref("a"), colref("b")),
+                colref("c")
+            )
+        );
+        assert_eq!(
+            parse_value_expr("(s.a + s.b) * s.c"),
+            ValueExpr::factory_mul(
+                ValueExpr::factory_add(colref("a"), colref("b")),
+                colref("c")
+            )
+        );
+        assert_eq!(
+            parse_value_expr("s.a = 1 AND s.b + 1 = s.c"),
+            ValueExpr::factory_and(
+                ValueExpr::factory_eq(colref("a"), ValueExpr::factory_integer(1)),
+                ValueExpr::factory_eq(
+                    ValueExpr::factory_add(colref("b"), ValueExpr::factory_integer(1)),
+                    colref("c")
+                )
+            )
+        );
+        assert_eq!(
+            parse_value_expr("-s.a + s.b"),
+            ValueExpr::factory_add(
+                ValueExpr::factory_uni_op(UnaryOperator::Minus, colref("a")),
+                colref("b")
+            )
+        );
+    }
+    #[test]
+    fn test_match_recognize_pattern() {
+        let parse = |pattern: &str| {
+            let sql = format!(
+                "MATCH_RECOGNIZE (ORDER BY s.ts MEASURES A.x AS x PATTERN ({}) WITHIN DURATION_SECS(1))",
+                pattern
+            );
+            GeneratedParser::parse(Rule::match_recognize_clause, &sql).map(|_| ())
+        };
+
+        assert!(parse("A B* C? D+").is_ok());
+        // alternation and grouping are not supported
+        assert!(parse("A | B").is_err());
+        assert!(parse("A (B C)+").is_err());
+    }
+
+    #[test]
+    fn test_reserved_keyword_identifier() {
+        let parse = |name: &str| {
+            GeneratedParser::parse(Rule::identifier, name)
+                .map(|mut pairs| pairs.next().unwrap().as_str() == name)
+                .unwrap_or(false)
+        };
+
+        for keyword in ["order", "JSON", "Lag", "within"] {
+            assert!(!parse(keyword), "{} is reserved", keyword);
+        }
+        for name in ["order_id", "json1", "lagging", "ticker"] {
+            assert!(parse(name), "{} is not reserved", name);
+        }
+    }
+
+    #[test]
+    fn test_resample_within() {
+        let parse = |sql: &str| {
+            let pairs = GeneratedParser::parse(Rule::resample_window_clause, sql).unwrap();
+            let mut params = FnParseParams {
+                sql,
+                children_pairs: pairs.collect(),
+                self_string: sql.to_string(),
+            };
+            parse_child(
+                &mut params,
+                Rule::resample_window_clause,
+                PestParserImpl::parse_resample_window_clause,
+                identity,
+            )
+            .unwrap()
+        };
+
+        assert!(matches!(
+            parse("RESAMPLE EVERY DURATION_SECS(10), DURATION_SECS(0) FILL LINEAR WITHIN DURATION_SECS(60)"),
+            WindowParameter::TimedResampleWindow {
+                fill: ResampleFill::Linear,
+                within: Some(within),
+                ..
+            } if within == SpringEventDuration::from_secs(60)
+        ));
+        assert!(matches!(
+            parse("RESAMPLE EVERY DURATION_SECS(10) ON PTIME WITHIN DURATION_SECS(60)"),
+            WindowParameter::TimedResampleWindow {
+                fill: ResampleFill::Null,
+                within: Some(_),
+                ..
+            }
+        ));
+        assert!(matches!(
+            parse("RESAMPLE EVERY DURATION_SECS(10), DURATION_SECS(0)"),
+            WindowParameter::TimedResampleWindow { within: None, .. }
+        ));
+    }
+}
@@ -2,13 +2,21 @@
 
 use crate::{
     expression::{AggrExpr, ValueExpr},
-    pipeline::{AggrAlias, CorrelationAlias, JoinType, StreamName, ValueAlias, WindowParameter},
+    pipeline::{
+        AggrAlias, ColumnName, ColumnReference, CorrelationAlias, JoinType, PatternQuantifier,
+        SortOrder, StreamName, ValueAlias, WindowParameter, WindowTime,
+    },
+    stream_engine::{time::SpringEventDuration, SqlValue},
 };
 
-#[derive(Clone, Eq, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug)]
 pub enum ColumnConstraintSyntax {
     NotNull, // this is treated as data type in pipeline
     Rowtime,
+    /// `DEFAULT constant`
+    Default(SqlValue),
+    /// `GENERATED ALWAYS AS (expr)`
+    Generated(ValueExpr),
 }
 
 #[derive(Clone, Eq, PartialEq, Debug)]
@@ -19,13 +27,31 @@ pub struct OptionSyntax {
 
 #[derive(Clone, PartialEq, Debug)]
 pub struct SelectStreamSyntax {
+    /// `SELECT DISTINCT STREAM`
+    pub distinct: bool,
+
     pub fields: Vec<SelectFieldSyntax>,
     pub from_item: FromItemSyntax,
+    /// `CROSS JOIN UNNEST(...)` following the FROM item
+    pub unnest: Option<UnnestSyntax>,
+
+    pub match_recognize: Option<MatchRecognizeSyntax>,
+
+    pub deduplicate: Option<DeduplicateSyntax>,
+
+    pub changes_only: Option<ChangesOnlySyntax>,
+
+    pub silence_alert: Option<SilenceAlertSyntax>,
+
+    /// `WHERE condition`
+    pub where_condition: Option<ValueExpr>,
 
     /// Empty when no GROUP BY clause is supplied.
     pub grouping_elements: Vec<GroupingElementSyntax>,
 
     pub window_clause: Option<WindowParameter>,
+
+    pub order_by: Option<OrderBySyntax>,
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -49,16 +75,96 @@ pub enum FromItemSyntax {
 
         join_type: JoinType,
         on_expr: ValueExpr,
+        /// Some for interval join
+        interval_condition: Option<Box<IntervalConditionSyntax>>,
         // TODO alias
     },
 }
 
+/// `column_reference BETWEEN lower AND upper` in ON clause of interval join.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct IntervalConditionSyntax {
+    pub column_reference: ColumnReference,
+    pub lower: IntervalBoundSyntax,
+    pub upper: IntervalBoundSyntax,
+}
+
+/// `column_reference [{+|-} duration]`
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct IntervalBoundSyntax {
+    pub column_reference: ColumnReference,
+    pub offset: Option<IntervalOffsetSyntax>,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum IntervalOffsetSyntax {
+    Plus(SpringEventDuration),
+    Minus(SpringEventDuration),
+}
+
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct SubFromItemSyntax {
     pub stream_name: StreamName,
     pub alias: Option<CorrelationAlias>,
 }
 
+/// `CROSS JOIN UNNEST(array) AS alias(column_name)`
+#[derive(Clone, PartialEq, Debug)]
+pub struct UnnestSyntax {
+    pub array: ValueExpr,
+    pub alias: CorrelationAlias,
+    pub column_name: ColumnName,
+}
+
+/// `MATCH_RECOGNIZE (PARTITION BY ... ORDER BY ... MEASURES ... PATTERN (...) WITHIN duration DEFINE ...)`
+#[derive(Clone, PartialEq, Debug)]
+pub struct MatchRecognizeSyntax {
+    pub partition_by: Vec<ValueExpr>,
+    pub order_by: ColumnReference,
+    pub measures: Vec<(ValueExpr, ColumnName)>,
+    pub pattern: Vec<(StreamName, PatternQuantifier)>,
+    pub within: SpringEventDuration,
+    pub defines: Vec<(StreamName, ValueExpr)>,
+}
+
+/// `DEDUPLICATE ON (key, ...) WITHIN duration`
+#[derive(Clone, PartialEq, Debug)]
+pub struct DeduplicateSyntax {
+    pub keys: Vec<ValueExpr>,
+    pub within: SpringEventDuration,
+}
+
+/// `CHANGES ONLY ON (column, ...) [PARTITION BY key, ...]`
+#[derive(Clone, PartialEq, Debug)]
+pub struct ChangesOnlySyntax {
+    pub columns: Vec<ValueExpr>,
+    pub partition_by: Vec<ValueExpr>,
+}
+
+/// `ALERT IF SILENT FOR duration [ON PTIME] [PARTITION BY key, ...]`
+#[derive(Clone, PartialEq, Debug)]
+pub struct SilenceAlertSyntax {
+    pub silent_for: SpringEventDuration,
+    pub time: WindowTime,
+    pub partition_by: Vec<ValueExpr>,
+}
+
+/// `ORDER BY sort_key [ASC|DESC] LIMIT n`
+#[derive(Clone, PartialEq, Debug)]
+pub struct OrderBySyntax {
+    pub sort_key: SortKeySyntax,
+    pub order: SortOrder,
+    pub limit: usize,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum SortKeySyntax {
+    ValueExpr(ValueExpr),
+    AggrExpr(AggrExpr),
+    /// Value alias or aggregate alias in select_list.
+    Alias(String),
+}
+
 #[derive(Clone, PartialEq, Debug)]
 pub enum GroupingElementSyntax {
     ValueExpr(ValueExpr),
@@ -67,6 +173,16 @@ pub enum GroupingElementSyntax {
 
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub enum DurationFunction {
+    Micros,
     Millis,
     Secs,
+    Minutes,
+    Hours,
+}
+
+/// `[key]` or `.field` following a value expression.
+#[derive(Clone, PartialEq, Debug)]
+pub enum ElementAccessorSyntax {
+    Key(ValueExpr),
+    Field(String),
 }
@@ -4,6 +4,7 @@
 
 pub mod autonomous_executor;
 pub mod command;
+pub mod decimal;
 mod in_memory_queue_repository;
 mod sql_executor;
 pub mod time;
@@ -35,6 +35,30 @@ impl TaskRepository {
     pub fn purge_windows(&self) {
         for task in self.repo.values() {
             if let Task::Pump(pump_task) = task.as_ref() {
+                if let Some(mut j) = pump_task.get_interval_join_mut() {
+                    j.purge()
+                }
+                if let Some(mut m) = pump_task.get_match_recognize_mut() {
+                    m.purge()
+                }
+                if let Some(mut d) = pump_task.get_deduplicate_mut() {
+                    d.purge()
+                }
+                if let Some(mut c) = pump_task.get_changes_only_mut() {
+                    c.purge()
+                }
+                if let Some(mut s) = pump_task.get_silence_alert_mut() {
+                    s.purge()
+                }
+                if let Some(mut n) = pump_task.get_navigation_mut() {
+                    n.purge()
+                }
+                if let Some(mut a) = pump_task.get_continuous_aggregate_mut() {
+                    a.purge()
+                }
+                if let Some(mut w) = pump_task.get_distinct_window_mut() {
+                    w.purge()
+                }
                 if let Some(mut w) = pump_task.get_join_window_mut() {
                     w.borrow_mut().purge()
                 } else if let Some(mut w) = pump_task.get_aggr_window_mut() {
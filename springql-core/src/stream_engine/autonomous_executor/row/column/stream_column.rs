@@ -7,10 +7,11 @@ use anyhow::{anyhow, Context};
 use crate::{
     api::error::{Result, SpringError},
     mem_size::{arc_overhead_size, MemSize},
-    pipeline::{ColumnDefinition, ColumnName, StreamModel},
+    pipeline::{ColumnDefinition, ColumnName, ColumnReference, Field, StreamModel},
     stream_engine::{
         autonomous_executor::row::{column_values::ColumnValues, value::SqlValue},
-        time::SpringTimestamp,
+        time::{SpringTimestamp, SystemTimestamp},
+        RowTime, Tuple,
     },
 };
 
@@ -58,6 +59,66 @@ impl StreamColumns {
         })
     }
 
+    /// Same as `new()` but for a row from a source:
+    ///
+    /// - DEFAULT value is used for a column lacking in `column_values` (or NULL in it).
+    /// - GENERATED ALWAYS AS column is evaluated from the other columns and preceding GENERATED ALWAYS AS columns, ignoring its value in `column_values`.
+    ///   Processing time in the expression is the current time.
+    ///
+    /// # Failure
+    ///
+    /// - `SpringError::Sql` when:
+    ///   - `column_values` lacks any of `stream.columns()` without DEFAULT nor GENERATED ALWAYS AS.
+    ///   - Type mismatch (and failed to convert type) with `stream_shape` and `column_values`.
+    ///   - Failed to evaluate GENERATED ALWAYS AS expression.
+    pub fn from_source(
+        stream_model: Arc<StreamModel>,
+        mut column_values: ColumnValues,
+    ) -> Result<Self> {
+        let coldefs = stream_model.shape().columns();
+        let colref = |coldef: &ColumnDefinition| ColumnReference::Column {
+            stream_name: stream_model.name().clone(),
+            column_name: coldef.column_name().clone(),
+        };
+
+        let mut tuple = Tuple::new(RowTime::ProcessingTime(SystemTimestamp::now()), vec![]);
+        let mut values = coldefs
+            .iter()
+            .map(|coldef| {
+                if coldef.generated_expr().is_some() {
+                    return Ok(None);
+                }
+                let value = match (
+                    column_values.remove(coldef.column_name()),
+                    coldef.default_value(),
+                ) {
+                    (Ok(SqlValue::Null) | Err(_), Some(default)) => default.clone(),
+                    (value, _) => value?,
+                };
+                let value = Self::validate_or_try_convert_value_type(value, coldef)?;
+                tuple.push_field(Field::new(colref(coldef), value.clone()));
+                Ok(Some(value))
+            })
+            .collect::<Result<Vec<Option<SqlValue>>>>()?;
+
+        for (coldef, value) in coldefs.iter().zip(values.iter_mut()) {
+            if let Some(expr) = coldef.generated_expr() {
+                let generated = expr.clone().resolve_colref(&tuple)?.eval()?;
+                let generated = Self::validate_or_try_convert_value_type(generated, coldef)?;
+                tuple.push_field(Field::new(colref(coldef), generated.clone()));
+                *value = Some(generated);
+            }
+        }
+
+        Ok(Self {
+            stream_model,
+            values: values
+                .into_iter()
+                .map(|value| value.expect("all the columns are evaluated"))
+                .collect(),
+        })
+    }
+
     pub fn stream_model(&self) -> &StreamModel {
         &self.stream_model
     }
@@ -102,7 +163,8 @@ impl StreamColumns {
 
         match &value {
             SqlValue::NotNull(nn_value) => {
-                if &nn_value.sql_type() == cdt.sql_type() {
+                // Type of a structured value is inferred from its elements, so always converted to the declared one.
+                if !cdt.sql_type().is_structured() && &nn_value.sql_type() == cdt.sql_type() {
                     Ok(value)
                 } else {
                     let nn_value = nn_value
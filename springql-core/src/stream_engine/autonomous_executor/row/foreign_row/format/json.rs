@@ -4,8 +4,11 @@ use anyhow::Context;
 
 use crate::{
     api::error::{Result, SpringError},
-    pipeline::ColumnName,
-    stream_engine::autonomous_executor::row::{column_values::ColumnValues, value::SqlValue},
+    pipeline::{ColumnName, ColumnPathOptions, TimestampFormatOptions},
+    stream_engine::autonomous_executor::row::{
+        column_values::ColumnValues,
+        value::{NnSqlValue, SqlValue},
+    },
 };
 
 #[derive(Clone, Eq, PartialEq, Debug, new)]
@@ -44,10 +47,13 @@ impl JsonObject {
     /// - `SpringError::InvalidFormat` when:
     ///   - Internal JSON cannot be mapped to SQL type (nested, for example).
     ///
-    /// # TODO
-    ///
-    /// See stream.options to more intelligently parse JSON. <https://docs.sqlstream.com/sql-reference-guide/create-statements/createforeignstream/#parsing-json>
-    pub fn into_column_values(self) -> Result<ColumnValues> {
+    /// Columns in `column_paths` are taken from their paths (NULL if missing), and other columns from top-level keys.
+    /// Columns in `timestamp_formats` are parsed into TIMESTAMP in their formats.
+    pub fn into_column_values(
+        self,
+        column_paths: &ColumnPathOptions,
+        timestamp_formats: &TimestampFormatOptions,
+    ) -> Result<ColumnValues> {
         let json_object = self.0;
 
         let top_object = json_object
@@ -58,22 +64,54 @@ impl JsonObject {
                 s: format!("{:?}", json_object),
             })?;
 
-        top_object
+        let mut column_values = top_object
             .into_iter()
+            .filter(|(k, _)| {
+                !column_paths
+                    .column_paths
+                    .contains_key(&ColumnName::new(k.to_string()))
+            })
             .fold(Ok(ColumnValues::default()), |acc, (k, v)| {
                 let mut column_values = acc?;
-                let (column_name, sql_value) = Self::to_column_value(k, v)?;
+                let (column_name, sql_value) = Self::to_column_value(k, v, timestamp_formats)?;
                 column_values.insert(column_name, sql_value)?;
                 Ok(column_values)
-            })
+            })?;
+
+        for (column_name, column_path) in &column_paths.column_paths {
+            let sql_value = match column_path.query(&json_object) {
+                Some(v) => Self::to_sql_value(column_name, v, timestamp_formats)?,
+                None => SqlValue::Null,
+            };
+            column_values.insert(column_name.clone(), sql_value)?;
+        }
+        Ok(column_values)
     }
 
     fn to_column_value(
         json_key: &str,
         json_value: &serde_json::Value,
+        timestamp_formats: &TimestampFormatOptions,
     ) -> Result<(ColumnName, SqlValue)> {
-        let sql_value = SqlValue::try_from(json_value)?;
         let column_name = ColumnName::new(json_key.to_string());
+        let sql_value = Self::to_sql_value(&column_name, json_value, timestamp_formats)?;
         Ok((column_name, sql_value))
     }
+
+    fn to_sql_value(
+        column_name: &ColumnName,
+        json_value: &serde_json::Value,
+        timestamp_formats: &TimestampFormatOptions,
+    ) -> Result<SqlValue> {
+        match (
+            timestamp_formats.timestamp_formats.get(column_name),
+            json_value,
+        ) {
+            (Some(timestamp_format), v) if !v.is_null() => {
+                let ts = timestamp_format.parse_json(v)?;
+                Ok(SqlValue::NotNull(NnSqlValue::Timestamp(ts)))
+            }
+            _ => SqlValue::try_from(json_value),
+        }
+    }
 }
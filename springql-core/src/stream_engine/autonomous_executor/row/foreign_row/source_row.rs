@@ -8,6 +8,7 @@ pub use json_source_row::JsonSourceRow;
 
 use crate::{
     api::{error::Result, SpringError},
+    pipeline::{ColumnPathOptions, TimestampFormatOptions},
     stream_engine::autonomous_executor::row::schemaless_row::SchemalessRow,
 };
 
@@ -30,16 +31,31 @@ impl SourceRow {
         let json_source_row = JsonSourceRow::parse(json)?;
         Ok(Self::Json(json_source_row))
     }
+
+    /// Same as `SchemalessRow::try_from()` but JSON values nested at `column_paths` are also taken as columns,
+    /// and JSON values of columns in `timestamp_formats` are parsed into TIMESTAMP.
+    pub fn into_schemaless_row(
+        self,
+        column_paths: &ColumnPathOptions,
+        timestamp_formats: &TimestampFormatOptions,
+    ) -> Result<SchemalessRow> {
+        match self {
+            SourceRow::Json(json_source_row) => {
+                json_source_row.into_schemaless_row(column_paths, timestamp_formats)
+            }
+            SourceRow::CANFrame(can_frame_source_row) => can_frame_source_row.into_schemaless_row(),
+            SourceRow::Raw(schemaless_row) => Ok(schemaless_row),
+        }
+    }
 }
 
 impl TryFrom<SourceRow> for SchemalessRow {
     type Error = SpringError;
 
     fn try_from(row: SourceRow) -> Result<Self> {
-        match row {
-            SourceRow::Json(json_source_row) => json_source_row.into_schemaless_row(),
-            SourceRow::CANFrame(can_frame_source_row) => can_frame_source_row.into_schemaless_row(),
-            SourceRow::Raw(schemaless_row) => Ok(schemaless_row),
-        }
+        row.into_schemaless_row(
+            &ColumnPathOptions::default(),
+            &TimestampFormatOptions::default(),
+        )
     }
 }
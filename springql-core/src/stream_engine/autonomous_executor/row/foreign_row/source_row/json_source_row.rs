@@ -2,6 +2,7 @@
 
 use crate::{
     api::error::Result,
+    pipeline::{ColumnPathOptions, TimestampFormatOptions},
     stream_engine::autonomous_executor::row::{
         foreign_row::format::JsonObject, schemaless_row::SchemalessRow,
     },
@@ -23,9 +24,13 @@ impl JsonSourceRow {
         Self(json)
     }
 
-    pub fn into_schemaless_row(self) -> Result<SchemalessRow> {
+    pub fn into_schemaless_row(
+        self,
+        column_paths: &ColumnPathOptions,
+        timestamp_formats: &TimestampFormatOptions,
+    ) -> Result<SchemalessRow> {
         // JsonSourceRow -> JsonObject -> ColumnValues -> SchemalessRow
-        let column_values = self.0.into_column_values()?;
+        let column_values = self.0.into_column_values(column_paths, timestamp_formats)?;
         Ok(column_values.into())
     }
 }
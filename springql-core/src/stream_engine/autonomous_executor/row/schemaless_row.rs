@@ -1,13 +1,13 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
-use std::vec;
+use std::{str::FromStr, vec};
 
 use crate::{
     api::error::Result,
-    pipeline::ColumnName,
+    pipeline::{ColumnName, DecimalFormat},
     stream_engine::{
         autonomous_executor::{ColumnValues, JsonObject},
-        SqlValue, StreamRow,
+        NnSqlValue, SqlValue, StreamRow,
     },
 };
 
@@ -48,6 +48,50 @@ impl SchemalessRow {
     pub fn into_column_values(self) -> ColumnValues {
         self.colvals
     }
+
+    /// DECIMAL values are written as `decimal_format`.
+    pub fn into_json(self, decimal_format: &DecimalFormat) -> JsonObject {
+        let map = self
+            .into_iter()
+            .map(|(col, val)| (col.to_string(), json_value(val, decimal_format)))
+            .collect::<serde_json::Map<String, serde_json::Value>>();
+        let v = serde_json::Value::from(map);
+        JsonObject::new(v)
+    }
+}
+
+/// Elements of structured values are also written with `decimal_format`.
+fn json_value(val: SqlValue, decimal_format: &DecimalFormat) -> serde_json::Value {
+    match (val, decimal_format) {
+        (SqlValue::NotNull(NnSqlValue::Decimal(d)), DecimalFormat::Number) => {
+            serde_json::Value::Number(
+                serde_json::Number::from_str(&d.to_string())
+                    .expect("a decimal is a valid JSON number"),
+            )
+        }
+        (SqlValue::NotNull(NnSqlValue::Decimal(d)), DecimalFormat::String) => {
+            serde_json::Value::from(d.to_string())
+        }
+        (SqlValue::NotNull(NnSqlValue::Array(elements)), _) => serde_json::Value::Array(
+            elements
+                .into_iter()
+                .map(|v| json_value(v, decimal_format))
+                .collect(),
+        ),
+        (SqlValue::NotNull(NnSqlValue::Map(entries)), _) => serde_json::Value::Object(
+            entries
+                .into_iter()
+                .map(|(key, v)| (key, json_value(v, decimal_format)))
+                .collect(),
+        ),
+        (SqlValue::NotNull(NnSqlValue::Row(fields)), _) => serde_json::Value::Object(
+            fields
+                .into_iter()
+                .map(|(name, v)| (name, json_value(v, decimal_format)))
+                .collect(),
+        ),
+        (val, _) => serde_json::Value::from(val),
+    }
 }
 
 impl From<StreamRow> for SchemalessRow {
@@ -74,12 +118,7 @@ impl IntoIterator for SchemalessRow {
 
 impl From<SchemalessRow> for JsonObject {
     fn from(row: SchemalessRow) -> Self {
-        let map = row
-            .into_iter()
-            .map(|(col, val)| (col.to_string(), serde_json::Value::from(val)))
-            .collect::<serde_json::Map<String, serde_json::Value>>();
-        let v = serde_json::Value::from(map);
-        JsonObject::new(v)
+        row.into_json(&DecimalFormat::default())
     }
 }
 
@@ -103,4 +142,34 @@ mod tests {
 
         assert_eq!(JsonObject::from(row), json);
     }
+
+    #[test]
+    fn test_into_json_decimal_format() -> Result<()> {
+        let mut row = SchemalessRow::default();
+        row.insert(
+            ColumnName::new("price".to_string()),
+            SqlValue::NotNull(NnSqlValue::Decimal("12.30".parse()?)),
+        )?;
+
+        assert_eq!(
+            row.clone().into_json(&DecimalFormat::Number),
+            JsonObject::new(serde_json::from_str(r#"{"price": 12.30}"#).unwrap())
+        );
+        assert_eq!(
+            row.into_json(&DecimalFormat::String),
+            JsonObject::new(json!({"price": "12.30"}))
+        );
+
+        // more digits than f64 holds
+        let d = "12345678901234567890.123456789";
+        assert_eq!(
+            json_value(
+                SqlValue::NotNull(NnSqlValue::Decimal(d.parse()?)),
+                &DecimalFormat::Number
+            )
+            .to_string(),
+            d
+        );
+        Ok(())
+    }
 }
@@ -39,8 +39,9 @@ impl StreamRow {
         }
     }
 
+    /// DEFAULT and GENERATED ALWAYS AS columns are filled here. See `StreamColumns::from_source()`.
     pub fn from_schemaless_row(row: SchemalessRow, stream_model: Arc<StreamModel>) -> Result<Self> {
-        let cols = StreamColumns::new(stream_model, row.into_column_values())?;
+        let cols = StreamColumns::from_source(stream_model, row.into_column_values())?;
         Ok(Self::new(cols))
     }
 
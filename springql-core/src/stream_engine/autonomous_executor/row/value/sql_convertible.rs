@@ -2,15 +2,18 @@
 
 mod blob;
 mod boolean;
+mod decimal;
 mod event_duration;
 mod float;
 mod int;
+mod json;
 mod text;
 mod timestamp;
 
 use crate::{
     api::error::{Result, SpringError},
     stream_engine::{
+        decimal::SpringDecimal,
         time::{SpringEventDuration, SpringTimestamp},
         SqlValue,
     },
@@ -84,6 +87,22 @@ pub trait SpringValue: ToNnSqlValue + Sized {
         Self::default_err("f32")
     }
 
+    /// # Failures
+    ///
+    /// - `SpringError::Sql` when:
+    ///   - the type implementing SqlConvertible is not convertible from f64
+    fn try_from_f64(_: &f64) -> Result<Self> {
+        Self::default_err("f64")
+    }
+
+    /// # Failures
+    ///
+    /// - `SpringError::Sql` when:
+    ///   - the type implementing SqlConvertible is not convertible from SpringDecimal
+    fn try_from_decimal(_: &SpringDecimal) -> Result<Self> {
+        Self::default_err("SpringDecimal")
+    }
+
     /// # Failures
     ///
     /// - `SpringError::Sql` when:
@@ -116,6 +135,14 @@ pub trait SpringValue: ToNnSqlValue + Sized {
         Self::default_err("Timestamp")
     }
 
+    /// # Failures
+    ///
+    /// - `SpringError::Sql` when:
+    ///   - the type implementing SqlConvertible is not convertible from Timestamp with time zone
+    fn try_from_timestamp_tz(_: &SpringTimestamp) -> Result<Self> {
+        Self::default_err("TimestampTz")
+    }
+
     /// # Failures
     ///
     /// - `SpringError::Sql` when:
@@ -124,6 +151,14 @@ pub trait SpringValue: ToNnSqlValue + Sized {
         Self::default_err("EventDuration")
     }
 
+    /// # Failures
+    ///
+    /// - `SpringError::Sql` when:
+    ///   - the type implementing SqlConvertible is not convertible from JSON
+    fn try_from_json(_: &serde_json::Value) -> Result<Self> {
+        Self::default_err("serde_json::Value")
+    }
+
     #[doc(hidden)]
     fn default_err(from_type: &str) -> Result<Self> {
         Err(SpringError::Sql(anyhow!(
@@ -0,0 +1,83 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::{
+    api::error::Result,
+    stream_engine::{
+        autonomous_executor::row::value::{
+            sql_convertible::ToNnSqlValue, sql_value::NnSqlValue, SpringValue,
+        },
+        decimal::SpringDecimal,
+    },
+};
+
+impl SpringValue for SpringDecimal {
+    fn try_from_i16(v: &i16) -> Result<Self> {
+        SpringDecimal::new(*v as i128, 0)
+    }
+    fn try_from_i32(v: &i32) -> Result<Self> {
+        SpringDecimal::new(*v as i128, 0)
+    }
+    fn try_from_i64(v: &i64) -> Result<Self> {
+        SpringDecimal::new(*v as i128, 0)
+    }
+
+    fn try_from_u32(v: &u32) -> Result<Self> {
+        SpringDecimal::new(*v as i128, 0)
+    }
+    fn try_from_u64(v: &u64) -> Result<Self> {
+        SpringDecimal::new(*v as i128, 0)
+    }
+
+    fn try_from_f32(v: &f32) -> Result<Self> {
+        SpringDecimal::from_f32(*v)
+    }
+    fn try_from_f64(v: &f64) -> Result<Self> {
+        SpringDecimal::from_f64(*v)
+    }
+
+    fn try_from_decimal(v: &SpringDecimal) -> Result<Self> {
+        Ok(*v)
+    }
+
+    fn try_from_string(s: &str) -> Result<Self> {
+        s.parse()
+    }
+}
+
+impl ToNnSqlValue for SpringDecimal {
+    fn into_nn_sql_value(self) -> NnSqlValue {
+        NnSqlValue::Decimal(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unpack_decimal_loosely() -> Result<()> {
+        let price = NnSqlValue::Decimal("12.50".parse()?);
+
+        assert_eq!(price.unpack::<SpringDecimal>()?.to_string(), "12.50");
+        assert_eq!(price.unpack::<String>()?, "12.50");
+        assert_eq!(price.unpack::<i64>()?, 13);
+        assert_eq!(price.unpack::<f64>()?, 12.5);
+
+        assert_eq!(
+            NnSqlValue::BigInt(-3)
+                .unpack::<SpringDecimal>()?
+                .to_string(),
+            "-3"
+        );
+        assert_eq!(
+            NnSqlValue::Double(ordered_float::OrderedFloat(0.1))
+                .unpack::<SpringDecimal>()?
+                .to_string(),
+            "0.1"
+        );
+        assert!(NnSqlValue::Decimal("-1.5".parse()?)
+            .unpack::<u32>()
+            .is_err());
+        Ok(())
+    }
+}
@@ -4,8 +4,11 @@ use ordered_float::OrderedFloat;
 
 use crate::{
     api::error::Result,
-    stream_engine::autonomous_executor::row::value::{
-        sql_convertible::ToNnSqlValue, sql_value::NnSqlValue, SpringValue,
+    stream_engine::{
+        autonomous_executor::row::value::{
+            sql_convertible::ToNnSqlValue, sql_value::NnSqlValue, SpringValue,
+        },
+        decimal::SpringDecimal,
     },
 };
 
@@ -20,9 +23,22 @@ impl SpringValue for f32 {
         Ok(*v as f32)
     }
 
+    fn try_from_u32(v: &u32) -> Result<Self> {
+        Ok(*v as f32)
+    }
+    fn try_from_u64(v: &u64) -> Result<Self> {
+        Ok(*v as f32)
+    }
+
     fn try_from_f32(v: &f32) -> Result<Self> {
         Ok(*v)
     }
+    fn try_from_f64(v: &f64) -> Result<Self> {
+        Ok(*v as f32)
+    }
+    fn try_from_decimal(v: &SpringDecimal) -> Result<Self> {
+        Ok(v.to_f64() as f32)
+    }
 }
 
 impl ToNnSqlValue for f32 {
@@ -31,6 +47,41 @@ impl ToNnSqlValue for f32 {
     }
 }
 
+impl SpringValue for f64 {
+    fn try_from_i16(v: &i16) -> Result<Self> {
+        Ok(*v as f64)
+    }
+    fn try_from_i32(v: &i32) -> Result<Self> {
+        Ok(*v as f64)
+    }
+    fn try_from_i64(v: &i64) -> Result<Self> {
+        Ok(*v as f64)
+    }
+
+    fn try_from_u32(v: &u32) -> Result<Self> {
+        Ok(*v as f64)
+    }
+    fn try_from_u64(v: &u64) -> Result<Self> {
+        Ok(*v as f64)
+    }
+
+    fn try_from_f32(v: &f32) -> Result<Self> {
+        Ok(*v as f64)
+    }
+    fn try_from_f64(v: &f64) -> Result<Self> {
+        Ok(*v)
+    }
+    fn try_from_decimal(v: &SpringDecimal) -> Result<Self> {
+        Ok(v.to_f64())
+    }
+}
+
+impl ToNnSqlValue for f64 {
+    fn into_nn_sql_value(self) -> NnSqlValue {
+        NnSqlValue::Double(OrderedFloat(self))
+    }
+}
+
 #[cfg(test)]
 mod tests_f32 {
     use float_cmp::approx_eq;
@@ -56,3 +107,37 @@ mod tests_f32 {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests_f64 {
+    use float_cmp::approx_eq;
+
+    use super::*;
+    use crate::{
+        api::error::Result, stream_engine::autonomous_executor::row::value::sql_value::NnSqlValue,
+    };
+
+    #[test]
+    fn test_pack_unpack_f64() -> Result<()> {
+        let rust_values = vec![0f64, 1., -1., 35.681236123456, f64::MAX, f64::MIN, f64::NAN];
+
+        for v in rust_values {
+            let sql_value = NnSqlValue::Double(OrderedFloat(v));
+            let unpacked: f64 = sql_value.unpack()?;
+            if v.is_nan() {
+                assert!(unpacked.is_nan());
+            } else {
+                assert!(approx_eq!(f64, unpacked, v, ulps = 0));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_unpack_float_as_f64() -> Result<()> {
+        let sql_value = NnSqlValue::Float(OrderedFloat(1.5));
+        let unpacked: f64 = sql_value.unpack()?;
+        assert!(approx_eq!(f64, unpacked, 1.5));
+        Ok(())
+    }
+}
@@ -4,8 +4,11 @@ use anyhow::{anyhow, Context};
 
 use crate::{
     api::error::{Result, SpringError},
-    stream_engine::autonomous_executor::row::value::{
-        sql_convertible::ToNnSqlValue, sql_value::NnSqlValue, SpringValue,
+    stream_engine::{
+        autonomous_executor::row::value::{
+            sql_convertible::ToNnSqlValue, sql_value::NnSqlValue, SpringValue,
+        },
+        decimal::SpringDecimal,
     },
 };
 
@@ -29,6 +32,17 @@ impl SpringValue for i16 {
     fn try_from_f32(v: &f32) -> Result<Self> {
         Ok(v.ceil() as i16)
     }
+
+    fn try_from_f64(v: &f64) -> Result<Self> {
+        Ok(v.ceil() as i16)
+    }
+
+    fn try_from_decimal(v: &SpringDecimal) -> Result<Self> {
+        let integer = v.round(0)?.mantissa();
+        i16::try_from(integer)
+            .with_context(|| format!("cannot convert DECIMAL value ({}) into i16", v))
+            .map_err(SpringError::Sql)
+    }
 }
 impl ToNnSqlValue for i16 {
     fn into_nn_sql_value(self) -> NnSqlValue {
@@ -54,6 +68,17 @@ impl SpringValue for i32 {
     fn try_from_f32(v: &f32) -> Result<Self> {
         Ok(v.ceil() as i32)
     }
+
+    fn try_from_f64(v: &f64) -> Result<Self> {
+        Ok(v.ceil() as i32)
+    }
+
+    fn try_from_decimal(v: &SpringDecimal) -> Result<Self> {
+        let integer = v.round(0)?.mantissa();
+        i32::try_from(integer)
+            .with_context(|| format!("cannot convert DECIMAL value ({}) into i32", v))
+            .map_err(SpringError::Sql)
+    }
 }
 impl ToNnSqlValue for i32 {
     fn into_nn_sql_value(self) -> NnSqlValue {
@@ -77,6 +102,17 @@ impl SpringValue for i64 {
     fn try_from_f32(v: &f32) -> Result<Self> {
         Ok(v.ceil() as i64)
     }
+
+    fn try_from_f64(v: &f64) -> Result<Self> {
+        Ok(v.ceil() as i64)
+    }
+
+    fn try_from_decimal(v: &SpringDecimal) -> Result<Self> {
+        let integer = v.round(0)?.mantissa();
+        i64::try_from(integer)
+            .with_context(|| format!("cannot convert DECIMAL value ({}) into i64", v))
+            .map_err(SpringError::Sql)
+    }
 }
 impl ToNnSqlValue for i64 {
     fn into_nn_sql_value(self) -> NnSqlValue {
@@ -131,6 +167,13 @@ impl SpringValue for u32 {
             .with_context(|| format!("cannot convert u64 value ({}) into u32", v))
             .map_err(SpringError::Sql)
     }
+
+    fn try_from_decimal(v: &SpringDecimal) -> Result<Self> {
+        let integer = v.round(0)?.mantissa();
+        u32::try_from(integer)
+            .with_context(|| format!("cannot convert DECIMAL value ({}) into u32", v))
+            .map_err(SpringError::Sql)
+    }
 }
 impl ToNnSqlValue for u32 {
     fn into_nn_sql_value(self) -> NnSqlValue {
@@ -183,6 +226,13 @@ impl SpringValue for u64 {
     fn try_from_u64(v: &u64) -> Result<Self> {
         Ok(*v)
     }
+
+    fn try_from_decimal(v: &SpringDecimal) -> Result<Self> {
+        let integer = v.round(0)?.mantissa();
+        u64::try_from(integer)
+            .with_context(|| format!("cannot convert DECIMAL value ({}) into u64", v))
+            .map_err(SpringError::Sql)
+    }
 }
 impl ToNnSqlValue for u64 {
     fn into_nn_sql_value(self) -> NnSqlValue {
@@ -0,0 +1,101 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::{
+    api::error::Result,
+    stream_engine::{
+        autonomous_executor::row::value::{
+            sql_convertible::ToNnSqlValue, sql_value::NnSqlValue, SpringValue,
+        },
+        decimal::SpringDecimal,
+        time::SpringTimestamp,
+    },
+};
+
+/// Scalars are converted into JSON scalars as they are (TEXT into JSON string, not parsed).
+impl SpringValue for serde_json::Value {
+    fn try_from_i16(v: &i16) -> Result<Self> {
+        Ok(Self::from(*v))
+    }
+    fn try_from_i32(v: &i32) -> Result<Self> {
+        Ok(Self::from(*v))
+    }
+    fn try_from_i64(v: &i64) -> Result<Self> {
+        Ok(Self::from(*v))
+    }
+
+    fn try_from_u16(v: &u16) -> Result<Self> {
+        Ok(Self::from(*v))
+    }
+    fn try_from_u32(v: &u32) -> Result<Self> {
+        Ok(Self::from(*v))
+    }
+    fn try_from_u64(v: &u64) -> Result<Self> {
+        Ok(Self::from(*v))
+    }
+
+    fn try_from_f32(v: &f32) -> Result<Self> {
+        Ok(Self::from(*v))
+    }
+    fn try_from_f64(v: &f64) -> Result<Self> {
+        Ok(Self::from(*v))
+    }
+
+    fn try_from_decimal(v: &SpringDecimal) -> Result<Self> {
+        Ok(Self::from(v.to_f64()))
+    }
+
+    fn try_from_string(v: &str) -> Result<Self> {
+        Ok(Self::from(v))
+    }
+
+    fn try_from_bool(v: &bool) -> Result<Self> {
+        Ok(Self::from(*v))
+    }
+
+    fn try_from_timestamp(v: &SpringTimestamp) -> Result<Self> {
+        Ok(Self::from(v.to_string()))
+    }
+
+    fn try_from_timestamp_tz(v: &SpringTimestamp) -> Result<Self> {
+        Ok(Self::from(v.to_rfc3339()))
+    }
+
+    fn try_from_json(v: &serde_json::Value) -> Result<Self> {
+        Ok(v.clone())
+    }
+}
+
+impl ToNnSqlValue for serde_json::Value {
+    fn into_nn_sql_value(self) -> NnSqlValue {
+        NnSqlValue::Json(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_unpack_json() -> Result<()> {
+        assert_eq!(
+            NnSqlValue::Integer(1).unpack::<serde_json::Value>()?,
+            json!(1)
+        );
+        assert_eq!(
+            NnSqlValue::Text(r#"{"a": 1}"#.to_string()).unpack::<serde_json::Value>()?,
+            json!(r#"{"a": 1}"#)
+        );
+
+        let payload = NnSqlValue::Json(json!({"a": [1, "x"]}));
+        assert_eq!(
+            payload.unpack::<serde_json::Value>()?,
+            json!({"a": [1, "x"]})
+        );
+        assert_eq!(payload.unpack::<String>()?, r#"{"a":[1,"x"]}"#);
+        assert_eq!(NnSqlValue::Json(json!("x")).unpack::<String>()?, "x");
+        assert!(payload.unpack::<i64>().is_err());
+        Ok(())
+    }
+}
@@ -6,6 +6,7 @@ use crate::{
         autonomous_executor::row::value::{
             sql_convertible::ToNnSqlValue, sql_value::NnSqlValue, SpringValue,
         },
+        decimal::SpringDecimal,
         time::SpringTimestamp,
     },
 };
@@ -18,6 +19,22 @@ impl SpringValue for String {
     fn try_from_timestamp(v: &SpringTimestamp) -> Result<Self> {
         Ok(v.to_string())
     }
+
+    fn try_from_timestamp_tz(v: &SpringTimestamp) -> Result<Self> {
+        Ok(v.to_rfc3339())
+    }
+
+    fn try_from_decimal(v: &SpringDecimal) -> Result<Self> {
+        Ok(v.to_string())
+    }
+
+    /// Content of a JSON string, or serialized JSON text for others.
+    fn try_from_json(v: &serde_json::Value) -> Result<Self> {
+        match v {
+            serde_json::Value::String(s) => Ok(s.clone()),
+            _ => Ok(v.to_string()),
+        }
+    }
 }
 
 impl ToNnSqlValue for String {
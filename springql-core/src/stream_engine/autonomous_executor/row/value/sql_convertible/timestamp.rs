@@ -18,6 +18,10 @@ impl SpringValue for SpringTimestamp {
     fn try_from_timestamp(v: &SpringTimestamp) -> Result<Self> {
         Ok(*v)
     }
+
+    fn try_from_timestamp_tz(v: &SpringTimestamp) -> Result<Self> {
+        Ok(*v)
+    }
 }
 
 impl ToNnSqlValue for SpringTimestamp {
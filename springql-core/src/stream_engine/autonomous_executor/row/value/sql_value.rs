@@ -11,7 +11,7 @@ pub use sql_value_hash_key::SqlValueHashKey;
 use std::{
     fmt::Display,
     hash::Hash,
-    ops::{Add, Mul},
+    ops::{Add, BitAnd, BitOr, Div, Mul, Shl, Shr, Sub},
 };
 
 use anyhow::anyhow;
@@ -20,6 +20,7 @@ use ordered_float::OrderedFloat;
 use crate::{
     api::error::{Result, SpringError},
     mem_size::MemSize,
+    pipeline::SqlType,
     stream_engine::time::SpringEventDuration,
 };
 
@@ -127,6 +128,19 @@ impl SqlValue {
         }
     }
 
+    /// Try to convert value into a type. NULL is kept as NULL.
+    ///
+    /// # Failures
+    ///
+    /// - `SpringError::Sql` when:
+    ///   - Value cannot be converted to `typ`.
+    pub fn try_convert(&self, typ: &SqlType) -> Result<SqlValue> {
+        match self {
+            SqlValue::Null => Ok(SqlValue::Null),
+            SqlValue::NotNull(nn_sql_value) => nn_sql_value.try_convert(typ).map(SqlValue::NotNull),
+        }
+    }
+
     /// Eval as bool if possible.
     ///
     /// # Failures
@@ -185,11 +199,14 @@ impl TryFrom<&serde_json::Value> for SqlValue {
             serde_json::Value::Null => Ok(SqlValue::Null),
             serde_json::Value::Bool(b) => Ok(SqlValue::NotNull(NnSqlValue::Boolean(*b))),
 
+            // Integers are kept as integers to be written back to JSON as they are.
             serde_json::Value::Number(n) => {
-                if let Some(f) = n.as_f64() {
-                    Ok(SqlValue::NotNull(NnSqlValue::Float(OrderedFloat(f as f32))))
-                } else if let Some(i) = n.as_i64() {
+                if let Some(i) = n.as_i64() {
                     Ok(SqlValue::NotNull(NnSqlValue::BigInt(i)))
+                } else if let Some(u) = n.as_u64() {
+                    Ok(SqlValue::NotNull(NnSqlValue::UnsignedBigInt(u)))
+                } else if let Some(f) = n.as_f64() {
+                    Ok(SqlValue::NotNull(NnSqlValue::Double(OrderedFloat(f))))
                 } else {
                     Err(SpringError::Sql(anyhow!(
                         "unsupported number as SQL type: {:?} cannot be evaluated as BIGINT",
@@ -199,12 +216,19 @@ impl TryFrom<&serde_json::Value> for SqlValue {
             }
 
             serde_json::Value::String(s) => Ok(SqlValue::NotNull(NnSqlValue::Text(s.clone()))),
-            serde_json::Value::Array(_) | serde_json::Value::Object(_) => {
-                Err(SpringError::InvalidFormat {
-                    source: anyhow!("JSON array or object are not supported as SQL type"),
-                    s: format!("{:?}", value),
-                })
-            }
+            // Typed as ARRAY or MAP here, and converted into the column's type (e.g. ROW) afterwards.
+            serde_json::Value::Array(elements) => Ok(SqlValue::NotNull(NnSqlValue::Array(
+                elements
+                    .iter()
+                    .map(SqlValue::try_from)
+                    .collect::<Result<_>>()?,
+            ))),
+            serde_json::Value::Object(entries) => Ok(SqlValue::NotNull(NnSqlValue::Map(
+                entries
+                    .iter()
+                    .map(|(key, v)| Ok((key.clone(), SqlValue::try_from(v)?)))
+                    .collect::<Result<_>>()?,
+            ))),
         }
     }
 }
@@ -230,6 +254,18 @@ impl Add for SqlValue {
         }
     }
 }
+impl Sub for SqlValue {
+    type Output = Result<Self>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (SqlValue::Null, _) | (_, SqlValue::Null) => Ok(SqlValue::Null),
+            (SqlValue::NotNull(lhs_nn), SqlValue::NotNull(rhs_nn)) => {
+                (lhs_nn - rhs_nn).map(SqlValue::NotNull)
+            }
+        }
+    }
+}
 impl Mul for SqlValue {
     type Output = Result<Self>;
 
@@ -242,6 +278,66 @@ impl Mul for SqlValue {
         }
     }
 }
+impl Div for SqlValue {
+    type Output = Result<Self>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (SqlValue::Null, _) | (_, SqlValue::Null) => Ok(SqlValue::Null),
+            (SqlValue::NotNull(lhs_nn), SqlValue::NotNull(rhs_nn)) => {
+                (lhs_nn / rhs_nn).map(SqlValue::NotNull)
+            }
+        }
+    }
+}
+impl BitAnd for SqlValue {
+    type Output = Result<Self>;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (SqlValue::Null, _) | (_, SqlValue::Null) => Ok(SqlValue::Null),
+            (SqlValue::NotNull(lhs_nn), SqlValue::NotNull(rhs_nn)) => {
+                (lhs_nn & rhs_nn).map(SqlValue::NotNull)
+            }
+        }
+    }
+}
+impl BitOr for SqlValue {
+    type Output = Result<Self>;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (SqlValue::Null, _) | (_, SqlValue::Null) => Ok(SqlValue::Null),
+            (SqlValue::NotNull(lhs_nn), SqlValue::NotNull(rhs_nn)) => {
+                (lhs_nn | rhs_nn).map(SqlValue::NotNull)
+            }
+        }
+    }
+}
+impl Shl for SqlValue {
+    type Output = Result<Self>;
+
+    fn shl(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (SqlValue::Null, _) | (_, SqlValue::Null) => Ok(SqlValue::Null),
+            (SqlValue::NotNull(lhs_nn), SqlValue::NotNull(rhs_nn)) => {
+                (lhs_nn << rhs_nn).map(SqlValue::NotNull)
+            }
+        }
+    }
+}
+impl Shr for SqlValue {
+    type Output = Result<Self>;
+
+    fn shr(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (SqlValue::Null, _) | (_, SqlValue::Null) => Ok(SqlValue::Null),
+            (SqlValue::NotNull(lhs_nn), SqlValue::NotNull(rhs_nn)) => {
+                (lhs_nn >> rhs_nn).map(SqlValue::NotNull)
+            }
+        }
+    }
+}
 
 #[cfg(test)]
 impl SqlValue {
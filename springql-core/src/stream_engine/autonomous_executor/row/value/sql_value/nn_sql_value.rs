@@ -1,10 +1,12 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
 use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
     fmt::Display,
     hash::Hash,
     mem::size_of,
-    ops::{Add, Mul},
+    ops::{Add, BitAnd, BitOr, Div, Mul, Shl, Shr, Sub},
 };
 
 use anyhow::anyhow;
@@ -14,16 +16,19 @@ use crate::{
     api::error::{Result, SpringError},
     mem_size::MemSize,
     pipeline::{
-        F32LooseType, I64LooseType, NumericComparableType, SqlType, StringComparableLoseType,
-        U64LooseType,
+        DecimalLooseType, F32LooseType, F64LooseType, I64LooseType, NumericComparableType, SqlType,
+        StringComparableLoseType, U64LooseType,
     },
     stream_engine::{
         autonomous_executor::row::value::{
-            sql_convertible::ToNnSqlValue, sql_value::sql_compare_result::SqlCompareResult,
+            sql_convertible::ToNnSqlValue,
+            sql_value::{sql_compare_result::SqlCompareResult, SqlValue},
         },
-        time::{SpringEventDuration, SpringTimestamp},
+        decimal::{SpringDecimal, MAX_DECIMAL_PRECISION},
+        time::{SpringDuration, SpringEventDuration, SpringTimestamp},
         SpringValue,
     },
+    time::Duration,
 };
 
 /// NOT NULL value.
@@ -46,6 +51,14 @@ pub enum NnSqlValue {
         // to implement Hash
         OrderedFloat<f32>,
     ),
+    /// DOUBLE
+    Double(
+        // to implement Hash
+        OrderedFloat<f64>,
+    ),
+
+    /// DECIMAL
+    Decimal(SpringDecimal),
 
     /// TEXT
     Text(String),
@@ -59,8 +72,23 @@ pub enum NnSqlValue {
     /// TIMESTAMP
     Timestamp(SpringTimestamp),
 
+    /// TIMESTAMP WITH TIME ZONE (held in UTC)
+    TimestampTz(SpringTimestamp),
+
     /// DURATION
     Duration(SpringEventDuration),
+
+    /// ARRAY
+    Array(Vec<SqlValue>),
+
+    /// MAP (keys are TEXT)
+    Map(BTreeMap<String, SqlValue>),
+
+    /// ROW (fields in declared order)
+    Row(Vec<(String, SqlValue)>),
+
+    /// JSON
+    Json(serde_json::Value),
 }
 
 impl MemSize for NnSqlValue {
@@ -74,15 +102,29 @@ impl MemSize for NnSqlValue {
             NnSqlValue::UnsignedBigInt(_) => size_of::<u64>(),
 
             NnSqlValue::Float(_) => size_of::<f32>(),
+            NnSqlValue::Double(_) => size_of::<f64>(),
+            NnSqlValue::Decimal(d) => d.mem_size(),
 
             NnSqlValue::Text(s) => s.capacity(),
             NnSqlValue::Blob(v) => v.capacity(),
 
             NnSqlValue::Boolean(_) => size_of::<bool>(),
 
-            NnSqlValue::Timestamp(ts) => ts.mem_size(),
+            NnSqlValue::Timestamp(ts) | NnSqlValue::TimestampTz(ts) => ts.mem_size(),
 
             NnSqlValue::Duration(dur) => dur.mem_size(),
+
+            NnSqlValue::Array(elements) => elements.iter().map(MemSize::mem_size).sum(),
+            NnSqlValue::Map(entries) => entries
+                .iter()
+                .map(|(key, v)| key.capacity() + v.mem_size())
+                .sum(),
+            NnSqlValue::Row(fields) => fields
+                .iter()
+                .map(|(name, v)| name.capacity() + v.mem_size())
+                .sum(),
+            // approximated by the serialized length
+            NnSqlValue::Json(v) => v.to_string().len(),
         }
     }
 }
@@ -102,7 +144,7 @@ impl MemSize for NnSqlValue {
 ///
 /// does not work properly with closures which capture &mut environments.
 macro_rules! for_all_loose_types {
-    ( $nn_sql_value:expr, $closure_i64:expr, $closure_u64:expr, $closure_ordered_float:expr, $closure_string:expr, $closure_blob:expr, $closure_bool:expr, $closure_timestamp:expr, $closure_duration:expr ) => {{
+    ( $nn_sql_value:expr, $closure_i64:expr, $closure_u64:expr, $closure_ordered_float:expr, $closure_ordered_double:expr, $closure_decimal:expr, $closure_string:expr, $closure_blob:expr, $closure_bool:expr, $closure_timestamp:expr, $closure_timestamp_tz:expr, $closure_duration:expr, $closure_array:expr, $closure_map:expr, $closure_row:expr, $closure_json:expr ) => {{
         match &$nn_sql_value {
             NnSqlValue::SmallInt(_) | NnSqlValue::Integer(_) | NnSqlValue::BigInt(_) => {
                 let v = $nn_sql_value.unpack::<i64>().unwrap();
@@ -116,11 +158,21 @@ macro_rules! for_all_loose_types {
                 let v = $nn_sql_value.unpack::<f32>().unwrap();
                 $closure_ordered_float(OrderedFloat(v))
             }
+            NnSqlValue::Double(_) => {
+                let v = $nn_sql_value.unpack::<f64>().unwrap();
+                $closure_ordered_double(OrderedFloat(v))
+            }
+            NnSqlValue::Decimal(d) => $closure_decimal(*d),
             NnSqlValue::Text(s) => $closure_string(s.to_string()),
             NnSqlValue::Blob(v) => $closure_blob(v.to_owned()),
             NnSqlValue::Boolean(b) => $closure_bool(b.clone()),
             NnSqlValue::Timestamp(t) => $closure_timestamp(*t),
+            NnSqlValue::TimestampTz(t) => $closure_timestamp_tz(*t),
             NnSqlValue::Duration(d) => $closure_duration(*d),
+            NnSqlValue::Array(elements) => $closure_array(elements),
+            NnSqlValue::Map(entries) => $closure_map(entries),
+            NnSqlValue::Row(fields) => $closure_row(fields),
+            NnSqlValue::Json(v) => $closure_json(v),
         }
     }};
 }
@@ -134,7 +186,8 @@ impl Eq for NnSqlValue {}
 
 impl Hash for NnSqlValue {
     /// Although raw format are different between two NnSqlValue, this hash function must return the same value if loosely typed values are the same.
-    /// E.g. `42 SMALLINT`'s hash value must be equal to that of `42 INTEGER`.
+    /// E.g. `42 SMALLINT`'s hash value must be equal to that of `42 INTEGER`, and `1.5 FLOAT`'s to that of `1.5 DOUBLE`.
+    /// Integral DECIMAL values are hashed as BIGINT.
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         for_all_loose_types!(
             self,
@@ -145,7 +198,18 @@ impl Hash for NnSqlValue {
                 u.hash(state);
             },
             |f: OrderedFloat<f32>| {
-                f.hash(state);
+                OrderedFloat(f.into_inner() as f64).hash(state);
+            },
+            |d: OrderedFloat<f64>| {
+                d.hash(state);
+            },
+            |d: SpringDecimal| {
+                match d.normalize() {
+                    (mantissa, 0) if i64::try_from(mantissa).is_ok() => {
+                        (mantissa as i64).hash(state)
+                    }
+                    _ => d.hash(state),
+                }
             },
             |s: String| {
                 s.hash(state);
@@ -155,7 +219,12 @@ impl Hash for NnSqlValue {
             },
             |b: bool| { b.hash(state) },
             |t: SpringTimestamp| { t.hash(state) },
-            |d: SpringEventDuration| { d.hash(state) }
+            |t: SpringTimestamp| { t.hash(state) },
+            |d: SpringEventDuration| { d.hash(state) },
+            |elements: &[SqlValue]| { elements.hash(state) },
+            |entries: &BTreeMap<String, SqlValue>| { entries.hash(state) },
+            |fields: &[(String, SqlValue)]| { fields.hash(state) },
+            |v: &serde_json::Value| { v.to_string().hash(state) }
         )
     }
 }
@@ -167,11 +236,39 @@ impl Display for NnSqlValue {
             |i: i64| i.to_string(),
             |u: u64| u.to_string(),
             |f: OrderedFloat<f32>| f.to_string(),
+            |d: OrderedFloat<f64>| d.to_string(),
+            |d: SpringDecimal| d.to_string(),
             |s: String| format!(r#""{}""#, s),
             |v: Vec<u8>| format!("{:?}", v),
             |b: bool| (if b { "TRUE" } else { "FALSE" }).to_string(),
             |t: SpringTimestamp| t.to_string(),
-            |d: SpringEventDuration| d.to_string()
+            |t: SpringTimestamp| t.to_rfc3339(),
+            |d: SpringEventDuration| d.to_string(),
+            |elements: &[SqlValue]| format!(
+                "[{}]",
+                elements
+                    .iter()
+                    .map(|v| v.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            |entries: &BTreeMap<String, SqlValue>| format!(
+                "{{{}}}",
+                entries
+                    .iter()
+                    .map(|(key, v)| format!(r#""{}": {}"#, key, v))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            |fields: &[(String, SqlValue)]| format!(
+                "ROW({})",
+                fields
+                    .iter()
+                    .map(|(name, v)| format!("{}: {}", name, v))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            |v: &serde_json::Value| v.to_string()
         );
         write!(f, "{}", s)
     }
@@ -198,15 +295,24 @@ impl NnSqlValue {
             NnSqlValue::UnsignedInteger(u32_) => T::try_from_u32(u32_),
             NnSqlValue::UnsignedBigInt(u64_) => T::try_from_u64(u64_),
             NnSqlValue::Float(f32_) => T::try_from_f32(f32_),
+            NnSqlValue::Double(f64_) => T::try_from_f64(f64_),
+            NnSqlValue::Decimal(d) => T::try_from_decimal(d),
             NnSqlValue::Text(string) => T::try_from_string(string),
             NnSqlValue::Blob(blob) => T::try_from_blob(blob),
             NnSqlValue::Boolean(b) => T::try_from_bool(b),
             NnSqlValue::Timestamp(t) => T::try_from_timestamp(t),
+            NnSqlValue::TimestampTz(t) => T::try_from_timestamp_tz(t),
             NnSqlValue::Duration(d) => T::try_from_duration(d),
+            NnSqlValue::Array(_) => T::default_err("ARRAY"),
+            NnSqlValue::Map(_) => T::default_err("MAP"),
+            NnSqlValue::Row(_) => T::default_err("ROW"),
+            NnSqlValue::Json(v) => T::try_from_json(v),
         }
     }
 
-    /// SqlType of this value
+    /// SqlType of this value.
+    ///
+    /// Element types of a structured value are inferred from its elements (TEXT if all of them are NULL).
     pub fn sql_type(&self) -> SqlType {
         match self {
             NnSqlValue::SmallInt(_) => SqlType::small_int(),
@@ -215,11 +321,45 @@ impl NnSqlValue {
             NnSqlValue::UnsignedInteger(_) => SqlType::unsigned_integer(),
             NnSqlValue::UnsignedBigInt(_) => SqlType::unsigned_big_int(),
             NnSqlValue::Float(_) => SqlType::float(),
+            NnSqlValue::Double(_) => SqlType::double(),
+            NnSqlValue::Decimal(d) => SqlType::decimal(MAX_DECIMAL_PRECISION, d.scale()),
             NnSqlValue::Text(_) => SqlType::text(),
             NnSqlValue::Blob(_) => SqlType::blob(),
             NnSqlValue::Boolean(_) => SqlType::boolean(),
             NnSqlValue::Timestamp(_) => SqlType::timestamp(),
+            NnSqlValue::TimestampTz(_) => SqlType::timestamp_tz(),
             NnSqlValue::Duration(_) => SqlType::duration(),
+            NnSqlValue::Array(elements) => SqlType::array(Self::element_type(elements.iter())),
+            NnSqlValue::Map(entries) => SqlType::map(Self::element_type(entries.values())),
+            NnSqlValue::Row(fields) => SqlType::row(
+                fields
+                    .iter()
+                    .map(|(name, v)| (name.clone(), Self::element_type(std::iter::once(v))))
+                    .collect(),
+            ),
+            NnSqlValue::Json(_) => SqlType::json(),
+        }
+    }
+
+    /// Type of the first NOT NULL element, or TEXT if none.
+    fn element_type<'a>(mut elements: impl Iterator<Item = &'a SqlValue>) -> SqlType {
+        elements
+            .find_map(|v| match v {
+                SqlValue::NotNull(nn_sql_value) => Some(nn_sql_value.sql_type()),
+                SqlValue::Null => None,
+            })
+            .unwrap_or_else(SqlType::text)
+    }
+
+    /// Field of a ROW value, or entry of a MAP value.
+    fn field(&self, name: &str) -> Option<&SqlValue> {
+        match self {
+            NnSqlValue::Map(entries) => entries.get(name),
+            NnSqlValue::Row(fields) => fields
+                .iter()
+                .find(|(field_name, _)| field_name == name)
+                .map(|(_, v)| v),
+            _ => None,
         }
     }
 
@@ -229,6 +369,9 @@ impl NnSqlValue {
     /// SqlValue -- (unpack by typ) --> Rust type --> SqlValue
     /// ```
     ///
+    /// Elements of a structured value are converted recursively.
+    /// MAP is converted to ROW by its keys, and missing fields are NULL.
+    ///
     /// # Failures
     ///
     /// - `SpringError::Sql` when:
@@ -252,6 +395,24 @@ impl NnSqlValue {
                 NumericComparableType::F32Loose(f) => match f {
                     F32LooseType::Float => self.unpack::<f32>().map(|v| v.into_nn_sql_value()),
                 },
+                NumericComparableType::F64Loose(f) => match f {
+                    F64LooseType::Double => self.unpack::<f64>().map(|v| v.into_nn_sql_value()),
+                },
+                NumericComparableType::DecimalLoose(d) => match d {
+                    DecimalLooseType::Decimal { precision, scale } => {
+                        let v = self.unpack::<SpringDecimal>()?.rescale(*scale)?;
+                        if v.precision() <= *precision {
+                            Ok(v.into_nn_sql_value())
+                        } else {
+                            Err(SpringError::Sql(anyhow!(
+                                "{} is out of range for DECIMAL({}, {})",
+                                v,
+                                precision,
+                                scale
+                            )))
+                        }
+                    }
+                },
             },
             SqlType::StringComparableLoose(s) => match s {
                 StringComparableLoseType::Text => {
@@ -263,13 +424,91 @@ impl NnSqlValue {
             SqlType::TimestampComparable => self
                 .unpack::<SpringTimestamp>()
                 .map(|v| v.into_nn_sql_value()),
+            SqlType::TimestampTzComparable => self
+                .unpack::<SpringTimestamp>()
+                .map(NnSqlValue::TimestampTz),
             SqlType::DurationComparable => self
                 .unpack::<SpringEventDuration>()
                 .map(|v| v.into_nn_sql_value()),
+            SqlType::Array(element_type) => match self {
+                NnSqlValue::Array(elements) => elements
+                    .iter()
+                    .map(|v| v.try_convert(element_type))
+                    .collect::<Result<_>>()
+                    .map(NnSqlValue::Array),
+                _ => self.structured_convert_err(typ),
+            },
+            SqlType::Map(value_type) => match self {
+                NnSqlValue::Map(entries) => entries
+                    .iter()
+                    .map(|(key, v)| Ok((key.clone(), v.try_convert(value_type)?)))
+                    .collect::<Result<_>>()
+                    .map(NnSqlValue::Map),
+                _ => self.structured_convert_err(typ),
+            },
+            SqlType::Row(field_types) => match self {
+                NnSqlValue::Map(_) | NnSqlValue::Row(_) => field_types
+                    .iter()
+                    .map(|(name, field_type)| {
+                        let v = self
+                            .field(name)
+                            .map_or(Ok(SqlValue::Null), |v| v.try_convert(field_type))?;
+                        Ok((name.clone(), v))
+                    })
+                    .collect::<Result<_>>()
+                    .map(NnSqlValue::Row),
+                _ => self.structured_convert_err(typ),
+            },
+            SqlType::Json => match self {
+                NnSqlValue::Array(_) | NnSqlValue::Map(_) | NnSqlValue::Row(_) => {
+                    Ok(NnSqlValue::Json(serde_json::Value::from(self.clone())))
+                }
+                _ => self
+                    .unpack::<serde_json::Value>()
+                    .map(|v| v.into_nn_sql_value()),
+            },
         }
     }
 
+    fn structured_convert_err(&self, typ: &SqlType) -> Result<NnSqlValue> {
+        Err(SpringError::Sql(anyhow!(
+            "{} cannot be converted into {:?}",
+            self,
+            typ
+        )))
+    }
+
     pub fn sql_compare(&self, other: &Self) -> Result<SqlCompareResult> {
+        match (self, other) {
+            (NnSqlValue::Array(self_elements), NnSqlValue::Array(other_elements)) => {
+                return Self::sql_compare_elements(
+                    self_elements.iter().map(|v| ("", v)),
+                    other_elements.iter().map(|v| ("", v)),
+                );
+            }
+            (NnSqlValue::Map(self_entries), NnSqlValue::Map(other_entries)) => {
+                return Self::sql_compare_elements(
+                    self_entries.iter().map(|(key, v)| (key.as_str(), v)),
+                    other_entries.iter().map(|(key, v)| (key.as_str(), v)),
+                );
+            }
+            (NnSqlValue::Row(self_fields), NnSqlValue::Row(other_fields)) => {
+                return Self::sql_compare_elements(
+                    self_fields.iter().map(|(name, v)| (name.as_str(), v)),
+                    other_fields.iter().map(|(name, v)| (name.as_str(), v)),
+                );
+            }
+            // JSON values are not ordered by nature, but ordered by their serialized text to be totally ordered.
+            (NnSqlValue::Json(self_json), NnSqlValue::Json(other_json)) => {
+                return Ok(if self_json == other_json {
+                    SqlCompareResult::Eq
+                } else {
+                    SqlCompareResult::from(self_json.to_string().cmp(&other_json.to_string()))
+                });
+            }
+            _ => {}
+        }
+
         match (self.sql_type(), other.sql_type()) {
             (SqlType::NumericComparable(ref self_n), SqlType::NumericComparable(ref other_n)) => {
                 match (self_n, other_n) {
@@ -285,6 +524,27 @@ impl NnSqlValue {
                         let (self_f32, other_f32) = (self.unpack::<f32>()?, other.unpack::<f32>()?);
                         Ok(SqlCompareResult::from(self_f32.partial_cmp(&other_f32)))
                     }
+                    (
+                        NumericComparableType::F32Loose(_)
+                        | NumericComparableType::F64Loose(_)
+                        | NumericComparableType::DecimalLoose(_),
+                        NumericComparableType::F32Loose(_) | NumericComparableType::F64Loose(_),
+                    )
+                    | (
+                        NumericComparableType::F32Loose(_) | NumericComparableType::F64Loose(_),
+                        NumericComparableType::DecimalLoose(_),
+                    ) => {
+                        let (self_f64, other_f64) = (self.unpack::<f64>()?, other.unpack::<f64>()?);
+                        Ok(SqlCompareResult::from(self_f64.partial_cmp(&other_f64)))
+                    }
+                    (NumericComparableType::DecimalLoose(_), _)
+                    | (_, NumericComparableType::DecimalLoose(_)) => {
+                        let (self_d, other_d) = (
+                            self.unpack::<SpringDecimal>()?,
+                            other.unpack::<SpringDecimal>()?,
+                        );
+                        Ok(SqlCompareResult::from(self_d.cmp(&other_d)))
+                    }
                     _ => Err(SpringError::Sql(anyhow!(
                         "Cannot compare {:?} and {:?}",
                         self_n,
@@ -305,13 +565,24 @@ impl NnSqlValue {
                 let (self_b, other_b) = (self.unpack::<bool>()?, other.unpack::<bool>()?);
                 Ok(SqlCompareResult::from(self_b.cmp(&other_b)))
             }
-            (SqlType::TimestampComparable, SqlType::TimestampComparable) => {
+            // TIMESTAMP is compared with TIMESTAMP WITH TIME ZONE as in UTC.
+            (
+                SqlType::TimestampComparable | SqlType::TimestampTzComparable,
+                SqlType::TimestampComparable | SqlType::TimestampTzComparable,
+            ) => {
                 let (self_t, other_t) = (
                     self.unpack::<SpringTimestamp>()?,
                     other.unpack::<SpringTimestamp>()?,
                 );
                 Ok(SqlCompareResult::from(self_t.cmp(&other_t)))
             }
+            (SqlType::DurationComparable, SqlType::DurationComparable) => {
+                let (self_d, other_d) = (
+                    self.unpack::<SpringEventDuration>()?,
+                    other.unpack::<SpringEventDuration>()?,
+                );
+                Ok(SqlCompareResult::from(self_d.cmp(&other_d)))
+            }
             (_, _) => Err(SpringError::Sql(anyhow!(
                 "`self` and `other` are not in comparable type - self: {:?}, other: {:?}",
                 self,
@@ -320,6 +591,25 @@ impl NnSqlValue {
         }
     }
 
+    /// Lexicographical comparison of structured values.
+    /// Keys (map keys or field names) are compared prior to values, and a shorter one is less if it is a prefix of the other.
+    fn sql_compare_elements<'a>(
+        self_elements: impl ExactSizeIterator<Item = (&'a str, &'a SqlValue)>,
+        other_elements: impl ExactSizeIterator<Item = (&'a str, &'a SqlValue)>,
+    ) -> Result<SqlCompareResult> {
+        let len_ord = self_elements.len().cmp(&other_elements.len());
+        for ((self_key, self_v), (other_key, other_v)) in self_elements.zip(other_elements) {
+            let res = match self_key.cmp(other_key) {
+                Ordering::Equal => self_v.sql_compare(other_v)?,
+                ord => SqlCompareResult::from(ord),
+            };
+            if res != SqlCompareResult::Eq {
+                return Ok(res);
+            }
+        }
+        Ok(SqlCompareResult::from(len_ord))
+    }
+
     /// # Failures
     ///
     /// - `SpringError::Sql` when:
@@ -330,6 +620,10 @@ impl NnSqlValue {
             NnSqlValue::Integer(v) => Ok(Self::Integer(-v)),
             NnSqlValue::BigInt(v) => Ok(Self::BigInt(-v)),
             NnSqlValue::Float(v) => Ok(Self::Float(-v)),
+            NnSqlValue::Double(v) => Ok(Self::Double(-v)),
+            NnSqlValue::Decimal(v) => {
+                SpringDecimal::new(-v.mantissa(), v.scale()).map(Self::Decimal)
+            }
 
             NnSqlValue::UnsignedInteger(_)
             | NnSqlValue::UnsignedBigInt(_)
@@ -337,9 +631,158 @@ impl NnSqlValue {
             | NnSqlValue::Blob(_)
             | NnSqlValue::Boolean(_)
             | NnSqlValue::Timestamp(_)
-            | NnSqlValue::Duration(_) => Err(SpringError::Sql(anyhow!("{} cannot negate", self))),
+            | NnSqlValue::TimestampTz(_)
+            | NnSqlValue::Duration(_)
+            | NnSqlValue::Array(_)
+            | NnSqlValue::Map(_)
+            | NnSqlValue::Row(_)
+            | NnSqlValue::Json(_) => Err(SpringError::Sql(anyhow!("{} cannot negate", self))),
         }
     }
+
+    /// Arithmetic operation on DECIMAL. The other operand, an integer or a DECIMAL, is converted into DECIMAL.
+    /// DECIMAL with FLOAT or DOUBLE is not here but promoted to DOUBLE.
+    fn decimal_op(
+        self,
+        rhs: Self,
+        op: fn(&SpringDecimal, &SpringDecimal) -> Result<SpringDecimal>,
+    ) -> Result<Self> {
+        let (self_d, rhs_d) = (
+            self.unpack::<SpringDecimal>()?,
+            rhs.unpack::<SpringDecimal>()?,
+        );
+        op(&self_d, &rhs_d).map(Self::Decimal)
+    }
+
+    /// Bitwise operation on integers.
+    /// Operated as BIGINT if both are signed, and as UNSIGNED BIGINT if either is unsigned.
+    fn bitwise_op(
+        self,
+        rhs: Self,
+        op: &str,
+        i64_op: fn(i64, i64) -> i64,
+        u64_op: fn(u64, u64) -> u64,
+    ) -> Result<Self> {
+        match (self.sql_type(), rhs.sql_type()) {
+            (
+                SqlType::NumericComparable(NumericComparableType::I64Loose(_)),
+                SqlType::NumericComparable(NumericComparableType::I64Loose(_)),
+            ) => Ok(Self::BigInt(i64_op(
+                self.unpack::<i64>()?,
+                rhs.unpack::<i64>()?,
+            ))),
+            (
+                SqlType::NumericComparable(
+                    NumericComparableType::I64Loose(_) | NumericComparableType::U64Loose(_),
+                ),
+                SqlType::NumericComparable(
+                    NumericComparableType::I64Loose(_) | NumericComparableType::U64Loose(_),
+                ),
+            ) => Ok(Self::UnsignedBigInt(u64_op(
+                self.unpack::<u64>()?,
+                rhs.unpack::<u64>()?,
+            ))),
+            (_, _) => Err(SpringError::Sql(anyhow!(
+                "`self` {} `rhs` is undefined - self: {:?}, other: {:?}",
+                op,
+                self,
+                rhs
+            ))),
+        }
+    }
+
+    /// Bit shift of an integer. Signed integer is shifted arithmetically as BIGINT.
+    fn shift_op(
+        self,
+        rhs: Self,
+        op: &str,
+        i64_op: fn(i64, u32) -> Option<i64>,
+        u64_op: fn(u64, u32) -> Option<u64>,
+    ) -> Result<Self> {
+        let shifted = match (self.sql_type(), rhs.sql_type()) {
+            (
+                SqlType::NumericComparable(NumericComparableType::I64Loose(_)),
+                SqlType::NumericComparable(
+                    NumericComparableType::I64Loose(_) | NumericComparableType::U64Loose(_),
+                ),
+            ) => i64_op(self.unpack::<i64>()?, rhs.unpack::<u32>()?).map(Self::BigInt),
+            (
+                SqlType::NumericComparable(NumericComparableType::U64Loose(_)),
+                SqlType::NumericComparable(
+                    NumericComparableType::I64Loose(_) | NumericComparableType::U64Loose(_),
+                ),
+            ) => u64_op(self.unpack::<u64>()?, rhs.unpack::<u32>()?).map(Self::UnsignedBigInt),
+            (_, _) => {
+                return Err(SpringError::Sql(anyhow!(
+                    "`self` {} `rhs` is undefined - self: {:?}, other: {:?}",
+                    op,
+                    self,
+                    rhs
+                )))
+            }
+        };
+        shifted.ok_or_else(|| {
+            SpringError::Sql(anyhow!(
+                "shift amount of `{}` must be less than 64 but {}",
+                op,
+                rhs
+            ))
+        })
+    }
+
+    /// TIMESTAMP (or TIMESTAMP WITH TIME ZONE) moved forward (or backward) by DURATION, in the same type.
+    fn shift_timestamp(self, duration: &Self, forward: bool) -> Result<Self> {
+        let ts = self.unpack::<SpringTimestamp>()?;
+        let d = duration.unpack::<SpringEventDuration>()?;
+        let shifted = Duration::from_std(*d.as_std())
+            .ok()
+            .and_then(|d| {
+                if forward {
+                    ts.checked_add(d)
+                } else {
+                    ts.checked_sub(d)
+                }
+            })
+            .ok_or_else(|| {
+                SpringError::Sql(anyhow!(
+                    "{} {} {:?} is out of range for TIMESTAMP",
+                    self,
+                    if forward { "+" } else { "-" },
+                    duration
+                ))
+            })?;
+        match self {
+            NnSqlValue::TimestampTz(_) => Ok(Self::TimestampTz(shifted)),
+            _ => Ok(Self::Timestamp(shifted)),
+        }
+    }
+
+    /// DURATION multiplied by a number. A fraction of nanosecond is rounded.
+    fn mul_duration(duration: &Self, factor: &Self) -> Result<Self> {
+        let nanos = duration.unpack::<SpringEventDuration>()?.as_nanos();
+        let product = match factor.sql_type() {
+            SqlType::NumericComparable(NumericComparableType::I64Loose(_)) => {
+                nanos.checked_mul(factor.unpack::<i64>()? as i128)
+            }
+            SqlType::NumericComparable(NumericComparableType::U64Loose(_)) => {
+                nanos.checked_mul(factor.unpack::<u64>()? as i128)
+            }
+            _ => {
+                let product = nanos as f64 * factor.unpack::<f64>()?;
+                product.is_finite().then(|| product.round() as i128)
+            }
+        };
+        product
+            .and_then(SpringEventDuration::checked_from_nanos)
+            .map(Self::Duration)
+            .ok_or_else(|| {
+                SpringError::Sql(anyhow!(
+                    "{:?} * {:?} is out of range for DURATION",
+                    duration,
+                    factor
+                ))
+            })
+    }
 }
 
 impl From<NnSqlValue> for serde_json::Value {
@@ -350,14 +793,34 @@ impl From<NnSqlValue> for serde_json::Value {
             NnSqlValue::BigInt(i) => serde_json::Value::from(i),
             NnSqlValue::UnsignedInteger(u) => serde_json::Value::from(u),
             NnSqlValue::UnsignedBigInt(u) => serde_json::Value::from(u),
-            NnSqlValue::Float(f) => serde_json::Value::from(f.into_inner()),
+            // as f64 since `arbitrary_precision` would write the shortest f32 digits
+            NnSqlValue::Float(f) => serde_json::Value::from(f64::from(f.into_inner())),
+            NnSqlValue::Double(f) => serde_json::Value::from(f.into_inner()),
+            NnSqlValue::Decimal(d) => serde_json::Value::from(d.to_f64()),
             NnSqlValue::Text(s) => serde_json::Value::from(s),
             NnSqlValue::Boolean(b) => serde_json::Value::from(b),
             NnSqlValue::Timestamp(t) => serde_json::Value::from(t.to_string()),
+            NnSqlValue::TimestampTz(t) => serde_json::Value::from(t.to_rfc3339()),
             NnSqlValue::Duration(_) => {
                 unimplemented!("never appear in stream definition (just an intermediate type)")
             }
             NnSqlValue::Blob(_) => unimplemented!("cannot convert BLOB data into JSON"),
+            NnSqlValue::Array(elements) => serde_json::Value::Array(
+                elements.into_iter().map(serde_json::Value::from).collect(),
+            ),
+            NnSqlValue::Map(entries) => serde_json::Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, v)| (key, serde_json::Value::from(v)))
+                    .collect(),
+            ),
+            NnSqlValue::Row(fields) => serde_json::Value::Object(
+                fields
+                    .into_iter()
+                    .map(|(name, v)| (name, serde_json::Value::from(v)))
+                    .collect(),
+            ),
+            NnSqlValue::Json(v) => v,
         }
     }
 }
@@ -381,6 +844,23 @@ impl Add for NnSqlValue {
                         let (self_f32, rhs_f32) = (self.unpack::<f32>()?, rhs.unpack::<f32>()?);
                         Ok(Self::Float(OrderedFloat(self_f32 + rhs_f32)))
                     }
+                    (
+                        NumericComparableType::F32Loose(_)
+                        | NumericComparableType::F64Loose(_)
+                        | NumericComparableType::DecimalLoose(_),
+                        NumericComparableType::F32Loose(_) | NumericComparableType::F64Loose(_),
+                    )
+                    | (
+                        NumericComparableType::F32Loose(_) | NumericComparableType::F64Loose(_),
+                        NumericComparableType::DecimalLoose(_),
+                    ) => {
+                        let (self_f64, rhs_f64) = (self.unpack::<f64>()?, rhs.unpack::<f64>()?);
+                        Ok(Self::Double(OrderedFloat(self_f64 + rhs_f64)))
+                    }
+                    (NumericComparableType::DecimalLoose(_), _)
+                    | (_, NumericComparableType::DecimalLoose(_)) => {
+                        self.decimal_op(rhs, SpringDecimal::checked_add)
+                    }
                     _ => Err(SpringError::Sql(anyhow!(
                         "Cannot add {:?} and {:?}",
                         self_n,
@@ -388,6 +868,29 @@ impl Add for NnSqlValue {
                     ))),
                 }
             }
+            (
+                SqlType::TimestampComparable | SqlType::TimestampTzComparable,
+                SqlType::DurationComparable,
+            ) => self.shift_timestamp(&rhs, true),
+            (
+                SqlType::DurationComparable,
+                SqlType::TimestampComparable | SqlType::TimestampTzComparable,
+            ) => rhs.shift_timestamp(&self, true),
+            (SqlType::DurationComparable, SqlType::DurationComparable) => {
+                let (self_d, rhs_d) = (
+                    self.unpack::<SpringEventDuration>()?,
+                    rhs.unpack::<SpringEventDuration>()?,
+                );
+                SpringEventDuration::checked_from_nanos(self_d.as_nanos() + rhs_d.as_nanos())
+                    .map(Self::Duration)
+                    .ok_or_else(|| {
+                        SpringError::Sql(anyhow!(
+                            "{:?} + {:?} is out of range for DURATION",
+                            self,
+                            rhs
+                        ))
+                    })
+            }
             (_, _) => Err(SpringError::Sql(anyhow!(
                 "`self` + `rhs` is undefined - self: {:?}, other: {:?}",
                 self,
@@ -415,6 +918,23 @@ impl Mul for NnSqlValue {
                         let (self_f32, rhs_f32) = (self.unpack::<f32>()?, rhs.unpack::<f32>()?);
                         Ok(Self::Float(OrderedFloat(self_f32 * rhs_f32)))
                     }
+                    (
+                        NumericComparableType::F32Loose(_)
+                        | NumericComparableType::F64Loose(_)
+                        | NumericComparableType::DecimalLoose(_),
+                        NumericComparableType::F32Loose(_) | NumericComparableType::F64Loose(_),
+                    )
+                    | (
+                        NumericComparableType::F32Loose(_) | NumericComparableType::F64Loose(_),
+                        NumericComparableType::DecimalLoose(_),
+                    ) => {
+                        let (self_f64, rhs_f64) = (self.unpack::<f64>()?, rhs.unpack::<f64>()?);
+                        Ok(Self::Double(OrderedFloat(self_f64 * rhs_f64)))
+                    }
+                    (NumericComparableType::DecimalLoose(_), _)
+                    | (_, NumericComparableType::DecimalLoose(_)) => {
+                        self.decimal_op(rhs, SpringDecimal::checked_mul)
+                    }
                     _ => Err(SpringError::Sql(anyhow!(
                         "Cannot multiply {:?} by {:?}",
                         self_n,
@@ -422,6 +942,12 @@ impl Mul for NnSqlValue {
                     ))),
                 }
             }
+            (SqlType::DurationComparable, SqlType::NumericComparable(_)) => {
+                Self::mul_duration(&self, &rhs)
+            }
+            (SqlType::NumericComparable(_), SqlType::DurationComparable) => {
+                Self::mul_duration(&rhs, &self)
+            }
             (_, _) => Err(SpringError::Sql(anyhow!(
                 "`self` + `rhs` is undefined - self: {:?}, other: {:?}",
                 self,
@@ -431,6 +957,196 @@ impl Mul for NnSqlValue {
     }
 }
 
+impl Sub for NnSqlValue {
+    type Output = Result<Self>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        match (self.sql_type(), rhs.sql_type()) {
+            (SqlType::NumericComparable(ref self_n), SqlType::NumericComparable(ref rhs_n)) => {
+                match (self_n, rhs_n) {
+                    (NumericComparableType::I64Loose(_), NumericComparableType::I64Loose(_)) => {
+                        let (self_i64, rhs_i64) = (self.unpack::<i64>()?, rhs.unpack::<i64>()?);
+                        Ok(Self::BigInt(self_i64 - rhs_i64))
+                    }
+                    (NumericComparableType::U64Loose(_), NumericComparableType::U64Loose(_)) => {
+                        let (self_u64, rhs_u64) = (self.unpack::<u64>()?, rhs.unpack::<u64>()?);
+                        self_u64
+                            .checked_sub(rhs_u64)
+                            .map(Self::UnsignedBigInt)
+                            .ok_or_else(|| {
+                                SpringError::Sql(anyhow!(
+                                    "{} - {} is negative in unsigned type",
+                                    self_u64,
+                                    rhs_u64
+                                ))
+                            })
+                    }
+                    (NumericComparableType::F32Loose(_), NumericComparableType::F32Loose(_)) => {
+                        let (self_f32, rhs_f32) = (self.unpack::<f32>()?, rhs.unpack::<f32>()?);
+                        Ok(Self::Float(OrderedFloat(self_f32 - rhs_f32)))
+                    }
+                    (
+                        NumericComparableType::F32Loose(_)
+                        | NumericComparableType::F64Loose(_)
+                        | NumericComparableType::DecimalLoose(_),
+                        NumericComparableType::F32Loose(_) | NumericComparableType::F64Loose(_),
+                    )
+                    | (
+                        NumericComparableType::F32Loose(_) | NumericComparableType::F64Loose(_),
+                        NumericComparableType::DecimalLoose(_),
+                    ) => {
+                        let (self_f64, rhs_f64) = (self.unpack::<f64>()?, rhs.unpack::<f64>()?);
+                        Ok(Self::Double(OrderedFloat(self_f64 - rhs_f64)))
+                    }
+                    (NumericComparableType::DecimalLoose(_), _)
+                    | (_, NumericComparableType::DecimalLoose(_)) => {
+                        self.decimal_op(rhs, SpringDecimal::checked_sub)
+                    }
+                    _ => Err(SpringError::Sql(anyhow!(
+                        "Cannot subtract {:?} from {:?}",
+                        rhs_n,
+                        self_n
+                    ))),
+                }
+            }
+            (
+                SqlType::TimestampComparable | SqlType::TimestampTzComparable,
+                SqlType::DurationComparable,
+            ) => self.shift_timestamp(&rhs, false),
+            // DURATION is not negative, so a TIMESTAMP must be subtracted from a later one.
+            (
+                SqlType::TimestampComparable | SqlType::TimestampTzComparable,
+                SqlType::TimestampComparable | SqlType::TimestampTzComparable,
+            ) => {
+                let (self_t, rhs_t) = (
+                    self.unpack::<SpringTimestamp>()?,
+                    rhs.unpack::<SpringTimestamp>()?,
+                );
+                SpringEventDuration::checked_from_nanos((self_t - rhs_t).num_nanoseconds())
+                    .map(Self::Duration)
+                    .ok_or_else(|| {
+                        SpringError::Sql(anyhow!("{} - {} is negative in DURATION", self, rhs))
+                    })
+            }
+            (SqlType::DurationComparable, SqlType::DurationComparable) => {
+                let (self_d, rhs_d) = (
+                    self.unpack::<SpringEventDuration>()?,
+                    rhs.unpack::<SpringEventDuration>()?,
+                );
+                SpringEventDuration::checked_from_nanos(self_d.as_nanos() - rhs_d.as_nanos())
+                    .map(Self::Duration)
+                    .ok_or_else(|| {
+                        SpringError::Sql(anyhow!("{} - {} is negative in DURATION", self, rhs))
+                    })
+            }
+            (_, _) => Err(SpringError::Sql(anyhow!(
+                "`self` - `rhs` is undefined - self: {:?}, other: {:?}",
+                self,
+                rhs
+            ))),
+        }
+    }
+}
+impl Div for NnSqlValue {
+    type Output = Result<Self>;
+
+    /// Division of integers truncates toward zero.
+    fn div(self, rhs: Self) -> Self::Output {
+        match (self.sql_type(), rhs.sql_type()) {
+            (SqlType::NumericComparable(ref self_n), SqlType::NumericComparable(ref rhs_n)) => {
+                match (self_n, rhs_n) {
+                    (NumericComparableType::I64Loose(_), NumericComparableType::I64Loose(_)) => {
+                        let (self_i64, rhs_i64) = (self.unpack::<i64>()?, rhs.unpack::<i64>()?);
+                        self_i64
+                            .checked_div(rhs_i64)
+                            .map(Self::BigInt)
+                            .ok_or_else(|| {
+                                SpringError::Sql(anyhow!(
+                                    "division by zero: {} / {}",
+                                    self_i64,
+                                    rhs_i64
+                                ))
+                            })
+                    }
+                    (NumericComparableType::U64Loose(_), NumericComparableType::U64Loose(_)) => {
+                        let (self_u64, rhs_u64) = (self.unpack::<u64>()?, rhs.unpack::<u64>()?);
+                        self_u64
+                            .checked_div(rhs_u64)
+                            .map(Self::UnsignedBigInt)
+                            .ok_or_else(|| {
+                                SpringError::Sql(anyhow!(
+                                    "division by zero: {} / {}",
+                                    self_u64,
+                                    rhs_u64
+                                ))
+                            })
+                    }
+                    (NumericComparableType::F32Loose(_), NumericComparableType::F32Loose(_)) => {
+                        let (self_f32, rhs_f32) = (self.unpack::<f32>()?, rhs.unpack::<f32>()?);
+                        Ok(Self::Float(OrderedFloat(self_f32 / rhs_f32)))
+                    }
+                    (
+                        NumericComparableType::F32Loose(_)
+                        | NumericComparableType::F64Loose(_)
+                        | NumericComparableType::DecimalLoose(_),
+                        NumericComparableType::F32Loose(_) | NumericComparableType::F64Loose(_),
+                    )
+                    | (
+                        NumericComparableType::F32Loose(_) | NumericComparableType::F64Loose(_),
+                        NumericComparableType::DecimalLoose(_),
+                    ) => {
+                        let (self_f64, rhs_f64) = (self.unpack::<f64>()?, rhs.unpack::<f64>()?);
+                        Ok(Self::Double(OrderedFloat(self_f64 / rhs_f64)))
+                    }
+                    (NumericComparableType::DecimalLoose(_), _)
+                    | (_, NumericComparableType::DecimalLoose(_)) => {
+                        self.decimal_op(rhs, SpringDecimal::checked_div)
+                    }
+                    _ => Err(SpringError::Sql(anyhow!(
+                        "Cannot divide {:?} by {:?}",
+                        self_n,
+                        rhs_n
+                    ))),
+                }
+            }
+            (_, _) => Err(SpringError::Sql(anyhow!(
+                "`self` / `rhs` is undefined - self: {:?}, other: {:?}",
+                self,
+                rhs
+            ))),
+        }
+    }
+}
+
+impl BitAnd for NnSqlValue {
+    type Output = Result<Self>;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.bitwise_op(rhs, "&", |l, r| l & r, |l, r| l & r)
+    }
+}
+impl BitOr for NnSqlValue {
+    type Output = Result<Self>;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.bitwise_op(rhs, "|", |l, r| l | r, |l, r| l | r)
+    }
+}
+impl Shl for NnSqlValue {
+    type Output = Result<Self>;
+
+    fn shl(self, rhs: Self) -> Self::Output {
+        self.shift_op(rhs, "<<", i64::checked_shl, u64::checked_shl)
+    }
+}
+impl Shr for NnSqlValue {
+    type Output = Result<Self>;
+
+    fn shr(self, rhs: Self) -> Self::Output {
+        self.shift_op(rhs, ">>", i64::checked_shr, u64::checked_shr)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -488,4 +1204,202 @@ mod tests {
             b"hello".to_vec()
         );
     }
+
+    #[test]
+    fn test_bitwise_ops() -> Result<()> {
+        assert_eq!(
+            (NnSqlValue::Integer(0b1100) & NnSqlValue::SmallInt(0b1010))?.unpack::<i64>()?,
+            0b1000
+        );
+        assert_eq!(
+            (NnSqlValue::UnsignedInteger(0b1100) | NnSqlValue::Integer(0b1010))?.unpack::<u64>()?,
+            0b1110
+        );
+        assert_eq!(
+            (NnSqlValue::Integer(-16) >> NnSqlValue::Integer(2))?.unpack::<i64>()?,
+            -4
+        );
+        assert_eq!(
+            (NnSqlValue::UnsignedBigInt(1) << NnSqlValue::Integer(63))?.unpack::<u64>()?,
+            1 << 63
+        );
+        assert!((NnSqlValue::Integer(1) << NnSqlValue::Integer(64)).is_err());
+        assert!((NnSqlValue::Float(OrderedFloat(1.0)) & NnSqlValue::Integer(1)).is_err());
+        Ok(())
+    }
+    #[test]
+    fn test_double_promotion() -> Result<()> {
+        let lat = NnSqlValue::Double(OrderedFloat(35.681236123456));
+
+        let sum = (lat.clone() + NnSqlValue::Float(OrderedFloat(0.5)))?;
+        assert_eq!(sum.sql_type(), SqlType::double());
+        assert_eq!(sum.unpack::<f64>()?, 36.181236123456);
+
+        let product = (NnSqlValue::Float(OrderedFloat(2.0)) * lat.clone())?;
+        assert_eq!(product.sql_type(), SqlType::double());
+        assert_eq!(product.unpack::<f64>()?, 71.362472246912);
+
+        assert_eq!(
+            NnSqlValue::Float(OrderedFloat(1.5)),
+            NnSqlValue::Double(OrderedFloat(1.5))
+        );
+        assert_eq!(
+            lat.sql_compare(&NnSqlValue::Float(OrderedFloat(35.68)))?,
+            SqlCompareResult::GreaterThan
+        );
+        assert!((lat + NnSqlValue::Integer(1)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal_ops() -> Result<()> {
+        let price = NnSqlValue::Decimal("19.99".parse()?);
+
+        let total = (price.clone() * NnSqlValue::Integer(3))?;
+        assert_eq!(total.to_string(), "59.97");
+        assert_eq!(
+            (total.clone() - NnSqlValue::Decimal("0.97".parse()?))?.to_string(),
+            "59.00"
+        );
+        assert_eq!((total / NnSqlValue::Integer(3))?.to_string(), "19.99000000");
+
+        // DECIMAL with FLOAT or DOUBLE is DOUBLE
+        let product = (price.clone() * NnSqlValue::Float(OrderedFloat(0.5)))?;
+        assert_eq!(product.sql_type(), SqlType::double());
+        assert_eq!(product.unpack::<f64>()?, 9.995);
+        let sum = (NnSqlValue::Double(OrderedFloat(0.01)) + price.clone())?;
+        assert_eq!(sum.sql_type(), SqlType::double());
+        assert_eq!(sum.unpack::<f64>()?, 20.0);
+        assert_eq!(
+            price.sql_compare(&NnSqlValue::Double(OrderedFloat(19.995)))?,
+            SqlCompareResult::LessThan
+        );
+        assert!((price.clone() / NnSqlValue::Decimal("0".parse()?)).is_err());
+
+        assert_eq!(price, NnSqlValue::Decimal("19.990".parse()?));
+        assert_eq!(NnSqlValue::Decimal("2.00".parse()?), NnSqlValue::BigInt(2));
+
+        let converted = price.try_convert(&SqlType::decimal(4, 1))?;
+        assert_eq!(converted.to_string(), "20.0");
+        assert!(price.try_convert(&SqlType::decimal(3, 2)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sub_div() -> Result<()> {
+        assert_eq!(
+            (NnSqlValue::Integer(7) - NnSqlValue::SmallInt(10))?.unpack::<i64>()?,
+            -3
+        );
+        assert_eq!(
+            (NnSqlValue::Integer(-7) / NnSqlValue::Integer(2))?.unpack::<i64>()?,
+            -3
+        );
+        assert!((NnSqlValue::Integer(1) / NnSqlValue::Integer(0)).is_err());
+        assert!((NnSqlValue::UnsignedInteger(1) - NnSqlValue::UnsignedInteger(2)).is_err());
+        assert_eq!(
+            (NnSqlValue::Float(OrderedFloat(1.0)) / NnSqlValue::Float(OrderedFloat(4.0)))?
+                .unpack::<f32>()?,
+            0.25
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_duration_ops() -> Result<()> {
+        let ts = |s: &str| NnSqlValue::Timestamp(s.parse().unwrap());
+        let secs = |secs: u64| NnSqlValue::Duration(SpringEventDuration::from_secs(secs));
+
+        assert_eq!(
+            (ts("2020-01-01 00:01:30.000000000") - ts("2020-01-01 00:00:00.000000000"))?,
+            secs(90)
+        );
+        assert!(
+            (ts("2020-01-01 00:00:00.000000000") - ts("2020-01-01 00:00:01.000000000")).is_err()
+        );
+        assert_eq!(
+            (ts("2020-01-01 00:00:00.000000000") + secs(90))?,
+            ts("2020-01-01 00:01:30.000000000")
+        );
+        assert_eq!(
+            (secs(90) + ts("2020-01-01 00:00:00.000000000"))?,
+            ts("2020-01-01 00:01:30.000000000")
+        );
+        assert_eq!(
+            (NnSqlValue::TimestampTz(ts("2020-01-01 00:01:30.000000000").unpack()?) - secs(90))?,
+            NnSqlValue::TimestampTz(ts("2020-01-01 00:00:00.000000000").unpack()?)
+        );
+
+        assert!((ts("2020-01-01 00:00:00.000000000")
+            + NnSqlValue::Duration(SpringEventDuration::from_secs(100_000_000 * 60 * 60)))
+        .is_err());
+        assert!((ts("2020-01-01 00:00:00.000000000")
+            - NnSqlValue::Duration(SpringEventDuration::from_secs(u64::MAX)))
+        .is_err());
+
+        assert_eq!((secs(2) * NnSqlValue::Integer(3))?, secs(6));
+        assert_eq!(
+            (NnSqlValue::Double(OrderedFloat(0.5)) * secs(3))?,
+            NnSqlValue::Duration(SpringEventDuration::from_millis(1500))
+        );
+        assert!((secs(2) * NnSqlValue::Integer(-1)).is_err());
+        assert_eq!((secs(2) + secs(3))?, secs(5));
+        assert!((secs(2) - secs(3)).is_err());
+
+        assert_eq!(secs(2).sql_compare(&secs(3))?, SqlCompareResult::LessThan);
+        assert_eq!(secs(3).sql_compare(&secs(3))?, SqlCompareResult::Eq);
+        Ok(())
+    }
+
+    #[test]
+    fn test_structured_convert_and_compare() -> Result<()> {
+        let json = serde_json::json!({"tags": ["a", null], "pos": {"x": 1.5, "extra": true}});
+        let obj = SqlValue::try_from(&json)?.unwrap();
+
+        let typ = SqlType::row(vec![
+            ("tags".to_string(), SqlType::array(SqlType::text())),
+            (
+                "pos".to_string(),
+                SqlType::row(vec![
+                    ("x".to_string(), SqlType::float()),
+                    ("y".to_string(), SqlType::float()),
+                ]),
+            ),
+        ]);
+        let row = obj.try_convert(&typ)?;
+        assert_eq!(
+            row.to_string(),
+            r#"ROW(tags: ["a", NULL], pos: ROW(x: 1.5, y: NULL))"#
+        );
+        assert_eq!(
+            serde_json::Value::from(row),
+            serde_json::json!({"tags": ["a", null], "pos": {"x": 1.5, "y": null}})
+        );
+        assert!(obj.try_convert(&SqlType::array(SqlType::text())).is_err());
+
+        let arr = |v: Vec<i64>| {
+            NnSqlValue::Array(
+                v.into_iter()
+                    .map(|i| SqlValue::NotNull(NnSqlValue::BigInt(i)))
+                    .collect(),
+            )
+        };
+        assert_eq!(
+            arr(vec![1, 2]),
+            NnSqlValue::Array(vec![
+                SqlValue::NotNull(NnSqlValue::SmallInt(1)),
+                SqlValue::NotNull(NnSqlValue::Integer(2))
+            ])
+        );
+        assert_eq!(
+            arr(vec![1, 2]).sql_compare(&arr(vec![1, 3]))?,
+            SqlCompareResult::LessThan
+        );
+        assert_eq!(
+            arr(vec![1, 2]).sql_compare(&arr(vec![1]))?,
+            SqlCompareResult::GreaterThan
+        );
+        assert!(arr(vec![1]).sql_compare(&NnSqlValue::BigInt(1)).is_err());
+        Ok(())
+    }
 }
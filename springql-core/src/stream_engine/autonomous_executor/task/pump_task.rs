@@ -2,13 +2,14 @@
 
 mod pump_subtask;
 
+use std::collections::HashMap;
 use std::sync::MutexGuard;
 use std::thread;
 use std::time::Duration;
 
 use crate::{
     api::error::Result,
-    pipeline::{PipelineGraph, PumpModel},
+    pipeline::{PipelineGraph, PumpModel, StreamName, WatermarkOptions},
     stream_engine::{
         autonomous_executor::{
             performance_metrics::{
@@ -18,12 +19,15 @@ use crate::{
             task::{
                 pump_task::pump_subtask::{InsertSubtask, QuerySubtask},
                 task_context::TaskContext,
-                window::{AggrWindow, JoinWindow},
+                window::{
+                    AggrWindow, ChangesOnly, ContinuousAggregate, Deduplicate, DistinctWindow,
+                    IntervalJoin, JoinWindow, MatchRecognize, Navigation, SilenceAlert,
+                },
                 ProcessedRows, TaskRunResult,
             },
             task_graph::TaskId,
         },
-        time::WallClockStopwatch,
+        time::{SpringEventDuration, WallClockStopwatch},
     },
 };
 
@@ -41,7 +45,10 @@ pub struct PumpTask {
 impl PumpTask {
     pub fn new(pump: &PumpModel, pipeline_graph: &PipelineGraph) -> Self {
         let id = TaskId::from_pump(pump);
-        let query_subtask = QuerySubtask::new(pump.query_plan().clone());
+        let query_subtask = QuerySubtask::new(
+            pump.query_plan().clone(),
+            &Self::source_idle_timeouts(pipeline_graph),
+        );
         let insert_subtask = InsertSubtask::new(pump.insert_plan(), pipeline_graph);
         Self {
             id,
@@ -50,6 +57,23 @@ impl PumpTask {
         }
     }
 
+    /// Idle timeouts given by source readers (`IDLE_TIMEOUT_MSEC` option) for their source streams.
+    fn source_idle_timeouts(
+        pipeline_graph: &PipelineGraph,
+    ) -> HashMap<StreamName, SpringEventDuration> {
+        pipeline_graph
+            .all_sources()
+            .into_iter()
+            .filter_map(|source_reader| {
+                let watermark_options = WatermarkOptions::try_from(source_reader.options())
+                    .expect("must be validated when the source reader is added");
+                watermark_options
+                    .idle_timeout
+                    .map(|idle_timeout| (source_reader.dest_source_stream().clone(), idle_timeout))
+            })
+            .collect()
+    }
+
     pub fn id(&self) -> &TaskId {
         &self.id
     }
@@ -103,4 +127,28 @@ impl PumpTask {
     pub fn get_join_window_mut(&self) -> Option<MutexGuard<JoinWindow>> {
         self.query_subtask.get_join_window_mut()
     }
+    pub fn get_interval_join_mut(&self) -> Option<MutexGuard<'_, IntervalJoin>> {
+        self.query_subtask.get_interval_join_mut()
+    }
+    pub fn get_distinct_window_mut(&self) -> Option<MutexGuard<'_, DistinctWindow>> {
+        self.query_subtask.get_distinct_window_mut()
+    }
+    pub fn get_continuous_aggregate_mut(&self) -> Option<MutexGuard<'_, ContinuousAggregate>> {
+        self.query_subtask.get_continuous_aggregate_mut()
+    }
+    pub fn get_match_recognize_mut(&self) -> Option<MutexGuard<'_, MatchRecognize>> {
+        self.query_subtask.get_match_recognize_mut()
+    }
+    pub fn get_deduplicate_mut(&self) -> Option<MutexGuard<'_, Deduplicate>> {
+        self.query_subtask.get_deduplicate_mut()
+    }
+    pub fn get_changes_only_mut(&self) -> Option<MutexGuard<'_, ChangesOnly>> {
+        self.query_subtask.get_changes_only_mut()
+    }
+    pub fn get_silence_alert_mut(&self) -> Option<MutexGuard<'_, SilenceAlert>> {
+        self.query_subtask.get_silence_alert_mut()
+    }
+    pub fn get_navigation_mut(&self) -> Option<MutexGuard<'_, Navigation>> {
+        self.query_subtask.get_navigation_mut()
+    }
 }
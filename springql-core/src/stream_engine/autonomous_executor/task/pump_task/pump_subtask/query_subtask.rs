@@ -1,11 +1,23 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
+mod changes_only_subtask;
 mod collect_subtask;
+mod continuous_aggregate_subtask;
+mod deduplicate_subtask;
+mod distinct_window_subtask;
 mod group_aggregate_window_subtask;
 mod join_subtask;
+mod match_recognize_subtask;
+mod navigation_subtask;
 mod projection_subtask;
+mod selection_subtask;
+mod silence_alert_subtask;
+mod unnest_subtask;
 
-use std::sync::{Arc, Mutex, MutexGuard};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+};
 
 use rand::{
     prelude::{SliceRandom, SmallRng},
@@ -15,7 +27,7 @@ use rand::{
 use crate::{
     api::error::Result,
     expr_resolver::ExprResolver,
-    pipeline::{ColumnName, StreamModel},
+    pipeline::{ColumnName, StreamModel, StreamName},
     stream_engine::{
         autonomous_executor::{
             performance_metrics::{
@@ -24,17 +36,27 @@ use crate::{
             row::{ColumnValues, StreamColumns, StreamRow},
             task::{
                 pump_task::pump_subtask::query_subtask::{
-                    collect_subtask::CollectSubtask,
+                    changes_only_subtask::ChangesOnlySubtask, collect_subtask::CollectSubtask,
+                    continuous_aggregate_subtask::ContinuousAggregateSubtask,
+                    deduplicate_subtask::DeduplicateSubtask,
+                    distinct_window_subtask::DistinctWindowSubtask,
                     group_aggregate_window_subtask::GroupAggregateWindowSubtask,
-                    join_subtask::JoinSubtask, projection_subtask::ProjectionSubtask,
+                    join_subtask::JoinSubtask, match_recognize_subtask::MatchRecognizeSubtask,
+                    navigation_subtask::NavigationSubtask, projection_subtask::ProjectionSubtask,
+                    selection_subtask::SelectionSubtask,
+                    silence_alert_subtask::SilenceAlertSubtask, unnest_subtask::UnnestSubtask,
                 },
                 task_context::TaskContext,
                 tuple::Tuple,
-                window::{AggrWindow, JoinDir, JoinWindow},
+                window::{
+                    AggrWindow, ChangesOnly, ContinuousAggregate, Deduplicate, DistinctWindow,
+                    IntervalJoin, JoinDir, JoinWindow, MatchRecognize, Navigation, SilenceAlert,
+                },
                 ProcessedRows,
             },
         },
         command::{JoinOp, LowerOps, QueryPlan},
+        time::SpringEventDuration,
         SqlValue,
     },
 };
@@ -47,6 +69,25 @@ pub struct QuerySubtask {
     projection_subtask: ProjectionSubtask,
 
     group_aggr_window_subtask: Option<GroupAggregateWindowSubtask>,
+    distinct_window_subtask: Option<DistinctWindowSubtask>,
+    continuous_aggr_subtask: Option<ContinuousAggregateSubtask>,
+
+    /// Applied to tuples from collect (or JOIN) subtask(s).
+    unnest_subtask: Option<UnnestSubtask>,
+    /// Applied to tuples from collect (or JOIN, UNNEST) subtask(s), with conjuncts of WHERE condition without navigation functions.
+    selection_subtask: Option<SelectionSubtask>,
+    /// Applied to tuples from collect (or UNNEST, selection) subtask.
+    match_recognize_subtask: Option<MatchRecognizeSubtask>,
+    /// Applied to tuples from collect (or JOIN, selection, MATCH_RECOGNIZE) subtask(s).
+    deduplicate_subtask: Option<DeduplicateSubtask>,
+    /// Applied to tuples from deduplicate subtask (if any).
+    changes_only_subtask: Option<ChangesOnlySubtask>,
+    /// Applied to tuples from changes only subtask (if any).
+    silence_alert_subtask: Option<SilenceAlertSubtask>,
+    /// Applied to tuples from silence alert subtask (if any).
+    navigation_subtask: Option<NavigationSubtask>,
+    /// Applied to tuples from navigation subtask (if any), with conjuncts of WHERE condition with navigation functions.
+    navigation_selection_subtask: Option<SelectionSubtask>,
 
     // TODO recursive JOIN
     join: Option<(
@@ -126,16 +167,73 @@ impl QuerySubtaskOut {
 }
 
 impl QuerySubtask {
-    pub fn new(plan: QueryPlan) -> Self {
+    /// `source_idle_timeouts` are idle timeouts given by source readers for their source streams.
+    pub fn new(
+        plan: QueryPlan,
+        source_idle_timeouts: &HashMap<StreamName, SpringEventDuration>,
+    ) -> Self {
         let rng =
             Mutex::new(SmallRng::from_rng(rand::thread_rng()).expect("this generally won't fail"));
 
-        let (left_collect_subtask, join) = Self::subtasks_from_lower_ops(plan.lower_ops);
+        let unnest_subtask = plan
+            .lower_ops
+            .unnest
+            .clone()
+            .map(|op| UnnestSubtask::new(op.unnest_param));
+        let selection_subtask = plan
+            .lower_ops
+            .selection
+            .clone()
+            .map(|op| SelectionSubtask::new(op.condition));
+        let match_recognize_op = plan.lower_ops.match_recognize.clone();
+        let deduplicate_op = plan.lower_ops.deduplicate.clone();
+        let silence_alert_op = plan.lower_ops.silence_alert.clone();
+        let changes_only_subtask = plan
+            .lower_ops
+            .changes_only
+            .clone()
+            .map(|op| ChangesOnlySubtask::new(op.changes_only_param));
+        let (left_collect_subtask, join) =
+            Self::subtasks_from_lower_ops(plan.lower_ops, source_idle_timeouts);
+
+        let source_idle_timeout = join
+            .is_none()
+            .then(|| source_idle_timeouts.get(left_collect_subtask.upstream()))
+            .flatten()
+            .copied();
+
+        let match_recognize_subtask = match_recognize_op
+            .map(|op| MatchRecognizeSubtask::new(op.match_param, source_idle_timeout));
+        let deduplicate_subtask =
+            deduplicate_op.map(|op| DeduplicateSubtask::new(op.dedup_param, source_idle_timeout));
+        let silence_alert_subtask = silence_alert_op
+            .map(|op| SilenceAlertSubtask::new(op.silence_alert_param, source_idle_timeout));
+
+        let group_aggr_window_subtask = plan.upper_ops.group_aggr_window.map(|op| {
+            GroupAggregateWindowSubtask::new(
+                op.window_param,
+                op.op_param,
+                op.resample_time,
+                source_idle_timeout,
+            )
+        });
+        let distinct_window_subtask = plan.upper_ops.distinct_window.map(|op| {
+            DistinctWindowSubtask::new(op.window_param, op.op_param, source_idle_timeout)
+        });
+
+        let continuous_aggr_subtask = plan
+            .upper_ops
+            .continuous_aggr
+            .map(|op| ContinuousAggregateSubtask::new(op.aggr_param, op.changelog_op));
 
-        let group_aggr_window_subtask = plan
+        let navigation_subtask = plan
+            .upper_ops
+            .navigation
+            .map(|op| NavigationSubtask::new(op.params, source_idle_timeout));
+        let navigation_selection_subtask = plan
             .upper_ops
-            .group_aggr_window
-            .map(|op| GroupAggregateWindowSubtask::new(op.window_param, op.op_param));
+            .navigation_selection
+            .map(|op| SelectionSubtask::new(op.condition));
 
         let projection_subtask = ProjectionSubtask::new(plan.upper_ops.projection.expr_labels);
 
@@ -143,6 +241,16 @@ impl QuerySubtask {
             expr_resolver: plan.expr_resolver,
             projection_subtask,
             group_aggr_window_subtask,
+            distinct_window_subtask,
+            continuous_aggr_subtask,
+            unnest_subtask,
+            selection_subtask,
+            match_recognize_subtask,
+            deduplicate_subtask,
+            changes_only_subtask,
+            silence_alert_subtask,
+            navigation_subtask,
+            navigation_selection_subtask,
             left_collect_subtask,
             join,
             rng,
@@ -151,6 +259,7 @@ impl QuerySubtask {
     /// (left collect subtask, Option<(join subtask, right collect subtask)>)
     fn subtasks_from_lower_ops(
         lower_ops: LowerOps,
+        source_idle_timeouts: &HashMap<StreamName, SpringEventDuration>,
     ) -> (CollectSubtask, Option<(JoinSubtask, CollectSubtask)>) {
         match lower_ops.join {
             JoinOp::Collect(collect_op) => {
@@ -160,8 +269,34 @@ impl QuerySubtask {
             JoinOp::JoinWindow(join_window_op) => {
                 let left_collect_subtask = CollectSubtask::from_collect_op(join_window_op.left);
                 let right_collect_subtask = CollectSubtask::from_collect_op(join_window_op.right);
-                let join_subtask =
-                    JoinSubtask::new(join_window_op.window_param, join_window_op.join_param);
+                let join_subtask = JoinSubtask::new_window(
+                    join_window_op.window_param,
+                    join_window_op.join_param,
+                    source_idle_timeouts
+                        .get(left_collect_subtask.upstream())
+                        .copied(),
+                    source_idle_timeouts
+                        .get(right_collect_subtask.upstream())
+                        .copied(),
+                );
+                (
+                    left_collect_subtask,
+                    Some((join_subtask, right_collect_subtask)),
+                )
+            }
+            JoinOp::IntervalJoin(interval_join_op) => {
+                let left_collect_subtask = CollectSubtask::from_collect_op(interval_join_op.left);
+                let right_collect_subtask = CollectSubtask::from_collect_op(interval_join_op.right);
+                let join_subtask = JoinSubtask::new_interval(
+                    interval_join_op.interval_param,
+                    interval_join_op.join_param,
+                    source_idle_timeouts
+                        .get(left_collect_subtask.upstream())
+                        .copied(),
+                    source_idle_timeouts
+                        .get(right_collect_subtask.upstream())
+                        .copied(),
+                );
                 (
                     left_collect_subtask,
                     Some((join_subtask, right_collect_subtask)),
@@ -179,8 +314,21 @@ impl QuerySubtask {
     /// TODO
     pub fn run(&self, context: &TaskContext) -> Result<Option<QuerySubtaskOut>> {
         match self.run_lower_ops(context) {
-            None => Ok(None),
+            None => self.run_idle(context),
             Some((lower_tuples, in_queue_metrics_update_by_task)) => {
+                let lower_tuples = self.run_unnest(lower_tuples)?;
+                let lower_tuples = self.run_selection(lower_tuples)?;
+                let (lower_tuples, in_queue_metrics_update_by_task) =
+                    self.run_match_recognize(lower_tuples, in_queue_metrics_update_by_task);
+                let (lower_tuples, in_queue_metrics_update_by_task) =
+                    self.run_deduplicate(lower_tuples, in_queue_metrics_update_by_task);
+                let (lower_tuples, in_queue_metrics_update_by_task) =
+                    self.run_changes_only(lower_tuples, in_queue_metrics_update_by_task);
+                let (lower_tuples, in_queue_metrics_update_by_task) =
+                    self.run_silence_alert(lower_tuples, in_queue_metrics_update_by_task);
+                let (lower_tuples, in_queue_metrics_update_by_task) =
+                    self.run_navigation(lower_tuples, in_queue_metrics_update_by_task);
+                let lower_tuples = self.run_navigation_selection(lower_tuples)?;
                 let (values_seq, in_queue_metrics_update) =
                     self.run_upper_ops(lower_tuples, in_queue_metrics_update_by_task)?;
 
@@ -192,6 +340,116 @@ impl QuerySubtask {
         }
     }
 
+    /// Let windows close panes by idleness of inputs.
+    ///
+    /// # Returns
+    ///
+    /// None when this query does not have windows or windows do not change.
+    fn run_idle(&self, context: &TaskContext) -> Result<Option<QuerySubtaskOut>> {
+        if self.join.is_none()
+            && self.group_aggr_window_subtask.is_none()
+            && self.distinct_window_subtask.is_none()
+            && self.match_recognize_subtask.is_none()
+            && self.deduplicate_subtask.is_none()
+            && self.silence_alert_subtask.is_none()
+            && self.navigation_subtask.is_none()
+        {
+            return Ok(None);
+        }
+        let queue_id = match self.left_collect_subtask.window_queue_id(context) {
+            Some(queue_id) => queue_id,
+            None => return Ok(None),
+        };
+
+        let (lower_tuples, window_in_flow_lower) = match &self.join {
+            Some((join_subtask, _)) => join_subtask.run_idle(&self.expr_resolver),
+            None => (Vec::new(), WindowInFlowByWindowTask::zero()),
+        };
+        let lower_tuples = self.run_unnest(lower_tuples)?;
+        let lower_tuples = self.run_selection(lower_tuples)?;
+        let in_queue_metrics_update_by_lower = InQueueMetricsUpdateByTask::new(
+            InQueueMetricsUpdateByCollect::Window {
+                queue_id,
+                waiting_bytes_dispatched: 0,
+                waiting_rows_dispatched: 0,
+            },
+            Some(window_in_flow_lower),
+        );
+        let (mut lower_tuples, mut in_queue_metrics_update_by_lower) =
+            self.run_match_recognize(lower_tuples, in_queue_metrics_update_by_lower);
+        if let Some(match_recognize_subtask) = &self.match_recognize_subtask {
+            let (tuples, window_in_flow) = match_recognize_subtask.run_idle(&self.expr_resolver);
+            lower_tuples.extend(tuples);
+            in_queue_metrics_update_by_lower.window_in_flow =
+                in_queue_metrics_update_by_lower.window_in_flow + window_in_flow;
+        }
+        let (lower_tuples, mut in_queue_metrics_update_by_lower) =
+            self.run_deduplicate(lower_tuples, in_queue_metrics_update_by_lower);
+        if let Some(deduplicate_subtask) = &self.deduplicate_subtask {
+            in_queue_metrics_update_by_lower.window_in_flow =
+                in_queue_metrics_update_by_lower.window_in_flow + deduplicate_subtask.run_idle();
+        }
+        let (lower_tuples, in_queue_metrics_update_by_lower) =
+            self.run_changes_only(lower_tuples, in_queue_metrics_update_by_lower);
+        let (mut lower_tuples, mut in_queue_metrics_update_by_lower) =
+            self.run_silence_alert(lower_tuples, in_queue_metrics_update_by_lower);
+        if let Some(silence_alert_subtask) = &self.silence_alert_subtask {
+            let (tuples, window_in_flow) = silence_alert_subtask.run_idle();
+            lower_tuples.extend(tuples);
+            in_queue_metrics_update_by_lower.window_in_flow =
+                in_queue_metrics_update_by_lower.window_in_flow + window_in_flow;
+        }
+        let (mut lower_tuples, mut in_queue_metrics_update_by_lower) =
+            self.run_navigation(lower_tuples, in_queue_metrics_update_by_lower);
+        if let Some(navigation_subtask) = &self.navigation_subtask {
+            let (tuples, window_in_flow) = navigation_subtask.run_idle();
+            lower_tuples.extend(tuples);
+            in_queue_metrics_update_by_lower.window_in_flow =
+                in_queue_metrics_update_by_lower.window_in_flow + window_in_flow;
+        }
+        let lower_tuples = self.run_navigation_selection(lower_tuples)?;
+
+        let (mut values_seq, mut in_queue_metrics_update) =
+            self.run_upper_ops(lower_tuples, in_queue_metrics_update_by_lower)?;
+
+        if let Some(group_aggr_window_subtask) = &self.group_aggr_window_subtask {
+            let (aggregated_and_grouping_values_seq, window_in_flow) =
+                group_aggr_window_subtask.run_idle(&self.expr_resolver);
+
+            for aggregated_and_grouping_values in aggregated_and_grouping_values_seq {
+                values_seq.push(
+                    self.projection_subtask
+                        .run_with_aggr(aggregated_and_grouping_values)?,
+                );
+            }
+            in_queue_metrics_update.window_in_flow =
+                in_queue_metrics_update.window_in_flow + window_in_flow;
+        }
+        if let Some(distinct_window_subtask) = &self.distinct_window_subtask {
+            let (tuples, window_in_flow) = distinct_window_subtask.run_idle(&self.expr_resolver);
+
+            for tuple in tuples {
+                values_seq.push(
+                    self.projection_subtask
+                        .run_without_aggr(&self.expr_resolver, &tuple)?,
+                );
+            }
+            in_queue_metrics_update.window_in_flow =
+                in_queue_metrics_update.window_in_flow + window_in_flow;
+        }
+
+        if values_seq.is_empty()
+            && in_queue_metrics_update.window_in_flow == WindowInFlowByWindowTask::zero()
+        {
+            Ok(None)
+        } else {
+            Ok(Some(QuerySubtaskOut::new(
+                values_seq,
+                in_queue_metrics_update,
+            )))
+        }
+    }
+
     fn run_upper_ops(
         &self,
         tuples: Vec<Tuple>,
@@ -230,6 +488,31 @@ impl QuerySubtask {
                 })
                 .collect::<Result<Vec<_>>>()?;
 
+            Ok((values_seq, window_in_flow))
+        } else if let Some(distinct_window_subtask) = &self.distinct_window_subtask {
+            let (tuples, window_in_flow) = distinct_window_subtask.run(&self.expr_resolver, tuple);
+
+            let values_seq = tuples
+                .iter()
+                .map(|tuple| {
+                    self.projection_subtask
+                        .run_without_aggr(&self.expr_resolver, tuple)
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            Ok((values_seq, window_in_flow))
+        } else if let Some(continuous_aggr_subtask) = &self.continuous_aggr_subtask {
+            let (aggregated_and_grouping_values_seq, window_in_flow) =
+                continuous_aggr_subtask.run(&self.expr_resolver, tuple);
+
+            let values_seq = aggregated_and_grouping_values_seq
+                .into_iter()
+                .map(|aggregated_and_grouping_values| {
+                    self.projection_subtask
+                        .run_with_aggr(aggregated_and_grouping_values)
+                })
+                .collect::<Result<Vec<_>>>()?;
+
             Ok((values_seq, window_in_flow))
         } else {
             let values = self
@@ -239,6 +522,120 @@ impl QuerySubtask {
         }
     }
 
+    fn run_unnest(&self, tuples: Vec<Tuple>) -> Result<Vec<Tuple>> {
+        match &self.unnest_subtask {
+            Some(unnest_subtask) => unnest_subtask.run(&self.expr_resolver, tuples),
+            None => Ok(tuples),
+        }
+    }
+
+    fn run_match_recognize(
+        &self,
+        tuples: Vec<Tuple>,
+        in_queue_metrics_update_by_lower: InQueueMetricsUpdateByTask,
+    ) -> (Vec<Tuple>, InQueueMetricsUpdateByTask) {
+        match &self.match_recognize_subtask {
+            Some(match_recognize_subtask) => {
+                let (tuples, window_in_flow) =
+                    match_recognize_subtask.run(&self.expr_resolver, tuples);
+                let in_queue_metrics_update = InQueueMetricsUpdateByTask::new(
+                    in_queue_metrics_update_by_lower.by_collect,
+                    Some(in_queue_metrics_update_by_lower.window_in_flow + window_in_flow),
+                );
+                (tuples, in_queue_metrics_update)
+            }
+            None => (tuples, in_queue_metrics_update_by_lower),
+        }
+    }
+
+    fn run_deduplicate(
+        &self,
+        tuples: Vec<Tuple>,
+        in_queue_metrics_update_by_lower: InQueueMetricsUpdateByTask,
+    ) -> (Vec<Tuple>, InQueueMetricsUpdateByTask) {
+        match &self.deduplicate_subtask {
+            Some(deduplicate_subtask) => {
+                let (tuples, window_in_flow) = deduplicate_subtask.run(&self.expr_resolver, tuples);
+                let in_queue_metrics_update = InQueueMetricsUpdateByTask::new(
+                    in_queue_metrics_update_by_lower.by_collect,
+                    Some(in_queue_metrics_update_by_lower.window_in_flow + window_in_flow),
+                );
+                (tuples, in_queue_metrics_update)
+            }
+            None => (tuples, in_queue_metrics_update_by_lower),
+        }
+    }
+
+    fn run_changes_only(
+        &self,
+        tuples: Vec<Tuple>,
+        in_queue_metrics_update_by_lower: InQueueMetricsUpdateByTask,
+    ) -> (Vec<Tuple>, InQueueMetricsUpdateByTask) {
+        match &self.changes_only_subtask {
+            Some(changes_only_subtask) => {
+                let (tuples, window_in_flow) =
+                    changes_only_subtask.run(&self.expr_resolver, tuples);
+                let in_queue_metrics_update = InQueueMetricsUpdateByTask::new(
+                    in_queue_metrics_update_by_lower.by_collect,
+                    Some(in_queue_metrics_update_by_lower.window_in_flow + window_in_flow),
+                );
+                (tuples, in_queue_metrics_update)
+            }
+            None => (tuples, in_queue_metrics_update_by_lower),
+        }
+    }
+
+    fn run_silence_alert(
+        &self,
+        tuples: Vec<Tuple>,
+        in_queue_metrics_update_by_lower: InQueueMetricsUpdateByTask,
+    ) -> (Vec<Tuple>, InQueueMetricsUpdateByTask) {
+        match &self.silence_alert_subtask {
+            Some(silence_alert_subtask) => {
+                let (tuples, window_in_flow) =
+                    silence_alert_subtask.run(&self.expr_resolver, tuples);
+                let in_queue_metrics_update = InQueueMetricsUpdateByTask::new(
+                    in_queue_metrics_update_by_lower.by_collect,
+                    Some(in_queue_metrics_update_by_lower.window_in_flow + window_in_flow),
+                );
+                (tuples, in_queue_metrics_update)
+            }
+            None => (tuples, in_queue_metrics_update_by_lower),
+        }
+    }
+
+    fn run_navigation(
+        &self,
+        tuples: Vec<Tuple>,
+        in_queue_metrics_update_by_lower: InQueueMetricsUpdateByTask,
+    ) -> (Vec<Tuple>, InQueueMetricsUpdateByTask) {
+        match &self.navigation_subtask {
+            Some(navigation_subtask) => {
+                let (tuples, window_in_flow) = navigation_subtask.run(&self.expr_resolver, tuples);
+                let in_queue_metrics_update = InQueueMetricsUpdateByTask::new(
+                    in_queue_metrics_update_by_lower.by_collect,
+                    Some(in_queue_metrics_update_by_lower.window_in_flow + window_in_flow),
+                );
+                (tuples, in_queue_metrics_update)
+            }
+            None => (tuples, in_queue_metrics_update_by_lower),
+        }
+    }
+
+    fn run_selection(&self, tuples: Vec<Tuple>) -> Result<Vec<Tuple>> {
+        match &self.selection_subtask {
+            Some(selection_subtask) => selection_subtask.run(&self.expr_resolver, tuples),
+            None => Ok(tuples),
+        }
+    }
+
+    fn run_navigation_selection(&self, tuples: Vec<Tuple>) -> Result<Vec<Tuple>> {
+        match &self.navigation_selection_subtask {
+            Some(selection_subtask) => selection_subtask.run(&self.expr_resolver, tuples),
+            None => Ok(tuples),
+        }
+    }
+
     /// # Returns
     ///
     /// None when input queue does not exist or is empty or JOIN op does not emit output yet.
@@ -326,6 +723,46 @@ impl QuerySubtask {
     pub fn get_join_window_mut(&self) -> Option<MutexGuard<JoinWindow>> {
         self.join
             .as_ref()
-            .map(|(subtask, _)| subtask.get_window_mut())
+            .and_then(|(subtask, _)| subtask.get_window_mut())
+    }
+    pub fn get_interval_join_mut(&self) -> Option<MutexGuard<'_, IntervalJoin>> {
+        self.join
+            .as_ref()
+            .and_then(|(subtask, _)| subtask.get_interval_join_mut())
+    }
+    pub fn get_distinct_window_mut(&self) -> Option<MutexGuard<'_, DistinctWindow>> {
+        self.distinct_window_subtask
+            .as_ref()
+            .map(|subtask| subtask.get_window_mut())
+    }
+    pub fn get_continuous_aggregate_mut(&self) -> Option<MutexGuard<'_, ContinuousAggregate>> {
+        self.continuous_aggr_subtask
+            .as_ref()
+            .map(|subtask| subtask.get_continuous_aggregate_mut())
+    }
+    pub fn get_match_recognize_mut(&self) -> Option<MutexGuard<'_, MatchRecognize>> {
+        self.match_recognize_subtask
+            .as_ref()
+            .map(|subtask| subtask.get_match_recognize_mut())
+    }
+    pub fn get_deduplicate_mut(&self) -> Option<MutexGuard<'_, Deduplicate>> {
+        self.deduplicate_subtask
+            .as_ref()
+            .map(|subtask| subtask.get_deduplicate_mut())
+    }
+    pub fn get_changes_only_mut(&self) -> Option<MutexGuard<'_, ChangesOnly>> {
+        self.changes_only_subtask
+            .as_ref()
+            .map(|subtask| subtask.get_changes_only_mut())
+    }
+    pub fn get_silence_alert_mut(&self) -> Option<MutexGuard<'_, SilenceAlert>> {
+        self.silence_alert_subtask
+            .as_ref()
+            .map(|subtask| subtask.get_silence_alert_mut())
+    }
+    pub fn get_navigation_mut(&self) -> Option<MutexGuard<'_, Navigation>> {
+        self.navigation_subtask
+            .as_ref()
+            .map(|subtask| subtask.get_navigation_mut())
     }
 }
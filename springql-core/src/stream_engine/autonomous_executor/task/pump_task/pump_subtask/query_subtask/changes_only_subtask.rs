@@ -0,0 +1,47 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::sync::{Mutex, MutexGuard};
+
+use crate::{
+    expr_resolver::ExprResolver,
+    pipeline::ChangesOnlyParameter,
+    stream_engine::autonomous_executor::{
+        performance_metrics::WindowInFlowByWindowTask,
+        task::{tuple::Tuple, window::ChangesOnly},
+    },
+};
+
+#[derive(Debug)]
+pub struct ChangesOnlySubtask(Mutex<ChangesOnly>);
+
+impl ChangesOnlySubtask {
+    pub fn new(changes_only_param: ChangesOnlyParameter) -> Self {
+        Self(Mutex::new(ChangesOnly::new(changes_only_param)))
+    }
+
+    /// Drops tuples with the same values as the last ones in their partitions.
+    pub fn run(
+        &self,
+        expr_resolver: &ExprResolver,
+        tuples: Vec<Tuple>,
+    ) -> (Vec<Tuple>, WindowInFlowByWindowTask) {
+        let mut changes_only = self.get_changes_only_mut();
+
+        tuples.into_iter().fold(
+            (Vec::new(), WindowInFlowByWindowTask::zero()),
+            |(mut out_acc, window_in_flow_acc), tuple| {
+                let (out, window_in_flow) = changes_only
+                    .dispatch(expr_resolver, tuple)
+                    .expect("dispatch failed");
+                out_acc.extend(out);
+                (out_acc, window_in_flow_acc + window_in_flow)
+            },
+        )
+    }
+
+    pub fn get_changes_only_mut(&self) -> MutexGuard<'_, ChangesOnly> {
+        self.0
+            .lock()
+            .expect("another thread accessing to changes only gets poisoned")
+    }
+}
@@ -27,6 +27,23 @@ impl CollectSubtask {
         Self { upstream }
     }
 
+    pub fn upstream(&self) -> &StreamName {
+        &self.upstream
+    }
+
+    /// # Returns
+    ///
+    /// None when input queue does not exist or is not a window queue.
+    pub fn window_queue_id(&self, context: &TaskContext) -> Option<WindowQueueId> {
+        let pipeline_derivatives = context.pipeline_derivatives();
+        let task_graph = pipeline_derivatives.task_graph();
+
+        match task_graph.input_queue(&context.task(), &self.upstream) {
+            Some(QueueId::Window(queue_id)) => Some(queue_id),
+            _ => None,
+        }
+    }
+
     /// # Returns
     ///
     /// None when input queue does not exist or is empty.
@@ -0,0 +1,48 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::sync::{Mutex, MutexGuard};
+
+use crate::{
+    expr_resolver::{ExprResolver, ValueExprLabel},
+    pipeline::AggregateParameter,
+    stream_engine::autonomous_executor::{
+        performance_metrics::WindowInFlowByWindowTask,
+        task::{
+            tuple::Tuple,
+            window::{AggregatedAndGroupingValues, ContinuousAggregate},
+        },
+    },
+};
+
+#[derive(Debug)]
+pub struct ContinuousAggregateSubtask(Mutex<ContinuousAggregate>);
+
+impl ContinuousAggregateSubtask {
+    pub fn new(aggr_param: AggregateParameter, changelog_op: Option<ValueExprLabel>) -> Self {
+        Self(Mutex::new(ContinuousAggregate::new(
+            aggr_param,
+            changelog_op,
+        )))
+    }
+
+    /// # Returns
+    ///
+    /// Current aggregate of the group of `tuple` if it has changed.
+    pub fn run(
+        &self,
+        expr_resolver: &ExprResolver,
+        tuple: Tuple,
+    ) -> (Vec<AggregatedAndGroupingValues>, WindowInFlowByWindowTask) {
+        let (out, window_in_flow) = self
+            .get_continuous_aggregate_mut()
+            .dispatch(expr_resolver, &tuple)
+            .expect("dispatch failed");
+        (out.into_iter().collect(), window_in_flow)
+    }
+
+    pub fn get_continuous_aggregate_mut(&self) -> MutexGuard<'_, ContinuousAggregate> {
+        self.0
+            .lock()
+            .expect("another thread accessing to continuous aggregate gets poisoned")
+    }
+}
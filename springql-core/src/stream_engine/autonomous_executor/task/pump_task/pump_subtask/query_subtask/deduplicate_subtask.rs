@@ -0,0 +1,62 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::sync::{Mutex, MutexGuard};
+
+use crate::{
+    expr_resolver::ExprResolver,
+    pipeline::DeduplicateParameter,
+    stream_engine::{
+        autonomous_executor::{
+            performance_metrics::WindowInFlowByWindowTask,
+            task::{tuple::Tuple, window::Deduplicate},
+        },
+        time::{SpringEventDuration, SystemTimestamp},
+    },
+};
+
+#[derive(Debug)]
+pub struct DeduplicateSubtask(Mutex<Deduplicate>);
+
+impl DeduplicateSubtask {
+    pub fn new(
+        dedup_param: DeduplicateParameter,
+        source_idle_timeout: Option<SpringEventDuration>,
+    ) -> Self {
+        Self(Mutex::new(Deduplicate::new(
+            dedup_param,
+            source_idle_timeout,
+        )))
+    }
+
+    /// Drops duplicate tuples.
+    pub fn run(
+        &self,
+        expr_resolver: &ExprResolver,
+        tuples: Vec<Tuple>,
+    ) -> (Vec<Tuple>, WindowInFlowByWindowTask) {
+        let mut dedup = self.get_deduplicate_mut();
+
+        tuples.into_iter().fold(
+            (Vec::new(), WindowInFlowByWindowTask::zero()),
+            |(mut out_acc, window_in_flow_acc), tuple| {
+                let (out, window_in_flow) = dedup
+                    .dispatch(expr_resolver, tuple)
+                    .expect("dispatch failed");
+                out_acc.extend(out);
+                (out_acc, window_in_flow_acc + window_in_flow)
+            },
+        )
+    }
+
+    /// Called when no tuple comes from input.
+    pub fn run_idle(&self) -> WindowInFlowByWindowTask {
+        self.get_deduplicate_mut()
+            .dispatch_idleness(SystemTimestamp::now())
+    }
+
+    pub fn get_deduplicate_mut(&self) -> MutexGuard<'_, Deduplicate> {
+        self.0
+            .lock()
+            .expect("another thread accessing to deduplicate gets poisoned")
+    }
+}
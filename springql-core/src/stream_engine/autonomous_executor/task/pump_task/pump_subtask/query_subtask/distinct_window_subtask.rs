@@ -0,0 +1,58 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::sync::{Mutex, MutexGuard};
+
+use crate::{
+    expr_resolver::ExprResolver,
+    pipeline::{WindowOperationParameter, WindowParameter},
+    stream_engine::{
+        autonomous_executor::{
+            performance_metrics::WindowInFlowByWindowTask,
+            task::{
+                tuple::Tuple,
+                window::{DistinctWindow, Window},
+            },
+        },
+        time::{SpringEventDuration, SystemTimestamp},
+    },
+};
+
+#[derive(Debug)]
+pub struct DistinctWindowSubtask(Mutex<DistinctWindow>);
+
+impl DistinctWindowSubtask {
+    pub fn new(
+        window_param: WindowParameter,
+        op_param: WindowOperationParameter,
+        source_idle_timeout: Option<SpringEventDuration>,
+    ) -> Self {
+        let window = DistinctWindow::new(window_param, op_param, source_idle_timeout);
+        Self(Mutex::new(window))
+    }
+
+    pub fn run(
+        &self,
+        expr_resolver: &ExprResolver,
+        tuple: Tuple,
+    ) -> (Vec<Tuple>, WindowInFlowByWindowTask) {
+        self.0
+            .lock()
+            .expect("another thread accessing to window gets poisoned")
+            .dispatch(expr_resolver, tuple, ())
+            .expect("dispatch failed")
+    }
+
+    /// Called when no tuple comes from input.
+    pub fn run_idle(&self, expr_resolver: &ExprResolver) -> (Vec<Tuple>, WindowInFlowByWindowTask) {
+        self.0
+            .lock()
+            .expect("another thread accessing to window gets poisoned")
+            .dispatch_idleness(expr_resolver, SystemTimestamp::now())
+    }
+
+    pub fn get_window_mut(&self) -> MutexGuard<'_, DistinctWindow> {
+        self.0
+            .lock()
+            .expect("another thread accessing to window gets poisoned")
+    }
+}
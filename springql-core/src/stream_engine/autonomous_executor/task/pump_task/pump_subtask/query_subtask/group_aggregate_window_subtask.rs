@@ -3,14 +3,17 @@
 use std::sync::{Mutex, MutexGuard};
 
 use crate::{
-    expr_resolver::ExprResolver,
+    expr_resolver::{ExprResolver, ValueExprLabel},
     pipeline::{WindowOperationParameter, WindowParameter},
-    stream_engine::autonomous_executor::{
-        performance_metrics::WindowInFlowByWindowTask,
-        task::{
-            tuple::Tuple,
-            window::{AggrWindow, AggregatedAndGroupingValues, Window},
+    stream_engine::{
+        autonomous_executor::{
+            performance_metrics::WindowInFlowByWindowTask,
+            task::{
+                tuple::Tuple,
+                window::{AggrWindow, AggregatedAndGroupingValues, Window},
+            },
         },
+        time::{SpringEventDuration, SystemTimestamp},
     },
 };
 
@@ -18,8 +21,13 @@ use crate::{
 pub struct GroupAggregateWindowSubtask(Mutex<AggrWindow>);
 
 impl GroupAggregateWindowSubtask {
-    pub fn new(window_param: WindowParameter, op_param: WindowOperationParameter) -> Self {
-        let window = AggrWindow::new(window_param, op_param);
+    pub fn new(
+        window_param: WindowParameter,
+        op_param: WindowOperationParameter,
+        resample_time: Option<ValueExprLabel>,
+        source_idle_timeout: Option<SpringEventDuration>,
+    ) -> Self {
+        let window = AggrWindow::new(window_param, op_param, resample_time, source_idle_timeout);
         Self(Mutex::new(window))
     }
 
@@ -35,6 +43,17 @@ impl GroupAggregateWindowSubtask {
             .expect("dispatch failed")
     }
 
+    /// Called when no tuple comes from input.
+    pub fn run_idle(
+        &self,
+        expr_resolver: &ExprResolver,
+    ) -> (Vec<AggregatedAndGroupingValues>, WindowInFlowByWindowTask) {
+        self.0
+            .lock()
+            .expect("another thread accessing to window gets poisoned")
+            .dispatch_idleness(expr_resolver, SystemTimestamp::now())
+    }
+
     pub fn get_window_mut(&self) -> MutexGuard<AggrWindow> {
         self.0
             .lock()
@@ -4,23 +4,54 @@ use std::sync::{Mutex, MutexGuard};
 
 use crate::{
     expr_resolver::ExprResolver,
-    pipeline::{JoinParameter, WindowParameter},
-    stream_engine::autonomous_executor::{
-        performance_metrics::WindowInFlowByWindowTask,
-        task::{
-            tuple::Tuple,
-            window::{JoinDir, JoinWindow, Window},
+    pipeline::{IntervalJoinParameter, JoinParameter, WindowParameter},
+    stream_engine::{
+        autonomous_executor::{
+            performance_metrics::WindowInFlowByWindowTask,
+            task::{
+                tuple::Tuple,
+                window::{IntervalJoin, JoinDir, JoinWindow, Window},
+            },
         },
+        time::{SpringEventDuration, SystemTimestamp},
     },
 };
 
 #[derive(Debug)]
-pub struct JoinSubtask(Mutex<JoinWindow>);
+pub enum JoinSubtask {
+    Window(Mutex<JoinWindow>),
+    Interval(Mutex<IntervalJoin>),
+}
 
 impl JoinSubtask {
-    pub fn new(window_param: WindowParameter, join_param: JoinParameter) -> Self {
-        let window = JoinWindow::new(window_param, join_param);
-        Self(Mutex::new(window))
+    pub fn new_window(
+        window_param: WindowParameter,
+        join_param: JoinParameter,
+        left_idle_timeout: Option<SpringEventDuration>,
+        right_idle_timeout: Option<SpringEventDuration>,
+    ) -> Self {
+        let window = JoinWindow::new(
+            window_param,
+            join_param,
+            left_idle_timeout,
+            right_idle_timeout,
+        );
+        Self::Window(Mutex::new(window))
+    }
+
+    pub fn new_interval(
+        interval_param: IntervalJoinParameter,
+        join_param: JoinParameter,
+        left_idle_timeout: Option<SpringEventDuration>,
+        right_idle_timeout: Option<SpringEventDuration>,
+    ) -> Self {
+        let interval_join = IntervalJoin::new(
+            interval_param,
+            join_param,
+            left_idle_timeout,
+            right_idle_timeout,
+        );
+        Self::Interval(Mutex::new(interval_join))
     }
 
     pub fn run(
@@ -29,16 +60,54 @@ impl JoinSubtask {
         tuple: Tuple,
         dir: JoinDir,
     ) -> (Vec<Tuple>, WindowInFlowByWindowTask) {
-        self.0
-            .lock()
-            .expect("another thread accessing to window gets poisoned")
-            .dispatch(expr_resolver, tuple, dir)
-            .expect("dispatch failed")
+        match self {
+            Self::Window(window) => window
+                .lock()
+                .expect("another thread accessing to window gets poisoned")
+                .dispatch(expr_resolver, tuple, dir)
+                .expect("dispatch failed"),
+            Self::Interval(interval_join) => interval_join
+                .lock()
+                .expect("another thread accessing to interval join gets poisoned")
+                .dispatch(expr_resolver, tuple, dir)
+                .expect("dispatch failed"),
+        }
+    }
+
+    /// Called when no tuple comes from both inputs.
+    pub fn run_idle(&self, expr_resolver: &ExprResolver) -> (Vec<Tuple>, WindowInFlowByWindowTask) {
+        let now = SystemTimestamp::now();
+        match self {
+            Self::Window(window) => window
+                .lock()
+                .expect("another thread accessing to window gets poisoned")
+                .dispatch_idleness(expr_resolver, now),
+            Self::Interval(interval_join) => interval_join
+                .lock()
+                .expect("another thread accessing to interval join gets poisoned")
+                .dispatch_idleness(now),
+        }
+    }
+
+    pub fn get_window_mut(&self) -> Option<MutexGuard<'_, JoinWindow>> {
+        match self {
+            Self::Window(window) => Some(
+                window
+                    .lock()
+                    .expect("another thread accessing to window gets poisoned"),
+            ),
+            Self::Interval(_) => None,
+        }
     }
 
-    pub fn get_window_mut(&self) -> MutexGuard<JoinWindow> {
-        self.0
-            .lock()
-            .expect("another thread accessing to window gets poisoned")
+    pub fn get_interval_join_mut(&self) -> Option<MutexGuard<'_, IntervalJoin>> {
+        match self {
+            Self::Window(_) => None,
+            Self::Interval(interval_join) => Some(
+                interval_join
+                    .lock()
+                    .expect("another thread accessing to interval join gets poisoned"),
+            ),
+        }
     }
 }
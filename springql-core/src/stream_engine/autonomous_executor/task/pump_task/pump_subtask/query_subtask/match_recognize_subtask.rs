@@ -0,0 +1,63 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::sync::{Mutex, MutexGuard};
+
+use crate::{
+    expr_resolver::ExprResolver,
+    pipeline::MatchRecognizeParameter,
+    stream_engine::{
+        autonomous_executor::{
+            performance_metrics::WindowInFlowByWindowTask,
+            task::{tuple::Tuple, window::MatchRecognize},
+        },
+        time::{SpringEventDuration, SystemTimestamp},
+    },
+};
+
+#[derive(Debug)]
+pub struct MatchRecognizeSubtask(Mutex<MatchRecognize>);
+
+impl MatchRecognizeSubtask {
+    pub fn new(
+        match_param: MatchRecognizeParameter,
+        source_idle_timeout: Option<SpringEventDuration>,
+    ) -> Self {
+        Self(Mutex::new(MatchRecognize::new(
+            match_param,
+            source_idle_timeout,
+        )))
+    }
+
+    /// Emits a tuple per match.
+    pub fn run(
+        &self,
+        expr_resolver: &ExprResolver,
+        tuples: Vec<Tuple>,
+    ) -> (Vec<Tuple>, WindowInFlowByWindowTask) {
+        let mut match_recognize = self.get_match_recognize_mut();
+
+        tuples.into_iter().fold(
+            (Vec::new(), WindowInFlowByWindowTask::zero()),
+            |(mut out_acc, window_in_flow_acc), tuple| {
+                let (out, window_in_flow) = match_recognize
+                    .dispatch(expr_resolver, tuple)
+                    .expect("dispatch failed");
+                out_acc.extend(out);
+                (out_acc, window_in_flow_acc + window_in_flow)
+            },
+        )
+    }
+
+    /// Called when no tuple comes from input.
+    pub fn run_idle(&self, expr_resolver: &ExprResolver) -> (Vec<Tuple>, WindowInFlowByWindowTask) {
+        self.get_match_recognize_mut()
+            .dispatch_idleness(expr_resolver, SystemTimestamp::now())
+            .expect("dispatch failed")
+    }
+
+    pub fn get_match_recognize_mut(&self) -> MutexGuard<'_, MatchRecognize> {
+        self.0
+            .lock()
+            .expect("another thread accessing to match_recognize gets poisoned")
+    }
+}
@@ -0,0 +1,59 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::sync::{Mutex, MutexGuard};
+
+use crate::{
+    expr_resolver::ExprResolver,
+    pipeline::NavigationParameter,
+    stream_engine::{
+        autonomous_executor::{
+            performance_metrics::WindowInFlowByWindowTask,
+            task::{tuple::Tuple, window::Navigation},
+        },
+        time::{SpringEventDuration, SystemTimestamp},
+    },
+};
+
+#[derive(Debug)]
+pub struct NavigationSubtask(Mutex<Navigation>);
+
+impl NavigationSubtask {
+    pub fn new(
+        params: Vec<NavigationParameter>,
+        source_idle_timeout: Option<SpringEventDuration>,
+    ) -> Self {
+        Self(Mutex::new(Navigation::new(params, source_idle_timeout)))
+    }
+
+    /// Appends values of navigation functions to tuples. Tuples waiting for LEAD values are held.
+    pub fn run(
+        &self,
+        expr_resolver: &ExprResolver,
+        tuples: Vec<Tuple>,
+    ) -> (Vec<Tuple>, WindowInFlowByWindowTask) {
+        let mut navigation = self.get_navigation_mut();
+
+        tuples.into_iter().fold(
+            (Vec::new(), WindowInFlowByWindowTask::zero()),
+            |(mut out_acc, window_in_flow_acc), tuple| {
+                let (out, window_in_flow) = navigation
+                    .dispatch(expr_resolver, tuple)
+                    .expect("dispatch failed");
+                out_acc.extend(out);
+                (out_acc, window_in_flow_acc + window_in_flow)
+            },
+        )
+    }
+
+    /// Called when no tuple comes from input. Emits tuples whose LEAD values are expired.
+    pub fn run_idle(&self) -> (Vec<Tuple>, WindowInFlowByWindowTask) {
+        self.get_navigation_mut()
+            .dispatch_idleness(SystemTimestamp::now())
+    }
+
+    pub fn get_navigation_mut(&self) -> MutexGuard<'_, Navigation> {
+        self.0
+            .lock()
+            .expect("another thread accessing to navigation gets poisoned")
+    }
+}
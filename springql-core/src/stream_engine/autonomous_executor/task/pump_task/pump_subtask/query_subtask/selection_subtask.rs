@@ -0,0 +1,35 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::{
+    api::error::Result,
+    expr_resolver::{ExprResolver, ValueExprLabel},
+    stream_engine::autonomous_executor::task::tuple::Tuple,
+};
+
+#[derive(Debug, new)]
+pub struct SelectionSubtask {
+    condition: ValueExprLabel,
+}
+
+impl SelectionSubtask {
+    /// Keeps tuples whose condition is evaluated as TRUE (NULL is regarded as FALSE).
+    ///
+    /// # Failures
+    ///
+    /// `SpringError::Sql` when the condition cannot be evaluated as BOOLEAN.
+    pub fn run(&self, expr_resolver: &ExprResolver, tuples: Vec<Tuple>) -> Result<Vec<Tuple>> {
+        tuples
+            .into_iter()
+            .filter_map(|tuple| {
+                match expr_resolver
+                    .eval_value_expr(self.condition, &tuple)
+                    .and_then(|value| value.to_bool())
+                {
+                    Ok(true) => Some(Ok(tuple)),
+                    Ok(false) => None,
+                    Err(e) => Some(Err(e)),
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,62 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::sync::{Mutex, MutexGuard};
+
+use crate::{
+    expr_resolver::ExprResolver,
+    pipeline::SilenceAlertParameter,
+    stream_engine::{
+        autonomous_executor::{
+            performance_metrics::WindowInFlowByWindowTask,
+            task::{tuple::Tuple, window::SilenceAlert},
+        },
+        time::{SpringEventDuration, SystemTimestamp},
+    },
+};
+
+#[derive(Debug)]
+pub struct SilenceAlertSubtask(Mutex<SilenceAlert>);
+
+impl SilenceAlertSubtask {
+    pub fn new(
+        silence_alert_param: SilenceAlertParameter,
+        source_idle_timeout: Option<SpringEventDuration>,
+    ) -> Self {
+        Self(Mutex::new(SilenceAlert::new(
+            silence_alert_param,
+            source_idle_timeout,
+        )))
+    }
+
+    /// Consumes tuples and emits the last tuples of silent partitions.
+    pub fn run(
+        &self,
+        expr_resolver: &ExprResolver,
+        tuples: Vec<Tuple>,
+    ) -> (Vec<Tuple>, WindowInFlowByWindowTask) {
+        let mut silence_alert = self.get_silence_alert_mut();
+
+        tuples.into_iter().fold(
+            (Vec::new(), WindowInFlowByWindowTask::zero()),
+            |(mut out_acc, window_in_flow_acc), tuple| {
+                let (out, window_in_flow) = silence_alert
+                    .dispatch(expr_resolver, tuple, SystemTimestamp::now())
+                    .expect("dispatch failed");
+                out_acc.extend(out);
+                (out_acc, window_in_flow_acc + window_in_flow)
+            },
+        )
+    }
+
+    /// Called when no tuple comes from input.
+    pub fn run_idle(&self) -> (Vec<Tuple>, WindowInFlowByWindowTask) {
+        self.get_silence_alert_mut()
+            .dispatch_idleness(SystemTimestamp::now())
+    }
+
+    pub fn get_silence_alert_mut(&self) -> MutexGuard<'_, SilenceAlert> {
+        self.0
+            .lock()
+            .expect("another thread accessing to silence alert gets poisoned")
+    }
+}
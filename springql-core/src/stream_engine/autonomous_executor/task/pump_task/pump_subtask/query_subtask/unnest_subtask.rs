@@ -0,0 +1,45 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use anyhow::anyhow;
+
+use crate::{
+    api::error::{Result, SpringError},
+    expr_resolver::ExprResolver,
+    pipeline::{Field, UnnestParameter},
+    stream_engine::{autonomous_executor::task::tuple::Tuple, NnSqlValue, SqlValue},
+};
+
+#[derive(Debug, new)]
+pub struct UnnestSubtask {
+    unnest_param: UnnestParameter,
+}
+
+impl UnnestSubtask {
+    /// Emits a tuple per element of the array evaluated from each input tuple.
+    ///
+    /// # Failures
+    ///
+    /// `SpringError::Sql` when the array expression is evaluated to a non-ARRAY value.
+    pub fn run(&self, expr_resolver: &ExprResolver, tuples: Vec<Tuple>) -> Result<Vec<Tuple>> {
+        let mut out = Vec::new();
+        for tuple in tuples {
+            match expr_resolver.eval_value_expr(self.unnest_param.array, &tuple)? {
+                SqlValue::Null => {}
+                SqlValue::NotNull(NnSqlValue::Array(elements)) => {
+                    out.extend(elements.into_iter().map(|element| {
+                        let mut tuple = tuple.clone();
+                        tuple.push_field(Field::new(self.unnest_param.element.clone(), element));
+                        tuple
+                    }))
+                }
+                SqlValue::NotNull(v) => {
+                    return Err(SpringError::Sql(anyhow!(
+                        "UNNEST requires an ARRAY but got {}",
+                        v
+                    )))
+                }
+            }
+        }
+        Ok(out)
+    }
+}
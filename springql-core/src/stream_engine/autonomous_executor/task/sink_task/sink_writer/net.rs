@@ -11,16 +11,15 @@ use anyhow::Context;
 use crate::{
     api::error::{foreign_info::ForeignInfo, Result, SpringError},
     api::SpringSinkWriterConfig,
-    pipeline::{NetClientOptions, Options},
-    stream_engine::autonomous_executor::{
-        row::JsonObject, task::sink_task::sink_writer::SinkWriter, SchemalessRow,
-    },
+    pipeline::{DecimalFormat, NetClientOptions, Options},
+    stream_engine::autonomous_executor::{task::sink_task::sink_writer::SinkWriter, SchemalessRow},
 };
 
 #[derive(Debug)]
 pub struct NetSinkWriter {
     foreign_addr: SocketAddr,
     tcp_stream_writer: BufWriter<TcpStream>, // TODO UDP
+    decimal_format: DecimalFormat,
 }
 
 impl SinkWriter for NetSinkWriter {
@@ -54,11 +53,12 @@ impl SinkWriter for NetSinkWriter {
         Ok(Self {
             tcp_stream_writer,
             foreign_addr: sock_addr,
+            decimal_format: options.decimal_format,
         })
     }
 
     fn send_row(&mut self, row: SchemalessRow) -> Result<()> {
-        let mut json_s = JsonObject::from(row).to_string();
+        let mut json_s = row.into_json(&self.decimal_format).to_string();
         json_s.push('\n');
 
         log::debug!("[NetSinkWriter] Writing message to remote: {}", json_s);
@@ -12,14 +12,16 @@ use std::sync::Arc;
 use crate::{
     api::error::Result,
     mem_size::MemSize,
-    pipeline::{SourceReaderModel, SourceReaderName, StreamName},
+    pipeline::{
+        ColumnPathOptions, SourceReaderModel, SourceReaderName, StreamName, TimestampFormatOptions,
+    },
     stream_engine::{
         autonomous_executor::{
             performance_metrics::{
                 MetricsUpdateByTaskExecution, OutQueueMetricsUpdateByTask, TaskMetricsUpdateByTask,
             },
             repositories::Repositories,
-            row::{SchemalessRow, StreamRow},
+            row::StreamRow,
             task::{task_context::TaskContext, ProcessedRows, TaskRunResult},
             task_graph::{QueueId, RowQueueId, TaskId, WindowQueueId},
             AutonomousExecutor,
@@ -33,15 +35,23 @@ pub struct SourceTask {
     id: TaskId,
     source_reader_name: SourceReaderName,
     source_stream_name: StreamName,
+    column_paths: ColumnPathOptions,
+    timestamp_formats: TimestampFormatOptions,
 }
 
 impl SourceTask {
     pub fn new(source_reader: &SourceReaderModel) -> Self {
         let id = TaskId::from_source(source_reader);
+        let column_paths = ColumnPathOptions::try_from(source_reader.options())
+            .expect("must be validated when the source reader is added");
+        let timestamp_formats = TimestampFormatOptions::try_from(source_reader.options())
+            .expect("must be validated when the source reader is added");
         Self {
             id,
             source_reader_name: source_reader.name().clone(),
             source_stream_name: source_reader.dest_source_stream().clone(),
+            column_paths,
+            timestamp_formats,
         }
     }
 
@@ -133,7 +143,8 @@ impl SourceTask {
         source_reader
             .next_row()
             .and_then(|source_row| {
-                let schemaless_row = SchemalessRow::try_from(source_row)?;
+                let schemaless_row =
+                    source_row.into_schemaless_row(&self.column_paths, &self.timestamp_formats)?;
                 StreamRow::from_schemaless_row(schemaless_row, source_stream)
             })
             .map_or_else(
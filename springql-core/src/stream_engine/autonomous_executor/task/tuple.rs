@@ -3,7 +3,7 @@
 use crate::{
     api::error::{Result, SpringError},
     mem_size::MemSize,
-    pipeline::{ColumnReference, Field},
+    pipeline::{ColumnReference, Field, StreamName},
     stream_engine::{
         autonomous_executor::row::{RowTime, StreamRow},
         NnSqlValue, SqlValue,
@@ -63,7 +63,9 @@ impl Tuple {
     ///   - processing time is referenced while event time is defined to the stream.
     pub fn get_value(&self, column_reference: &ColumnReference) -> Result<SqlValue> {
         match column_reference {
-            ColumnReference::Column { .. } => self.get_column_value(column_reference),
+            ColumnReference::Column { .. } | ColumnReference::Derived { .. } => {
+                self.get_column_value(column_reference)
+            }
             ColumnReference::PTime { .. } => self.get_processing_time(),
         }
     }
@@ -94,6 +96,38 @@ impl Tuple {
         }
     }
 
+    /// Appends a field derived from expressions.
+    pub fn push_field(&mut self, field: Field) {
+        self.fields.push(field)
+    }
+
+    /// Appends column fields of `other` as fields of `correlation` (e.g. a pattern variable of MATCH_RECOGNIZE).
+    pub fn correlate(&mut self, other: &Tuple, correlation: &StreamName) {
+        self.correlate_with(other, correlation, |field| field.sql_value().clone())
+    }
+
+    /// Same as `correlate()` but all the appended values are NULL.
+    pub fn correlate_null(&mut self, other: &Tuple, correlation: &StreamName) {
+        self.correlate_with(other, correlation, |_| SqlValue::Null)
+    }
+
+    fn correlate_with<F>(&mut self, other: &Tuple, correlation: &StreamName, value: F)
+    where
+        F: Fn(&Field) -> SqlValue,
+    {
+        let fields = other.fields.iter().filter_map(|field| match field.name() {
+            ColumnReference::Column { column_name, .. } => {
+                let colref = ColumnReference::Column {
+                    stream_name: correlation.clone(),
+                    column_name: column_name.clone(),
+                };
+                Some(Field::new(colref, value(field)))
+            }
+            ColumnReference::PTime { .. } | ColumnReference::Derived { .. } => None,
+        });
+        self.fields.extend(fields);
+    }
+
     /// Left rowtime is used for joined tuple.
     pub fn join(self, right: Self) -> Tuple {
         let rowtime = self.rowtime;
@@ -1,25 +1,45 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
 mod aggregate;
+mod changes_only;
+mod continuous_aggregate;
+mod deduplicate;
+mod distinct;
+mod interval_join;
 mod join_window;
+mod match_recognize;
+mod navigation;
 mod panes;
+mod resample;
+mod silence_alert;
 
 mod watermark;
 
 pub use aggregate::{AggrWindow, AggregatedAndGroupingValues};
+pub use changes_only::ChangesOnly;
+pub use continuous_aggregate::ContinuousAggregate;
+pub use deduplicate::Deduplicate;
+pub use distinct::DistinctWindow;
+pub use interval_join::IntervalJoin;
 pub use join_window::JoinWindow;
+pub use match_recognize::MatchRecognize;
+pub use navigation::Navigation;
 pub use panes::{AggrPane, GroupByValues, JoinDir, JoinPane, Pane, Panes};
+pub use silence_alert::SilenceAlert;
 
 use crate::{
     api::SpringError,
     expr_resolver::ExprResolver,
+    pipeline::WindowTime,
     stream_engine::{
         autonomous_executor::{
             performance_metrics::WindowInFlowByWindowTask, task::window::watermark::Watermark,
         },
-        Tuple,
+        time::{SpringTimestamp, SystemTimestamp},
+        RowTime, Tuple,
     },
 };
+use anyhow::anyhow;
 
 type Success<T> = (Vec<T>, WindowInFlowByWindowTask);
 
@@ -34,21 +54,55 @@ pub trait Window {
 
     fn purge(&mut self);
 
+    /// Updates the watermark of the input a tuple comes from.
+    ///
+    /// `now` is a wall-clock timestamp when the tuple is dispatched.
+    fn update_watermark(
+        &mut self,
+        rowtime: SpringTimestamp,
+        _arg: &<<Self as Window>::Pane as Pane>::DispatchArg,
+        now: SpringTimestamp,
+    ) {
+        self.watermark_mut().update(rowtime, now);
+    }
+
+    /// Advances watermark(s) of idle input(s) by processing time.
+    fn advance_watermark_by_idleness(&mut self, now: SpringTimestamp) {
+        self.watermark_mut().advance_by_idleness(now);
+    }
+
     /// A task dispatches a tuple from waiting queue.
+    ///
+    /// Panes are keyed by the tuple's ROWTIME, or by its arrival time with `ON PTIME`.
+    ///
+    /// # Failures
+    ///
+    /// - `SpringError::Sql` when:
+    ///   - `ON PTIME` is used for a tuple without processing time (from a stream with a ROWTIME column).
     fn dispatch(
         &mut self,
         expr_resolver: &ExprResolver,
         tuple: Tuple,
         arg: <<Self as Window>::Pane as Pane>::DispatchArg,
     ) -> Result<Success<<Self::Pane as Pane>::CloseOut>, SpringError> {
-        let rowtime = tuple.rowtime().as_timestamp();
+        let now = SystemTimestamp::now();
+        let rowtime = match self.panes().window_param().window_time() {
+            WindowTime::Rowtime => tuple.rowtime().as_timestamp(),
+            WindowTime::PTime => match tuple.rowtime() {
+                RowTime::ProcessingTime(ptime) => ptime,
+                RowTime::EventTime(_) => {
+                    return Err(SpringError::Sql(anyhow!(
+                        "ON PTIME window requires processing time, which is not available for a stream with ROWTIME column"
+                    )))
+                }
+            },
+        };
 
         if rowtime < self.watermark().as_timestamp() {
             // too late tuple does not have any chance to be dispatched nor to close a pane.
             Ok((Vec::new(), WindowInFlowByWindowTask::zero()))
         } else {
-            self.watermark_mut().update(rowtime);
-            let wm = *self.watermark();
+            self.update_watermark(rowtime, &arg, now);
 
             let window_in_flow_dispatch = self
                 .panes_mut()
@@ -58,20 +112,50 @@ pub trait Window {
                     acc + window_in_flow
                 });
 
-            let (out, window_in_flow_close) = self
-                .panes_mut()
-                .remove_panes_to_close(&wm)
-                .into_iter()
-                .fold(
-                    (Vec::new(), WindowInFlowByWindowTask::zero()),
-                    |(mut out_acc, window_in_flow_acc), pane| {
-                        let (mut out_seq, window_in_flow) = pane.close(expr_resolver);
-                        out_acc.append(&mut out_seq);
-                        (out_acc, window_in_flow_acc + window_in_flow)
-                    },
-                );
+            let (out, window_in_flow_close) = self.close_panes(expr_resolver);
 
             Ok((out, window_in_flow_dispatch + window_in_flow_close))
         }
     }
+
+    /// A task calls this when no tuple comes from its input(s).
+    ///
+    /// Closes panes if idle inputs advance the watermark enough, or if wall clock passes panes keyed by processing time.
+    fn dispatch_idleness(
+        &mut self,
+        expr_resolver: &ExprResolver,
+        now: SpringTimestamp,
+    ) -> Success<<Self::Pane as Pane>::CloseOut> {
+        match self.panes().window_param().window_time() {
+            WindowTime::Rowtime => self.advance_watermark_by_idleness(now),
+            WindowTime::PTime => self.watermark_mut().update(now, now),
+        }
+        self.close_panes(expr_resolver)
+    }
+
+    fn close_panes(
+        &mut self,
+        expr_resolver: &ExprResolver,
+    ) -> Success<<Self::Pane as Pane>::CloseOut> {
+        let wm = *self.watermark();
+
+        let panes = self.panes_mut().remove_panes_to_close(&wm);
+        self.close_removed_panes(expr_resolver, panes)
+    }
+
+    /// Closes panes already removed from `panes()`, sorted by `Pane::open_at`.
+    fn close_removed_panes(
+        &mut self,
+        expr_resolver: &ExprResolver,
+        panes: Vec<Self::Pane>,
+    ) -> Success<<Self::Pane as Pane>::CloseOut> {
+        panes.into_iter().fold(
+            (Vec::new(), WindowInFlowByWindowTask::zero()),
+            |(mut out_acc, window_in_flow_acc), pane| {
+                let (mut out_seq, window_in_flow) = pane.close(expr_resolver);
+                out_acc.append(&mut out_seq);
+                (out_acc, window_in_flow_acc + window_in_flow)
+            },
+        )
+    }
 }
@@ -6,14 +6,20 @@ use anyhow::anyhow;
 
 use crate::{
     api::error::{Result, SpringError},
+    expr_resolver::ExprResolver,
     expr_resolver::{AggrExprLabel, ValueExprLabel},
     pipeline::{WindowOperationParameter, WindowParameter},
     stream_engine::{
-        autonomous_executor::task::window::{
-            panes::{AggrPane, Panes},
-            watermark::Watermark,
-            Window,
+        autonomous_executor::{
+            performance_metrics::WindowInFlowByWindowTask,
+            task::window::{
+                panes::{AggrPane, Pane, Panes},
+                resample::Resampler,
+                watermark::Watermark,
+                Window,
+            },
         },
+        time::{SpringDuration, SpringEventDuration},
         SqlValue,
     },
 };
@@ -63,6 +69,9 @@ impl AggregatedAndGroupingValues {
 pub struct AggrWindow {
     watermark: Watermark,
     panes: Panes<AggrPane>,
+
+    /// Only for RESAMPLE window.
+    resampler: Option<Resampler>,
 }
 
 impl Window for AggrWindow {
@@ -85,16 +94,66 @@ impl Window for AggrWindow {
     }
 
     fn purge(&mut self) {
-        self.panes.purge()
+        self.panes.purge();
+        if let Some(resampler) = &mut self.resampler {
+            resampler.purge();
+        }
+    }
+
+    fn close_removed_panes(
+        &mut self,
+        expr_resolver: &ExprResolver,
+        panes: Vec<Self::Pane>,
+    ) -> (Vec<AggregatedAndGroupingValues>, WindowInFlowByWindowTask) {
+        match &mut self.resampler {
+            Some(resampler) => resampler.resample(panes, self.watermark.as_timestamp()),
+            None => panes.into_iter().fold(
+                (Vec::new(), WindowInFlowByWindowTask::zero()),
+                |(mut out_acc, window_in_flow_acc), pane| {
+                    let (mut out_seq, window_in_flow) = pane.close(expr_resolver);
+                    out_acc.append(&mut out_seq);
+                    (out_acc, window_in_flow_acc + window_in_flow)
+                },
+            ),
+        }
     }
 }
 
 impl AggrWindow {
-    pub fn new(window_param: WindowParameter, op_param: WindowOperationParameter) -> Self {
-        let watermark = Watermark::new(window_param.allowed_delay());
+    /// `source_idle_timeout` is used if the window clause does not have an idle timeout.
+    ///
+    /// `resample_time` is the label of `RESAMPLE_TIME` in select list, used only for RESAMPLE window.
+    pub fn new(
+        window_param: WindowParameter,
+        op_param: WindowOperationParameter,
+        resample_time: Option<ValueExprLabel>,
+        source_idle_timeout: Option<SpringEventDuration>,
+    ) -> Self {
+        let watermark = Watermark::new(
+            window_param.allowed_delay(),
+            window_param.idle_timeout().or(source_idle_timeout),
+        );
+        let resampler = window_param.resample_fill().map(|fill| {
+            let aggregate_parameter = match &op_param {
+                WindowOperationParameter::Aggregate(aggregate_parameter) => {
+                    aggregate_parameter.clone()
+                }
+                _ => panic!("op_param {:?} is not GroupAggregateParameter", op_param),
+            };
+            Resampler::new(
+                window_param.length().to_duration(),
+                fill,
+                window_param
+                    .resample_within()
+                    .map(|within| within.to_duration()),
+                aggregate_parameter,
+                resample_time,
+            )
+        });
         Self {
             watermark,
             panes: Panes::new(window_param, op_param),
+            resampler,
         }
     }
 }
@@ -112,12 +171,13 @@ mod tests {
         expression::{AggrExpr, ValueExpr},
         pipeline::{
             AggrAlias, AggregateFunctionParameter, AggregateParameter, ColumnName, GroupByLabels,
-            StreamName,
+            StreamName, WindowTime,
         },
         sql_processor::SelectFieldSyntax,
         stream_engine::{
             autonomous_executor::task::tuple::Tuple,
             time::{SpringDuration, SpringEventDuration, SpringTimestamp},
+            RowTime,
         },
     };
 
@@ -202,12 +262,17 @@ mod tests {
                         length: SpringEventDuration::from_secs(10),
                         period: SpringEventDuration::from_secs(5),
                         allowed_delay: SpringEventDuration::from_secs(1),
+                        idle_timeout: None,
+                        window_time: WindowTime::Rowtime,
                     },
                     WindowOperationParameter::Aggregate(AggregateParameter {
                         aggr_func: AggregateFunctionParameter::Avg,
                         aggr_expr: aggr_label,
                         group_by: GroupByLabels::new(vec![group_by_label]),
+                        top_n: None,
                     }),
+                    None,
+                    None,
                 );
 
                 // [:55, :05): ("GOOGL", 100)
@@ -449,12 +514,17 @@ mod tests {
                     WindowParameter::TimedFixedWindow {
                         length: SpringEventDuration::from_secs(10),
                         allowed_delay: SpringEventDuration::from_secs(1),
+                        idle_timeout: None,
+                        window_time: WindowTime::Rowtime,
                     },
                     WindowOperationParameter::Aggregate(AggregateParameter {
                         aggr_func: AggregateFunctionParameter::Avg,
                         aggr_expr: aggr_label,
                         group_by: GroupByLabels::new(vec![group_by_label]),
+                        top_n: None,
                     }),
+                    None,
+                    None,
                 );
 
                 // [:00, :10): ("GOOGL", 100)
@@ -621,4 +691,120 @@ mod tests {
             _ => unreachable!(),
         }
     }
+
+    #[test]
+    fn test_ptime_window_keyed_by_arrival_time() {
+        setup_test_logger();
+
+        // SELECT ticker, AVG(amount) AS avg_amount
+        //   FROM trade
+        //   FIXED WINDOW duration_secs(10) ON PTIME
+        //   GROUP BY ticker;
+
+        let ticker_expr = ValueExpr::factory_colref(
+            StreamName::fx_trade().as_ref(),
+            ColumnName::fx_ticker().as_ref(),
+        );
+        let avg_amount_expr = AggrExpr {
+            func: AggregateFunctionParameter::Avg,
+            aggregated: ValueExpr::factory_colref(
+                StreamName::fx_trade().as_ref(),
+                ColumnName::fx_amount().as_ref(),
+            ),
+        };
+
+        let select_list = vec![
+            SelectFieldSyntax::ValueExpr {
+                value_expr: ticker_expr,
+                alias: None,
+            },
+            SelectFieldSyntax::AggrExpr {
+                aggr_expr: avg_amount_expr,
+                alias: Some(AggrAlias::new("avg_amount".to_string())),
+            },
+        ];
+
+        // tuple arrived at `ptime`
+        let ptime_trade = |ptime: &str, ticker: &str, amount: i16| {
+            let ptime = SpringTimestamp::from_str(ptime).unwrap();
+            Tuple::new(RowTime::ProcessingTime(ptime), vec![])
+                .join(Tuple::factory_trade(ptime, ticker, amount))
+        };
+
+        let (expr_resolver, labels) = ExprResolver::new(select_list);
+        match &labels[..] {
+            &[ExprLabel::Value(group_by_label), ExprLabel::Aggr(aggr_label)] => {
+                let mut window = AggrWindow::new(
+                    WindowParameter::TimedFixedWindow {
+                        length: SpringEventDuration::from_secs(10),
+                        allowed_delay: SpringEventDuration::from_secs(0),
+                        idle_timeout: None,
+                        window_time: WindowTime::PTime,
+                    },
+                    WindowOperationParameter::Aggregate(AggregateParameter {
+                        aggr_func: AggregateFunctionParameter::Avg,
+                        aggr_expr: aggr_label,
+                        group_by: GroupByLabels::new(vec![group_by_label]),
+                        top_n: None,
+                    }),
+                    None,
+                    None,
+                );
+
+                // [:00, :10): ("GOOGL", 100), ("ORCL", 100)
+                for (ptime, ticker) in [
+                    ("2020-01-01 00:00:00.000000000", "GOOGL"),
+                    ("2020-01-01 00:00:09.999999999", "ORCL"),
+                ] {
+                    let (out, _) = window
+                        .dispatch(&expr_resolver, ptime_trade(ptime, ticker, 100), ())
+                        .unwrap();
+                    assert!(out.is_empty());
+                }
+
+                // [:00, :10): -> "GOOGL" AVG = 100; "ORCL" AVG = 100
+                //
+                // [:10, :20): ("ORCL", 400)
+                let (mut out, _) = window
+                    .dispatch(
+                        &expr_resolver,
+                        ptime_trade("2020-01-01 00:00:10.000000000", "ORCL", 400),
+                        (),
+                    )
+                    .unwrap();
+                assert_eq!(out.len(), 2);
+                out.sort_by_key(|aggregated_and_grouping_values| {
+                    sort_key(&group_by_label, aggregated_and_grouping_values)
+                });
+                t_expect(
+                    aggr_label,
+                    group_by_label,
+                    out.get(0).cloned().unwrap(),
+                    "GOOGL",
+                    100,
+                );
+                t_expect(
+                    aggr_label,
+                    group_by_label,
+                    out.get(1).cloned().unwrap(),
+                    "ORCL",
+                    100,
+                );
+
+                // no processing time for a stream with ROWTIME column
+                assert!(window
+                    .dispatch(
+                        &expr_resolver,
+                        Tuple::factory_trade(
+                            SpringTimestamp::from_str("2020-01-01 00:00:11.000000000").unwrap(),
+                            "ORCL",
+                            100,
+                        ),
+                        (),
+                    )
+                    .is_err());
+            }
+            _ => unreachable!(),
+        }
+    }
 }
@@ -0,0 +1,145 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::collections::{hash_map::Entry, HashMap};
+
+use crate::{
+    api::error::Result,
+    expr_resolver::ExprResolver,
+    mem_size::MemSize,
+    pipeline::ChangesOnlyParameter,
+    stream_engine::{
+        autonomous_executor::{
+            performance_metrics::WindowInFlowByWindowTask, task::window::panes::DistinctKey,
+        },
+        Tuple,
+    },
+};
+
+/// Emit-on-change operator for `CHANGES ONLY ON (columns) PARTITION BY keys`.
+///
+/// It remembers the values of `columns` in the last tuple of each partition and drops a tuple whose values are the same as them.
+/// The first tuple of a partition is always emitted.
+///
+/// Remembered values never expire until purged.
+#[derive(Debug)]
+pub struct ChangesOnly {
+    changes_only_param: ChangesOnlyParameter,
+
+    /// partition -> values of columns in the last tuple
+    last_values: HashMap<DistinctKey, DistinctKey>,
+}
+
+impl ChangesOnly {
+    pub fn new(changes_only_param: ChangesOnlyParameter) -> Self {
+        Self {
+            changes_only_param,
+            last_values: HashMap::new(),
+        }
+    }
+
+    /// # Returns
+    ///
+    /// The tuple if its values differ from the last tuple in the partition.
+    ///
+    /// # Failures
+    ///
+    /// `SpringError::Sql` when columns or partition keys cannot be evaluated.
+    pub fn dispatch(
+        &mut self,
+        expr_resolver: &ExprResolver,
+        tuple: Tuple,
+    ) -> Result<(Option<Tuple>, WindowInFlowByWindowTask)> {
+        let partition =
+            DistinctKey::from_labels(&self.changes_only_param.partition_by, expr_resolver, &tuple)?;
+        let values =
+            DistinctKey::from_labels(&self.changes_only_param.columns, expr_resolver, &tuple)?;
+
+        match self.last_values.entry(partition) {
+            Entry::Vacant(entry) => {
+                let states_bytes = entry.key().mem_size() + values.mem_size();
+                entry.insert(values);
+                Ok((
+                    Some(tuple),
+                    WindowInFlowByWindowTask::new(states_bytes as i64, 0),
+                ))
+            }
+            Entry::Occupied(mut entry) => {
+                if entry.get() == &values {
+                    Ok((None, WindowInFlowByWindowTask::zero()))
+                } else {
+                    let new_bytes = values.mem_size() as i64;
+                    let old = entry.insert(values);
+                    Ok((
+                        Some(tuple),
+                        WindowInFlowByWindowTask::new(new_bytes - old.mem_size() as i64, 0),
+                    ))
+                }
+            }
+        }
+    }
+
+    pub fn purge(&mut self) {
+        self.last_values.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{
+        expression::ValueExpr, sql_processor::SelectFieldSyntax,
+        stream_engine::time::SpringTimestamp,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_changes_only() {
+        // SELECT STREAM trade.ticker, trade.amount
+        //   FROM trade
+        //   CHANGES ONLY ON (trade.amount) PARTITION BY trade.ticker;
+        let select_list = vec![SelectFieldSyntax::ValueExpr {
+            value_expr: ValueExpr::factory_colref("trade", "ticker"),
+            alias: None,
+        }];
+        let (mut expr_resolver, _) = ExprResolver::new(select_list);
+        let amount =
+            expr_resolver.register_value_expr(ValueExpr::factory_colref("trade", "amount"));
+        let ticker =
+            expr_resolver.register_value_expr(ValueExpr::factory_colref("trade", "ticker"));
+
+        let mut changes_only =
+            ChangesOnly::new(ChangesOnlyParameter::new(vec![amount], vec![ticker]));
+
+        let mut states_bytes = 0;
+
+        // returns true if emitted
+        let mut dispatch = |ticker: &str, amount: i16| {
+            let tuple = Tuple::factory_trade(
+                SpringTimestamp::from_str("2020-01-01 00:00:00.000000000").unwrap(),
+                ticker,
+                amount,
+            );
+            let (out, in_flow) = changes_only.dispatch(&expr_resolver, tuple).unwrap();
+
+            states_bytes += in_flow.window_gain_bytes_states;
+            let actual_bytes: usize = changes_only
+                .last_values
+                .iter()
+                .map(|(partition, values)| partition.mem_size() + values.mem_size())
+                .sum();
+            assert_eq!(states_bytes, actual_bytes as i64);
+
+            out.is_some()
+        };
+
+        assert!(dispatch("ORCL", 100));
+        assert!(!dispatch("ORCL", 100));
+        assert!(dispatch("GOOGL", 100));
+        assert!(dispatch("ORCL", 200));
+        assert!(!dispatch("ORCL", 200));
+        assert!(dispatch("ORCL", 100));
+        assert!(!dispatch("GOOGL", 100));
+    }
+}
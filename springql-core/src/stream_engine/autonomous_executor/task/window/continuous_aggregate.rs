@@ -0,0 +1,268 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::collections::{hash_map::Entry, HashMap};
+
+use ordered_float::OrderedFloat;
+
+use crate::{
+    api::error::Result,
+    expr_resolver::{ExprResolver, ValueExprLabel},
+    mem_size::MemSize,
+    pipeline::AggregateParameter,
+    stream_engine::{
+        autonomous_executor::{
+            performance_metrics::WindowInFlowByWindowTask,
+            task::window::{
+                aggregate::AggregatedAndGroupingValues,
+                panes::{AvgState, GroupByValues},
+            },
+        },
+        NnSqlValue, SqlValue, Tuple,
+    },
+};
+
+/// Aggregation without window (`GROUP BY` without FIXED / SLIDING WINDOW).
+///
+/// It keeps running aggregates for each group forever (until purged) and emits the current aggregate of a group
+/// every time a tuple changes it.
+///
+/// `CHANGELOG_OP` in the select list is 'INSERT' for the first output of a group and 'UPDATE' for the following ones.
+#[derive(Debug)]
+pub struct ContinuousAggregate {
+    aggregate_parameter: AggregateParameter,
+    changelog_op: Option<ValueExprLabel>,
+
+    states: HashMap<GroupByValues, AvgState>,
+}
+
+impl ContinuousAggregate {
+    pub fn new(
+        aggregate_parameter: AggregateParameter,
+        changelog_op: Option<ValueExprLabel>,
+    ) -> Self {
+        Self {
+            aggregate_parameter,
+            changelog_op,
+            states: HashMap::new(),
+        }
+    }
+
+    /// # Returns
+    ///
+    /// Aggregated and grouping values of the group of `tuple` if its aggregate has changed.
+    /// A tuple with NULL aggregated value does not change aggregates.
+    ///
+    /// # Failures
+    ///
+    /// `SpringError::Sql` when GROUP BY elements or the aggregated expression cannot be evaluated.
+    pub fn dispatch(
+        &mut self,
+        expr_resolver: &ExprResolver,
+        tuple: &Tuple,
+    ) -> Result<(
+        Option<AggregatedAndGroupingValues>,
+        WindowInFlowByWindowTask,
+    )> {
+        let group_by_values = GroupByValues::from_group_by_labels(
+            self.aggregate_parameter.group_by.clone(),
+            expr_resolver,
+            tuple,
+        )?;
+
+        let aggregated_value =
+            expr_resolver.eval_aggr_expr_inner(self.aggregate_parameter.aggr_expr, tuple)?;
+        let aggregated_value = if let SqlValue::NotNull(v) = aggregated_value {
+            v.unpack::<f32>()?
+        } else {
+            return Ok((None, WindowInFlowByWindowTask::zero()));
+        };
+
+        let (changed, window_in_flow) = match self.states.entry(group_by_values.clone()) {
+            Entry::Vacant(entry) => {
+                let states_bytes = state_size(entry.key());
+                let state = entry.insert(AvgState::default());
+                state.next(aggregated_value);
+                (
+                    Some((state.current(), "INSERT")),
+                    WindowInFlowByWindowTask::new(states_bytes as i64, 0),
+                )
+            }
+            Entry::Occupied(mut entry) => {
+                let state = entry.get_mut();
+                let prev = state.current();
+                state.next(aggregated_value);
+                let current = state.current();
+                (
+                    (current != prev).then_some((current, "UPDATE")),
+                    WindowInFlowByWindowTask::zero(),
+                )
+            }
+        };
+
+        let out = changed.map(|(current, changelog_op)| {
+            self.aggregated_and_grouping_values(group_by_values, current, changelog_op)
+        });
+        Ok((out, window_in_flow))
+    }
+
+    pub fn purge(&mut self) {
+        self.states.clear();
+    }
+
+    fn aggregated_and_grouping_values(
+        &self,
+        group_by_values: GroupByValues,
+        aggregated_value: f32,
+        changelog_op: &str,
+    ) -> AggregatedAndGroupingValues {
+        let aggr_value = SqlValue::NotNull(NnSqlValue::Float(OrderedFloat(aggregated_value)));
+
+        let mut group_bys = self
+            .aggregate_parameter
+            .group_by
+            .as_labels()
+            .iter()
+            .cloned()
+            .zip(group_by_values.into_sql_values())
+            .collect::<Vec<_>>();
+        if let Some(label) = self.changelog_op {
+            group_bys.push((
+                label,
+                SqlValue::NotNull(NnSqlValue::Text(changelog_op.to_string())),
+            ));
+        }
+
+        AggregatedAndGroupingValues::new(
+            vec![(self.aggregate_parameter.aggr_expr, aggr_value)],
+            group_bys,
+        )
+    }
+}
+
+fn state_size(group_by_values: &GroupByValues) -> usize {
+    group_by_values
+        .clone()
+        .into_sql_values()
+        .iter()
+        .map(|v| v.mem_size())
+        .sum::<usize>()
+        + std::mem::size_of::<AvgState>()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{
+        expr_resolver::ExprLabel,
+        expression::{AggrExpr, ValueExpr},
+        pipeline::{AggrAlias, AggregateFunctionParameter, ColumnName, GroupByLabels, StreamName},
+        sql_processor::SelectFieldSyntax,
+        stream_engine::time::SpringTimestamp,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_continuous_aggregate() {
+        // SELECT CHANGELOG_OP, ticker, AVG(amount) AS avg_amount
+        //   FROM trade
+        //   GROUP BY ticker;
+        let ticker_expr = ValueExpr::factory_colref(
+            StreamName::fx_trade().as_ref(),
+            ColumnName::fx_ticker().as_ref(),
+        );
+        let avg_amount_expr = AggrExpr {
+            func: AggregateFunctionParameter::Avg,
+            aggregated: ValueExpr::factory_colref(
+                StreamName::fx_trade().as_ref(),
+                ColumnName::fx_amount().as_ref(),
+            ),
+        };
+        let select_list = vec![
+            SelectFieldSyntax::ValueExpr {
+                value_expr: ValueExpr::ChangelogOp,
+                alias: None,
+            },
+            SelectFieldSyntax::ValueExpr {
+                value_expr: ticker_expr,
+                alias: None,
+            },
+            SelectFieldSyntax::AggrExpr {
+                aggr_expr: avg_amount_expr,
+                alias: Some(AggrAlias::new("avg_amount".to_string())),
+            },
+        ];
+
+        let (expr_resolver, labels) = ExprResolver::new(select_list);
+        let (changelog_op_label, ticker_label, aggr_label) = match &labels[..] {
+            &[ExprLabel::Value(changelog_op), ExprLabel::Value(ticker), ExprLabel::Aggr(aggr)] => {
+                (changelog_op, ticker, aggr)
+            }
+            _ => unreachable!(),
+        };
+
+        let mut aggr = ContinuousAggregate::new(
+            AggregateParameter {
+                aggr_func: AggregateFunctionParameter::Avg,
+                aggr_expr: aggr_label,
+                group_by: GroupByLabels::new(vec![ticker_label]),
+                top_n: None,
+            },
+            Some(changelog_op_label),
+        );
+
+        // returns (CHANGELOG_OP, ticker, avg_amount) if emitted
+        let dispatch = |aggr: &mut ContinuousAggregate, ticker: &str, amount: i16| {
+            let tuple = Tuple::factory_trade(
+                SpringTimestamp::from_str("2020-01-01 00:00:00.000000000").unwrap(),
+                ticker,
+                amount,
+            );
+            let (out, _) = aggr.dispatch(&expr_resolver, &tuple).unwrap();
+            out.map(|v| {
+                let text = |label| {
+                    v.get_group_by_value(&label)
+                        .unwrap()
+                        .clone()
+                        .unwrap()
+                        .unpack::<String>()
+                        .unwrap()
+                };
+                let avg_amount = v
+                    .get_aggregated_value(&aggr_label)
+                    .unwrap()
+                    .clone()
+                    .unwrap()
+                    .unpack::<f32>()
+                    .unwrap();
+                (
+                    text(changelog_op_label),
+                    text(ticker_label),
+                    avg_amount as i16,
+                )
+            })
+        };
+
+        assert_eq!(
+            dispatch(&mut aggr, "ORCL", 100),
+            Some(("INSERT".to_string(), "ORCL".to_string(), 100))
+        );
+        assert_eq!(
+            dispatch(&mut aggr, "GOOGL", 200),
+            Some(("INSERT".to_string(), "GOOGL".to_string(), 200))
+        );
+        assert_eq!(
+            dispatch(&mut aggr, "ORCL", 300),
+            Some(("UPDATE".to_string(), "ORCL".to_string(), 200))
+        );
+        // average does not change
+        assert_eq!(dispatch(&mut aggr, "ORCL", 200), None);
+
+        aggr.purge();
+        assert_eq!(
+            dispatch(&mut aggr, "ORCL", 400),
+            Some(("INSERT".to_string(), "ORCL".to_string(), 400))
+        );
+    }
+}
@@ -0,0 +1,178 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{
+    api::error::Result,
+    expr_resolver::ExprResolver,
+    mem_size::{chrono_naive_date_time_overhead_size, MemSize},
+    pipeline::DeduplicateParameter,
+    stream_engine::{
+        autonomous_executor::{
+            performance_metrics::WindowInFlowByWindowTask,
+            task::window::{panes::DistinctKey, watermark::Watermark},
+        },
+        time::{SpringDuration, SpringEventDuration, SpringTimestamp, SystemTimestamp},
+        Tuple,
+    },
+};
+
+/// Deduplication operator for `DEDUPLICATE ON (keys) WITHIN duration`.
+///
+/// It does not have panes. It remembers the keys of emitted tuples and drops a tuple whose keys are seen
+/// within the duration (in ROWTIME) from it.
+/// A remembered key expires when the watermark passes `ROWTIME + within`.
+///
+/// Allowed delay is zero. A tuple older than the watermark is not dropped as too late but just checked against remembered keys.
+#[derive(Debug)]
+pub struct Deduplicate {
+    dedup_param: DeduplicateParameter,
+
+    watermark: Watermark,
+
+    /// key -> ROWTIME of the last emitted tuple with the key
+    seen: HashMap<DistinctKey, SpringTimestamp>,
+    /// expire_at -> keys; to expire `seen` in order.
+    ///
+    /// A key re-emitted after `within` remains here with its old expire_at until the watermark passes.
+    expirations: BTreeMap<SpringTimestamp, Vec<DistinctKey>>,
+}
+
+impl Deduplicate {
+    pub fn new(
+        dedup_param: DeduplicateParameter,
+        source_idle_timeout: Option<SpringEventDuration>,
+    ) -> Self {
+        Self {
+            dedup_param,
+            watermark: Watermark::new(SpringEventDuration::from_secs(0), source_idle_timeout),
+            seen: HashMap::new(),
+            expirations: BTreeMap::new(),
+        }
+    }
+
+    /// # Returns
+    ///
+    /// The tuple if it is not a duplicate.
+    ///
+    /// # Failures
+    ///
+    /// `SpringError::Sql` when keys cannot be evaluated.
+    pub fn dispatch(
+        &mut self,
+        expr_resolver: &ExprResolver,
+        tuple: Tuple,
+    ) -> Result<(Option<Tuple>, WindowInFlowByWindowTask)> {
+        let rowtime = tuple.rowtime().as_timestamp();
+        self.watermark.update(rowtime, SystemTimestamp::now());
+
+        let key = DistinctKey::from_labels(&self.dedup_param.keys, expr_resolver, &tuple)?;
+        let within = self.dedup_param.within.to_duration();
+
+        let is_duplicate = self.seen.get(&key).map_or(false, |seen_at| {
+            *seen_at - within <= rowtime && rowtime <= *seen_at + within
+        });
+
+        let (out, window_in_flow_dispatch) = if is_duplicate {
+            (None, WindowInFlowByWindowTask::zero())
+        } else {
+            let window_in_flow = WindowInFlowByWindowTask::new(entry_size(&key) as i64, 0);
+            self.seen.insert(key.clone(), rowtime);
+            self.expirations
+                .entry(rowtime + within)
+                .or_default()
+                .push(key);
+            (Some(tuple), window_in_flow)
+        };
+
+        Ok((out, window_in_flow_dispatch + self.expire()))
+    }
+
+    /// A task calls this when no tuple comes from the input.
+    pub fn dispatch_idleness(&mut self, now: SpringTimestamp) -> WindowInFlowByWindowTask {
+        self.watermark.advance_by_idleness(now);
+        self.expire()
+    }
+
+    pub fn purge(&mut self) {
+        self.seen.clear();
+        self.expirations.clear();
+    }
+
+    fn expire(&mut self) -> WindowInFlowByWindowTask {
+        let wm = self.watermark.as_timestamp();
+        let within = self.dedup_param.within.to_duration();
+
+        let mut expired_bytes = 0;
+        while let Some(expire_at) = self.expirations.keys().next().copied() {
+            if expire_at >= wm {
+                break;
+            }
+            let keys = self.expirations.remove(&expire_at).expect("key exists");
+            for key in keys {
+                expired_bytes += entry_size(&key);
+                if self
+                    .seen
+                    .get(&key)
+                    .map_or(false, |seen_at| *seen_at + within == expire_at)
+                {
+                    self.seen.remove(&key);
+                }
+            }
+        }
+
+        WindowInFlowByWindowTask::new(-(expired_bytes as i64), 0)
+    }
+}
+
+/// A key is held both in `seen` and in `expirations`.
+fn entry_size(key: &DistinctKey) -> usize {
+    2 * key.mem_size() + chrono_naive_date_time_overhead_size()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{
+        expression::ValueExpr, pipeline::ColumnReference, sql_processor::SelectFieldSyntax,
+    };
+
+    use super::*;
+
+    fn ts(s: &str) -> SpringTimestamp {
+        SpringTimestamp::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_deduplicate() {
+        let select_list = vec![SelectFieldSyntax::ValueExpr {
+            value_expr: ValueExpr::factory_colref("trade", "ticker"),
+            alias: None,
+        }];
+        let (mut expr_resolver, _) = ExprResolver::new(select_list);
+        let key = expr_resolver.register_value_expr(ValueExpr::factory_colref("trade", "ticker"));
+
+        let mut dedup = Deduplicate::new(
+            DeduplicateParameter::new(vec![key], SpringEventDuration::from_secs(10)),
+            None,
+        );
+
+        // returns true if emitted
+        let mut dispatch = |rowtime: &str, ticker: &str| {
+            let tuple = Tuple::factory_trade(ts(rowtime), ticker, 100);
+            let (out, _) = dedup.dispatch(&expr_resolver, tuple).unwrap();
+            out.is_some()
+        };
+
+        assert!(dispatch("2020-01-01 00:00:00.000000000", "ORCL"));
+        assert!(dispatch("2020-01-01 00:00:05.000000000", "GOOGL"));
+        assert!(!dispatch("2020-01-01 00:00:09.000000000", "ORCL"));
+        assert!(!dispatch("2020-01-01 00:00:10.000000000", "ORCL"));
+
+        // ORCL expires
+        assert!(dispatch("2020-01-01 00:00:10.000000001", "ORCL"));
+        assert!(!dispatch("2020-01-01 00:00:15.000000000", "GOOGL"));
+        assert!(dispatch("2020-01-01 00:00:15.000000001", "GOOGL"));
+    }
+}
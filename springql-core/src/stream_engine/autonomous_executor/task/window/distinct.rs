@@ -0,0 +1,62 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::{
+    pipeline::{WindowOperationParameter, WindowParameter},
+    stream_engine::{
+        autonomous_executor::task::window::{
+            panes::{DistinctPane, Panes},
+            watermark::Watermark,
+            Window,
+        },
+        time::SpringEventDuration,
+    },
+};
+
+/// Window for `SELECT DISTINCT STREAM`. Each pane emits distinct tuples on close.
+#[derive(Debug)]
+pub struct DistinctWindow {
+    watermark: Watermark,
+    panes: Panes<DistinctPane>,
+}
+
+impl Window for DistinctWindow {
+    type Pane = DistinctPane;
+
+    fn watermark(&self) -> &Watermark {
+        &self.watermark
+    }
+
+    fn watermark_mut(&mut self) -> &mut Watermark {
+        &mut self.watermark
+    }
+
+    fn panes(&self) -> &Panes<Self::Pane> {
+        &self.panes
+    }
+
+    fn panes_mut(&mut self) -> &mut Panes<Self::Pane> {
+        &mut self.panes
+    }
+
+    fn purge(&mut self) {
+        self.panes.purge()
+    }
+}
+
+impl DistinctWindow {
+    /// `source_idle_timeout` is used if the window clause does not have an idle timeout.
+    pub fn new(
+        window_param: WindowParameter,
+        op_param: WindowOperationParameter,
+        source_idle_timeout: Option<SpringEventDuration>,
+    ) -> Self {
+        let watermark = Watermark::new(
+            window_param.allowed_delay(),
+            window_param.idle_timeout().or(source_idle_timeout),
+        );
+        Self {
+            watermark,
+            panes: Panes::new(window_param, op_param),
+        }
+    }
+}
@@ -0,0 +1,529 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use crate::{
+    api::error::Result,
+    expr_resolver::{ExprResolver, ValueExprLabel},
+    mem_size::MemSize,
+    pipeline::{IntervalJoinParameter, JoinParameter, JoinType},
+    stream_engine::{
+        autonomous_executor::{
+            performance_metrics::WindowInFlowByWindowTask,
+            task::window::{
+                panes::{JoinDir, JoinPane},
+                watermark::Watermark,
+            },
+        },
+        time::{SpringDuration, SpringEventDuration, SpringTimestamp, SystemTimestamp},
+        Tuple,
+    },
+};
+
+/// Interval join operator, used instead of [JoinWindow](super::JoinWindow) when JOIN does not take a window clause.
+///
+/// It does not have panes. Each input buffers its tuples and a tuple is evicted when the other input's watermark
+/// passes the tuple's interval, since no tuple to match can come anymore.
+/// On eviction, a left tuple which has not matched to any right tuple is joined with NULLs (LEFT OUTER JOIN).
+///
+/// Allowed delay is zero: a tuple older than the watermark of its input is ignored.
+#[derive(Debug)]
+pub struct IntervalJoin {
+    interval_param: IntervalJoinParameter,
+    join_param: JoinParameter,
+
+    left_watermark: Watermark,
+    right_watermark: Watermark,
+
+    /// (tuple, matched to any right tuple)
+    left_tuples: Vec<(Tuple, bool)>,
+    right_tuples: Vec<Tuple>,
+}
+
+impl IntervalJoin {
+    pub fn new(
+        interval_param: IntervalJoinParameter,
+        join_param: JoinParameter,
+        left_idle_timeout: Option<SpringEventDuration>,
+        right_idle_timeout: Option<SpringEventDuration>,
+    ) -> Self {
+        let allowed_delay = SpringEventDuration::from_secs(0);
+        Self {
+            interval_param,
+            join_param,
+            left_watermark: Watermark::new(allowed_delay, left_idle_timeout),
+            right_watermark: Watermark::new(allowed_delay, right_idle_timeout),
+            left_tuples: Vec::new(),
+            right_tuples: Vec::new(),
+        }
+    }
+
+    /// A task dispatches a tuple from waiting queue.
+    ///
+    /// # Returns
+    ///
+    /// Joined tuples: newly matched ones and NULL-padded ones for evicted left tuples.
+    ///
+    /// # Failures
+    ///
+    /// `SpringError::Sql` when ON condition cannot be evaluated.
+    pub fn dispatch(
+        &mut self,
+        expr_resolver: &ExprResolver,
+        tuple: Tuple,
+        dir: JoinDir,
+    ) -> Result<(Vec<Tuple>, WindowInFlowByWindowTask)> {
+        let now = SystemTimestamp::now();
+        let rowtime = tuple.rowtime().as_timestamp();
+
+        let watermark = match dir {
+            JoinDir::Left => &mut self.left_watermark,
+            JoinDir::Right => &mut self.right_watermark,
+        };
+        if rowtime < watermark.as_timestamp() {
+            // too late tuple does not have any chance to be joined.
+            return Ok((Vec::new(), WindowInFlowByWindowTask::zero()));
+        }
+        watermark.update(rowtime, now);
+
+        let window_in_flow_dispatch = WindowInFlowByWindowTask::new(0, tuple.mem_size() as i64);
+
+        let interval_param = self.interval_param;
+        let on_expr = self.join_param.on_expr;
+
+        let mut out = Vec::new();
+        match dir {
+            JoinDir::Left => {
+                for right_tuple in &self.right_tuples {
+                    if in_interval(
+                        interval_param,
+                        rowtime,
+                        right_tuple.rowtime().as_timestamp(),
+                    ) {
+                        let joined_tuple = tuple.clone().join(right_tuple.clone());
+                        if eval_on(expr_resolver, on_expr, &joined_tuple)? {
+                            out.push(joined_tuple);
+                        }
+                    }
+                }
+                let matched = !out.is_empty();
+                self.left_tuples.push((tuple, matched));
+            }
+            JoinDir::Right => {
+                for (left_tuple, matched) in &mut self.left_tuples {
+                    if in_interval(interval_param, left_tuple.rowtime().as_timestamp(), rowtime) {
+                        let joined_tuple = left_tuple.clone().join(tuple.clone());
+                        if eval_on(expr_resolver, on_expr, &joined_tuple)? {
+                            out.push(joined_tuple);
+                            *matched = true;
+                        }
+                    }
+                }
+                self.right_tuples.push(tuple);
+            }
+        }
+
+        let (evicted_out, window_in_flow_evict) = self.evict();
+        out.extend(evicted_out);
+
+        Ok((out, window_in_flow_dispatch + window_in_flow_evict))
+    }
+
+    /// A task calls this when no tuple comes from both inputs.
+    pub fn dispatch_idleness(
+        &mut self,
+        now: SpringTimestamp,
+    ) -> (Vec<Tuple>, WindowInFlowByWindowTask) {
+        self.left_watermark.advance_by_idleness(now);
+        self.right_watermark.advance_by_idleness(now);
+        self.evict()
+    }
+
+    pub fn purge(&mut self) {
+        self.left_tuples.clear();
+        self.right_tuples.clear();
+    }
+
+    /// Evicts tuples which no tuple from the other input can match anymore.
+    fn evict(&mut self) -> (Vec<Tuple>, WindowInFlowByWindowTask) {
+        let left_wm = self.left_watermark.as_timestamp();
+        let right_wm = self.right_watermark.as_timestamp();
+        let lower_bound = self.interval_param.lower_bound.to_duration();
+        let upper_bound = self.interval_param.upper_bound.to_duration();
+
+        let mut evicted_bytes = 0;
+
+        let (evicted_left, left_tuples): (Vec<_>, Vec<_>) = self
+            .left_tuples
+            .drain(..)
+            .partition(|(t, _)| t.rowtime().as_timestamp() + upper_bound < right_wm);
+        self.left_tuples = left_tuples;
+
+        let mut out = Vec::new();
+        for (left_tuple, matched) in evicted_left {
+            evicted_bytes += left_tuple.mem_size();
+            match self.join_param.join_type {
+                JoinType::LeftOuter => {
+                    if !matched {
+                        out.push(left_tuple.join(JoinPane::null_right_tuple(&self.join_param)));
+                    }
+                }
+            }
+        }
+
+        self.right_tuples.retain(|t| {
+            let evict = t.rowtime().as_timestamp() + lower_bound < left_wm;
+            if evict {
+                evicted_bytes += t.mem_size();
+            }
+            !evict
+        });
+
+        (
+            out,
+            WindowInFlowByWindowTask::new(0, -(evicted_bytes as i64)),
+        )
+    }
+}
+
+fn in_interval(
+    interval_param: IntervalJoinParameter,
+    left_rowtime: SpringTimestamp,
+    right_rowtime: SpringTimestamp,
+) -> bool {
+    left_rowtime - interval_param.lower_bound.to_duration() <= right_rowtime
+        && right_rowtime <= left_rowtime + interval_param.upper_bound.to_duration()
+}
+
+fn eval_on(
+    expr_resolver: &ExprResolver,
+    on_expr: ValueExprLabel,
+    joined_tuple: &Tuple,
+) -> Result<bool> {
+    expr_resolver
+        .eval_value_expr(on_expr, joined_tuple)?
+        .to_bool()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use springql_test_logger::setup_test_logger;
+
+    use crate::{
+        expression::ValueExpr,
+        pipeline::{ColumnName, ColumnReference, StreamName},
+        sql_processor::SelectFieldSyntax,
+        stream_engine::SqlValue,
+    };
+
+    use super::*;
+
+    fn ts(s: &str) -> SpringTimestamp {
+        SpringTimestamp::from_str(s).unwrap()
+    }
+
+    fn t_expect(tuple: &Tuple, expected_amount: i32, expected_temperature: Option<i32>) {
+        let amount = tuple
+            .get_value(&ColumnReference::fx_trade_amount())
+            .unwrap()
+            .unwrap();
+        assert_eq!(amount.unpack::<i32>().unwrap(), expected_amount);
+
+        let temperature = tuple
+            .get_value(&ColumnReference::fx_city_temperature_temperature())
+            .unwrap();
+        match temperature {
+            SqlValue::Null => assert!(expected_temperature.is_none()),
+            SqlValue::NotNull(t) => assert_eq!(
+                t.unpack::<i32>().unwrap(),
+                expected_temperature.expect("joined tuple has non-NULL temperature")
+            ),
+        }
+    }
+
+    /// ```sql
+    /// SELECT trade.amount, city_temperature.temperature
+    ///   FROM trade
+    ///   LEFT OUTER JOIN city_temperature
+    ///   ON trade.ticker = city_temperature.city
+    ///     AND city_temperature.timestamp BETWEEN trade.timestamp - DURATION_SECS(1) AND trade.timestamp + DURATION_SECS(2);
+    /// ```
+    fn fx_interval_join(idle_timeout: Option<SpringEventDuration>) -> (IntervalJoin, ExprResolver) {
+        let on_expr = ValueExpr::factory_eq(
+            ValueExpr::factory_colref(
+                StreamName::fx_trade().as_ref(),
+                ColumnName::fx_ticker().as_ref(),
+            ),
+            ValueExpr::factory_colref(
+                StreamName::fx_city_temperature().as_ref(),
+                ColumnName::fx_city().as_ref(),
+            ),
+        );
+
+        let (mut expr_resolver, _) = ExprResolver::new(Vec::<SelectFieldSyntax>::new());
+        let on_expr_label = expr_resolver.register_value_expr(on_expr);
+
+        let join = IntervalJoin::new(
+            IntervalJoinParameter::new(
+                SpringEventDuration::from_secs(1),
+                SpringEventDuration::from_secs(2),
+            ),
+            JoinParameter {
+                join_type: JoinType::LeftOuter,
+                left_colrefs: vec![
+                    ColumnReference::fx_trade_timestamp(),
+                    ColumnReference::fx_trade_ticker(),
+                    ColumnReference::fx_trade_amount(),
+                ],
+                right_colrefs: vec![
+                    ColumnReference::fx_city_temperature_timestamp(),
+                    ColumnReference::fx_city_temperature_city(),
+                    ColumnReference::fx_city_temperature_temperature(),
+                ],
+                on_expr: on_expr_label,
+                top_n: None,
+            },
+            idle_timeout,
+            idle_timeout,
+        );
+
+        (join, expr_resolver)
+    }
+
+    #[test]
+    fn test_interval_left_outer_join() {
+        setup_test_logger();
+
+        let (mut join, expr_resolver) = fx_interval_join(None);
+
+        // c(:09, X, 10)
+        let (out, window_in_flow) = join
+            .dispatch(
+                &expr_resolver,
+                Tuple::factory_city_temperature(ts("2020-01-01 00:00:09.000000000"), "X", 10),
+                JoinDir::Right,
+            )
+            .unwrap();
+        assert!(out.is_empty());
+        assert!(window_in_flow.window_gain_bytes_rows > 0);
+
+        // t(:10, X, 100) matches c(:09, X, 10)
+        let (out, _) = join
+            .dispatch(
+                &expr_resolver,
+                Tuple::factory_trade(ts("2020-01-01 00:00:10.000000000"), "X", 100),
+                JoinDir::Left,
+            )
+            .unwrap();
+        assert_eq!(out.len(), 1);
+        t_expect(&out[0], 100, Some(10));
+
+        // t(:11, Y, 200) does not match c(:09, X, 10) (different key)
+        let (out, _) = join
+            .dispatch(
+                &expr_resolver,
+                Tuple::factory_trade(ts("2020-01-01 00:00:11.000000000"), "Y", 200),
+                JoinDir::Left,
+            )
+            .unwrap();
+        assert!(out.is_empty());
+
+        // t(:20, X, 300) does not match c(:09, X, 10) (out of interval)
+        let (out, _) = join
+            .dispatch(
+                &expr_resolver,
+                Tuple::factory_trade(ts("2020-01-01 00:00:20.000000000"), "X", 300),
+                JoinDir::Left,
+            )
+            .unwrap();
+        assert!(out.is_empty());
+
+        // c(:12, X, 20) matches t(:10, X, 100) (upper bound inclusive)
+        let (out, _) = join
+            .dispatch(
+                &expr_resolver,
+                Tuple::factory_city_temperature(ts("2020-01-01 00:00:12.000000000"), "X", 20),
+                JoinDir::Right,
+            )
+            .unwrap();
+        assert_eq!(out.len(), 1);
+        t_expect(&out[0], 100, Some(20));
+
+        // c(:30, X, 30) evicts t(:10), t(:11) and t(:20) because no right tuple can match them anymore.
+        // t(:11, Y, 200) and t(:20, X, 300) are emitted with NULLs.
+        let (out, window_in_flow) = join
+            .dispatch(
+                &expr_resolver,
+                Tuple::factory_city_temperature(ts("2020-01-01 00:00:30.000000000"), "X", 30),
+                JoinDir::Right,
+            )
+            .unwrap();
+        assert_eq!(out.len(), 2);
+        t_expect(&out[0], 200, None);
+        t_expect(&out[1], 300, None);
+        assert!(window_in_flow.window_gain_bytes_rows < 0);
+
+        // too late tuple is ignored
+        let (out, window_in_flow) = join
+            .dispatch(
+                &expr_resolver,
+                Tuple::factory_city_temperature(ts("2020-01-01 00:00:29.000000000"), "X", 40),
+                JoinDir::Right,
+            )
+            .unwrap();
+        assert!(out.is_empty());
+        assert_eq!(window_in_flow, WindowInFlowByWindowTask::zero());
+    }
+    #[test]
+    fn test_interval_join_both_bounds() {
+        setup_test_logger();
+
+        let (mut join, expr_resolver) = fx_interval_join(None);
+
+        // t(:10, X, 100)
+        let (out, _) = join
+            .dispatch(
+                &expr_resolver,
+                Tuple::factory_trade(ts("2020-01-01 00:00:10.000000000"), "X", 100),
+                JoinDir::Left,
+            )
+            .unwrap();
+        assert!(out.is_empty());
+
+        // c(:08.999999999, X, 10) is just before the lower bound
+        let (out, _) = join
+            .dispatch(
+                &expr_resolver,
+                Tuple::factory_city_temperature(ts("2020-01-01 00:00:08.999999999"), "X", 10),
+                JoinDir::Right,
+            )
+            .unwrap();
+        assert!(out.is_empty());
+
+        // c(:09, X, 20) on the lower bound
+        let (out, _) = join
+            .dispatch(
+                &expr_resolver,
+                Tuple::factory_city_temperature(ts("2020-01-01 00:00:09.000000000"), "X", 20),
+                JoinDir::Right,
+            )
+            .unwrap();
+        assert_eq!(out.len(), 1);
+        t_expect(&out[0], 100, Some(20));
+
+        // c(:12, X, 30) on the upper bound
+        let (out, _) = join
+            .dispatch(
+                &expr_resolver,
+                Tuple::factory_city_temperature(ts("2020-01-01 00:00:12.000000000"), "X", 30),
+                JoinDir::Right,
+            )
+            .unwrap();
+        assert_eq!(out.len(), 1);
+        t_expect(&out[0], 100, Some(30));
+
+        // c(:12.000000001, X, 40) is just after the upper bound, and evicts matched t(:10) without NULLs
+        let (out, _) = join
+            .dispatch(
+                &expr_resolver,
+                Tuple::factory_city_temperature(ts("2020-01-01 00:00:12.000000001"), "X", 40),
+                JoinDir::Right,
+            )
+            .unwrap();
+        assert!(out.is_empty());
+        assert!(join.left_tuples.is_empty());
+
+        // t(:11, X, 200) matches all the right tuples in [:10, :13]: c(:12) and c(:12.000000001)
+        let (out, _) = join
+            .dispatch(
+                &expr_resolver,
+                Tuple::factory_trade(ts("2020-01-01 00:00:11.000000000"), "X", 200),
+                JoinDir::Left,
+            )
+            .unwrap();
+        assert_eq!(out.len(), 2);
+        t_expect(&out[0], 200, Some(30));
+        t_expect(&out[1], 200, Some(40));
+    }
+
+    #[test]
+    fn test_interval_join_late_left() {
+        setup_test_logger();
+
+        let (mut join, expr_resolver) = fx_interval_join(None);
+
+        // t(:10, X, 100)
+        let (out, _) = join
+            .dispatch(
+                &expr_resolver,
+                Tuple::factory_trade(ts("2020-01-01 00:00:10.000000000"), "X", 100),
+                JoinDir::Left,
+            )
+            .unwrap();
+        assert!(out.is_empty());
+
+        // t(:05, X, 200) is too late for the left watermark (:10), even though a right tuple could match it
+        let (out, window_in_flow) = join
+            .dispatch(
+                &expr_resolver,
+                Tuple::factory_trade(ts("2020-01-01 00:00:05.000000000"), "X", 200),
+                JoinDir::Left,
+            )
+            .unwrap();
+        assert!(out.is_empty());
+        assert_eq!(window_in_flow, WindowInFlowByWindowTask::zero());
+        assert_eq!(join.left_tuples.len(), 1);
+
+        // c(:09, X, 10) matches only t(:10)
+        let (out, _) = join
+            .dispatch(
+                &expr_resolver,
+                Tuple::factory_city_temperature(ts("2020-01-01 00:00:09.000000000"), "X", 10),
+                JoinDir::Right,
+            )
+            .unwrap();
+        assert_eq!(out.len(), 1);
+        t_expect(&out[0], 100, Some(10));
+    }
+
+    #[test]
+    fn test_interval_left_outer_join_without_partner() {
+        setup_test_logger();
+
+        let (mut join, expr_resolver) = fx_interval_join(Some(SpringEventDuration::from_secs(1)));
+
+        // c(:05, Y, 10)
+        let (out, _) = join
+            .dispatch(
+                &expr_resolver,
+                Tuple::factory_city_temperature(ts("2020-01-01 00:00:05.000000000"), "Y", 10),
+                JoinDir::Right,
+            )
+            .unwrap();
+        assert!(out.is_empty());
+
+        // t(:10, X, 100): no right tuple with X comes
+        let (out, _) = join
+            .dispatch(
+                &expr_resolver,
+                Tuple::factory_trade(ts("2020-01-01 00:00:10.000000000"), "X", 100),
+                JoinDir::Left,
+            )
+            .unwrap();
+        assert!(out.is_empty());
+
+        let idle_after =
+            |secs| SystemTimestamp::now() + SpringEventDuration::from_secs(secs).to_duration();
+
+        // the idle right watermark advances to about :10, not passing :12 yet
+        let (out, _) = join.dispatch_idleness(idle_after(5));
+        assert!(out.is_empty());
+
+        // the idle right watermark advances to about :25
+        let (out, window_in_flow) = join.dispatch_idleness(idle_after(20));
+        assert_eq!(out.len(), 1);
+        t_expect(&out[0], 100, None);
+        assert!(window_in_flow.window_gain_bytes_rows < 0);
+        assert!(join.left_tuples.is_empty());
+    }
+}
@@ -2,15 +2,21 @@
 
 use crate::{
     pipeline::{JoinParameter, WindowOperationParameter, WindowParameter},
-    stream_engine::autonomous_executor::task::window::{
-        panes::{JoinPane, Panes},
-        watermark::Watermark,
-        Window,
+    stream_engine::{
+        autonomous_executor::task::window::{
+            panes::{JoinDir, JoinPane, Panes},
+            watermark::Watermark,
+            Window,
+        },
+        time::{SpringEventDuration, SpringTimestamp},
     },
 };
 
+/// Holds a watermark for each input and an aligned one.
 #[derive(Debug)]
 pub struct JoinWindow {
+    left_watermark: Watermark,
+    right_watermark: Watermark,
     watermark: Watermark,
     panes: Panes<JoinPane>,
 }
@@ -37,16 +43,49 @@ impl Window for JoinWindow {
     fn purge(&mut self) {
         self.panes.purge()
     }
+
+    fn update_watermark(&mut self, rowtime: SpringTimestamp, dir: &JoinDir, now: SpringTimestamp) {
+        match dir {
+            JoinDir::Left => self.left_watermark.update(rowtime, now),
+            JoinDir::Right => self.right_watermark.update(rowtime, now),
+        }
+        self.align_watermark(now);
+    }
+
+    fn advance_watermark_by_idleness(&mut self, now: SpringTimestamp) {
+        self.left_watermark.advance_by_idleness(now);
+        self.right_watermark.advance_by_idleness(now);
+        self.align_watermark(now);
+    }
 }
 
 impl JoinWindow {
-    pub fn new(window_param: WindowParameter, join_param: JoinParameter) -> Self {
-        let watermark = Watermark::new(window_param.allowed_delay());
+    /// `left_idle_timeout` and `right_idle_timeout` are used for each input if the window clause does not have an idle timeout.
+    pub fn new(
+        window_param: WindowParameter,
+        join_param: JoinParameter,
+        left_idle_timeout: Option<SpringEventDuration>,
+        right_idle_timeout: Option<SpringEventDuration>,
+    ) -> Self {
+        let allowed_delay = window_param.allowed_delay();
+        let idle_timeout = window_param.idle_timeout();
+
+        let left_watermark = Watermark::new(allowed_delay, idle_timeout.or(left_idle_timeout));
+        let right_watermark = Watermark::new(allowed_delay, idle_timeout.or(right_idle_timeout));
+        let watermark = Watermark::new(allowed_delay, None);
+
         Self {
+            left_watermark,
+            right_watermark,
             watermark,
             panes: Panes::new(window_param, WindowOperationParameter::Join(join_param)),
         }
     }
+
+    fn align_watermark(&mut self, now: SpringTimestamp) {
+        self.watermark
+            .align(&[self.left_watermark, self.right_watermark], now);
+    }
 }
 
 #[cfg(test)]
@@ -58,7 +97,7 @@ mod tests {
     use crate::{
         expr_resolver::ExprResolver,
         expression::ValueExpr,
-        pipeline::{ColumnName, ColumnReference, JoinParameter, JoinType, StreamName},
+        pipeline::{ColumnName, ColumnReference, JoinParameter, JoinType, StreamName, WindowTime},
         sql_processor::SelectFieldSyntax,
         stream_engine::{
             autonomous_executor::task::window::panes::JoinDir,
@@ -157,6 +196,8 @@ mod tests {
             WindowParameter::TimedFixedWindow {
                 length: SpringEventDuration::from_secs(10),
                 allowed_delay: SpringEventDuration::from_secs(1),
+                idle_timeout: None,
+                window_time: WindowTime::Rowtime,
             },
             JoinParameter {
                 join_type: JoinType::LeftOuter,
@@ -171,7 +212,10 @@ mod tests {
                     ColumnReference::fx_city_temperature_temperature(),
                 ],
                 on_expr: on_expr_label,
+                top_n: None,
             },
+            None,
+            None,
         );
 
         // [:00, :10): t(:00, 100)
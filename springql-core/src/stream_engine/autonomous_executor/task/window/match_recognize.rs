@@ -0,0 +1,803 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::{cmp::Reverse, collections::HashMap, iter, mem};
+
+use crate::{
+    api::error::Result,
+    expr_resolver::{ExprResolver, ValueExprLabel},
+    mem_size::MemSize,
+    pipeline::{ColumnReference, Field, MatchRecognizeParameter, PatternElement, StreamName},
+    stream_engine::{
+        autonomous_executor::{
+            performance_metrics::WindowInFlowByWindowTask,
+            task::window::{panes::DistinctKey, watermark::Watermark},
+        },
+        time::{SpringDuration, SpringEventDuration, SpringTimestamp, SystemTimestamp},
+        Tuple,
+    },
+};
+
+/// Row pattern recognition operator for `MATCH_RECOGNIZE`, backed by an NFA.
+///
+/// Each partition holds partial matches (runs) of the pattern. A run advances with rows of its partition in arrival order
+/// (rows must be contiguous; a row not mapped to the pattern kills the run).
+///
+/// - Quantifiers are greedy: an accepting run still waits for more rows while it can extend.
+/// - Among overlapping matches, the one starting earliest (then the longest) wins.
+/// - After a match, the search restarts from the row next to the last row of the match (`AFTER MATCH SKIP PAST LAST ROW`).
+/// - A run dies when the watermark passes `WITHIN` from its first row. Its longest match so far is emitted then.
+///
+/// Allowed delay is zero.
+#[derive(Debug)]
+pub struct MatchRecognize {
+    match_param: MatchRecognizeParameter,
+
+    watermark: Watermark,
+
+    partitions: HashMap<DistinctKey, Partition>,
+    next_seq: u64,
+
+    /// Bytes of tuples held in `partitions`, reported last time.
+    held_bytes: i64,
+}
+
+impl MatchRecognize {
+    pub fn new(
+        match_param: MatchRecognizeParameter,
+        source_idle_timeout: Option<SpringEventDuration>,
+    ) -> Self {
+        Self {
+            match_param,
+            watermark: Watermark::new(SpringEventDuration::from_secs(0), source_idle_timeout),
+            partitions: HashMap::new(),
+            next_seq: 0,
+            held_bytes: 0,
+        }
+    }
+
+    /// # Returns
+    ///
+    /// A tuple per completed match: the last row of the match with measures appended.
+    ///
+    /// # Failures
+    ///
+    /// `SpringError::Sql` when partition keys, DEFINE conditions or measures cannot be evaluated.
+    pub fn dispatch(
+        &mut self,
+        expr_resolver: &ExprResolver,
+        tuple: Tuple,
+    ) -> Result<(Vec<Tuple>, WindowInFlowByWindowTask)> {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+
+        self.watermark
+            .update(tuple.rowtime().as_timestamp(), SystemTimestamp::now());
+        let mut matches = self.expire();
+
+        let key = DistinctKey::from_labels(&self.match_param.partition_by, expr_resolver, &tuple)?;
+        let partition = self.partitions.entry(key.clone()).or_default();
+        partition.feed(&self.match_param.pattern, expr_resolver, seq, &tuple)?;
+        matches.extend(partition.take_matches());
+        if partition.is_empty() {
+            self.partitions.remove(&key);
+        }
+
+        self.finish(matches, expr_resolver)
+    }
+
+    /// A task calls this when no tuple comes from the input.
+    pub fn dispatch_idleness(
+        &mut self,
+        expr_resolver: &ExprResolver,
+        now: SpringTimestamp,
+    ) -> Result<(Vec<Tuple>, WindowInFlowByWindowTask)> {
+        self.watermark.advance_by_idleness(now);
+        let matches = self.expire();
+        self.finish(matches, expr_resolver)
+    }
+
+    pub fn purge(&mut self) {
+        self.partitions.clear();
+        self.held_bytes = 0;
+    }
+
+    /// Kills runs older than `WITHIN` and returns matches emitted by them.
+    fn expire(&mut self) -> Vec<Match> {
+        let wm = self.watermark.as_timestamp();
+        let within = self.match_param.within.to_duration();
+
+        let mut matches = Vec::new();
+        for partition in self.partitions.values_mut() {
+            partition.expire(|run| run.start_at + within < wm);
+            matches.extend(partition.take_matches());
+        }
+        self.partitions.retain(|_, partition| !partition.is_empty());
+
+        matches
+    }
+
+    fn finish(
+        &mut self,
+        matches: Vec<Match>,
+        expr_resolver: &ExprResolver,
+    ) -> Result<(Vec<Tuple>, WindowInFlowByWindowTask)> {
+        let held_bytes = self
+            .partitions
+            .values()
+            .map(|partition| partition.mem_size())
+            .sum::<usize>() as i64;
+        let window_in_flow = WindowInFlowByWindowTask::new(0, held_bytes - self.held_bytes);
+        self.held_bytes = held_bytes;
+
+        let tuples = matches
+            .into_iter()
+            .map(|m| {
+                m.into_tuple(
+                    &self.match_param.pattern,
+                    &self.match_param.measures,
+                    expr_resolver,
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok((tuples, window_in_flow))
+    }
+}
+
+#[derive(Debug, Default)]
+struct Partition {
+    /// Partial matches in order of priority (earlier start first, then greedier first).
+    runs: Vec<Run>,
+    /// (start_seq, match) of dead runs, waiting for earlier runs to die.
+    candidates: Vec<(u64, Match)>,
+}
+
+impl MemSize for Partition {
+    fn mem_size(&self) -> usize {
+        let runs_size: usize = self.runs.iter().map(|run| run.mem_size()).sum();
+        let candidates_size: usize = self.candidates.iter().map(|(_, m)| m.mem_size()).sum();
+        runs_size + candidates_size
+    }
+}
+
+impl Partition {
+    fn is_empty(&self) -> bool {
+        self.runs.is_empty() && self.candidates.is_empty()
+    }
+
+    /// Advances all runs (and a new run starting from `tuple`) by `tuple`.
+    fn feed(
+        &mut self,
+        pattern: &[PatternElement],
+        expr_resolver: &ExprResolver,
+        seq: u64,
+        tuple: &Tuple,
+    ) -> Result<()> {
+        let start = Run::new(seq, tuple.rowtime().as_timestamp());
+
+        for run in mem::take(&mut self.runs)
+            .into_iter()
+            .chain(iter::once(start))
+        {
+            let successors = run.advance(pattern, expr_resolver, seq, tuple)?;
+            if successors.is_empty() {
+                self.die(run);
+                continue;
+            }
+
+            for successor in successors {
+                if self.runs.iter().any(|r| r.is_same_state(&successor)) {
+                    continue;
+                }
+                if successor.can_extend(pattern) {
+                    self.runs.push(successor);
+                } else {
+                    self.die(successor);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn expire<F>(&mut self, is_expired: F)
+    where
+        F: Fn(&Run) -> bool,
+    {
+        let (expired, alive): (Vec<_>, Vec<_>) =
+            mem::take(&mut self.runs).into_iter().partition(is_expired);
+        self.runs = alive;
+        for run in expired {
+            self.die(run);
+        }
+    }
+
+    fn die(&mut self, run: Run) {
+        if let Some(m) = run.matched {
+            self.candidates.push((run.start_seq, m));
+        }
+    }
+
+    /// Takes matches which no alive run can precede.
+    fn take_matches(&mut self) -> Vec<Match> {
+        let mut matches = Vec::new();
+
+        while let Some((idx, start_seq)) = self
+            .candidates
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, (start_seq, m))| (*start_seq, Reverse(m.end_seq)))
+            .map(|(idx, (start_seq, _))| (idx, *start_seq))
+        {
+            if self.runs.iter().any(|run| run.start_seq <= start_seq) {
+                break;
+            }
+
+            let (_, m) = self.candidates.swap_remove(idx);
+            self.runs.retain(|run| run.start_seq > m.end_seq);
+            self.candidates
+                .retain(|(start_seq, _)| *start_seq > m.end_seq);
+            matches.push(m);
+        }
+
+        matches
+    }
+}
+
+/// A partial match: an NFA state with rows mapped to pattern variables.
+#[derive(Clone, Debug)]
+struct Run {
+    start_seq: u64,
+    start_at: SpringTimestamp,
+
+    /// Index of the current pattern element.
+    pos: usize,
+    /// Number of rows mapped to the current pattern element.
+    count: usize,
+
+    /// Last row mapped to each pattern variable.
+    variables: Vec<(StreamName, Tuple)>,
+
+    /// The longest match of this run so far.
+    matched: Option<Match>,
+}
+
+impl MemSize for Run {
+    fn mem_size(&self) -> usize {
+        let variables_size: usize = self.variables.iter().map(|(_, t)| t.mem_size()).sum();
+        let matched_size = self.matched.as_ref().map_or(0, |m| m.mem_size());
+        variables_size + matched_size
+    }
+}
+
+impl Run {
+    fn new(start_seq: u64, start_at: SpringTimestamp) -> Self {
+        Self {
+            start_seq,
+            start_at,
+            pos: 0,
+            count: 0,
+            variables: Vec::new(),
+            matched: None,
+        }
+    }
+
+    fn is_same_state(&self, other: &Run) -> bool {
+        self.start_seq == other.start_seq && self.pos == other.pos && self.count == other.count
+    }
+
+    fn is_accepting(&self, pattern: &[PatternElement]) -> bool {
+        self.count >= pattern[self.pos].quantifier.min()
+            && pattern[self.pos + 1..]
+                .iter()
+                .all(|elem| elem.quantifier.min() == 0)
+    }
+
+    fn can_extend(&self, pattern: &[PatternElement]) -> bool {
+        self.pos + 1 < pattern.len()
+            || pattern[self.pos]
+                .quantifier
+                .max()
+                .map_or(true, |max| self.count < max)
+    }
+
+    /// # Returns
+    ///
+    /// Successor runs in order of priority. Empty if `tuple` cannot be mapped to the pattern.
+    fn advance(
+        &self,
+        pattern: &[PatternElement],
+        expr_resolver: &ExprResolver,
+        seq: u64,
+        tuple: &Tuple,
+    ) -> Result<Vec<Run>> {
+        let mut successors = Vec::new();
+        self.advance_from(
+            self.pos,
+            self.count,
+            pattern,
+            expr_resolver,
+            seq,
+            tuple,
+            &mut successors,
+        )?;
+        Ok(successors)
+    }
+
+    /// Maps `tuple` to the element at `pos` (greedy), or skips the element if it is already satisfied.
+    #[allow(clippy::too_many_arguments)]
+    fn advance_from(
+        &self,
+        pos: usize,
+        count: usize,
+        pattern: &[PatternElement],
+        expr_resolver: &ExprResolver,
+        seq: u64,
+        tuple: &Tuple,
+        successors: &mut Vec<Run>,
+    ) -> Result<()> {
+        let elem = &pattern[pos];
+
+        if elem.quantifier.max().map_or(true, |max| count < max)
+            && self.satisfies(elem, pattern, expr_resolver, tuple)?
+        {
+            let mut run = self.clone();
+            run.pos = pos;
+            run.count = count + 1;
+            run.bind(&elem.variable, tuple);
+            if run.is_accepting(pattern) {
+                run.matched = Some(Match {
+                    end_seq: seq,
+                    last_row: tuple.clone(),
+                    variables: run.variables.clone(),
+                });
+            }
+            successors.push(run);
+        }
+
+        if count >= elem.quantifier.min() && pos + 1 < pattern.len() {
+            self.advance_from(pos + 1, 0, pattern, expr_resolver, seq, tuple, successors)?;
+        }
+        Ok(())
+    }
+
+    fn satisfies(
+        &self,
+        elem: &PatternElement,
+        pattern: &[PatternElement],
+        expr_resolver: &ExprResolver,
+        tuple: &Tuple,
+    ) -> Result<bool> {
+        match elem.condition {
+            None => Ok(true),
+            Some(condition) => {
+                let mut correlated = tuple.clone();
+                correlated.correlate(tuple, &elem.variable);
+                correlate_variables(&mut correlated, &self.variables, pattern, tuple);
+                expr_resolver
+                    .eval_value_expr(condition, &correlated)?
+                    .to_bool()
+            }
+        }
+    }
+
+    fn bind(&mut self, variable: &StreamName, tuple: &Tuple) {
+        match self.variables.iter_mut().find(|(v, _)| v == variable) {
+            Some((_, t)) => *t = tuple.clone(),
+            None => self.variables.push((variable.clone(), tuple.clone())),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Match {
+    end_seq: u64,
+    last_row: Tuple,
+    variables: Vec<(StreamName, Tuple)>,
+}
+
+impl MemSize for Match {
+    fn mem_size(&self) -> usize {
+        let variables_size: usize = self.variables.iter().map(|(_, t)| t.mem_size()).sum();
+        self.last_row.mem_size() + variables_size
+    }
+}
+
+impl Match {
+    fn into_tuple(
+        self,
+        pattern: &[PatternElement],
+        measures: &[(ValueExprLabel, ColumnReference)],
+        expr_resolver: &ExprResolver,
+    ) -> Result<Tuple> {
+        let mut correlated = self.last_row.clone();
+        correlate_variables(&mut correlated, &self.variables, pattern, &self.last_row);
+
+        let mut tuple = self.last_row;
+        for (label, colref) in measures {
+            let value = expr_resolver.eval_value_expr(*label, &correlated)?;
+            tuple.push_field(Field::new(colref.clone(), value));
+        }
+        Ok(tuple)
+    }
+}
+
+/// Appends rows mapped to pattern variables to `tuple`. Variables without rows are NULL (shaped like `template`).
+fn correlate_variables(
+    tuple: &mut Tuple,
+    variables: &[(StreamName, Tuple)],
+    pattern: &[PatternElement],
+    template: &Tuple,
+) {
+    for (variable, row) in variables {
+        tuple.correlate(row, variable);
+    }
+    for elem in pattern {
+        if !variables.iter().any(|(v, _)| v == &elem.variable) {
+            tuple.correlate_null(template, &elem.variable);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{
+        expression::ValueExpr,
+        pipeline::PatternQuantifier,
+        sql_processor::SelectFieldSyntax,
+        stream_engine::{NnSqlValue, SqlValue},
+    };
+
+    use super::*;
+
+    fn ts(s: &str) -> SpringTimestamp {
+        SpringTimestamp::from_str(s).unwrap()
+    }
+
+    /// `variable.ticker = 'ticker'`
+    fn ticker_is(variable: &str, ticker: &str) -> ValueExpr {
+        ValueExpr::factory_eq(
+            ValueExpr::factory_colref(variable, "ticker"),
+            ValueExpr::Constant(SqlValue::NotNull(NnSqlValue::Text(ticker.to_string()))),
+        )
+    }
+
+    #[test]
+    fn test_match_recognize() {
+        // MATCH_RECOGNIZE (
+        //   ORDER BY trade.timestamp
+        //   MEASURES A.amount AS a_amount, B.amount AS b_amount
+        //   PATTERN (A B+ C?)
+        //   WITHIN DURATION_SECS(10)
+        //   DEFINE A AS A.ticker = 'A', B AS B.ticker = 'B', C AS C.ticker = 'C'
+        // )
+        let select_list = vec![SelectFieldSyntax::ValueExpr {
+            value_expr: ValueExpr::factory_colref("trade", "amount"),
+            alias: None,
+        }];
+        let (mut expr_resolver, _) = ExprResolver::new(select_list);
+
+        let mut element = |variable: &str, quantifier| {
+            let condition = expr_resolver.register_value_expr(ticker_is(variable, variable));
+            PatternElement::new(
+                StreamName::new(variable.to_string()),
+                quantifier,
+                Some(condition),
+            )
+        };
+        let pattern = vec![
+            element("A", PatternQuantifier::One),
+            element("B", PatternQuantifier::OneOrMore),
+            element("C", PatternQuantifier::ZeroOrOne),
+        ];
+        let a_amount = expr_resolver.register_value_expr(ValueExpr::factory_colref("A", "amount"));
+        let b_amount = expr_resolver.register_value_expr(ValueExpr::factory_colref("B", "amount"));
+        let measures = vec![
+            (a_amount, ColumnReference::factory("trade", "a_amount")),
+            (b_amount, ColumnReference::factory("trade", "b_amount")),
+        ];
+
+        let mut match_recognize = MatchRecognize::new(
+            MatchRecognizeParameter::new(
+                vec![],
+                measures,
+                pattern,
+                SpringEventDuration::from_secs(10),
+            ),
+            None,
+        );
+
+        // returns (a_amount, b_amount, last amount) of matches
+        let mut dispatch = |rowtime: &str, ticker: &str, amount: i16| {
+            let tuple = Tuple::factory_trade(ts(rowtime), ticker, amount);
+            let (out, _) = match_recognize.dispatch(&expr_resolver, tuple).unwrap();
+            out.iter()
+                .map(|t| {
+                    let get = |colref: ColumnReference| {
+                        t.get_value(&colref)
+                            .unwrap()
+                            .unwrap()
+                            .unpack::<i16>()
+                            .unwrap()
+                    };
+                    (
+                        get(ColumnReference::factory("trade", "a_amount")),
+                        get(ColumnReference::factory("trade", "b_amount")),
+                        get(ColumnReference::fx_trade_amount()),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert!(dispatch("2020-01-01 00:00:00.000000000", "A", 1).is_empty());
+        assert!(dispatch("2020-01-01 00:00:01.000000000", "B", 2).is_empty());
+        assert!(dispatch("2020-01-01 00:00:02.000000000", "B", 3).is_empty());
+        // A B B C
+        assert_eq!(
+            dispatch("2020-01-01 00:00:03.000000000", "C", 4),
+            vec![(1, 3, 4)]
+        );
+
+        assert!(dispatch("2020-01-01 00:00:04.000000000", "A", 5).is_empty());
+        assert!(dispatch("2020-01-01 00:00:05.000000000", "B", 6).is_empty());
+        // A B (greedy B+ ends by a row not mapped)
+        assert_eq!(
+            dispatch("2020-01-01 00:00:06.000000000", "X", 7),
+            vec![(5, 6, 6)]
+        );
+
+        assert!(dispatch("2020-01-01 00:00:07.000000000", "A", 8).is_empty());
+        assert!(dispatch("2020-01-01 00:00:08.000000000", "B", 9).is_empty());
+        // A B (the run expires by WITHIN)
+        assert_eq!(
+            dispatch("2020-01-01 00:00:17.000000001", "X", 10),
+            vec![(8, 9, 9)]
+        );
+
+        assert!(dispatch("2020-01-01 00:00:18.000000000", "A", 11).is_empty());
+        // A ... B is too late
+        assert!(dispatch("2020-01-01 00:00:28.000000001", "B", 12).is_empty());
+        assert!(match_recognize.partitions.is_empty());
+    }
+
+    /// `MATCH_RECOGNIZE (MEASURES A.amount AS a_amount, B.amount AS b_amount PATTERN (...) WITHIN DURATION_SECS(10) DEFINE ...)`
+    ///
+    /// Each pattern element is `(variable, quantifier, ticker)` with `DEFINE variable AS variable.ticker = 'ticker'`.
+    fn fx_match_recognize(
+        pattern: &[(&str, PatternQuantifier, &str)],
+        source_idle_timeout: Option<SpringEventDuration>,
+    ) -> (MatchRecognize, ExprResolver) {
+        let select_list = vec![SelectFieldSyntax::ValueExpr {
+            value_expr: ValueExpr::factory_colref("trade", "amount"),
+            alias: None,
+        }];
+        let (mut expr_resolver, _) = ExprResolver::new(select_list);
+
+        let pattern = pattern
+            .iter()
+            .map(|(variable, quantifier, ticker)| {
+                let condition = expr_resolver.register_value_expr(ticker_is(variable, ticker));
+                PatternElement::new(
+                    StreamName::new(variable.to_string()),
+                    *quantifier,
+                    Some(condition),
+                )
+            })
+            .collect();
+        let a_amount = expr_resolver.register_value_expr(ValueExpr::factory_colref("A", "amount"));
+        let b_amount = expr_resolver.register_value_expr(ValueExpr::factory_colref("B", "amount"));
+        let measures = vec![
+            (a_amount, ColumnReference::factory("trade", "a_amount")),
+            (b_amount, ColumnReference::factory("trade", "b_amount")),
+        ];
+
+        let match_recognize = MatchRecognize::new(
+            MatchRecognizeParameter::new(
+                vec![],
+                measures,
+                pattern,
+                SpringEventDuration::from_secs(10),
+            ),
+            source_idle_timeout,
+        );
+        (match_recognize, expr_resolver)
+    }
+
+    /// (a_amount, b_amount, last amount) of a match. NULL measures are None.
+    fn fx_match_out(tuple: &Tuple) -> (Option<i16>, Option<i16>, i16) {
+        let get = |colref: ColumnReference| match tuple.get_value(&colref).unwrap() {
+            SqlValue::Null => None,
+            SqlValue::NotNull(v) => Some(v.unpack::<i16>().unwrap()),
+        };
+        (
+            get(ColumnReference::factory("trade", "a_amount")),
+            get(ColumnReference::factory("trade", "b_amount")),
+            get(ColumnReference::fx_trade_amount()).unwrap(),
+        )
+    }
+
+    /// Dispatches a row at `00:00:<sec>` and returns matches.
+    fn fx_dispatch(
+        match_recognize: &mut MatchRecognize,
+        expr_resolver: &ExprResolver,
+        sec: u32,
+        ticker: &str,
+        amount: i16,
+    ) -> Vec<(Option<i16>, Option<i16>, i16)> {
+        let rowtime = ts(&format!("2020-01-01 00:00:{:02}.000000000", sec));
+        let tuple = Tuple::factory_trade(rowtime, ticker, amount);
+        let (out, _) = match_recognize.dispatch(expr_resolver, tuple).unwrap();
+        out.iter().map(fx_match_out).collect()
+    }
+
+    #[test]
+    fn test_match_recognize_zero_or_more() {
+        // PATTERN (A B* C)
+        let (mut mr, resolver) = fx_match_recognize(
+            &[
+                ("A", PatternQuantifier::One, "A"),
+                ("B", PatternQuantifier::ZeroOrMore, "B"),
+                ("C", PatternQuantifier::One, "C"),
+            ],
+            None,
+        );
+
+        assert!(fx_dispatch(&mut mr, &resolver, 0, "A", 1).is_empty());
+        // A C
+        assert_eq!(
+            fx_dispatch(&mut mr, &resolver, 1, "C", 2),
+            vec![(Some(1), None, 2)]
+        );
+
+        assert!(fx_dispatch(&mut mr, &resolver, 2, "A", 3).is_empty());
+        assert!(fx_dispatch(&mut mr, &resolver, 3, "B", 4).is_empty());
+        assert!(fx_dispatch(&mut mr, &resolver, 4, "B", 5).is_empty());
+        assert!(fx_dispatch(&mut mr, &resolver, 5, "B", 6).is_empty());
+        // A B B B C
+        assert_eq!(
+            fx_dispatch(&mut mr, &resolver, 6, "C", 7),
+            vec![(Some(3), Some(6), 7)]
+        );
+
+        // B C without A
+        assert!(fx_dispatch(&mut mr, &resolver, 7, "B", 8).is_empty());
+        assert!(fx_dispatch(&mut mr, &resolver, 8, "C", 9).is_empty());
+        assert!(mr.partitions.is_empty());
+    }
+
+    #[test]
+    fn test_match_recognize_zero_or_one() {
+        // PATTERN (A B? C)
+        let (mut mr, resolver) = fx_match_recognize(
+            &[
+                ("A", PatternQuantifier::One, "A"),
+                ("B", PatternQuantifier::ZeroOrOne, "B"),
+                ("C", PatternQuantifier::One, "C"),
+            ],
+            None,
+        );
+
+        assert!(fx_dispatch(&mut mr, &resolver, 0, "A", 1).is_empty());
+        // A C
+        assert_eq!(
+            fx_dispatch(&mut mr, &resolver, 1, "C", 2),
+            vec![(Some(1), None, 2)]
+        );
+
+        assert!(fx_dispatch(&mut mr, &resolver, 2, "A", 3).is_empty());
+        assert!(fx_dispatch(&mut mr, &resolver, 3, "B", 4).is_empty());
+        // A B C
+        assert_eq!(
+            fx_dispatch(&mut mr, &resolver, 4, "C", 5),
+            vec![(Some(3), Some(4), 5)]
+        );
+
+        // A B B C: at most one B
+        assert!(fx_dispatch(&mut mr, &resolver, 5, "A", 6).is_empty());
+        assert!(fx_dispatch(&mut mr, &resolver, 6, "B", 7).is_empty());
+        assert!(fx_dispatch(&mut mr, &resolver, 7, "B", 8).is_empty());
+        assert!(fx_dispatch(&mut mr, &resolver, 8, "C", 9).is_empty());
+        assert!(mr.partitions.is_empty());
+    }
+
+    #[test]
+    fn test_match_recognize_one_or_more_overlapping() {
+        // PATTERN (A+ B)
+        let (mut mr, resolver) = fx_match_recognize(
+            &[
+                ("A", PatternQuantifier::OneOrMore, "A"),
+                ("B", PatternQuantifier::One, "B"),
+            ],
+            None,
+        );
+
+        // B without A
+        assert!(fx_dispatch(&mut mr, &resolver, 0, "B", 1).is_empty());
+
+        assert!(fx_dispatch(&mut mr, &resolver, 1, "A", 2).is_empty());
+        assert!(fx_dispatch(&mut mr, &resolver, 2, "A", 3).is_empty());
+        assert!(fx_dispatch(&mut mr, &resolver, 3, "A", 4).is_empty());
+        // A A A B, A A B and A B overlap: the earliest one wins and the others are skipped.
+        assert_eq!(
+            fx_dispatch(&mut mr, &resolver, 4, "B", 5),
+            vec![(Some(4), Some(5), 5)]
+        );
+        assert!(mr.partitions.is_empty());
+    }
+
+    #[test]
+    fn test_match_recognize_skip_past_last_row() {
+        // PATTERN (A B) DEFINE A AS A.ticker = 'X', B AS B.ticker = 'X'
+        let (mut mr, resolver) = fx_match_recognize(
+            &[
+                ("A", PatternQuantifier::One, "X"),
+                ("B", PatternQuantifier::One, "X"),
+            ],
+            None,
+        );
+
+        assert!(fx_dispatch(&mut mr, &resolver, 0, "X", 1).is_empty());
+        assert_eq!(
+            fx_dispatch(&mut mr, &resolver, 1, "X", 2),
+            vec![(Some(1), Some(2), 2)]
+        );
+        // X(2) X(3) is not a match since X(2) is in the previous match.
+        assert!(fx_dispatch(&mut mr, &resolver, 2, "X", 3).is_empty());
+        assert_eq!(
+            fx_dispatch(&mut mr, &resolver, 3, "X", 4),
+            vec![(Some(3), Some(4), 4)]
+        );
+    }
+
+    #[test]
+    fn test_match_recognize_within_expiry() {
+        // PATTERN (A B*)
+        let (mut mr, resolver) = fx_match_recognize(
+            &[
+                ("A", PatternQuantifier::One, "A"),
+                ("B", PatternQuantifier::ZeroOrMore, "B"),
+            ],
+            Some(SpringEventDuration::from_secs(1)),
+        );
+        let idle_after =
+            |secs| SystemTimestamp::now() + SpringEventDuration::from_secs(secs).to_duration();
+
+        assert!(fx_dispatch(&mut mr, &resolver, 0, "A", 1).is_empty());
+        assert!(fx_dispatch(&mut mr, &resolver, 1, "B", 2).is_empty());
+
+        // the watermark does not pass WITHIN yet: 00:00:01 + 5s
+        let (out, _) = mr.dispatch_idleness(&resolver, idle_after(5)).unwrap();
+        assert!(out.is_empty());
+
+        // A B (greedy B* still waits for more rows until the run expires)
+        let (out, _) = mr.dispatch_idleness(&resolver, idle_after(20)).unwrap();
+        assert_eq!(
+            out.iter().map(fx_match_out).collect::<Vec<_>>(),
+            vec![(Some(1), Some(2), 2)]
+        );
+        assert!(mr.partitions.is_empty());
+    }
+
+    #[test]
+    fn test_match_recognize_within_expiry_without_match() {
+        // PATTERN (A B)
+        let (mut mr, resolver) = fx_match_recognize(
+            &[
+                ("A", PatternQuantifier::One, "A"),
+                ("B", PatternQuantifier::One, "B"),
+            ],
+            Some(SpringEventDuration::from_secs(1)),
+        );
+
+        assert!(fx_dispatch(&mut mr, &resolver, 0, "A", 1).is_empty());
+
+        let now = SystemTimestamp::now() + SpringEventDuration::from_secs(20).to_duration();
+        let (out, _) = mr.dispatch_idleness(&resolver, now).unwrap();
+        assert!(out.is_empty());
+        assert!(mr.partitions.is_empty());
+
+        // B alone after A expired
+        assert!(fx_dispatch(&mut mr, &resolver, 30, "B", 2).is_empty());
+    }
+}
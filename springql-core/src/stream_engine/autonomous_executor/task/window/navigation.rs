@@ -0,0 +1,1055 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::{
+    collections::{hash_map::Entry, BTreeMap, BTreeSet, HashMap, VecDeque},
+    mem,
+};
+
+use ordered_float::OrderedFloat;
+
+use crate::{
+    api::error::Result,
+    expr_resolver::ExprResolver,
+    expression::NavigationFunction,
+    mem_size::MemSize,
+    pipeline::{Field, NavigationParameter},
+    stream_engine::{
+        autonomous_executor::{
+            performance_metrics::WindowInFlowByWindowTask,
+            task::window::{panes::DistinctKey, watermark::Watermark},
+        },
+        time::{SpringDuration, SpringEventDuration, SpringTimestamp, SystemTimestamp},
+        NnSqlValue, SqlValue, Tuple,
+    },
+};
+
+/// Operator for navigation functions (`LAG`, `LEAD`, `EWMA`, `RATE`, `DERIVATIVE`, `INTEGRAL`, `ZSCORE` and `IS_OUTLIER`).
+///
+/// It evaluates navigation functions in arrival order of tuples in each partition and appends the results to tuples as derived fields.
+///
+/// - LAG keeps only the last `offset` values for each partition. A tuple without enough preceding tuples gets NULL.
+/// - LEAD holds a tuple until `offset` more tuples come into its partition.
+///   A tuple is emitted as soon as all of its LEAD values are resolved, so output order may differ from input order.
+///   With WINDOW, a tuple waits for a LEAD value until the watermark passes `ROWTIME + window` and gets NULL after that,
+///   so a partition which stops receiving tuples does not hold tuples forever. The watermark is driven by ROWTIME of
+///   all input tuples (allowed delay is zero) and advances by idle timeout of the source reader.
+/// - EWMA, RATE, DERIVATIVE and INTEGRAL keep only the last value, its ROWTIME and an accumulated value for each partition.
+///   They evaluate to DOUBLE. A NULL value does not change the state.
+///   RATE and DERIVATIVE are NULL for the first tuple of a partition and for a tuple not later than the last one.
+/// - ZSCORE and IS_OUTLIER keep the count, mean and sum of squared deviations of the preceding values for each partition
+///   (and the values themselves if WINDOW is supplied, to evict ones older than the window in arrival order).
+///   ZSCORE evaluates to DOUBLE and IS_OUTLIER to BOOLEAN.
+///   They are NULL for a NULL value, until 2 values precede in the partition, and while the standard deviation is 0.
+#[derive(Debug)]
+pub struct Navigation {
+    params: Vec<NavigationParameter>,
+
+    watermark: Watermark,
+
+    /// (index of params, partition) -> last values (oldest first)
+    lag_histories: HashMap<(usize, DistinctKey), VecDeque<SqlValue>>,
+    /// (index of params, partition) -> sequence numbers of tuples waiting for LEAD values (oldest first)
+    lead_waitings: HashMap<(usize, DistinctKey), VecDeque<u64>>,
+    /// expire_at -> (sequence number, index of params, partition) of tuples waiting for LEAD values with WINDOW.
+    ///
+    /// A tuple whose LEAD value is resolved remains here until the watermark passes.
+    lead_expirations: BTreeMap<SpringTimestamp, Vec<(u64, usize, DistinctKey)>>,
+    /// (index of params, partition) -> state of EWMA, RATE, DERIVATIVE or INTEGRAL
+    accumulations: HashMap<(usize, DistinctKey), Accumulation>,
+    /// (index of params, partition) -> statistics of ZSCORE or IS_OUTLIER
+    statistics: HashMap<(usize, DistinctKey), RunningStatistics>,
+
+    /// sequence number -> tuple waiting for LEAD values
+    pending: HashMap<u64, PendingTuple>,
+    next_seq: u64,
+}
+
+#[derive(Debug)]
+struct PendingTuple {
+    tuple: Tuple,
+    /// Result of each navigation function (same order as params). None if not resolved yet.
+    values: Vec<Option<SqlValue>>,
+}
+
+#[derive(Debug)]
+struct Accumulation {
+    /// ROWTIME and value of the last tuple with non-NULL value.
+    last: (SpringTimestamp, f64),
+    /// Moving average for EWMA, integral for INTEGRAL.
+    acc: f64,
+}
+
+/// Running mean and variance by Welford's algorithm, to avoid cancellation of a sum of squares.
+#[derive(Debug, Default)]
+struct RunningStatistics {
+    /// ROWTIME and value of tuples within the window (oldest first). Always empty without window.
+    values: VecDeque<(SpringTimestamp, f64)>,
+    count: u64,
+    mean: f64,
+    /// Sum of squared deviations from `mean`.
+    m2: f64,
+}
+
+impl RunningStatistics {
+    fn add(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    /// Reverse of `add()`, for a value evicted from the window (and already popped from `values`).
+    ///
+    /// If the removal cancels `m2` down to its rounding errors, the statistics are recomputed from `values`
+    /// not to take the errors as deviation.
+    fn remove(&mut self, value: f64) {
+        self.count -= 1;
+        if self.count == 0 {
+            self.mean = 0.0;
+            self.m2 = 0.0;
+            return;
+        }
+        let delta = value - self.mean;
+        self.mean -= delta / self.count as f64;
+        let removed = delta * (value - self.mean);
+        self.m2 -= removed;
+
+        if self.m2 <= 1024.0 * f64::EPSILON * removed.abs() {
+            let values = mem::take(&mut self.values);
+            *self = Self::default();
+            for &(_, value) in &values {
+                self.add(value);
+            }
+            self.values = values;
+        }
+    }
+
+    /// Standard score of `value` with the mean and the sample standard deviation.
+    ///
+    /// None when less than 2 values are added or the standard deviation is 0.
+    fn zscore(&self, value: f64) -> Option<f64> {
+        if self.count < 2 {
+            return None;
+        }
+        let variance = self.m2 / (self.count - 1) as f64;
+        (variance > 0.0).then(|| (value - self.mean) / variance.sqrt())
+    }
+}
+
+impl PendingTuple {
+    fn is_resolved(&self) -> bool {
+        self.values.iter().all(Option::is_some)
+    }
+}
+
+impl Navigation {
+    pub fn new(
+        params: Vec<NavigationParameter>,
+        source_idle_timeout: Option<SpringEventDuration>,
+    ) -> Self {
+        Self {
+            params,
+            watermark: Watermark::new(SpringEventDuration::from_secs(0), source_idle_timeout),
+            lag_histories: HashMap::new(),
+            lead_waitings: HashMap::new(),
+            lead_expirations: BTreeMap::new(),
+            accumulations: HashMap::new(),
+            statistics: HashMap::new(),
+            pending: HashMap::new(),
+            next_seq: 0,
+        }
+    }
+
+    /// # Returns
+    ///
+    /// Tuples with derived fields whose navigation functions are resolved by `tuple`.
+    ///
+    /// # Failures
+    ///
+    /// `SpringError::Sql` when expressions or partition keys cannot be evaluated.
+    pub fn dispatch(
+        &mut self,
+        expr_resolver: &ExprResolver,
+        tuple: Tuple,
+    ) -> Result<(Vec<Tuple>, WindowInFlowByWindowTask)> {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+
+        let rowtime = tuple.rowtime().as_timestamp();
+        self.watermark.update(rowtime, SystemTimestamp::now());
+
+        let mut states_bytes = 0;
+        let mut values = Vec::with_capacity(self.params.len());
+        let mut filled_seqs = BTreeSet::new();
+
+        for (idx, param) in self.params.iter().enumerate() {
+            let partition = DistinctKey::from_labels(&param.partition_by, expr_resolver, &tuple)?;
+            let value = expr_resolver.eval_value_expr(param.expr, &tuple)?;
+
+            match param.func {
+                NavigationFunction::Lag => {
+                    let history = match self.lag_histories.entry((idx, partition)) {
+                        Entry::Occupied(entry) => entry.into_mut(),
+                        Entry::Vacant(entry) => {
+                            states_bytes += (entry.key().1.mem_size()
+                                + std::mem::size_of::<VecDeque<SqlValue>>())
+                                as i64;
+                            entry.insert(VecDeque::new())
+                        }
+                    };
+                    let lagged = if history.len() == param.offset {
+                        history.front().cloned().expect("offset is positive")
+                    } else {
+                        SqlValue::Null
+                    };
+
+                    states_bytes += value.mem_size() as i64;
+                    history.push_back(value);
+                    if history.len() > param.offset {
+                        let expired = history.pop_front().expect("history is not empty");
+                        states_bytes -= expired.mem_size() as i64;
+                    }
+
+                    values.push(Some(lagged));
+                }
+                NavigationFunction::Lead { window } => {
+                    if let Some(window) = window {
+                        self.lead_expirations
+                            .entry(rowtime + window.to_duration())
+                            .or_default()
+                            .push((seq, idx, partition.clone()));
+                    }
+
+                    let waitings = match self.lead_waitings.entry((idx, partition)) {
+                        Entry::Occupied(entry) => entry.into_mut(),
+                        Entry::Vacant(entry) => {
+                            states_bytes += Self::lead_waitings_bytes(entry.key());
+                            entry.insert(VecDeque::new())
+                        }
+                    };
+                    waitings.push_back(seq);
+                    if waitings.len() > param.offset {
+                        let waiting_seq = waitings.pop_front().expect("waitings is not empty");
+                        // skips a tuple whose LEAD value is already expired
+                        if let Some(waiting) = self
+                            .pending
+                            .get_mut(&waiting_seq)
+                            .filter(|waiting| waiting.values[idx].is_none())
+                        {
+                            waiting.values[idx] = Some(value);
+                            filled_seqs.insert(waiting_seq);
+                        }
+                    }
+                    values.push(None);
+                }
+                NavigationFunction::Ewma { .. }
+                | NavigationFunction::Rate
+                | NavigationFunction::Derivative
+                | NavigationFunction::Integral => {
+                    let (accumulated, states_bytes_gain) = Self::accumulate(
+                        param.func,
+                        self.accumulations.entry((idx, partition)),
+                        rowtime,
+                        value,
+                    )?;
+                    states_bytes += states_bytes_gain;
+                    values.push(Some(accumulated));
+                }
+                NavigationFunction::ZScore { .. } | NavigationFunction::IsOutlier { .. } => {
+                    let (scored, states_bytes_gain) = Self::score(
+                        param.func,
+                        self.statistics.entry((idx, partition)),
+                        rowtime,
+                        value,
+                    )?;
+                    states_bytes += states_bytes_gain;
+                    values.push(Some(scored));
+                }
+            }
+        }
+
+        let (mut out, mut rows_bytes) = self.emit_resolved(filled_seqs);
+
+        let pending = PendingTuple { tuple, values };
+        if pending.is_resolved() {
+            out.push(self.append_fields(pending.tuple, pending.values));
+        } else {
+            rows_bytes += pending.tuple.mem_size() as i64;
+            self.pending.insert(seq, pending);
+        }
+
+        let (expired, window_in_flow_expire) = self.expire();
+        out.extend(expired);
+
+        Ok((
+            out,
+            WindowInFlowByWindowTask::new(states_bytes, rows_bytes) + window_in_flow_expire,
+        ))
+    }
+
+    /// A task calls this when no tuple comes from the input.
+    ///
+    /// # Returns
+    ///
+    /// Tuples whose LEAD values are expired.
+    pub fn dispatch_idleness(
+        &mut self,
+        now: SpringTimestamp,
+    ) -> (Vec<Tuple>, WindowInFlowByWindowTask) {
+        self.watermark.advance_by_idleness(now);
+        self.expire()
+    }
+
+    pub fn purge(&mut self) {
+        self.lag_histories.clear();
+        self.lead_waitings.clear();
+        self.lead_expirations.clear();
+        self.accumulations.clear();
+        self.statistics.clear();
+        self.pending.clear();
+    }
+
+    /// Emits tuples in `seqs` whose navigation functions are all resolved.
+    ///
+    /// # Returns
+    ///
+    /// (emitted tuples in arrival order, gain of rows bytes)
+    fn emit_resolved(&mut self, seqs: BTreeSet<u64>) -> (Vec<Tuple>, i64) {
+        let mut rows_bytes = 0;
+        let mut out = Vec::new();
+
+        for seq in seqs {
+            if self
+                .pending
+                .get(&seq)
+                .map_or(false, PendingTuple::is_resolved)
+            {
+                let pending = self.pending.remove(&seq).expect("just checked");
+                rows_bytes -= pending.tuple.mem_size() as i64;
+                out.push(self.append_fields(pending.tuple, pending.values));
+            }
+        }
+        (out, rows_bytes)
+    }
+
+    /// Resolves LEAD values of tuples waiting longer than WINDOW into NULL.
+    fn expire(&mut self) -> (Vec<Tuple>, WindowInFlowByWindowTask) {
+        let wm = self.watermark.as_timestamp();
+
+        let mut states_bytes = 0;
+        let mut expired_seqs = BTreeSet::new();
+        while let Some(expire_at) = self.lead_expirations.keys().next().copied() {
+            if expire_at >= wm {
+                break;
+            }
+            let expirations = self
+                .lead_expirations
+                .remove(&expire_at)
+                .expect("key exists");
+            for (seq, idx, partition) in expirations {
+                if let Some(waiting) = self
+                    .pending
+                    .get_mut(&seq)
+                    .filter(|waiting| waiting.values[idx].is_none())
+                {
+                    waiting.values[idx] = Some(SqlValue::Null);
+                    expired_seqs.insert(seq);
+                    states_bytes += self.trim_lead_waitings((idx, partition));
+                }
+            }
+        }
+
+        let (out, rows_bytes) = self.emit_resolved(expired_seqs);
+        (out, WindowInFlowByWindowTask::new(states_bytes, rows_bytes))
+    }
+
+    /// Drops tuples already resolved from the front of waitings, and the waitings itself if it gets empty.
+    ///
+    /// Tuples in the middle are kept to count `offset` of LEAD since waitings are in arrival order (not in ROWTIME order).
+    ///
+    /// # Returns
+    ///
+    /// Gain of states bytes
+    fn trim_lead_waitings(&mut self, key: (usize, DistinctKey)) -> i64 {
+        let idx = key.0;
+        if let Some(waitings) = self.lead_waitings.get_mut(&key) {
+            while let Some(waiting_seq) = waitings.front() {
+                let is_waiting = self
+                    .pending
+                    .get(waiting_seq)
+                    .map_or(false, |waiting| waiting.values[idx].is_none());
+                if is_waiting {
+                    break;
+                }
+                waitings.pop_front();
+            }
+            if waitings.is_empty() {
+                self.lead_waitings.remove(&key);
+                return -Self::lead_waitings_bytes(&key);
+            }
+        }
+        0
+    }
+
+    /// Bytes of a partition in `lead_waitings`, except for sequence numbers.
+    fn lead_waitings_bytes(key: &(usize, DistinctKey)) -> i64 {
+        (key.1.mem_size() + std::mem::size_of::<VecDeque<u64>>()) as i64
+    }
+
+    /// # Returns
+    ///
+    /// (value of the function, gain of states bytes)
+    fn accumulate(
+        func: NavigationFunction,
+        entry: Entry<(usize, DistinctKey), Accumulation>,
+        rowtime: SpringTimestamp,
+        value: SqlValue,
+    ) -> Result<(SqlValue, i64)> {
+        let double = |v: f64| SqlValue::NotNull(NnSqlValue::Double(OrderedFloat(v)));
+
+        let value = match value {
+            SqlValue::Null => {
+                let current = match (func, entry) {
+                    (
+                        NavigationFunction::Ewma { .. } | NavigationFunction::Integral,
+                        Entry::Occupied(entry),
+                    ) => double(entry.get().acc),
+                    _ => SqlValue::Null,
+                };
+                return Ok((current, 0));
+            }
+            SqlValue::NotNull(value) => value.unpack::<f64>()?,
+        };
+
+        match entry {
+            Entry::Vacant(entry) => {
+                let states_bytes = entry.key().1.mem_size() + std::mem::size_of::<Accumulation>();
+                let acc = match func {
+                    NavigationFunction::Ewma { .. } => value,
+                    _ => 0.0,
+                };
+                entry.insert(Accumulation {
+                    last: (rowtime, value),
+                    acc,
+                });
+                let out = match func {
+                    NavigationFunction::Ewma { .. } | NavigationFunction::Integral => double(acc),
+                    _ => SqlValue::Null,
+                };
+                Ok((out, states_bytes as i64))
+            }
+            Entry::Occupied(mut entry) => {
+                let accumulation = entry.get_mut();
+                let (last_rowtime, last_value) = accumulation.last;
+
+                if let NavigationFunction::Ewma { alpha } = func {
+                    accumulation.acc = alpha.0 * value + (1.0 - alpha.0) * accumulation.acc;
+                    accumulation.last = (rowtime, value);
+                    return Ok((double(accumulation.acc), 0));
+                }
+
+                let secs = (rowtime - last_rowtime).num_nanoseconds() as f64 / 1_000_000_000.0;
+                if secs <= 0.0 {
+                    let out = match func {
+                        NavigationFunction::Integral => double(accumulation.acc),
+                        _ => SqlValue::Null,
+                    };
+                    return Ok((out, 0));
+                }
+
+                let out = match func {
+                    NavigationFunction::Rate => {
+                        let increase = if value >= last_value {
+                            value - last_value
+                        } else {
+                            // counter reset
+                            value
+                        };
+                        double(increase / secs)
+                    }
+                    NavigationFunction::Derivative => double((value - last_value) / secs),
+                    NavigationFunction::Integral => {
+                        accumulation.acc += (last_value + value) / 2.0 * secs;
+                        double(accumulation.acc)
+                    }
+                    NavigationFunction::Lag
+                    | NavigationFunction::Lead { .. }
+                    | NavigationFunction::Ewma { .. }
+                    | NavigationFunction::ZScore { .. }
+                    | NavigationFunction::IsOutlier { .. } => unreachable!(),
+                };
+                accumulation.last = (rowtime, value);
+                Ok((out, 0))
+            }
+        }
+    }
+
+    /// # Returns
+    ///
+    /// (value of the function, gain of states bytes)
+    fn score(
+        func: NavigationFunction,
+        entry: Entry<(usize, DistinctKey), RunningStatistics>,
+        rowtime: SpringTimestamp,
+        value: SqlValue,
+    ) -> Result<(SqlValue, i64)> {
+        let value_bytes = std::mem::size_of::<(SpringTimestamp, f64)>() as i64;
+        let window = match func {
+            NavigationFunction::ZScore { window }
+            | NavigationFunction::IsOutlier { window, .. } => window,
+            _ => unreachable!(),
+        };
+
+        let value = match value {
+            SqlValue::Null => return Ok((SqlValue::Null, 0)),
+            SqlValue::NotNull(value) => value.unpack::<f64>()?,
+        };
+
+        let mut states_bytes = 0;
+        let statistics = match entry {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                states_bytes +=
+                    (entry.key().1.mem_size() + std::mem::size_of::<RunningStatistics>()) as i64;
+                entry.insert(RunningStatistics::default())
+            }
+        };
+
+        if let Some(window) = window {
+            let since = rowtime - window.to_duration();
+            while let Some(&(oldest_rowtime, oldest_value)) = statistics.values.front() {
+                if oldest_rowtime >= since {
+                    break;
+                }
+                statistics.values.pop_front();
+                statistics.remove(oldest_value);
+                states_bytes -= value_bytes;
+            }
+        }
+
+        let zscore = statistics.zscore(value);
+
+        statistics.add(value);
+        if window.is_some() {
+            statistics.values.push_back((rowtime, value));
+            states_bytes += value_bytes;
+        }
+
+        let out = match (func, zscore) {
+            (_, None) => SqlValue::Null,
+            (NavigationFunction::IsOutlier { k_sigma, .. }, Some(zscore)) => {
+                SqlValue::NotNull(NnSqlValue::Boolean(zscore.abs() > k_sigma.0))
+            }
+            (_, Some(zscore)) => SqlValue::NotNull(NnSqlValue::Double(OrderedFloat(zscore))),
+        };
+        Ok((out, states_bytes))
+    }
+
+    fn append_fields(&self, mut tuple: Tuple, values: Vec<Option<SqlValue>>) -> Tuple {
+        for (param, value) in self.params.iter().zip(values) {
+            tuple.push_field(Field::new(
+                param.derived.clone(),
+                value.expect("all values are resolved"),
+            ));
+        }
+        tuple
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{
+        expression::ValueExpr,
+        pipeline::{ColumnName, ColumnReference},
+        sql_processor::SelectFieldSyntax,
+        stream_engine::time::{SpringEventDuration, SpringTimestamp},
+    };
+
+    use super::*;
+
+    fn ts(s: &str) -> SpringTimestamp {
+        SpringTimestamp::from_str(s).unwrap()
+    }
+
+    fn derived(name: &str) -> ColumnReference {
+        ColumnReference::Derived {
+            field_name: ColumnName::new(name.to_string()),
+        }
+    }
+
+    fn amount_of(tuple: &Tuple, colref: &ColumnReference) -> Option<i16> {
+        match tuple.get_value(colref).unwrap() {
+            SqlValue::Null => None,
+            SqlValue::NotNull(v) => Some(v.unpack::<i16>().unwrap()),
+        }
+    }
+
+    #[test]
+    fn test_navigation_lag_lead() {
+        // SELECT trade.amount,
+        //   LAG(trade.amount) OVER (PARTITION BY trade.ticker),
+        //   LEAD(trade.amount, 2) OVER (PARTITION BY trade.ticker)
+        //   FROM trade;
+        let select_list = vec![SelectFieldSyntax::ValueExpr {
+            value_expr: ValueExpr::factory_colref("trade", "amount"),
+            alias: None,
+        }];
+        let (mut expr_resolver, _) = ExprResolver::new(select_list);
+        let amount =
+            expr_resolver.register_value_expr(ValueExpr::factory_colref("trade", "amount"));
+        let ticker =
+            expr_resolver.register_value_expr(ValueExpr::factory_colref("trade", "ticker"));
+
+        let mut navigation = Navigation::new(
+            vec![
+                NavigationParameter::new(
+                    NavigationFunction::Lag,
+                    amount,
+                    1,
+                    vec![ticker],
+                    derived("LAG#0"),
+                ),
+                NavigationParameter::new(
+                    NavigationFunction::Lead { window: None },
+                    amount,
+                    2,
+                    vec![ticker],
+                    derived("LEAD#1"),
+                ),
+            ],
+            None,
+        );
+
+        // returns (amount, LAG, LEAD) of emitted tuples
+        let mut dispatch = |rowtime: &str, ticker: &str, amount: i16| {
+            let tuple = Tuple::factory_trade(ts(rowtime), ticker, amount);
+            let (out, _) = navigation.dispatch(&expr_resolver, tuple).unwrap();
+            out.iter()
+                .map(|t| {
+                    (
+                        amount_of(t, &ColumnReference::fx_trade_amount()).unwrap(),
+                        amount_of(t, &derived("LAG#0")),
+                        amount_of(t, &derived("LEAD#1")),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert!(dispatch("2020-01-01 00:00:00.000000000", "ORCL", 10).is_empty());
+        assert!(dispatch("2020-01-01 00:00:01.000000000", "GOOGL", 100).is_empty());
+        assert!(dispatch("2020-01-01 00:00:02.000000000", "ORCL", 20).is_empty());
+        assert_eq!(
+            dispatch("2020-01-01 00:00:03.000000000", "ORCL", 30),
+            vec![(10, None, Some(30))]
+        );
+        assert_eq!(
+            dispatch("2020-01-01 00:00:04.000000000", "ORCL", 40),
+            vec![(20, Some(10), Some(40))]
+        );
+
+        navigation.purge();
+        assert!(navigation.pending.is_empty());
+    }
+
+    #[test]
+    fn test_navigation_lag_states_bytes() {
+        // SELECT LAG(trade.amount) OVER (PARTITION BY trade.ticker) FROM trade;
+        let (mut expr_resolver, _) = ExprResolver::new(Vec::<SelectFieldSyntax>::new());
+        let amount =
+            expr_resolver.register_value_expr(ValueExpr::factory_colref("trade", "amount"));
+        let ticker_label =
+            expr_resolver.register_value_expr(ValueExpr::factory_colref("trade", "ticker"));
+
+        let mut navigation = Navigation::new(
+            vec![NavigationParameter::new(
+                NavigationFunction::Lag,
+                amount,
+                1,
+                vec![ticker_label],
+                derived("LAG#0"),
+            )],
+            None,
+        );
+
+        let mut dispatch = |rowtime: &str, ticker: &str, amount: i16| {
+            let tuple = Tuple::factory_trade(ts(rowtime), ticker, amount);
+            let (_, window_in_flow) = navigation.dispatch(&expr_resolver, tuple).unwrap();
+            window_in_flow.window_gain_bytes_states
+        };
+
+        let partition_bytes = |ticker: &str| {
+            let key = DistinctKey::from_labels(
+                &[ticker_label],
+                &expr_resolver,
+                &Tuple::factory_trade(ts("2020-01-01 00:00:00.000000000"), ticker, 0),
+            )
+            .unwrap();
+            key.mem_size() + std::mem::size_of::<VecDeque<SqlValue>>()
+        };
+        let value_bytes = expr_resolver
+            .eval_value_expr(
+                amount,
+                &Tuple::factory_trade(ts("2020-01-01 00:00:00.000000000"), "ORCL", 0),
+            )
+            .unwrap()
+            .mem_size();
+
+        // a new partition and its value
+        assert_eq!(
+            dispatch("2020-01-01 00:00:00.000000000", "ORCL", 10),
+            (partition_bytes("ORCL") + value_bytes) as i64
+        );
+        // a value replaces the previous one
+        assert_eq!(dispatch("2020-01-01 00:00:01.000000000", "ORCL", 20), 0);
+        assert_eq!(
+            dispatch("2020-01-01 00:00:02.000000000", "GOOGL", 100),
+            (partition_bytes("GOOGL") + value_bytes) as i64
+        );
+    }
+
+    #[test]
+    fn test_navigation_repeated_lead_offsets() {
+        // SELECT
+        //   LEAD(trade.amount, 2), LEAD(trade.amount, 1), LEAD(trade.amount, 2)
+        //   FROM trade;
+        let select_list = vec![SelectFieldSyntax::ValueExpr {
+            value_expr: ValueExpr::factory_colref("trade", "amount"),
+            alias: None,
+        }];
+        let (mut expr_resolver, _) = ExprResolver::new(select_list);
+        let amount =
+            expr_resolver.register_value_expr(ValueExpr::factory_colref("trade", "amount"));
+
+        let mut navigation = Navigation::new(
+            [2, 1, 2]
+                .iter()
+                .enumerate()
+                .map(|(i, offset)| {
+                    NavigationParameter::new(
+                        NavigationFunction::Lead { window: None },
+                        amount,
+                        *offset,
+                        vec![],
+                        derived(&format!("LEAD#{}", i)),
+                    )
+                })
+                .collect(),
+            None,
+        );
+
+        // returns (amount, LEAD#0, LEAD#1, LEAD#2) of emitted tuples
+        let mut dispatch = |rowtime: &str, amount: i16| {
+            let tuple = Tuple::factory_trade(ts(rowtime), "ORCL", amount);
+            let (out, _) = navigation.dispatch(&expr_resolver, tuple).unwrap();
+            out.iter()
+                .map(|t| {
+                    (
+                        amount_of(t, &ColumnReference::fx_trade_amount()).unwrap(),
+                        amount_of(t, &derived("LEAD#0")),
+                        amount_of(t, &derived("LEAD#1")),
+                        amount_of(t, &derived("LEAD#2")),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert!(dispatch("2020-01-01 00:00:00.000000000", 10).is_empty());
+        assert!(dispatch("2020-01-01 00:00:01.000000000", 20).is_empty());
+        // fills LEAD#0 and LEAD#2 of the 1st tuple, and LEAD#1 of the 2nd tuple
+        assert_eq!(
+            dispatch("2020-01-01 00:00:02.000000000", 30),
+            vec![(10, Some(30), Some(20), Some(30))]
+        );
+        assert_eq!(
+            dispatch("2020-01-01 00:00:03.000000000", 40),
+            vec![(20, Some(40), Some(30), Some(40))]
+        );
+    }
+
+    #[test]
+    fn test_navigation_lead_window() {
+        // SELECT
+        //   LEAD(trade.amount, 1) OVER (PARTITION BY trade.ticker WINDOW DURATION_SECS(2))
+        //   FROM trade;
+        let select_list = vec![SelectFieldSyntax::ValueExpr {
+            value_expr: ValueExpr::factory_colref("trade", "amount"),
+            alias: None,
+        }];
+        let (mut expr_resolver, _) = ExprResolver::new(select_list);
+        let amount =
+            expr_resolver.register_value_expr(ValueExpr::factory_colref("trade", "amount"));
+        let ticker =
+            expr_resolver.register_value_expr(ValueExpr::factory_colref("trade", "ticker"));
+
+        let lead = |offset: usize| {
+            NavigationParameter::new(
+                NavigationFunction::Lead {
+                    window: Some(SpringEventDuration::from_secs(2)),
+                },
+                amount,
+                offset,
+                vec![ticker],
+                derived("LEAD#0"),
+            )
+        };
+
+        // returns (amount, LEAD) of emitted tuples
+        let dispatch = |navigation: &mut Navigation, rowtime: &str, ticker: &str, amount: i16| {
+            let tuple = Tuple::factory_trade(ts(rowtime), ticker, amount);
+            let (out, _) = navigation.dispatch(&expr_resolver, tuple).unwrap();
+            out.iter()
+                .map(|t| {
+                    (
+                        amount_of(t, &ColumnReference::fx_trade_amount()).unwrap(),
+                        amount_of(t, &derived("LEAD#0")),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut navigation = Navigation::new(vec![lead(1)], None);
+        let nav = &mut navigation;
+        assert!(dispatch(nav, "2020-01-01 00:00:00.000000000", "ORCL", 10).is_empty());
+        assert!(dispatch(nav, "2020-01-01 00:00:01.000000000", "GOOGL", 100).is_empty());
+        // ORCL stops receiving tuples and its tuple expires
+        assert_eq!(
+            dispatch(nav, "2020-01-01 00:00:03.000000000", "GOOGL", 200),
+            vec![(100, Some(200)), (10, None)]
+        );
+        // only the last GOOGL tuple waits
+        assert_eq!(nav.pending.len(), 1);
+        assert_eq!(nav.lead_waitings.len(), 1);
+
+        navigation.purge();
+        assert!(navigation.lead_expirations.is_empty());
+
+        // A late tuple expires in the middle of waitings, but still counts for offset.
+        let mut navigation = Navigation::new(vec![lead(2)], None);
+        let nav = &mut navigation;
+        assert!(dispatch(nav, "2020-01-01 00:00:10.000000000", "ORCL", 10).is_empty());
+        assert_eq!(
+            dispatch(nav, "2020-01-01 00:00:05.000000000", "ORCL", 20),
+            vec![(20, None)]
+        );
+        assert_eq!(
+            dispatch(nav, "2020-01-01 00:00:10.000000000", "ORCL", 30),
+            vec![(10, Some(30))]
+        );
+    }
+
+    #[test]
+    fn test_navigation_accumulations() {
+        // SELECT
+        //   EWMA(trade.amount, 0.5) OVER (PARTITION BY trade.ticker),
+        //   RATE(trade.amount) OVER (PARTITION BY trade.ticker),
+        //   DERIVATIVE(trade.amount) OVER (PARTITION BY trade.ticker),
+        //   INTEGRAL(trade.amount) OVER (PARTITION BY trade.ticker)
+        //   FROM trade;
+        let select_list = vec![SelectFieldSyntax::ValueExpr {
+            value_expr: ValueExpr::factory_colref("trade", "amount"),
+            alias: None,
+        }];
+        let (mut expr_resolver, _) = ExprResolver::new(select_list);
+        let amount =
+            expr_resolver.register_value_expr(ValueExpr::factory_colref("trade", "amount"));
+        let ticker =
+            expr_resolver.register_value_expr(ValueExpr::factory_colref("trade", "ticker"));
+
+        let funcs = [
+            NavigationFunction::Ewma {
+                alpha: OrderedFloat(0.5),
+            },
+            NavigationFunction::Rate,
+            NavigationFunction::Derivative,
+            NavigationFunction::Integral,
+        ];
+        let mut navigation = Navigation::new(
+            funcs
+                .iter()
+                .enumerate()
+                .map(|(i, func)| {
+                    NavigationParameter::new(
+                        *func,
+                        amount,
+                        1,
+                        vec![ticker],
+                        derived(&format!("{}#{}", func.name(), i)),
+                    )
+                })
+                .collect(),
+            None,
+        );
+
+        // returns (EWMA, RATE, DERIVATIVE, INTEGRAL) of an emitted tuple
+        let mut dispatch = |rowtime: &str, ticker: &str, amount: i16| {
+            let tuple = Tuple::factory_trade(ts(rowtime), ticker, amount);
+            let (out, _) = navigation.dispatch(&expr_resolver, tuple).unwrap();
+            assert_eq!(out.len(), 1);
+            let double_of = |i: usize| match out[0]
+                .get_value(&derived(&format!("{}#{}", funcs[i].name(), i)))
+                .unwrap()
+            {
+                SqlValue::Null => None,
+                SqlValue::NotNull(NnSqlValue::Double(v)) => Some(v.0),
+                SqlValue::NotNull(v) => panic!("not a DOUBLE: {:?}", v),
+            };
+            (double_of(0), double_of(1), double_of(2), double_of(3))
+        };
+
+        assert_eq!(
+            dispatch("2020-01-01 00:00:00.000000000", "ORCL", 100),
+            (Some(100.0), None, None, Some(0.0))
+        );
+        assert_eq!(
+            dispatch("2020-01-01 00:00:01.000000000", "GOOGL", 10),
+            (Some(10.0), None, None, Some(0.0))
+        );
+        assert_eq!(
+            dispatch("2020-01-01 00:00:02.000000000", "ORCL", 200),
+            (Some(150.0), Some(50.0), Some(50.0), Some(300.0))
+        );
+        // counter reset
+        assert_eq!(
+            dispatch("2020-01-01 00:00:04.000000000", "ORCL", 40),
+            (Some(95.0), Some(20.0), Some(-80.0), Some(540.0))
+        );
+        // not later than the last one
+        assert_eq!(
+            dispatch("2020-01-01 00:00:04.000000000", "ORCL", 60),
+            (Some(77.5), None, None, Some(540.0))
+        );
+
+        navigation.purge();
+        assert!(navigation.accumulations.is_empty());
+    }
+
+    #[test]
+    fn test_running_statistics() {
+        // large offset where a sum of squares loses the deviation
+        let offset = 1e9;
+        let mut statistics = RunningStatistics::default();
+        let slide_in = |statistics: &mut RunningStatistics, value: f64| {
+            statistics
+                .values
+                .push_back((ts("2020-01-01 00:00:00.000000000"), value));
+            statistics.add(value);
+        };
+        let slide_out = |statistics: &mut RunningStatistics| {
+            let (_, value) = statistics.values.pop_front().unwrap();
+            statistics.remove(value);
+        };
+
+        for value in [4.0, 7.0, 13.0, 16.0] {
+            slide_in(&mut statistics, offset + value);
+        }
+        // mean = 10, sample variance = 30
+        assert!((statistics.zscore(offset + 10.0 + 30f64.sqrt()).unwrap() - 1.0).abs() < 1e-6);
+
+        // slides out 4 and 7: mean = 14.5, sample variance = 4.5
+        slide_out(&mut statistics);
+        slide_out(&mut statistics);
+        assert!((statistics.zscore(offset + 14.5 - 4.5f64.sqrt()).unwrap() + 1.0).abs() < 1e-6);
+
+        // the same values left: no deviation
+        slide_in(&mut statistics, offset + 16.0);
+        slide_out(&mut statistics);
+        assert_eq!(statistics.zscore(offset), None);
+
+        slide_out(&mut statistics);
+        slide_out(&mut statistics);
+        assert_eq!(statistics.count, 0);
+        assert_eq!(statistics.zscore(offset), None);
+    }
+
+    #[test]
+    fn test_navigation_zscore_is_outlier() {
+        // SELECT
+        //   ZSCORE(trade.amount) OVER (PARTITION BY trade.ticker WINDOW DURATION_SECS(3)),
+        //   IS_OUTLIER(trade.amount, 2) OVER (PARTITION BY trade.ticker)
+        //   FROM trade;
+        let select_list = vec![SelectFieldSyntax::ValueExpr {
+            value_expr: ValueExpr::factory_colref("trade", "amount"),
+            alias: None,
+        }];
+        let (mut expr_resolver, _) = ExprResolver::new(select_list);
+        let amount =
+            expr_resolver.register_value_expr(ValueExpr::factory_colref("trade", "amount"));
+        let ticker =
+            expr_resolver.register_value_expr(ValueExpr::factory_colref("trade", "ticker"));
+
+        let mut navigation = Navigation::new(
+            vec![
+                NavigationParameter::new(
+                    NavigationFunction::ZScore {
+                        window: Some(SpringEventDuration::from_secs(3)),
+                    },
+                    amount,
+                    1,
+                    vec![ticker],
+                    derived("ZSCORE#0"),
+                ),
+                NavigationParameter::new(
+                    NavigationFunction::IsOutlier {
+                        k_sigma: OrderedFloat(2.0),
+                        window: None,
+                    },
+                    amount,
+                    1,
+                    vec![ticker],
+                    derived("IS_OUTLIER#1"),
+                ),
+            ],
+            None,
+        );
+
+        // returns (ZSCORE rounded to 3 decimal places, IS_OUTLIER) of an emitted tuple
+        let mut dispatch = |rowtime: &str, ticker: &str, amount: i16| {
+            let tuple = Tuple::factory_trade(ts(rowtime), ticker, amount);
+            let (out, _) = navigation.dispatch(&expr_resolver, tuple).unwrap();
+            assert_eq!(out.len(), 1);
+            let zscore = match out[0].get_value(&derived("ZSCORE#0")).unwrap() {
+                SqlValue::Null => None,
+                SqlValue::NotNull(NnSqlValue::Double(v)) => Some((v.0 * 1000.0).round() / 1000.0),
+                SqlValue::NotNull(v) => panic!("not a DOUBLE: {:?}", v),
+            };
+            let is_outlier = match out[0].get_value(&derived("IS_OUTLIER#1")).unwrap() {
+                SqlValue::Null => None,
+                SqlValue::NotNull(v) => Some(v.unpack::<bool>().unwrap()),
+            };
+            (zscore, is_outlier)
+        };
+
+        assert_eq!(
+            dispatch("2020-01-01 00:00:00.000000000", "ORCL", 10),
+            (None, None)
+        );
+        assert_eq!(
+            dispatch("2020-01-01 00:00:01.000000000", "GOOGL", 100),
+            (None, None)
+        );
+        assert_eq!(
+            dispatch("2020-01-01 00:00:01.000000000", "ORCL", 12),
+            (None, None)
+        );
+        // mean: 11, standard deviation: sqrt(2)
+        assert_eq!(
+            dispatch("2020-01-01 00:00:02.000000000", "ORCL", 14),
+            (Some(2.121), Some(true))
+        );
+        // mean: 12, standard deviation: 2
+        assert_eq!(
+            dispatch("2020-01-01 00:00:03.000000000", "ORCL", 11),
+            (Some(-0.5), Some(false))
+        );
+        // 10 and 12 are out of the window of ZSCORE
+        assert_eq!(
+            dispatch("2020-01-01 00:00:05.000000000", "ORCL", 30),
+            (Some(8.25), Some(true))
+        );
+
+        navigation.purge();
+        assert!(navigation.statistics.is_empty());
+    }
+}
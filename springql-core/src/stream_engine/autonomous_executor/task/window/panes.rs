@@ -2,7 +2,10 @@
 
 mod pane;
 
-pub use pane::{AggrPane, AggrPaneInner, GroupByValues, JoinDir, JoinPane, Pane};
+pub use pane::{
+    AggrPane, AggrPaneInner, AvgState, DistinctKey, DistinctPane, GroupByValues, JoinDir, JoinPane,
+    Pane,
+};
 
 use std::cmp::Ordering;
 
@@ -79,6 +82,10 @@ where
         self.panes.clear()
     }
 
+    pub fn window_param(&self) -> &WindowParameter {
+        &self.window_param
+    }
+
     fn generate_panes_if_not_exist(&mut self, rowtime: SpringTimestamp) -> Result<(), SpringError> {
         // Sort-Merge Join like algorithm
         let mut pane_idx = 0;
@@ -86,7 +93,12 @@ where
             loop {
                 if pane_idx < self.panes.len() {
                     match open_at.cmp(&self.panes[pane_idx].open_at()) {
-                        Ordering::Less => unreachable!("watermark must kick this rowtime"),
+                        Ordering::Less => {
+                            // An input behind the others can bring a rowtime earlier than existing panes
+                            // since the watermark is aligned to the minimum across inputs.
+                            self.panes.insert(pane_idx, self.generate_pane(open_at));
+                            break; // next open_at
+                        }
                         Ordering::Equal => {
                             // Pane already exists.
                             break; // next open_at
@@ -147,7 +159,7 @@ mod tests {
     use crate::{
         expr_resolver::{ExprLabel, ExprResolver},
         expression::{AggrExpr, ValueExpr},
-        pipeline::{AggregateFunctionParameter, AggregateParameter, GroupByLabels},
+        pipeline::{AggregateFunctionParameter, AggregateParameter, GroupByLabels, WindowTime},
         sql_processor::SelectFieldSyntax,
         stream_engine::{
             autonomous_executor::task::window::panes::pane::AggrPane,
@@ -182,6 +194,7 @@ mod tests {
                 unreachable!()
             },
             group_by: group_by_labels,
+            top_n: None,
         })
     }
 
@@ -196,6 +209,8 @@ mod tests {
                     length,
                     period,
                     allowed_delay: SpringEventDuration::from_secs(0),
+                    idle_timeout: None,
+                    window_time: WindowTime::Rowtime,
                 },
                 dont_care_window_operation_parameter(),
             )
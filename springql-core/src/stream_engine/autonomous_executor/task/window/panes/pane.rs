@@ -1,9 +1,12 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
 mod aggregate_pane;
+mod distinct_pane;
 mod join_pane;
+mod top_n;
 
-pub use aggregate_pane::{AggrPane, AggrPaneInner, GroupByValues};
+pub use aggregate_pane::{AggrPane, AggrPaneInner, AvgState, GroupByValues};
+pub use distinct_pane::{DistinctKey, DistinctPane};
 pub use join_pane::{JoinDir, JoinPane};
 
 use crate::{
@@ -2,13 +2,15 @@
 
 mod aggregate_state;
 
+pub use aggregate_state::AvgState;
+
 use std::collections::HashMap;
 
 use ordered_float::OrderedFloat;
 
 use crate::{
     api::error::Result,
-    expr_resolver::ExprResolver,
+    expr_resolver::{ExprLabel, ExprResolver},
     pipeline::{
         AggregateFunctionParameter, AggregateParameter, GroupByLabels, WindowOperationParameter,
     },
@@ -19,7 +21,10 @@ use crate::{
                 tuple::Tuple,
                 window::{
                     aggregate::AggregatedAndGroupingValues,
-                    panes::pane::{aggregate_pane::aggregate_state::AvgState, Pane},
+                    panes::pane::{
+                        top_n::{select_top_n, TopN},
+                        Pane,
+                    },
                 },
             },
         },
@@ -36,6 +41,10 @@ pub struct AggrPane {
     aggregate_parameter: AggregateParameter,
 
     inner: AggrPaneInner,
+
+    /// Groups ranked by a GROUP BY sort key while dispatching, so that groups out of the TOP N are not kept.
+    /// `None` without TOP N or with an aggregate sort key, whose value is only fixed on close.
+    top_groups: Option<TopN<GroupByValues>>,
 }
 
 impl Pane for AggrPane {
@@ -57,11 +66,18 @@ impl Pane for AggrPane {
                 },
             };
 
+            let top_groups = aggregate_parameter
+                .top_n
+                .as_ref()
+                .filter(|top_n| matches!(top_n.sort_key, ExprLabel::Value(_)))
+                .map(TopN::new);
+
             Self {
                 open_at,
                 close_at,
                 aggregate_parameter,
                 inner,
+                top_groups,
             }
         } else {
             panic!("op_param {:?} is not GroupAggregateParameter", op_param)
@@ -89,6 +105,10 @@ impl Pane for AggrPane {
         )
         .expect("TODO handle Result");
 
+        if !self.admit_group(expr_resolver, tuple, &group_by_values) {
+            return WindowInFlowByWindowTask::zero();
+        }
+
         let aggregated_value = expr_resolver
             .eval_aggr_expr_inner(self.aggregate_parameter.aggr_expr, tuple)
             .expect("TODO Result");
@@ -121,12 +141,12 @@ impl Pane for AggrPane {
     ) -> (Vec<Self::CloseOut>, WindowInFlowByWindowTask) {
         let aggr_label = self.aggregate_parameter.aggr_expr;
         let group_by_labels = self.aggregate_parameter.group_by;
+        let top_n = self.aggregate_parameter.top_n;
 
         match self.inner {
             AggrPaneInner::Avg { states } => {
-                let aggregated_and_grouping_values_seq = states
-                    .into_iter()
-                    .map(|(group_by_values, state)| {
+                let aggregated_and_grouping_values_seq =
+                    states.into_iter().map(|(group_by_values, state)| {
                         let aggr_value =
                             SqlValue::NotNull(NnSqlValue::Float(OrderedFloat(state.finalize())));
 
@@ -138,8 +158,20 @@ impl Pane for AggrPane {
                             .collect();
 
                         AggregatedAndGroupingValues::new(vec![(aggr_label, aggr_value)], group_bys)
+                    });
+
+                let aggregated_and_grouping_values_seq = match top_n {
+                    Some(top_n) => select_top_n(aggregated_and_grouping_values_seq, &top_n, |v| {
+                        match top_n.sort_key {
+                            ExprLabel::Aggr(label) => v.get_aggregated_value(&label).cloned(),
+                            ExprLabel::Value(label) => v.get_group_by_value(&label).cloned(),
+                        }
                     })
-                    .collect();
+                    .expect(
+                        "sort key must be an aggregate or GROUP BY element (checked on planning)",
+                    ),
+                    None => aggregated_and_grouping_values_seq.collect(),
+                };
 
                 (
                     aggregated_and_grouping_values_seq,
@@ -150,6 +182,60 @@ impl Pane for AggrPane {
     }
 }
 
+impl AggrPane {
+    /// Ranks a new group by its GROUP BY sort key and drops the group pushed out of the TOP N.
+    /// Returns false if the tuple's group is out of the TOP N.
+    ///
+    /// A dropped group never comes back, because the groups in the ranking only get better.
+    fn admit_group(
+        &mut self,
+        expr_resolver: &ExprResolver,
+        tuple: &Tuple,
+        group_by_values: &GroupByValues,
+    ) -> bool {
+        let (top_groups, sort_label) = match (&mut self.top_groups, &self.aggregate_parameter.top_n)
+        {
+            (Some(top_groups), Some(top_n)) => match top_n.sort_key {
+                ExprLabel::Value(label) => (top_groups, label),
+                ExprLabel::Aggr(_) => unreachable!("aggregate sort key is not ranked on dispatch"),
+            },
+            _ => return true,
+        };
+
+        let states = match &mut self.inner {
+            AggrPaneInner::Avg { states } => states,
+        };
+        if states.contains_key(group_by_values) {
+            return true;
+        }
+
+        let sort_value = expr_resolver
+            .eval_value_expr(sort_label, tuple)
+            .expect("TODO Result");
+        match top_groups
+            .push(sort_value, group_by_values.clone())
+            .expect("TODO Result")
+        {
+            Some(dropped) if &dropped == group_by_values => false,
+            Some(dropped) => {
+                states.remove(&dropped);
+                true
+            }
+            None => true,
+        }
+    }
+
+    /// Finalized aggregated value of each group, without TOP N applied.
+    pub fn into_aggregates(self) -> Vec<(GroupByValues, f32)> {
+        match self.inner {
+            AggrPaneInner::Avg { states } => states
+                .into_iter()
+                .map(|(group_by_values, state)| (group_by_values, state.finalize()))
+                .collect(),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum AggrPaneInner {
     Avg {
@@ -165,7 +251,7 @@ pub struct GroupByValues(
 
 impl GroupByValues {
     /// Order of elements in GROUP BY clause is preserved.
-    fn from_group_by_labels(
+    pub fn from_group_by_labels(
         group_by_labels: GroupByLabels,
         expr_resolver: &ExprResolver,
         tuple: &Tuple,
@@ -20,9 +20,14 @@ impl AvgState {
         self.current_n = next_n;
     }
 
-    pub fn finalize(self) -> f32 {
+    /// Average of values so far. Same as `finalize()` but keeps the state.
+    pub fn current(&self) -> f32 {
         self.current_avg.round()
     }
+
+    pub fn finalize(self) -> f32 {
+        self.current()
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,125 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::collections::HashSet;
+
+use crate::{
+    api::error::Result,
+    expr_resolver::{ExprResolver, ValueExprLabel},
+    mem_size::MemSize,
+    pipeline::{DistinctParameter, WindowOperationParameter},
+    stream_engine::{
+        autonomous_executor::{
+            performance_metrics::WindowInFlowByWindowTask,
+            task::{tuple::Tuple, window::panes::pane::Pane},
+            SqlValueHashKey,
+        },
+        time::SpringTimestamp,
+    },
+};
+
+#[derive(Debug)]
+pub struct DistinctPane {
+    open_at: SpringTimestamp,
+    close_at: SpringTimestamp,
+
+    distinct_parameter: DistinctParameter,
+
+    keys: HashSet<DistinctKey>,
+    /// The first tuple of each key, in arrival order.
+    tuples: Vec<Tuple>,
+}
+
+impl Pane for DistinctPane {
+    type CloseOut = Tuple;
+    type DispatchArg = ();
+
+    /// # Panics
+    ///
+    /// if `op_param` is not `DistinctParameter`
+    fn new(
+        open_at: SpringTimestamp,
+        close_at: SpringTimestamp,
+        op_param: WindowOperationParameter,
+    ) -> Self {
+        let distinct_parameter = if let WindowOperationParameter::Distinct(p) = op_param {
+            p
+        } else {
+            panic!("op_param {:?} is not DistinctParameter", op_param)
+        };
+
+        Self {
+            open_at,
+            close_at,
+            distinct_parameter,
+            keys: HashSet::new(),
+            tuples: Vec::new(),
+        }
+    }
+
+    fn open_at(&self) -> SpringTimestamp {
+        self.open_at
+    }
+
+    fn close_at(&self) -> SpringTimestamp {
+        self.close_at
+    }
+
+    fn dispatch(
+        &mut self,
+        expr_resolver: &ExprResolver,
+        tuple: &Tuple,
+        _arg: (),
+    ) -> WindowInFlowByWindowTask {
+        let key = DistinctKey::from_labels(&self.distinct_parameter.keys, expr_resolver, tuple)
+            .expect("TODO handle Result");
+
+        let key_size = key.mem_size() as i64;
+        if self.keys.insert(key) {
+            self.tuples.push(tuple.clone());
+            WindowInFlowByWindowTask::new(key_size, tuple.mem_size() as i64)
+        } else {
+            WindowInFlowByWindowTask::zero()
+        }
+    }
+
+    fn close(
+        self,
+        _expr_resolver: &ExprResolver,
+    ) -> (Vec<Self::CloseOut>, WindowInFlowByWindowTask) {
+        let keys_size = self.keys.iter().map(|k| k.mem_size()).sum::<usize>();
+        let tuples_size = self.tuples.iter().map(|t| t.mem_size()).sum::<usize>();
+
+        (
+            self.tuples,
+            WindowInFlowByWindowTask::new(-(keys_size as i64), -(tuples_size as i64)),
+        )
+    }
+}
+
+/// Hash keys of values evaluated from a tuple. Tuples with the same `DistinctKey` are regarded as duplicates.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct DistinctKey(Vec<SqlValueHashKey>);
+
+impl DistinctKey {
+    /// Order of `labels` is preserved.
+    pub fn from_labels(
+        labels: &[ValueExprLabel],
+        expr_resolver: &ExprResolver,
+        tuple: &Tuple,
+    ) -> Result<Self> {
+        let hash_keys = labels
+            .iter()
+            .map(|label| {
+                let value = expr_resolver.eval_value_expr(*label, tuple)?;
+                Ok(SqlValueHashKey::from(&value))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self(hash_keys))
+    }
+}
+
+impl MemSize for DistinctKey {
+    fn mem_size(&self) -> usize {
+        self.0.len() * std::mem::size_of::<SqlValueHashKey>()
+    }
+}
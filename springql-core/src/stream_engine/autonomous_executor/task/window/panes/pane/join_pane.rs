@@ -3,13 +3,16 @@
 use std::str::FromStr;
 
 use crate::{
-    expr_resolver::ExprResolver,
+    expr_resolver::{ExprLabel, ExprResolver},
     mem_size::MemSize,
     pipeline::{Field, JoinParameter, JoinType, WindowOperationParameter},
     stream_engine::{
         autonomous_executor::{
             performance_metrics::WindowInFlowByWindowTask,
-            task::{tuple::Tuple, window::panes::pane::Pane},
+            task::{
+                tuple::Tuple,
+                window::panes::pane::{top_n::TopN, Pane},
+            },
         },
         time::SpringTimestamp,
         RowTime, SqlValue,
@@ -99,7 +102,28 @@ impl JoinPane {
     ) -> (Vec<Tuple>, WindowInFlowByWindowTask) {
         let window_in_flow = self.calc_window_in_flow_on_close();
 
-        let null_right = self.null_right_tuple();
+        let null_right = Self::null_right_tuple(&self.join_parameter);
+
+        // Joined tuples are ranked as they are made, not to hold all of them with TOP N.
+        let mut top_tuples = self.join_parameter.top_n.as_ref().map(TopN::new);
+        let mut collect = |tuples: Vec<Tuple>, res_tuples: &mut Vec<Tuple>| match (
+            &mut top_tuples,
+            &self.join_parameter.top_n,
+        ) {
+            (Some(top_tuples), Some(top_n)) => {
+                for tuple in tuples {
+                    let sort_value = match top_n.sort_key {
+                        ExprLabel::Value(label) => expr_resolver.eval_value_expr(label, &tuple),
+                        ExprLabel::Aggr(_) => {
+                            unreachable!("aggregate sort key is for aggregation window")
+                        }
+                    }
+                    .expect("TODO Result");
+                    top_tuples.push(sort_value, tuple).expect("TODO Result");
+                }
+            }
+            _ => res_tuples.extend(tuples),
+        };
 
         // using Nested Loop Join.
         let mut res_tuples = Vec::new();
@@ -126,7 +150,11 @@ impl JoinPane {
                 joined_to_the_left.push(joined_tuple);
             }
 
-            res_tuples.extend(joined_to_the_left);
+            collect(joined_to_the_left, &mut res_tuples);
+        }
+
+        if let Some(top_tuples) = top_tuples {
+            res_tuples = top_tuples.into_sorted_vec();
         }
 
         (res_tuples, window_in_flow)
@@ -143,13 +171,13 @@ impl JoinPane {
         WindowInFlowByWindowTask::new(0, -((left_size + right_size) as i64))
     }
 
-    fn null_right_tuple(&self) -> Tuple {
+    /// A tuple with NULL values for all the right columns, to be joined to unmatched left tuples.
+    pub fn null_right_tuple(join_parameter: &JoinParameter) -> Tuple {
         // unused
         let rowtime =
             RowTime::EventTime(SpringTimestamp::from_str("1970-01-01 00:00:00.000000000").unwrap());
 
-        let fields = self
-            .join_parameter
+        let fields = join_parameter
             .right_colrefs
             .iter()
             .map(|colref| Field::new(colref.clone(), SqlValue::Null))
@@ -0,0 +1,173 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::cmp::Ordering;
+
+use crate::{
+    api::error::Result,
+    pipeline::{SortOrder, TopNParameter},
+    stream_engine::{SqlCompareResult, SqlValue},
+};
+
+/// Selects the top `top_n.limit` items ordered by `sort_key`, best first.
+///
+/// Items are fed into a [TopN] so that only `limit` items are kept at a time.
+///
+/// # Failures
+///
+/// Error from `sort_key` or from comparing sort keys.
+pub fn select_top_n<T>(
+    items: impl IntoIterator<Item = T>,
+    top_n: &TopNParameter,
+    sort_key: impl Fn(&T) -> Result<SqlValue>,
+) -> Result<Vec<T>> {
+    let mut ranking = TopN::new(top_n);
+    for item in items {
+        let key = sort_key(&item)?;
+        ranking.push(key, item)?;
+    }
+    Ok(ranking.into_sorted_vec())
+}
+
+/// Bounded ranking which keeps at most `limit` items, best first.
+///
+/// NULL sort keys come last in both ASC and DESC; ties keep the push order.
+#[derive(Debug)]
+pub struct TopN<T> {
+    limit: usize,
+    order: SortOrder,
+
+    /// Sorted best first. `len() <= limit`.
+    ranked: Vec<(SqlValue, T)>,
+}
+
+impl<T> TopN<T> {
+    pub fn new(top_n: &TopNParameter) -> Self {
+        Self {
+            limit: top_n.limit,
+            order: top_n.order,
+            ranked: Vec::with_capacity(top_n.limit + 1),
+        }
+    }
+
+    /// Pushes an item and returns the item dropped from the ranking, if any.
+    /// The dropped one is either the worst item so far or the pushed item itself.
+    ///
+    /// # Failures
+    ///
+    /// - `SpringError::Sql` when:
+    ///   - `key` is not comparable to the sort keys in the ranking.
+    pub fn push(&mut self, key: SqlValue, item: T) -> Result<Option<T>> {
+        // first position whose item is strictly worse than the new one
+        let (mut lo, mut hi) = (0, self.ranked.len());
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if self.cmp_key(&self.ranked[mid].0, &key)? == Ordering::Greater {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+
+        if lo >= self.limit {
+            return Ok(Some(item));
+        }
+        self.ranked.insert(lo, (key, item));
+        if self.ranked.len() > self.limit {
+            Ok(self.ranked.pop().map(|(_, item)| item))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        self.ranked.into_iter().map(|(_, item)| item).collect()
+    }
+
+    /// Greater is worse.
+    fn cmp_key(&self, k1: &SqlValue, k2: &SqlValue) -> Result<Ordering> {
+        let ord = match (k1, k2) {
+            (SqlValue::Null, SqlValue::Null) => Ordering::Equal,
+            (SqlValue::Null, _) => Ordering::Greater,
+            (_, SqlValue::Null) => Ordering::Less,
+            (k1, k2) => {
+                let ord = match k1.sql_compare(k2)? {
+                    SqlCompareResult::LessThan => Ordering::Less,
+                    SqlCompareResult::GreaterThan => Ordering::Greater,
+                    SqlCompareResult::Eq | SqlCompareResult::Null => Ordering::Equal,
+                };
+                match self.order {
+                    SortOrder::Asc => ord,
+                    SortOrder::Desc => ord.reverse(),
+                }
+            }
+        };
+        Ok(ord)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        expr_resolver::{ExprLabel, ExprLabelGenerator},
+        stream_engine::NnSqlValue,
+    };
+
+    use super::*;
+
+    fn top_n(order: SortOrder, limit: usize, values: Vec<Option<i32>>) -> Vec<Option<i32>> {
+        let sort_key = ExprLabel::Value(ExprLabelGenerator::default().next_value());
+        let top_n = TopNParameter::new(sort_key, order, limit);
+
+        select_top_n(values, &top_n, |v| {
+            Ok(v.map_or(SqlValue::Null, |v| {
+                SqlValue::NotNull(NnSqlValue::Integer(v))
+            }))
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_select_top_n() {
+        let values = vec![Some(3), None, Some(5), Some(1), Some(5), Some(4)];
+
+        assert_eq!(
+            top_n(SortOrder::Desc, 3, values.clone()),
+            vec![Some(5), Some(5), Some(4)]
+        );
+        assert_eq!(
+            top_n(SortOrder::Asc, 2, values.clone()),
+            vec![Some(1), Some(3)]
+        );
+        assert_eq!(
+            top_n(SortOrder::Asc, 10, values.clone()),
+            vec![Some(1), Some(3), Some(4), Some(5), Some(5), None]
+        );
+        assert!(top_n(SortOrder::Asc, 0, values).is_empty());
+    }
+
+    #[test]
+    fn test_top_n_push_returns_dropped() {
+        let sort_key = ExprLabel::Value(ExprLabelGenerator::default().next_value());
+        let mut ranking = TopN::new(&TopNParameter::new(sort_key, SortOrder::Desc, 2));
+        let int = |v| SqlValue::NotNull(NnSqlValue::Integer(v));
+
+        assert_eq!(ranking.push(int(1), "a").unwrap(), None);
+        assert_eq!(ranking.push(int(3), "b").unwrap(), None);
+        assert_eq!(ranking.push(int(2), "c").unwrap(), Some("a"));
+        assert_eq!(ranking.push(int(2), "d").unwrap(), Some("d"));
+        assert_eq!(ranking.into_sorted_vec(), vec!["b", "c"]);
+    }
+
+    #[test]
+    fn test_top_n_incomparable_keys() {
+        let sort_key = ExprLabel::Value(ExprLabelGenerator::default().next_value());
+        let mut ranking = TopN::new(&TopNParameter::new(sort_key, SortOrder::Asc, 2));
+
+        ranking
+            .push(SqlValue::NotNull(NnSqlValue::Integer(1)), ())
+            .unwrap();
+        assert!(ranking
+            .push(SqlValue::NotNull(NnSqlValue::Boolean(true)), ())
+            .is_err());
+    }
+}
@@ -0,0 +1,579 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+
+use ordered_float::OrderedFloat;
+
+use crate::{
+    expr_resolver::ValueExprLabel,
+    mem_size::{chrono_naive_date_time_overhead_size, MemSize},
+    pipeline::{AggregateParameter, ResampleFill},
+    stream_engine::{
+        autonomous_executor::{
+            performance_metrics::WindowInFlowByWindowTask,
+            task::window::{
+                aggregate::AggregatedAndGroupingValues,
+                panes::{AggrPane, GroupByValues, Pane},
+            },
+        },
+        time::SpringTimestamp,
+        NnSqlValue, SqlValue,
+    },
+    time::Duration,
+};
+
+/// Gap filler of `RESAMPLE EVERY` window.
+///
+/// Closed panes of the fixed window are given in order. For every interval (including ones without any pane),
+/// it emits a row for each group seen so far; an aggregated value for a group with tuples in the interval,
+/// or a filled value for a group without them.
+///
+/// A group starts to be resampled from the interval it is first seen. With `within`, a group is evicted when
+/// the watermark passes `within` after its last interval without any tuple of the group (pending gaps of FILL LINEAR are NULL then).
+/// Otherwise groups are never forgotten until purged.
+#[derive(Debug)]
+pub struct Resampler {
+    every: Duration,
+    fill: ResampleFill,
+    within: Option<Duration>,
+    aggregate_parameter: AggregateParameter,
+    resample_time: Option<ValueExprLabel>,
+
+    /// Start of the next interval to emit. None until the first pane closes.
+    next_open_at: Option<SpringTimestamp>,
+    /// group -> series
+    series: HashMap<GroupByValues, Series>,
+}
+
+#[derive(Debug)]
+struct Series {
+    /// Start of the last interval with an aggregated value, and the value.
+    last: (SpringTimestamp, f64),
+    /// Starts of intervals waiting for the next aggregated value to interpolate (only for FILL LINEAR).
+    gaps: Vec<SpringTimestamp>,
+}
+
+impl Resampler {
+    pub fn new(
+        every: Duration,
+        fill: ResampleFill,
+        within: Option<Duration>,
+        aggregate_parameter: AggregateParameter,
+        resample_time: Option<ValueExprLabel>,
+    ) -> Self {
+        Self {
+            every,
+            fill,
+            within,
+            aggregate_parameter,
+            resample_time,
+            next_open_at: None,
+            series: HashMap::new(),
+        }
+    }
+
+    /// # Parameters
+    ///
+    /// - `panes`: closed panes sorted by `Pane::open_at`.
+    /// - `watermark`: intervals closed by this watermark are emitted even if they do not have panes.
+    pub fn resample(
+        &mut self,
+        panes: Vec<AggrPane>,
+        watermark: SpringTimestamp,
+    ) -> (Vec<AggregatedAndGroupingValues>, WindowInFlowByWindowTask) {
+        let mut out = Vec::new();
+        let mut states_bytes = 0;
+
+        for pane in panes {
+            let open_at = pane.open_at();
+            states_bytes += self.fill_intervals_closed_by(open_at, &mut out);
+            states_bytes += self.emit_interval(open_at, pane.into_aggregates(), &mut out);
+        }
+        states_bytes += self.fill_intervals_closed_by(watermark, &mut out);
+
+        (out, WindowInFlowByWindowTask::new(states_bytes, 0))
+    }
+
+    pub fn purge(&mut self) {
+        self.next_open_at = None;
+        self.series.clear();
+    }
+
+    /// Fills intervals without panes, which close at or before `close_at`.
+    ///
+    /// # Returns
+    ///
+    /// Gain of states bytes.
+    fn fill_intervals_closed_by(
+        &mut self,
+        close_at: SpringTimestamp,
+        out: &mut Vec<AggregatedAndGroupingValues>,
+    ) -> i64 {
+        let mut states_bytes = 0;
+        while let Some(open_at) = self.next_open_at {
+            if open_at + self.every > close_at {
+                break;
+            }
+            let groups = self.series.keys().cloned().collect::<Vec<_>>();
+            for group_by_values in groups {
+                states_bytes += self.fill(group_by_values, open_at, out);
+            }
+            self.next_open_at = Some(open_at + self.every);
+        }
+        states_bytes
+    }
+
+    /// # Returns
+    ///
+    /// Gain of states bytes.
+    fn emit_interval(
+        &mut self,
+        open_at: SpringTimestamp,
+        aggregates: Vec<(GroupByValues, f32)>,
+        out: &mut Vec<AggregatedAndGroupingValues>,
+    ) -> i64 {
+        let mut states_bytes = 0;
+
+        let mut seen = HashSet::new();
+        for (group_by_values, value) in aggregates {
+            let value = value as f64;
+            let interpolated = match self.series.entry(group_by_values.clone()) {
+                Entry::Vacant(entry) => {
+                    states_bytes += series_size(entry.key()) as i64;
+                    entry.insert(Series {
+                        last: (open_at, value),
+                        gaps: Vec::new(),
+                    });
+                    Vec::new()
+                }
+                Entry::Occupied(mut entry) => {
+                    let series = entry.get_mut();
+                    let last = series.last;
+                    series.last = (open_at, value);
+                    std::mem::take(&mut series.gaps)
+                        .into_iter()
+                        .map(|gap| (gap, interpolate(last, (open_at, value), gap)))
+                        .collect()
+                }
+            };
+            for (gap, interpolated_value) in interpolated {
+                states_bytes -= chrono_naive_date_time_overhead_size() as i64;
+                out.push(self.row(group_by_values.clone(), gap, float(interpolated_value)));
+            }
+            out.push(self.row(group_by_values.clone(), open_at, float(value)));
+            seen.insert(group_by_values);
+        }
+
+        let missing = self
+            .series
+            .keys()
+            .filter(|group_by_values| !seen.contains(*group_by_values))
+            .cloned()
+            .collect::<Vec<_>>();
+        for group_by_values in missing {
+            states_bytes += self.fill(group_by_values, open_at, out);
+        }
+
+        self.next_open_at = Some(open_at + self.every);
+        states_bytes
+    }
+
+    /// Fills the interval starting at `open_at` for a group without tuples in it, or evicts the group if it has been
+    /// without tuples for `within`.
+    ///
+    /// # Returns
+    ///
+    /// Gain of states bytes.
+    fn fill(
+        &mut self,
+        group_by_values: GroupByValues,
+        open_at: SpringTimestamp,
+        out: &mut Vec<AggregatedAndGroupingValues>,
+    ) -> i64 {
+        let (last_open_at, _) = self.series[&group_by_values].last;
+        if self.within.map_or(false, |within| {
+            open_at >= last_open_at + self.every + within
+        }) {
+            return self.evict(group_by_values, out);
+        }
+
+        match self.fill {
+            ResampleFill::Null => {
+                out.push(self.row(group_by_values, open_at, SqlValue::Null));
+                0
+            }
+            ResampleFill::Previous => {
+                let (_, last_value) = self.series[&group_by_values].last;
+                out.push(self.row(group_by_values, open_at, float(last_value)));
+                0
+            }
+            ResampleFill::Linear => {
+                self.series
+                    .get_mut(&group_by_values)
+                    .expect("only groups seen so far are filled")
+                    .gaps
+                    .push(open_at);
+                chrono_naive_date_time_overhead_size() as i64
+            }
+        }
+    }
+
+    /// Forgets a group. Gaps waiting for interpolation are NULL since no value to interpolate comes.
+    ///
+    /// # Returns
+    ///
+    /// Gain of states bytes.
+    fn evict(
+        &mut self,
+        group_by_values: GroupByValues,
+        out: &mut Vec<AggregatedAndGroupingValues>,
+    ) -> i64 {
+        let series = self
+            .series
+            .remove(&group_by_values)
+            .expect("only groups seen so far are evicted");
+        let states_bytes = series_size(&group_by_values)
+            + series.gaps.len() * chrono_naive_date_time_overhead_size();
+        for gap in series.gaps {
+            out.push(self.row(group_by_values.clone(), gap, SqlValue::Null));
+        }
+        -(states_bytes as i64)
+    }
+
+    fn row(
+        &self,
+        group_by_values: GroupByValues,
+        open_at: SpringTimestamp,
+        aggregated_value: SqlValue,
+    ) -> AggregatedAndGroupingValues {
+        let mut group_bys = self
+            .aggregate_parameter
+            .group_by
+            .as_labels()
+            .iter()
+            .cloned()
+            .zip(group_by_values.into_sql_values())
+            .collect::<Vec<_>>();
+        if let Some(label) = self.resample_time {
+            group_bys.push((label, SqlValue::NotNull(NnSqlValue::Timestamp(open_at))));
+        }
+
+        AggregatedAndGroupingValues::new(
+            vec![(self.aggregate_parameter.aggr_expr, aggregated_value)],
+            group_bys,
+        )
+    }
+}
+
+/// Linear interpolation at `at` between `(t0, v0)` and `(t1, v1)`.
+fn interpolate(
+    (t0, v0): (SpringTimestamp, f64),
+    (t1, v1): (SpringTimestamp, f64),
+    at: SpringTimestamp,
+) -> f64 {
+    let ratio = (at - t0).num_nanoseconds() as f64 / (t1 - t0).num_nanoseconds() as f64;
+    v0 + (v1 - v0) * ratio
+}
+
+/// Aggregated value of AVG, which is FLOAT.
+fn float(value: f64) -> SqlValue {
+    SqlValue::NotNull(NnSqlValue::Float(OrderedFloat(value as f32)))
+}
+
+fn series_size(group_by_values: &GroupByValues) -> usize {
+    group_by_values
+        .clone()
+        .into_sql_values()
+        .iter()
+        .map(|v| v.mem_size())
+        .sum::<usize>()
+        + std::mem::size_of::<Series>()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{
+        expr_resolver::{ExprLabel, ExprResolver},
+        expression::{AggrExpr, ValueExpr},
+        pipeline::{
+            AggrAlias, AggregateFunctionParameter, ColumnName, GroupByLabels, StreamName,
+            WindowOperationParameter, WindowParameter, WindowTime,
+        },
+        sql_processor::SelectFieldSyntax,
+        stream_engine::{
+            autonomous_executor::task::window::{AggrWindow, Window},
+            time::{SpringDuration, SpringEventDuration},
+            Tuple,
+        },
+    };
+
+    use super::*;
+
+    fn ts(s: &str) -> SpringTimestamp {
+        SpringTimestamp::from_str(s).unwrap()
+    }
+
+    /// Dispatches (rowtime, ticker, amount) to
+    ///
+    /// ```sql
+    /// SELECT RESAMPLE_TIME, ticker, AVG(amount) AS avg_amount
+    ///   FROM trade
+    ///   GROUP BY ticker
+    ///   RESAMPLE EVERY DURATION_SECS(10), DURATION_SECS(0) FILL <fill> [WITHIN <within>];
+    /// ```
+    ///
+    /// and returns sorted (RESAMPLE_TIME, ticker, avg_amount) for each input.
+    #[allow(clippy::type_complexity)]
+    fn resample(
+        fill: ResampleFill,
+        within: Option<SpringEventDuration>,
+        input: &[(&str, &str, i16)],
+    ) -> Vec<Vec<(SpringTimestamp, String, Option<i16>)>> {
+        let ticker_expr = ValueExpr::factory_colref(
+            StreamName::fx_trade().as_ref(),
+            ColumnName::fx_ticker().as_ref(),
+        );
+        let avg_amount_expr = AggrExpr {
+            func: AggregateFunctionParameter::Avg,
+            aggregated: ValueExpr::factory_colref(
+                StreamName::fx_trade().as_ref(),
+                ColumnName::fx_amount().as_ref(),
+            ),
+        };
+        let select_list = vec![
+            SelectFieldSyntax::ValueExpr {
+                value_expr: ValueExpr::ResampleTime,
+                alias: None,
+            },
+            SelectFieldSyntax::ValueExpr {
+                value_expr: ticker_expr,
+                alias: None,
+            },
+            SelectFieldSyntax::AggrExpr {
+                aggr_expr: avg_amount_expr,
+                alias: Some(AggrAlias::new("avg_amount".to_string())),
+            },
+        ];
+
+        let (expr_resolver, labels) = ExprResolver::new(select_list);
+        let (resample_time_label, ticker_label, aggr_label) = match &labels[..] {
+            &[ExprLabel::Value(resample_time), ExprLabel::Value(ticker), ExprLabel::Aggr(aggr)] => {
+                (resample_time, ticker, aggr)
+            }
+            _ => unreachable!(),
+        };
+
+        let mut window = AggrWindow::new(
+            WindowParameter::TimedResampleWindow {
+                every: SpringEventDuration::from_secs(10),
+                fill,
+                within,
+                allowed_delay: SpringEventDuration::from_secs(0),
+                idle_timeout: None,
+                window_time: WindowTime::Rowtime,
+            },
+            WindowOperationParameter::Aggregate(AggregateParameter {
+                aggr_func: AggregateFunctionParameter::Avg,
+                aggr_expr: aggr_label,
+                group_by: GroupByLabels::new(vec![ticker_label]),
+                top_n: None,
+            }),
+            Some(resample_time_label),
+            None,
+        );
+
+        input
+            .iter()
+            .map(|(rowtime, ticker, amount)| {
+                let tuple = Tuple::factory_trade(ts(rowtime), ticker, *amount);
+                let (out, _) = window.dispatch(&expr_resolver, tuple, ()).unwrap();
+
+                let mut out = out
+                    .into_iter()
+                    .map(|v| {
+                        let resample_time = v
+                            .get_group_by_value(&resample_time_label)
+                            .unwrap()
+                            .clone()
+                            .unwrap()
+                            .unpack::<SpringTimestamp>()
+                            .unwrap();
+                        let ticker = v
+                            .get_group_by_value(&ticker_label)
+                            .unwrap()
+                            .clone()
+                            .unwrap()
+                            .unpack::<String>()
+                            .unwrap();
+                        let avg_amount = match v.get_aggregated_value(&aggr_label).unwrap() {
+                            SqlValue::Null => None,
+                            SqlValue::NotNull(v) => Some(v.unpack::<f32>().unwrap().round() as i16),
+                        };
+                        (resample_time, ticker, avg_amount)
+                    })
+                    .collect::<Vec<_>>();
+                out.sort();
+                out
+            })
+            .collect()
+    }
+
+    const INPUT: &[(&str, &str, i16)] = &[
+        ("2020-01-01 00:00:01.000000000", "ORCL", 100),
+        ("2020-01-01 00:00:03.000000000", "GOOGL", 200),
+        // closes [:00, :10)
+        ("2020-01-01 00:00:12.000000000", "ORCL", 300),
+        // closes [:10, :20) and empty [:20, :30)
+        ("2020-01-01 00:00:35.000000000", "ORCL", 600),
+        // closes [:30, :40)
+        ("2020-01-01 00:00:41.000000000", "GOOGL", 500),
+        // closes [:40, :50)
+        ("2020-01-01 00:00:50.000000000", "ORCL", 0),
+    ];
+
+    fn row(
+        t: &str,
+        ticker: &str,
+        avg_amount: Option<i16>,
+    ) -> (SpringTimestamp, String, Option<i16>) {
+        (ts(t), ticker.to_string(), avg_amount)
+    }
+
+    #[test]
+    fn test_resample_fill_null() {
+        let out = resample(ResampleFill::Null, None, INPUT);
+        assert!(out[0].is_empty());
+        assert!(out[1].is_empty());
+        assert_eq!(
+            out[2],
+            vec![
+                row("2020-01-01 00:00:00.000000000", "GOOGL", Some(200)),
+                row("2020-01-01 00:00:00.000000000", "ORCL", Some(100)),
+            ]
+        );
+        assert_eq!(
+            out[3],
+            vec![
+                row("2020-01-01 00:00:10.000000000", "GOOGL", None),
+                row("2020-01-01 00:00:10.000000000", "ORCL", Some(300)),
+                row("2020-01-01 00:00:20.000000000", "GOOGL", None),
+                row("2020-01-01 00:00:20.000000000", "ORCL", None),
+            ]
+        );
+        assert_eq!(
+            out[4],
+            vec![
+                row("2020-01-01 00:00:30.000000000", "GOOGL", None),
+                row("2020-01-01 00:00:30.000000000", "ORCL", Some(600)),
+            ]
+        );
+        assert_eq!(
+            out[5],
+            vec![
+                row("2020-01-01 00:00:40.000000000", "GOOGL", Some(500)),
+                row("2020-01-01 00:00:40.000000000", "ORCL", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resample_fill_previous() {
+        let out = resample(ResampleFill::Previous, None, INPUT);
+        assert_eq!(
+            out[3],
+            vec![
+                row("2020-01-01 00:00:10.000000000", "GOOGL", Some(200)),
+                row("2020-01-01 00:00:10.000000000", "ORCL", Some(300)),
+                row("2020-01-01 00:00:20.000000000", "GOOGL", Some(200)),
+                row("2020-01-01 00:00:20.000000000", "ORCL", Some(300)),
+            ]
+        );
+        assert_eq!(
+            out[5],
+            vec![
+                row("2020-01-01 00:00:40.000000000", "GOOGL", Some(500)),
+                row("2020-01-01 00:00:40.000000000", "ORCL", Some(600)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resample_fill_linear() {
+        let out = resample(ResampleFill::Linear, None, INPUT);
+        assert_eq!(
+            out[3],
+            vec![row("2020-01-01 00:00:10.000000000", "ORCL", Some(300))]
+        );
+        // ORCL: 300 at :10 and 600 at :30
+        assert_eq!(
+            out[4],
+            vec![
+                row("2020-01-01 00:00:20.000000000", "ORCL", Some(450)),
+                row("2020-01-01 00:00:30.000000000", "ORCL", Some(600)),
+            ]
+        );
+        // GOOGL: 200 at :00 and 500 at :40
+        assert_eq!(
+            out[5],
+            vec![
+                row("2020-01-01 00:00:10.000000000", "GOOGL", Some(275)),
+                row("2020-01-01 00:00:20.000000000", "GOOGL", Some(350)),
+                row("2020-01-01 00:00:30.000000000", "GOOGL", Some(425)),
+                row("2020-01-01 00:00:40.000000000", "GOOGL", Some(500)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resample_within_null() {
+        let out = resample(
+            ResampleFill::Null,
+            Some(SpringEventDuration::from_secs(10)),
+            INPUT,
+        );
+        // GOOGL is evicted at [:20, :30) since it has no tuple in [:10, :20)
+        assert_eq!(
+            out[3],
+            vec![
+                row("2020-01-01 00:00:10.000000000", "GOOGL", None),
+                row("2020-01-01 00:00:10.000000000", "ORCL", Some(300)),
+                row("2020-01-01 00:00:20.000000000", "ORCL", None),
+            ]
+        );
+        assert_eq!(
+            out[4],
+            vec![row("2020-01-01 00:00:30.000000000", "ORCL", Some(600))]
+        );
+        // GOOGL is resampled again from the interval it comes back
+        assert_eq!(
+            out[5],
+            vec![
+                row("2020-01-01 00:00:40.000000000", "GOOGL", Some(500)),
+                row("2020-01-01 00:00:40.000000000", "ORCL", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resample_within_linear() {
+        let out = resample(
+            ResampleFill::Linear,
+            Some(SpringEventDuration::from_secs(10)),
+            INPUT,
+        );
+        // GOOGL's gap at :10 is NULL since GOOGL is evicted before any value to interpolate
+        assert_eq!(
+            out[3],
+            vec![
+                row("2020-01-01 00:00:10.000000000", "GOOGL", None),
+                row("2020-01-01 00:00:10.000000000", "ORCL", Some(300)),
+            ]
+        );
+        assert_eq!(
+            out[5],
+            vec![row("2020-01-01 00:00:40.000000000", "GOOGL", Some(500))]
+        );
+    }
+}
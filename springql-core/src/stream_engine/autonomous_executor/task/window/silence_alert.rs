@@ -0,0 +1,244 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{
+    api::error::Result,
+    expr_resolver::ExprResolver,
+    mem_size::{chrono_naive_date_time_overhead_size, MemSize},
+    pipeline::{SilenceAlertParameter, WindowTime},
+    stream_engine::{
+        autonomous_executor::{
+            performance_metrics::WindowInFlowByWindowTask,
+            task::window::{panes::DistinctKey, watermark::Watermark},
+        },
+        time::{SpringDuration, SpringEventDuration, SpringTimestamp},
+        Tuple,
+    },
+    time::Duration,
+};
+
+/// Silence alert operator for `ALERT IF SILENT FOR duration [ON PTIME] PARTITION BY keys`.
+///
+/// It remembers the last tuple of each partition and emits it as an alert when no tuple comes into the partition
+/// until the watermark passes `last seen + silent_for`. Input tuples themselves are not emitted.
+///
+/// - ROWTIME: the watermark is driven by ROWTIME of input tuples (allowed delay is zero).
+///   A silent stream as a whole is detected only if the watermark advances by idle timeout of the source reader.
+/// - PTIME: the watermark is driven by wall clock, so a silent stream as a whole is also detected.
+///
+/// A partition is forgotten once alerted. It is alerted again if it comes back and gets silent again.
+#[derive(Debug)]
+pub struct SilenceAlert {
+    silence_alert_param: SilenceAlertParameter,
+
+    watermark: Watermark,
+
+    /// partition -> (last seen time, last tuple)
+    last_seen: HashMap<DistinctKey, (SpringTimestamp, Tuple)>,
+    /// deadline -> partitions; to alert in order.
+    ///
+    /// A partition seen again remains here with its old deadline until the watermark passes.
+    deadlines: BTreeMap<SpringTimestamp, Vec<DistinctKey>>,
+}
+
+impl SilenceAlert {
+    pub fn new(
+        silence_alert_param: SilenceAlertParameter,
+        source_idle_timeout: Option<SpringEventDuration>,
+    ) -> Self {
+        Self {
+            silence_alert_param,
+            watermark: Watermark::new(SpringEventDuration::from_secs(0), source_idle_timeout),
+            last_seen: HashMap::new(),
+            deadlines: BTreeMap::new(),
+        }
+    }
+
+    /// # Returns
+    ///
+    /// Last tuples of partitions which get silent.
+    ///
+    /// # Failures
+    ///
+    /// `SpringError::Sql` when partition keys cannot be evaluated.
+    pub fn dispatch(
+        &mut self,
+        expr_resolver: &ExprResolver,
+        tuple: Tuple,
+        now: SpringTimestamp,
+    ) -> Result<(Vec<Tuple>, WindowInFlowByWindowTask)> {
+        let seen_at = match self.silence_alert_param.time {
+            WindowTime::Rowtime => tuple.rowtime().as_timestamp(),
+            WindowTime::PTime => now,
+        };
+        self.watermark.update(seen_at, now);
+
+        let partition = DistinctKey::from_labels(
+            &self.silence_alert_param.partition_by,
+            expr_resolver,
+            &tuple,
+        )?;
+
+        let window_in_flow = match self.last_seen.get(&partition) {
+            Some((last_seen_at, _)) if *last_seen_at > seen_at => {
+                // out-of-order tuple does not postpone the deadline.
+                WindowInFlowByWindowTask::zero()
+            }
+            last => {
+                let mut states_bytes = deadline_entry_size(&partition) as i64;
+                let mut rows_bytes = tuple.mem_size() as i64;
+                match last {
+                    Some((_, last_tuple)) => rows_bytes -= last_tuple.mem_size() as i64,
+                    None => states_bytes += partition.mem_size() as i64,
+                }
+
+                self.deadlines
+                    .entry(seen_at + self.silent_for())
+                    .or_default()
+                    .push(partition.clone());
+                self.last_seen.insert(partition, (seen_at, tuple));
+
+                WindowInFlowByWindowTask::new(states_bytes, rows_bytes)
+            }
+        };
+
+        let (out, window_in_flow_alert) = self.alert();
+        Ok((out, window_in_flow + window_in_flow_alert))
+    }
+
+    /// A task calls this when no tuple comes from the input.
+    pub fn dispatch_idleness(
+        &mut self,
+        now: SpringTimestamp,
+    ) -> (Vec<Tuple>, WindowInFlowByWindowTask) {
+        match self.silence_alert_param.time {
+            WindowTime::Rowtime => self.watermark.advance_by_idleness(now),
+            WindowTime::PTime => self.watermark.update(now, now),
+        }
+        self.alert()
+    }
+
+    pub fn purge(&mut self) {
+        self.last_seen.clear();
+        self.deadlines.clear();
+    }
+
+    fn silent_for(&self) -> Duration {
+        self.silence_alert_param.silent_for.to_duration()
+    }
+
+    fn alert(&mut self) -> (Vec<Tuple>, WindowInFlowByWindowTask) {
+        let wm = self.watermark.as_timestamp();
+        let silent_for = self.silent_for();
+
+        let mut out = Vec::new();
+        let mut states_bytes = 0;
+        let mut rows_bytes = 0;
+        while let Some(deadline) = self.deadlines.keys().next().copied() {
+            if deadline >= wm {
+                break;
+            }
+            let partitions = self.deadlines.remove(&deadline).expect("key exists");
+            for partition in partitions {
+                states_bytes -= deadline_entry_size(&partition) as i64;
+                if self
+                    .last_seen
+                    .get(&partition)
+                    .map_or(false, |(last_seen_at, _)| {
+                        *last_seen_at + silent_for == deadline
+                    })
+                {
+                    let (_, last_tuple) = self.last_seen.remove(&partition).expect("just checked");
+                    states_bytes -= partition.mem_size() as i64;
+                    rows_bytes -= last_tuple.mem_size() as i64;
+                    out.push(last_tuple);
+                }
+            }
+        }
+
+        (out, WindowInFlowByWindowTask::new(states_bytes, rows_bytes))
+    }
+}
+
+fn deadline_entry_size(partition: &DistinctKey) -> usize {
+    partition.mem_size() + chrono_naive_date_time_overhead_size()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{
+        expression::ValueExpr, pipeline::ColumnReference, sql_processor::SelectFieldSyntax,
+    };
+
+    use super::*;
+
+    fn ts(s: &str) -> SpringTimestamp {
+        SpringTimestamp::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_silence_alert() {
+        // SELECT STREAM trade.ticker, trade.amount
+        //   FROM trade
+        //   ALERT IF SILENT FOR DURATION_SECS(3) PARTITION BY trade.ticker;
+        let select_list = vec![SelectFieldSyntax::ValueExpr {
+            value_expr: ValueExpr::factory_colref("trade", "ticker"),
+            alias: None,
+        }];
+        let (mut expr_resolver, _) = ExprResolver::new(select_list);
+        let ticker =
+            expr_resolver.register_value_expr(ValueExpr::factory_colref("trade", "ticker"));
+
+        let mut silence_alert = SilenceAlert::new(
+            SilenceAlertParameter::new(
+                SpringEventDuration::from_secs(3),
+                WindowTime::Rowtime,
+                vec![ticker],
+            ),
+            None,
+        );
+
+        // returns amounts of alerted tuples
+        let mut dispatch = |rowtime: &str, ticker: &str, amount: i16| {
+            let tuple = Tuple::factory_trade(ts(rowtime), ticker, amount);
+            let (out, _) = silence_alert
+                .dispatch(&expr_resolver, tuple, ts(rowtime))
+                .unwrap();
+            out.iter()
+                .map(|t| {
+                    t.get_value(&ColumnReference::fx_trade_amount())
+                        .unwrap()
+                        .unwrap()
+                        .unpack::<i16>()
+                        .unwrap()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert!(dispatch("2020-01-01 00:00:00.000000000", "ORCL", 1).is_empty());
+        assert!(dispatch("2020-01-01 00:00:00.000000000", "GOOGL", 2).is_empty());
+        assert!(dispatch("2020-01-01 00:00:02.000000000", "ORCL", 3).is_empty());
+        assert!(dispatch("2020-01-01 00:00:03.000000000", "ORCL", 4).is_empty());
+        // GOOGL is silent for more than 3 secs
+        assert_eq!(
+            dispatch("2020-01-01 00:00:04.000000000", "ORCL", 5),
+            vec![2]
+        );
+        // ORCL is silent. GOOGL comes back after it is alerted.
+        assert_eq!(
+            dispatch("2020-01-01 00:00:10.000000000", "GOOGL", 6),
+            vec![5]
+        );
+        assert!(dispatch("2020-01-01 00:00:13.000000000", "ORCL", 7).is_empty());
+        assert_eq!(
+            dispatch("2020-01-01 00:00:13.000000001", "ORCL", 8),
+            vec![6]
+        );
+
+        silence_alert.purge();
+        assert!(silence_alert.last_seen.is_empty());
+    }
+}
@@ -3,25 +3,39 @@
 use std::cmp::max;
 
 use crate::stream_engine::time::{
-    SpringDuration, SpringEventDuration, SpringTimestamp, MIN_TIMESTAMP,
+    SpringDuration, SpringEventDuration, SpringTimestamp, SystemTimestamp, MIN_TIMESTAMP,
 };
 
-/// A watermark is held by each window.
+/// A watermark is held by each window (or by each input of a window).
 ///
 /// ```text
 /// watermark = max(ROWTIME) - allowed_delay
 /// ```
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+///
+/// # Idleness
+///
+/// If `idle_timeout` is set and no row comes for the timeout (in wall-clock time), the input is regarded as idle.
+/// An idle watermark advances by the processing time elapsed since the last row.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub struct Watermark {
     max_rowtime: SpringTimestamp,
     allowed_delay: SpringEventDuration,
+
+    idle_timeout: Option<SpringEventDuration>,
+    /// Wall-clock time when this watermark is updated (or advanced by idleness) last.
+    last_active_at: SpringTimestamp,
 }
 
 impl Watermark {
-    pub fn new(allowed_delay: SpringEventDuration) -> Self {
+    pub fn new(
+        allowed_delay: SpringEventDuration,
+        idle_timeout: Option<SpringEventDuration>,
+    ) -> Self {
         Self {
             max_rowtime: MIN_TIMESTAMP + allowed_delay.to_duration(), // to avoid overflow
             allowed_delay,
+            idle_timeout,
+            last_active_at: SystemTimestamp::now(),
         }
     }
 
@@ -29,7 +43,169 @@ impl Watermark {
         self.max_rowtime - self.allowed_delay.to_duration()
     }
 
-    pub fn update(&mut self, rowtime: SpringTimestamp) {
+    /// `now` is a wall-clock timestamp when `rowtime` arrives.
+    pub fn update(&mut self, rowtime: SpringTimestamp, now: SpringTimestamp) {
         self.max_rowtime = max(rowtime, self.max_rowtime);
+        self.last_active_at = now;
+    }
+
+    pub fn is_idle(&self, now: SpringTimestamp) -> bool {
+        self.idle_timeout.map_or(false, |idle_timeout| {
+            now >= self.last_active_at + idle_timeout.to_duration()
+        })
+    }
+
+    /// Advances the watermark by the processing time elapsed since the last activity, only if this watermark is idle.
+    pub fn advance_by_idleness(&mut self, now: SpringTimestamp) {
+        if self.is_idle(now) {
+            self.max_rowtime = self.max_rowtime + (now - self.last_active_at);
+            self.last_active_at = now;
+        }
+    }
+
+    /// Aligns this watermark to multiple inputs' watermarks.
+    ///
+    /// - When any of `inputs` has an idle timeout, the minimum across non-idle inputs is taken (the maximum if all inputs are idle).
+    ///   Therefore a window does not close panes before all active inputs reach there. An input without idle timeout is never left out.
+    /// - Otherwise, the maximum across inputs is taken.
+    ///
+    /// This watermark never goes backward.
+    pub fn align(&mut self, inputs: &[Watermark], now: SpringTimestamp) {
+        let all_max_rowtime = inputs.iter().map(|wm| wm.max_rowtime).max();
+
+        let aligned_max_rowtime = if inputs.iter().any(|wm| wm.idle_timeout.is_some()) {
+            inputs
+                .iter()
+                .filter(|wm| !wm.is_idle(now))
+                .map(|wm| wm.max_rowtime)
+                .min()
+                .or(all_max_rowtime)
+        } else {
+            all_max_rowtime
+        };
+
+        if let Some(aligned_max_rowtime) = aligned_max_rowtime {
+            self.update(aligned_max_rowtime, now);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn ts(s: &str) -> SpringTimestamp {
+        SpringTimestamp::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_advance_by_idleness() {
+        let mut wm = Watermark::new(
+            SpringEventDuration::from_secs(1),
+            Some(SpringEventDuration::from_secs(5)),
+        );
+        wm.update(
+            ts("2020-01-01 00:00:10.000000000"),
+            ts("2022-01-01 00:00:00.000000000"),
+        );
+        assert_eq!(wm.as_timestamp(), ts("2020-01-01 00:00:09.000000000"));
+
+        // not idle yet
+        wm.advance_by_idleness(ts("2022-01-01 00:00:04.999999999"));
+        assert_eq!(wm.as_timestamp(), ts("2020-01-01 00:00:09.000000000"));
+
+        wm.advance_by_idleness(ts("2022-01-01 00:00:06.000000000"));
+        assert_eq!(wm.as_timestamp(), ts("2020-01-01 00:00:15.000000000"));
+    }
+
+    #[test]
+    fn test_no_advance_without_idle_timeout() {
+        let mut wm = Watermark::new(SpringEventDuration::from_secs(1), None);
+        wm.update(
+            ts("2020-01-01 00:00:10.000000000"),
+            ts("2022-01-01 00:00:00.000000000"),
+        );
+
+        wm.advance_by_idleness(ts("2022-01-02 00:00:00.000000000"));
+        assert_eq!(wm.as_timestamp(), ts("2020-01-01 00:00:09.000000000"));
+    }
+
+    #[test]
+    fn test_align_min_across_active_inputs() {
+        let idle_timeout = Some(SpringEventDuration::from_secs(5));
+        let delay = SpringEventDuration::from_secs(0);
+
+        let mut left = Watermark::new(delay, idle_timeout);
+        let mut right = Watermark::new(delay, idle_timeout);
+        let mut aligned = Watermark::new(delay, idle_timeout);
+
+        left.update(
+            ts("2020-01-01 00:00:20.000000000"),
+            ts("2022-01-01 00:00:00.000000000"),
+        );
+        right.update(
+            ts("2020-01-01 00:00:10.000000000"),
+            ts("2022-01-01 00:00:03.000000000"),
+        );
+
+        aligned.align(&[left, right], ts("2022-01-01 00:00:04.000000000"));
+        assert_eq!(aligned.as_timestamp(), ts("2020-01-01 00:00:10.000000000"));
+
+        // left is idle
+        aligned.align(&[left, right], ts("2022-01-01 00:00:06.000000000"));
+        assert_eq!(aligned.as_timestamp(), ts("2020-01-01 00:00:10.000000000"));
+
+        // both are idle
+        aligned.align(&[left, right], ts("2022-01-01 00:00:09.000000000"));
+        assert_eq!(aligned.as_timestamp(), ts("2020-01-01 00:00:20.000000000"));
+    }
+
+    #[test]
+    fn test_align_max_without_idle_timeout() {
+        let delay = SpringEventDuration::from_secs(0);
+
+        let mut left = Watermark::new(delay, None);
+        let right = Watermark::new(delay, None);
+        let mut aligned = Watermark::new(delay, None);
+
+        left.update(
+            ts("2020-01-01 00:00:20.000000000"),
+            ts("2022-01-01 00:00:00.000000000"),
+        );
+
+        aligned.align(&[left, right], ts("2022-01-01 00:00:00.000000000"));
+        assert_eq!(aligned.as_timestamp(), ts("2020-01-01 00:00:20.000000000"));
+    }
+
+    #[test]
+    fn test_align_min_with_partial_idle_timeout() {
+        let delay = SpringEventDuration::from_secs(0);
+
+        // only left has an idle timeout
+        let mut left = Watermark::new(delay, Some(SpringEventDuration::from_secs(5)));
+        let mut right = Watermark::new(delay, None);
+        let mut aligned = Watermark::new(delay, None);
+
+        left.update(
+            ts("2020-01-01 00:00:20.000000000"),
+            ts("2022-01-01 00:00:00.000000000"),
+        );
+        right.update(
+            ts("2020-01-01 00:00:10.000000000"),
+            ts("2022-01-01 00:00:00.000000000"),
+        );
+
+        aligned.align(&[left, right], ts("2022-01-01 00:00:01.000000000"));
+        assert_eq!(aligned.as_timestamp(), ts("2020-01-01 00:00:10.000000000"));
+
+        // left is idle and left out
+        right.update(
+            ts("2020-01-01 00:00:30.000000000"),
+            ts("2022-01-01 00:00:06.000000000"),
+        );
+        aligned.align(&[left, right], ts("2022-01-01 00:00:06.000000000"));
+        assert_eq!(aligned.as_timestamp(), ts("2020-01-01 00:00:30.000000000"));
     }
 }
@@ -7,8 +7,9 @@ mod query_plan;
 pub use crate::stream_engine::command::alter_pipeline_command::AlterPipelineCommand;
 pub use insert_plan::InsertPlan;
 pub use query_plan::{
-    CollectOp, GroupAggregateWindowOp, JoinOp, JoinWindowOp, LowerOps, ProjectionOp, QueryPlan,
-    UpperOps,
+    ChangesOnlyOp, CollectOp, ContinuousAggregateOp, DeduplicateOp, DistinctWindowOp,
+    GroupAggregateWindowOp, IntervalJoinOp, JoinOp, JoinWindowOp, LowerOps, MatchRecognizeOp,
+    NavigationOp, ProjectionOp, QueryPlan, SelectionOp, SilenceAlertOp, UnnestOp, UpperOps,
 };
 
 #[derive(Clone, PartialEq, Debug)]
@@ -3,7 +3,9 @@
 mod query_plan_operation;
 
 pub use query_plan_operation::{
-    CollectOp, GroupAggregateWindowOp, JoinOp, JoinWindowOp, LowerOps, ProjectionOp, UpperOps,
+    ChangesOnlyOp, CollectOp, ContinuousAggregateOp, DeduplicateOp, DistinctWindowOp,
+    GroupAggregateWindowOp, IntervalJoinOp, JoinOp, JoinWindowOp, LowerOps, MatchRecognizeOp,
+    NavigationOp, ProjectionOp, SelectionOp, SilenceAlertOp, UnnestOp, UpperOps,
 };
 
 use crate::{
@@ -33,7 +35,8 @@ impl QueryPlan {
     pub fn upstreams(&self) -> Vec<&StreamName> {
         match &self.lower_ops.join {
             JoinOp::Collect(collect) => vec![&collect.stream],
-            JoinOp::JoinWindow(JoinWindowOp { left, right, .. }) => {
+            JoinOp::JoinWindow(JoinWindowOp { left, right, .. })
+            | JoinOp::IntervalJoin(IntervalJoinOp { left, right, .. }) => {
                 vec![&left.stream, &right.stream]
             }
         }
@@ -1,28 +1,53 @@
 // This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
 
 use crate::{
-    expr_resolver::ExprLabel,
-    pipeline::{JoinParameter, StreamName, WindowOperationParameter, WindowParameter},
+    expr_resolver::{ExprLabel, ValueExprLabel},
+    pipeline::{
+        AggregateParameter, ChangesOnlyParameter, DeduplicateParameter, IntervalJoinParameter,
+        JoinParameter, MatchRecognizeParameter, NavigationParameter, SilenceAlertParameter,
+        StreamName, UnnestParameter, WindowOperationParameter, WindowParameter,
+    },
 };
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct UpperOps {
     pub projection: ProjectionOp,
     pub group_aggr_window: Option<GroupAggregateWindowOp>,
+    pub distinct_window: Option<DistinctWindowOp>,
+    pub continuous_aggr: Option<ContinuousAggregateOp>,
+    pub navigation: Option<NavigationOp>,
+    /// Conjuncts of WHERE condition with navigation functions.
+    pub navigation_selection: Option<SelectionOp>,
 }
 impl UpperOps {
+    /// Aggregation without window and navigation are regarded as windows since they hold states across tuples.
     pub fn has_window(&self) -> bool {
         self.group_aggr_window.is_some()
+            || self.distinct_window.is_some()
+            || self.continuous_aggr.is_some()
+            || self.navigation.is_some()
     }
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct LowerOps {
     pub join: JoinOp,
+    pub unnest: Option<UnnestOp>,
+    /// Conjuncts of WHERE condition without navigation functions.
+    pub selection: Option<SelectionOp>,
+    pub match_recognize: Option<MatchRecognizeOp>,
+    pub deduplicate: Option<DeduplicateOp>,
+    pub changes_only: Option<ChangesOnlyOp>,
+    pub silence_alert: Option<SilenceAlertOp>,
 }
 impl LowerOps {
+    /// Row pattern recognition, deduplication, emit-on-change and silence alert are regarded as windows since they hold states across tuples.
     pub fn has_window(&self) -> bool {
-        matches!(self.join, JoinOp::JoinWindow(_))
+        matches!(self.join, JoinOp::JoinWindow(_) | JoinOp::IntervalJoin(_))
+            || self.match_recognize.is_some()
+            || self.deduplicate.is_some()
+            || self.changes_only.is_some()
+            || self.silence_alert.is_some()
     }
 }
 
@@ -35,6 +60,67 @@ pub struct ProjectionOp {
 pub struct GroupAggregateWindowOp {
     pub window_param: WindowParameter,
     pub op_param: WindowOperationParameter,
+    /// Label of `RESAMPLE_TIME` in select list, if any (only for RESAMPLE window).
+    pub resample_time: Option<ValueExprLabel>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DistinctWindowOp {
+    pub window_param: WindowParameter,
+    pub op_param: WindowOperationParameter,
+}
+
+/// Aggregation without window. Emits the current aggregate of a group every time it changes.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ContinuousAggregateOp {
+    pub aggr_param: AggregateParameter,
+    /// Label of `CHANGELOG_OP` in select list, if any.
+    pub changelog_op: Option<ValueExprLabel>,
+}
+
+/// Adds derived fields of navigation functions (LAG, LEAD, EWMA, ...) to tuples, before aggregation and projection.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct NavigationOp {
+    pub params: Vec<NavigationParameter>,
+}
+
+/// Drops tuples whose WHERE condition is not TRUE.
+///
+/// Conjuncts without navigation functions are applied to tuples from collect (or JOIN, UNNEST) operation, so that dropped tuples do not affect
+/// row pattern recognition, deduplication, emit-on-change and silence alert. Conjuncts with navigation functions are applied to tuples from navigation operation.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SelectionOp {
+    pub condition: ValueExprLabel,
+}
+
+/// Applied to tuples from collect (or JOIN) operation. Emits a tuple per element of an array.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct UnnestOp {
+    pub unnest_param: UnnestParameter,
+}
+
+/// Applied to tuples from collect (or UNNEST, selection) operation. Emits a tuple per match.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct MatchRecognizeOp {
+    pub match_param: MatchRecognizeParameter,
+}
+
+/// Applied to tuples from collect (or JOIN, selection, MATCH_RECOGNIZE) operation.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DeduplicateOp {
+    pub dedup_param: DeduplicateParameter,
+}
+
+/// Applied to tuples from deduplicate operation (if any).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ChangesOnlyOp {
+    pub changes_only_param: ChangesOnlyParameter,
+}
+
+/// Applied to tuples from changes only operation (if any). Emits the last tuples of silent partitions.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SilenceAlertOp {
+    pub silence_alert_param: SilenceAlertParameter,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -47,6 +133,7 @@ pub struct CollectOp {
 pub enum JoinOp {
     Collect(CollectOp),
     JoinWindow(JoinWindowOp),
+    IntervalJoin(IntervalJoinOp),
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -57,3 +144,13 @@ pub struct JoinWindowOp {
     pub window_param: WindowParameter,
     pub join_param: JoinParameter,
 }
+
+/// Join without window clause. Each input keeps its tuples until the other input's watermark passes their interval.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct IntervalJoinOp {
+    pub left: CollectOp,
+    pub right: CollectOp,
+
+    pub interval_param: IntervalJoinParameter,
+    pub join_param: JoinParameter,
+}
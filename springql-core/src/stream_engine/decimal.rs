@@ -0,0 +1,588 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+//! Fixed-point decimal.
+
+use std::{
+    cmp::{max, min, Ordering},
+    fmt::Display,
+    hash::Hash,
+    mem::size_of,
+    str::FromStr,
+};
+
+use anyhow::anyhow;
+
+use crate::{
+    api::error::{Result, SpringError},
+    mem_size::MemSize,
+};
+
+/// Maximum number of digits of `DECIMAL`, both for precision and scale.
+pub const MAX_DECIMAL_PRECISION: u32 = 38;
+
+/// Number of fractional digits added to the larger scale of operands on division.
+const DIV_SCALE_INCREMENT: u32 = 6;
+
+/// Exact fixed-point decimal, representing `mantissa * 10^(-scale)`.
+///
+/// Rounding (on rescale, multiplication and division) is half away from zero.
+#[derive(Copy, Clone, Debug)]
+pub struct SpringDecimal {
+    mantissa: i128,
+    scale: u32,
+}
+
+impl MemSize for SpringDecimal {
+    fn mem_size(&self) -> usize {
+        size_of::<Self>()
+    }
+}
+
+impl SpringDecimal {
+    /// # Failure
+    ///
+    /// - `SpringError::Sql` when:
+    ///   - `mantissa` has more than 38 digits.
+    ///   - `scale` is larger than 38.
+    pub fn new(mantissa: i128, scale: u32) -> Result<Self> {
+        if scale > MAX_DECIMAL_PRECISION || num_digits(mantissa) > MAX_DECIMAL_PRECISION {
+            Err(SpringError::Sql(anyhow!(
+                "DECIMAL overflow: {}e-{} exceeds {} digits",
+                mantissa,
+                scale,
+                MAX_DECIMAL_PRECISION
+            )))
+        } else {
+            Ok(Self { mantissa, scale })
+        }
+    }
+
+    /// Unscaled value.
+    pub fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    /// Number of fractional digits.
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Number of digits in the mantissa.
+    pub fn precision(&self) -> u32 {
+        num_digits(self.mantissa)
+    }
+
+    /// # Failure
+    ///
+    /// - `SpringError::Sql` when:
+    ///   - the value is not finite or does not fit in 38 digits.
+    pub fn from_f64(v: f64) -> Result<Self> {
+        if v.is_finite() {
+            // shortest representation which round-trips; 0.1 becomes exactly 0.1
+            v.to_string().parse()
+        } else {
+            Err(SpringError::Sql(anyhow!("{} cannot be a DECIMAL", v)))
+        }
+    }
+
+    /// # Failure
+    ///
+    /// - `SpringError::Sql` when:
+    ///   - the value is not finite or does not fit in 38 digits.
+    pub fn from_f32(v: f32) -> Result<Self> {
+        if v.is_finite() {
+            v.to_string().parse()
+        } else {
+            Err(SpringError::Sql(anyhow!("{} cannot be a DECIMAL", v)))
+        }
+    }
+
+    /// Nearest f64 value.
+    pub fn to_f64(&self) -> f64 {
+        self.to_string()
+            .parse()
+            .expect("decimal representation must be parsed as f64")
+    }
+
+    /// Value with `scale` fractional digits, rounded half away from zero if `scale` is smaller than the current one.
+    ///
+    /// # Failure
+    ///
+    /// - `SpringError::Sql` when:
+    ///   - the value does not fit in 38 digits with the scale.
+    pub fn rescale(&self, scale: u32) -> Result<Self> {
+        match scale.cmp(&self.scale) {
+            Ordering::Equal => Ok(*self),
+            Ordering::Greater => {
+                let mantissa = self
+                    .mantissa
+                    .checked_mul(pow10(scale - self.scale)?)
+                    .ok_or_else(|| overflow(self, "rescale"))?;
+                Self::new(mantissa, scale)
+            }
+            Ordering::Less => {
+                Self::new(div_round(self.mantissa, pow10(self.scale - scale)?), scale)
+            }
+        }
+    }
+
+    /// Rounds half away from zero to `scale` fractional digits. Values with a scale not larger than `scale` are kept as-is.
+    pub fn round(&self, scale: u32) -> Result<Self> {
+        if scale < self.scale {
+            self.rescale(scale)
+        } else {
+            Ok(*self)
+        }
+    }
+
+    /// Result scale is the larger scale of operands.
+    ///
+    /// # Failure
+    ///
+    /// - `SpringError::Sql` when:
+    ///   - the result overflows.
+    pub fn checked_add(&self, rhs: &Self) -> Result<Self> {
+        let (lhs_m, rhs_m, scale) = self.align(rhs)?;
+        let mantissa = lhs_m
+            .checked_add(rhs_m)
+            .ok_or_else(|| overflow(self, "addition"))?;
+        Self::fit(mantissa, scale)
+    }
+
+    /// Result scale is the larger scale of operands.
+    ///
+    /// # Failure
+    ///
+    /// - `SpringError::Sql` when:
+    ///   - the result overflows.
+    pub fn checked_sub(&self, rhs: &Self) -> Result<Self> {
+        let (lhs_m, rhs_m, scale) = self.align(rhs)?;
+        let mantissa = lhs_m
+            .checked_sub(rhs_m)
+            .ok_or_else(|| overflow(self, "subtraction"))?;
+        Self::fit(mantissa, scale)
+    }
+
+    /// Result scale is the sum of scales of operands (rounded if it exceeds 38 digits).
+    ///
+    /// # Failure
+    ///
+    /// - `SpringError::Sql` when:
+    ///   - the result overflows.
+    pub fn checked_mul(&self, rhs: &Self) -> Result<Self> {
+        let scale = self.scale + rhs.scale;
+        match self.mantissa.checked_mul(rhs.mantissa) {
+            Some(mantissa) => Self::fit(mantissa, scale),
+            None => {
+                let digits = mul_digits(self.mantissa.unsigned_abs(), rhs.mantissa.unsigned_abs());
+                let negative = (self.mantissa < 0) != (rhs.mantissa < 0);
+                Self::fit_digits(negative, &digits, scale, 0)
+                    .map_err(|_| overflow(self, "multiplication"))
+            }
+        }
+    }
+
+    /// Result scale is the larger scale of operands plus 6 (up to 38), and the last digit is rounded.
+    ///
+    /// # Failure
+    ///
+    /// - `SpringError::Sql` when:
+    ///   - `rhs` is zero.
+    ///   - the result overflows.
+    pub fn checked_div(&self, rhs: &Self) -> Result<Self> {
+        if rhs.mantissa == 0 {
+            return Err(SpringError::Sql(anyhow!(
+                "division by zero: {} / {}",
+                self,
+                rhs
+            )));
+        }
+
+        let scale = min(
+            max(self.scale, rhs.scale) + DIV_SCALE_INCREMENT,
+            MAX_DECIMAL_PRECISION,
+        );
+        // (m1 * 10^-s1) / (m2 * 10^-s2) = (m1 * 10^(scale + s2 - s1) / m2) * 10^-scale
+        let exp = scale + rhs.scale - self.scale;
+        let dividend = pow10(exp)
+            .ok()
+            .and_then(|pow| self.mantissa.checked_mul(pow));
+        match dividend {
+            Some(dividend) => Self::fit(div_round(dividend, rhs.mantissa), scale),
+            None => {
+                // one more digit to round off
+                let digits = div_digits(
+                    self.mantissa.unsigned_abs(),
+                    rhs.mantissa.unsigned_abs(),
+                    exp + 1,
+                );
+                let negative = (self.mantissa < 0) != (rhs.mantissa < 0);
+                Self::fit_digits(negative, &digits, scale + 1, 1)
+                    .map_err(|_| overflow(self, "division"))
+            }
+        }
+    }
+
+    /// Mantissa and scale without trailing zeros in fractional digits. 1.50 and 1.5 have the same one.
+    pub fn normalize(&self) -> (i128, u32) {
+        let (mut mantissa, mut scale) = (self.mantissa, self.scale);
+        while scale > 0 && mantissa % 10 == 0 {
+            mantissa /= 10;
+            scale -= 1;
+        }
+        (mantissa, scale)
+    }
+
+    fn align(&self, rhs: &Self) -> Result<(i128, i128, u32)> {
+        let scale = max(self.scale, rhs.scale);
+        Ok((
+            self.rescale(scale)?.mantissa,
+            rhs.rescale(scale)?.mantissa,
+            scale,
+        ))
+    }
+
+    /// Drops fractional digits exceeding 38 digits.
+    fn fit(mantissa: i128, scale: u32) -> Result<Self> {
+        let excess = max(
+            num_digits(mantissa).saturating_sub(MAX_DECIMAL_PRECISION),
+            scale.saturating_sub(MAX_DECIMAL_PRECISION),
+        );
+        let excess = min(excess, scale);
+        if excess > 0 {
+            Self::new(div_round(mantissa, pow10(excess)?), scale - excess)
+        } else {
+            Self::new(mantissa, scale)
+        }
+    }
+
+    /// Same as `fit()` for a magnitude given as decimal digits, which may not fit in `i128`.
+    /// At least `min_excess` fractional digits are dropped.
+    fn fit_digits(negative: bool, digits: &str, scale: u32, min_excess: u32) -> Result<Self> {
+        let digits = digits.trim_start_matches('0');
+        let len = digits.len() as u32;
+        let excess = max(
+            max(
+                len.saturating_sub(MAX_DECIMAL_PRECISION),
+                scale.saturating_sub(MAX_DECIMAL_PRECISION),
+            ),
+            min_excess,
+        );
+        let excess = min(excess, scale);
+
+        let kept_len = len.saturating_sub(excess) as usize;
+        let kept = &digits[..kept_len];
+        // the first dropped digit is a leading zero if `len < excess`
+        let round_up = excess > 0 && len >= excess && digits.as_bytes()[kept_len] >= b'5';
+        if kept.len() > MAX_DECIMAL_PRECISION as usize {
+            return Err(SpringError::Sql(anyhow!(
+                "DECIMAL overflow: {} digits in integer part",
+                kept.len()
+            )));
+        }
+        let mantissa = if kept.is_empty() {
+            0
+        } else {
+            kept.parse::<i128>()
+                .expect("up to 38 digits must be parsed as i128")
+        } + i128::from(round_up);
+        Self::new(if negative { -mantissa } else { mantissa }, scale - excess)
+    }
+}
+
+impl PartialEq for SpringDecimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl Eq for SpringDecimal {}
+
+impl PartialOrd for SpringDecimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for SpringDecimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.scale.cmp(&other.scale) {
+            Ordering::Equal => self.mantissa.cmp(&other.mantissa),
+            Ordering::Less => match self.rescale(other.scale) {
+                Ok(scaled) => scaled.mantissa.cmp(&other.mantissa),
+                // scaled `self` exceeds `other` in magnitude
+                Err(_) => self.mantissa.cmp(&0),
+            },
+            Ordering::Greater => other.cmp(self).reverse(),
+        }
+    }
+}
+
+impl Hash for SpringDecimal {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.normalize().hash(state);
+    }
+}
+
+impl Display for SpringDecimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        let digits = format!(
+            "{:0>width$}",
+            self.mantissa.unsigned_abs(),
+            width = self.scale as usize + 1
+        );
+        let (int_part, frac_part) = digits.split_at(digits.len() - self.scale as usize);
+        if frac_part.is_empty() {
+            write!(f, "{}{}", sign, int_part)
+        } else {
+            write!(f, "{}{}.{}", sign, int_part, frac_part)
+        }
+    }
+}
+
+impl FromStr for SpringDecimal {
+    type Err = SpringError;
+
+    /// Parses `[+-]digits[.digits]`. The scale is the number of digits after `.`.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let invalid = |reason: &str| SpringError::InvalidFormat {
+            s: s.to_string(),
+            source: anyhow!("failed to parse decimal: {}", reason),
+        };
+
+        let trimmed = s.trim();
+        let (negative, unsigned) = match trimmed.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+        };
+        let (int_part, frac_part) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+
+        if int_part.is_empty() && frac_part.is_empty() {
+            return Err(invalid("no digits"));
+        }
+        if !int_part
+            .chars()
+            .chain(frac_part.chars())
+            .all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid("only digits and a decimal point are allowed"));
+        }
+
+        let digits = format!("{}{}", int_part, frac_part);
+        let digits = digits.trim_start_matches('0');
+        if digits.len() > MAX_DECIMAL_PRECISION as usize {
+            return Err(invalid("more than 38 digits"));
+        }
+        let mantissa = if digits.is_empty() {
+            0
+        } else {
+            digits
+                .parse::<i128>()
+                .map_err(|e| invalid(&e.to_string()))?
+        };
+        let mantissa = if negative { -mantissa } else { mantissa };
+
+        Self::new(mantissa, frac_part.len() as u32).map_err(|_| invalid("scale exceeds 38"))
+    }
+}
+
+fn num_digits(mantissa: i128) -> u32 {
+    let mut abs = mantissa.unsigned_abs();
+    let mut digits = 1;
+    while abs >= 10 {
+        abs /= 10;
+        digits += 1;
+    }
+    digits
+}
+
+fn pow10(exp: u32) -> Result<i128> {
+    10i128
+        .checked_pow(exp)
+        .ok_or_else(|| SpringError::Sql(anyhow!("DECIMAL overflow: 10^{}", exp)))
+}
+
+/// `dividend / divisor` rounded half away from zero.
+fn div_round(dividend: i128, divisor: i128) -> i128 {
+    let (quotient, remainder) = (dividend / divisor, dividend % divisor);
+    if remainder.unsigned_abs() >= divisor.unsigned_abs() - remainder.unsigned_abs() {
+        quotient + dividend.signum() * divisor.signum()
+    } else {
+        quotient
+    }
+}
+
+/// Decimal digits of `a * b` for `a` and `b` of up to 38 digits. The product may exceed `u128`.
+fn mul_digits(a: u128, b: u128) -> String {
+    const BASE: u128 = 10_000_000_000_000_000_000; // 10^19
+
+    // (a1 * BASE + a0) * (b1 * BASE + b0), where each partial product fits in u128
+    let (a1, a0) = (a / BASE, a % BASE);
+    let (b1, b0) = (b / BASE, b % BASE);
+
+    let low = a0 * b0;
+    let middle = a1 * b0 + a0 * b1 + low / BASE;
+    let high = a1 * b1 + middle / BASE;
+    format!(
+        "{}{:019}{:019}{:019}",
+        high / BASE,
+        high % BASE,
+        middle % BASE,
+        low % BASE
+    )
+}
+
+/// Decimal digits of `a * 10^exp / b` rounded toward zero, for `a` and `b` of up to 38 digits.
+fn div_digits(a: u128, b: u128, exp: u32) -> String {
+    let mut digits = (a / b).to_string();
+    let mut remainder = a % b;
+    for _ in 0..exp {
+        // 10 * remainder may exceed u128 but remainder + remainder does not
+        let (mut digit, mut acc) = (0u8, 0u128);
+        for _ in 0..10 {
+            acc += remainder;
+            if acc >= b {
+                acc -= b;
+                digit += 1;
+            }
+        }
+        remainder = acc;
+        digits.push(char::from(b'0' + digit));
+    }
+    digits
+}
+
+fn overflow(lhs: &SpringDecimal, op: &str) -> SpringError {
+    SpringError::Sql(anyhow!("DECIMAL overflow in {} of {}", op, lhs))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hasher;
+
+    use super::*;
+
+    fn dec(s: &str) -> SpringDecimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_display() {
+        for s in [
+            "0",
+            "12.34",
+            "-0.05",
+            "100",
+            "0.000",
+            "-12345678901234567890.123456789012345678",
+        ] {
+            assert_eq!(dec(s).to_string(), s);
+        }
+        assert_eq!(dec("+.5").to_string(), "0.5");
+        assert_eq!(dec("007.10").scale(), 2);
+
+        assert!("1.2.3".parse::<SpringDecimal>().is_err());
+        assert!("1e3".parse::<SpringDecimal>().is_err());
+        assert!("-".parse::<SpringDecimal>().is_err());
+        assert!("123456789012345678901234567890123456789"
+            .parse::<SpringDecimal>()
+            .is_err());
+    }
+
+    #[test]
+    fn test_rescale_rounds_half_away_from_zero() -> Result<()> {
+        assert_eq!(dec("2.345").rescale(2)?.to_string(), "2.35");
+        assert_eq!(dec("-2.345").rescale(2)?.to_string(), "-2.35");
+        assert_eq!(dec("2.3449").rescale(2)?.to_string(), "2.34");
+        assert_eq!(dec("2.5").rescale(4)?.to_string(), "2.5000");
+        assert_eq!(dec("2.5").round(4)?.to_string(), "2.5");
+        assert!(dec("12345678901234567890123456789012345678")
+            .rescale(1)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_arithmetic() -> Result<()> {
+        assert_eq!(dec("0.1").checked_add(&dec("0.2"))?.to_string(), "0.3");
+        assert_eq!(dec("1.05").checked_sub(&dec("2.1"))?.to_string(), "-1.05");
+        assert_eq!(dec("1.10").checked_mul(&dec("3.3"))?.to_string(), "3.630");
+        assert_eq!(dec("10").checked_div(&dec("3"))?.to_string(), "3.333333");
+        assert_eq!(
+            dec("2.00").checked_div(&dec("3"))?.to_string(),
+            "0.66666667"
+        );
+        assert!(dec("1").checked_div(&dec("0.0")).is_err());
+        assert!(dec("99999999999999999999999999999999999999")
+            .checked_add(&dec("1"))
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_arithmetic_beyond_i128() -> Result<()> {
+        // products and dividends exceeding i128 are rounded into 38 digits
+        assert_eq!(
+            dec("1234567890.1234567890")
+                .checked_mul(&dec("1234567890.1234567890"))?
+                .to_string(),
+            "1524157875323883675.0190519987501905210"
+        );
+        assert_eq!(
+            dec("-98765432109876543210.987654321")
+                .checked_mul(&dec("12345678901234567.89"))?
+                .to_string(),
+            "-1219326311370217952249657064223746380.1"
+        );
+        assert!(dec("12345678901234567890")
+            .checked_mul(&dec("12345678901234567890"))
+            .is_err());
+
+        assert_eq!(
+            dec("10000000000.0000000000")
+                .checked_div(&dec("3"))?
+                .to_string(),
+            "3333333333.3333333333333333"
+        );
+        assert_eq!(
+            dec("1000000000000000000000.0000000000")
+                .checked_div(&dec("3"))?
+                .to_string(),
+            "333333333333333333333.3333333333333333"
+        );
+        assert_eq!(
+            dec("-12345678901234567890123456789.012345678")
+                .checked_div(&dec("0.000000007"))?
+                .to_string(),
+            "-1763668414462081127160493827001763668.3"
+        );
+        assert!(dec("99999999999999999999999999999999999999")
+            .checked_div(&dec("0.01"))
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_eq_ord_hash_across_scales() {
+        let hash = |d: SpringDecimal| {
+            let mut hasher = DefaultHasher::new();
+            d.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        assert_eq!(dec("1.50"), dec("1.5"));
+        assert_eq!(hash(dec("1.50")), hash(dec("1.5")));
+        assert!(dec("1.49") < dec("1.5"));
+        assert!(dec("-1.5") < dec("-1.49"));
+        assert!(dec("99999999999999999999999999999999999999") > dec("0.5"));
+    }
+
+    #[test]
+    fn test_float_conversion() -> Result<()> {
+        assert_eq!(SpringDecimal::from_f64(0.1)?.to_string(), "0.1");
+        assert_eq!(SpringDecimal::from_f32(1.1)?.to_string(), "1.1");
+        assert!(SpringDecimal::from_f64(f64::NAN).is_err());
+        assert_eq!(dec("-12.25").to_f64(), -12.25);
+        Ok(())
+    }
+}
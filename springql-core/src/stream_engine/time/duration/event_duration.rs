@@ -29,6 +29,20 @@ impl SpringDuration for SpringEventDuration {
     }
 }
 
+impl SpringEventDuration {
+    /// None if `nanos` is negative or too large.
+    pub fn checked_from_nanos(nanos: i128) -> Option<Self> {
+        u64::try_from(nanos)
+            .ok()
+            .map(|nanos| Self(Duration::from_nanos(nanos)))
+    }
+
+    /// Whole nanoseconds.
+    pub fn as_nanos(&self) -> i128 {
+        self.0.as_nanos() as i128
+    }
+}
+
 impl Display for SpringEventDuration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{} secs", self.0.as_secs())
@@ -3,20 +3,22 @@
 //! Timestamp.
 
 mod system_timestamp;
+mod timestamp_field;
 pub use system_timestamp::SystemTimestamp;
+pub use timestamp_field::TimestampField;
 
 use std::{
     ops::{Add, Sub},
     str::FromStr,
 };
 
-use anyhow::Context;
+use anyhow::{anyhow, Context};
 use serde::{Deserialize, Serialize};
 
 use crate::{
     api::error::{Result, SpringError},
     mem_size::{chrono_naive_date_time_overhead_size, MemSize},
-    time::{DateTime, Duration, NaiveDateTime, MIN_DATETIME},
+    time::{DateTime, Duration, FormatDescription, NaiveDateTime, MIN_DATETIME},
 };
 
 /// The minimum possible `Timestamp`.
@@ -61,6 +63,121 @@ impl SpringTimestamp {
         }
     }
 
+    /// Value of the field. Year is as-is, month and day are 1-origin, and day of the week is 0 for Sunday.
+    pub fn extract(&self, field: TimestampField) -> i32 {
+        match field {
+            TimestampField::Year => self.0.year(),
+            TimestampField::Month => self.0.month() as i32,
+            TimestampField::Day => self.0.day() as i32,
+            TimestampField::Hour => self.0.hour() as i32,
+            TimestampField::Minute => self.0.minute() as i32,
+            TimestampField::Second => self.0.second() as i32,
+            TimestampField::DayOfWeek => self.0.weekday_from_sunday() as i32,
+        }
+    }
+
+    /// Truncates the fields smaller than `field` (e.g. `Day` into midnight).
+    ///
+    /// # Failures
+    ///
+    /// - `SpringError::Sql` when:
+    ///   - `field` is `DayOfWeek`.
+    pub fn trunc(&self, field: TimestampField) -> Result<SpringTimestamp> {
+        let ndt = &self.0;
+        let (year, month, day, hour, minute, second) = match field {
+            TimestampField::Year => (ndt.year(), 1, 1, 0, 0, 0),
+            TimestampField::Month => (ndt.year(), ndt.month(), 1, 0, 0, 0),
+            TimestampField::Day => (ndt.year(), ndt.month(), ndt.day(), 0, 0, 0),
+            TimestampField::Hour => (ndt.year(), ndt.month(), ndt.day(), ndt.hour(), 0, 0),
+            TimestampField::Minute => (
+                ndt.year(),
+                ndt.month(),
+                ndt.day(),
+                ndt.hour(),
+                ndt.minute(),
+                0,
+            ),
+            TimestampField::Second => (
+                ndt.year(),
+                ndt.month(),
+                ndt.day(),
+                ndt.hour(),
+                ndt.minute(),
+                ndt.second(),
+            ),
+            TimestampField::DayOfWeek => {
+                return Err(SpringError::Sql(anyhow!(
+                    "cannot truncate timestamp into day of the week"
+                )))
+            }
+        };
+        NaiveDateTime::from_ymd_hms(year, month, day, hour, minute, second)
+            .map(SpringTimestamp)
+            .map_err(SpringError::Time)
+    }
+
+    /// Parses by `format` parsed by `parse_format()`.
+    /// A timestamp with offset is converted into UTC, and a date without time is at midnight.
+    pub fn parse_with_description(s: &str, format: &FormatDescription) -> Result<Self> {
+        NaiveDateTime::parse_with_description(s, format)
+            .with_context(|| format!("failed to parse timestamp `{}`", s))
+            .map(SpringTimestamp)
+            .map_err(|e| SpringError::InvalidFormat {
+                s: s.to_string(),
+                source: e,
+            })
+    }
+
+    /// Parses `format` in the syntax of the `time` crate (e.g. `[year]-[month]-[day] [hour]:[minute]`).
+    ///
+    /// # Failures
+    ///
+    /// - `SpringError::Time` when:
+    ///   - `format` is not in the syntax of the `time` crate.
+    pub fn parse_format(format: &str) -> Result<FormatDescription> {
+        FormatDescription::parse(format).map_err(SpringError::Time)
+    }
+
+    /// Formats by `format` parsed by `parse_format()`. Offset components are formatted as UTC.
+    pub fn format_with_description(&self, format: &FormatDescription) -> Result<String> {
+        self.0.format_with(format).map_err(SpringError::Time)
+    }
+
+    /// Timestamp from nanoseconds since the Unix epoch.
+    pub fn from_unix_nanos(nanos: i128) -> Result<Self> {
+        NaiveDateTime::from_timestamp_nanos(nanos)
+            .map(SpringTimestamp)
+            .map_err(SpringError::Time)
+    }
+
+    /// Parses as RFC-3339, ISO 8601 or `"%Y-%m-%d %H:%M:%S%.9f"` format. A timestamp without offset is regarded as in UTC.
+    pub fn parse_iso8601(s: &str) -> Result<Self> {
+        Self::from_str(s).or_else(|_| {
+            NaiveDateTime::parse_iso8601(s)
+                .with_context(|| format!("failed to parse timestamp: {}", s))
+                .map(SpringTimestamp)
+                .map_err(|e| SpringError::InvalidFormat {
+                    s: s.to_string(),
+                    source: e,
+                })
+        })
+    }
+
+    /// None if the result is out of range.
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        self.0.checked_add(duration).map(SpringTimestamp)
+    }
+
+    /// None if the result is out of range.
+    pub fn checked_sub(self, duration: Duration) -> Option<Self> {
+        self.0.checked_sub(duration).map(SpringTimestamp)
+    }
+
+    /// RFC-3339 in UTC, e.g. `2020-01-01T00:00:00.000000000Z`.
+    pub fn to_rfc3339(&self) -> String {
+        self.0.format_rfc3339()
+    }
+
     fn try_parse_original(s: &str) -> Result<Self> {
         let ndt = NaiveDateTime::parse_from_str(s)
             .with_context(|| format!("failed to parse timestamp: {}", s))
@@ -316,6 +433,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_extract() {
+        let ts = SpringTimestamp::from_str("2020-02-29 23:59:58.123456789").unwrap();
+        assert_eq!(ts.extract(TimestampField::Year), 2020);
+        assert_eq!(ts.extract(TimestampField::Month), 2);
+        assert_eq!(ts.extract(TimestampField::Day), 29);
+        assert_eq!(ts.extract(TimestampField::Hour), 23);
+        assert_eq!(ts.extract(TimestampField::Minute), 59);
+        assert_eq!(ts.extract(TimestampField::Second), 58);
+        assert_eq!(ts.extract(TimestampField::DayOfWeek), 6);
+    }
+
+    #[test]
+    fn test_trunc() {
+        fn t(field: TimestampField, expected: &str) {
+            let ts = SpringTimestamp::from_str("2020-02-29 23:59:58.123456789").unwrap();
+            let expected = SpringTimestamp::from_str(expected).unwrap();
+            assert_eq!(ts.trunc(field).unwrap(), expected);
+        }
+
+        t(TimestampField::Year, "2020-01-01 00:00:00.000000000");
+        t(TimestampField::Month, "2020-02-01 00:00:00.000000000");
+        t(TimestampField::Day, "2020-02-29 00:00:00.000000000");
+        t(TimestampField::Hour, "2020-02-29 23:00:00.000000000");
+        t(TimestampField::Minute, "2020-02-29 23:59:00.000000000");
+        t(TimestampField::Second, "2020-02-29 23:59:58.000000000");
+
+        let ts = SpringTimestamp::from_str("2020-02-29 23:59:58.123456789").unwrap();
+        assert!(ts.trunc(TimestampField::DayOfWeek).is_err());
+    }
+
     #[test]
     fn test_timestamp_ser_de() -> Result<()> {
         let ts = vec![
@@ -0,0 +1,38 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+use std::str::FromStr;
+
+use anyhow::anyhow;
+
+use crate::api::error::{Result, SpringError};
+
+/// Field of a timestamp to extract or to truncate into.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum TimestampField {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    /// Day of the week: 0 for Sunday to 6 for Saturday.
+    DayOfWeek,
+}
+
+impl FromStr for TimestampField {
+    type Err = SpringError;
+
+    /// Case-insensitive `YEAR`, `MONTH`, `DAY`, `HOUR`, `MINUTE`, `SECOND` or `DOW`.
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_uppercase().as_str() {
+            "YEAR" => Ok(Self::Year),
+            "MONTH" => Ok(Self::Month),
+            "DAY" => Ok(Self::Day),
+            "HOUR" => Ok(Self::Hour),
+            "MINUTE" => Ok(Self::Minute),
+            "SECOND" => Ok(Self::Second),
+            "DOW" => Ok(Self::DayOfWeek),
+            _ => Err(SpringError::Sql(anyhow!("unknown timestamp field `{}`", s))),
+        }
+    }
+}
@@ -16,6 +16,8 @@ pub enum TimeError {
     OverflowError(#[from] time::error::ConversionRange),
     #[error("Range {0}")]
     ComponentRange(#[from] time::error::ComponentRange),
+    #[error("Invalid format description {0}")]
+    InvalidFormatDescription(#[from] time::error::InvalidFormatDescription),
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -94,6 +96,9 @@ pub const MIN_DATETIME: NaiveDateTime = NaiveDateTime(time::PrimitiveDateTime::M
 const FORMAT_DESCRIPTION: &[time::format_description::FormatItem<'static>] =
     format_description!("[year]-[month]-[day] [hour]:[minute]:[second].[subsecond digits:9]");
 
+const RFC3339_FORMAT_DESCRIPTION: &[time::format_description::FormatItem<'static>] =
+    format_description!("[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond digits:9]Z");
+
 fn parse_to_primitive(s: &str) -> Result<time::PrimitiveDateTime, TimeError> {
     Ok(time::PrimitiveDateTime::parse(s, FORMAT_DESCRIPTION)?)
 }
@@ -107,6 +112,18 @@ fn to_primitive(odt: time::OffsetDateTime) -> time::PrimitiveDateTime {
     time::PrimitiveDateTime::new(udt.date(), udt.time())
 }
 
+/// Format description in the syntax of the `time` crate (e.g. `[year]-[month]-[day]`), parsed in advance.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct FormatDescription(time::format_description::OwnedFormatItem);
+
+impl FormatDescription {
+    pub fn parse(description: &str) -> Result<Self, TimeError> {
+        Ok(Self(time::format_description::parse_owned::<1>(
+            description,
+        )?))
+    }
+}
+
 impl NaiveDateTime {
     pub fn utc_now() -> Self {
         let utc_now = time::OffsetDateTime::now_utc();
@@ -123,6 +140,21 @@ impl NaiveDateTime {
         Ok(Self(to_primitive(odt)))
     }
 
+    pub fn from_timestamp_nanos(nanos: i128) -> Result<Self, TimeError> {
+        let odt = time::OffsetDateTime::from_unix_timestamp_nanos(nanos)?;
+        Ok(Self(to_primitive(odt)))
+    }
+
+    /// Parses ISO 8601. A date-time with offset is converted into UTC, and one without offset is regarded as in UTC.
+    pub fn parse_iso8601(s: &str) -> Result<Self, TimeError> {
+        let iso8601 = time::format_description::well_known::Iso8601::DEFAULT;
+        time::OffsetDateTime::parse(s, &iso8601)
+            .map(to_primitive)
+            .or_else(|_| time::PrimitiveDateTime::parse(s, &iso8601))
+            .map(Self)
+            .map_err(TimeError::from)
+    }
+
     pub fn parse_from_str(s: &str) -> Result<Self, TimeError> {
         Ok(Self(parse_to_primitive(s)?))
     }
@@ -130,6 +162,83 @@ impl NaiveDateTime {
     pub fn format(&self) -> String {
         format_primitive(&self.0).unwrap() // TODO: avoid panic
     }
+
+    /// Formats as RFC-3339 in UTC (`Z`).
+    pub fn format_rfc3339(&self) -> String {
+        self.0.format(RFC3339_FORMAT_DESCRIPTION).unwrap() // TODO: avoid panic
+    }
+
+    /// Offset components are formatted as UTC.
+    pub fn format_with(&self, description: &FormatDescription) -> Result<String, TimeError> {
+        Ok(self.0.assume_utc().format(&description.0)?)
+    }
+
+    /// A date-time with offset is converted into UTC, and a date without time is at midnight.
+    pub fn parse_with_description(
+        s: &str,
+        description: &FormatDescription,
+    ) -> Result<Self, TimeError> {
+        let items = &description.0;
+        time::OffsetDateTime::parse(s, items)
+            .map(to_primitive)
+            .or_else(|_| time::PrimitiveDateTime::parse(s, items))
+            .or_else(|_| time::Date::parse(s, items).map(|date| date.midnight()))
+            .map(Self)
+            .map_err(TimeError::from)
+    }
+
+    /// Date-time of the given components. `month` and `day` are 1-origin.
+    pub fn from_ymd_hms(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<Self, TimeError> {
+        let date = time::Date::from_calendar_date(year, time::Month::try_from(month)?, day)?;
+        let time = time::Time::from_hms(hour, minute, second)?;
+        Ok(Self(time::PrimitiveDateTime::new(date, time)))
+    }
+
+    pub fn year(&self) -> i32 {
+        self.0.year()
+    }
+
+    /// 1-origin
+    pub fn month(&self) -> u8 {
+        self.0.month() as u8
+    }
+
+    /// 1-origin
+    pub fn day(&self) -> u8 {
+        self.0.day()
+    }
+
+    pub fn hour(&self) -> u8 {
+        self.0.hour()
+    }
+
+    pub fn minute(&self) -> u8 {
+        self.0.minute()
+    }
+
+    pub fn second(&self) -> u8 {
+        self.0.second()
+    }
+
+    /// 0 for Sunday to 6 for Saturday.
+    pub fn weekday_from_sunday(&self) -> u8 {
+        self.0.weekday().number_days_from_sunday()
+    }
+
+    pub fn checked_add(self, rhs: Duration) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    pub fn checked_sub(self, rhs: Duration) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
 }
 
 impl Add<Duration> for NaiveDateTime {
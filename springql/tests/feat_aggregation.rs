@@ -242,3 +242,532 @@ fn test_feat_aggregation_with_2_group_bys() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_feat_aggregation_top_n() -> Result<()> {
+    setup_test_logger();
+
+    let source_input = gen_source_input();
+
+    let test_source = ForeignSource::new().unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_trade (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          ticker TEXT NOT NULL,
+          amount INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_top_ticker (
+            ts TIMESTAMP NOT NULL ROWTIME,
+            ticker TEXT NOT NULL,
+            avg_amount FLOAT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP top_ticker AS
+        INSERT INTO sink_top_ticker (ts, ticker, avg_amount)
+        SELECT STREAM
+            FLOOR_TIME(source_trade.ts, DURATION_SECS(10)) AS min_ts,
+            source_trade.ticker AS ticker,
+            AVG(source_trade.amount) AS avg_amount
+        FROM source_trade
+        GROUP BY min_ts, ticker
+        FIXED WINDOW DURATION_SECS(10), DURATION_SECS(0)
+        ORDER BY avg_amount DESC LIMIT 1;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_trade FOR sink_top_ticker
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_trade FOR source_trade
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let mut sink_received = run_and_drain(
+        &ddls,
+        ForeignSourceInput::new_fifo_batch(source_input),
+        test_source,
+        &test_sink,
+    );
+    sink_received.sort_by_key(|r| r["ts"].as_str().unwrap().to_string());
+
+    // [:00, :10): GOOGL (30) > ORCL (10)
+    // [:10, :20): IBM (50)
+    assert_eq!(sink_received.len(), 2);
+
+    assert_eq!(sink_received[0]["ticker"].as_str().unwrap(), "GOOGL");
+    assert_eq!(
+        sink_received[0]["avg_amount"].as_f64().unwrap().round() as i32,
+        30,
+    );
+
+    assert_eq!(sink_received[1]["ticker"].as_str().unwrap(), "IBM");
+    assert_eq!(
+        sink_received[1]["avg_amount"].as_f64().unwrap().round() as i32,
+        50,
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_feat_aggregation_top_n_by_group_by_key() -> Result<()> {
+    setup_test_logger();
+
+    let source_input = vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "ticker": "ORCL", "amount": 10}),
+        json!({"ts": "2020-01-01 00:00:01.000000000", "ticker": "GOOGL", "amount": 30}),
+        // ORCL is out of the TOP 1 since GOOGL came
+        json!({"ts": "2020-01-01 00:00:02.000000000", "ticker": "ORCL", "amount": 100}),
+        json!({"ts": "2020-01-01 00:00:03.000000000", "ticker": "GOOGL", "amount": 50}),
+        json!({"ts": "2020-01-01 00:00:10.000000000", "ticker": "IBM", "amount": 50}),
+        json!({"ts": "2020-01-01 00:00:20.000000000", "ticker": "IBM", "amount": 70}),
+    ];
+
+    let test_source = ForeignSource::new().unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_trade (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          ticker TEXT NOT NULL,
+          amount INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_first_ticker (
+            ts TIMESTAMP NOT NULL ROWTIME,
+            ticker TEXT NOT NULL,
+            avg_amount FLOAT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP first_ticker AS
+        INSERT INTO sink_first_ticker (ts, ticker, avg_amount)
+        SELECT STREAM
+            FLOOR_TIME(source_trade.ts, DURATION_SECS(10)) AS min_ts,
+            source_trade.ticker AS ticker,
+            AVG(source_trade.amount) AS avg_amount
+        FROM source_trade
+        GROUP BY min_ts, ticker
+        FIXED WINDOW DURATION_SECS(10), DURATION_SECS(0)
+        ORDER BY ticker ASC LIMIT 1;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_trade FOR sink_first_ticker
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_trade FOR source_trade
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let mut sink_received = run_and_drain(
+        &ddls,
+        ForeignSourceInput::new_fifo_batch(source_input),
+        test_source,
+        &test_sink,
+    );
+    sink_received.sort_by_key(|r| r["ts"].as_str().unwrap().to_string());
+
+    assert_eq!(sink_received.len(), 2);
+
+    assert_eq!(sink_received[0]["ticker"].as_str().unwrap(), "GOOGL");
+    assert_eq!(
+        sink_received[0]["avg_amount"].as_f64().unwrap().round() as i32,
+        40,
+    );
+
+    assert_eq!(sink_received[1]["ticker"].as_str().unwrap(), "IBM");
+    assert_eq!(
+        sink_received[1]["avg_amount"].as_f64().unwrap().round() as i32,
+        50,
+    );
+
+    Ok(())
+}
+
+fn ddls_avg_all(
+    window_clause: &str,
+    source_reader_extra_options: &str,
+    test_source: &ForeignSource,
+    test_sink: &ForeignSink,
+) -> Vec<String> {
+    vec![
+        "
+        CREATE SOURCE STREAM source_trade (
+          ts TIMESTAMP NOT NULL ROWTIME,    
+          ticker TEXT NOT NULL,
+          amount INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_avg_all (
+          avg_amount FLOAT NOT NULL
+        );
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE PUMP avg_all AS
+        INSERT INTO sink_avg_all (avg_amount)
+        SELECT STREAM
+            AVG(source_trade.amount) AS avg_amount
+        FROM source_trade
+        {window_clause};
+        ",
+            window_clause = window_clause
+        ),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_trade FOR sink_avg_all
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_trade FOR source_trade
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'{source_reader_extra_options}
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port(),
+            source_reader_extra_options = source_reader_extra_options
+        ),
+    ]
+}
+
+/// The last pane `[:20.0, :20.5)` is closed by idleness of the source although no later row comes.
+fn assert_all_panes_closed(sink_received: &[serde_json::Value]) {
+    assert_eq!(sink_received.len(), 4);
+
+    let avg_amounts = sink_received
+        .iter()
+        .map(|r| r["avg_amount"].as_f64().unwrap().round() as i32)
+        .collect::<Vec<_>>();
+    assert_eq!(avg_amounts, vec![10, 30, 50, 70]);
+}
+
+#[test]
+fn test_feat_aggregation_idle_timeout_in_window_clause() -> Result<()> {
+    setup_test_logger();
+
+    let source_input = gen_source_input();
+
+    let test_source = ForeignSource::new().unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = ddls_avg_all(
+        "FIXED WINDOW DURATION_MILLIS(500), DURATION_SECS(0) IDLE TIMEOUT DURATION_MILLIS(300)",
+        "",
+        &test_source,
+        &test_sink,
+    );
+
+    let sink_received = run_and_drain(
+        &ddls,
+        ForeignSourceInput::new_fifo_batch(source_input),
+        test_source,
+        &test_sink,
+    );
+    assert_all_panes_closed(&sink_received);
+
+    Ok(())
+}
+
+#[test]
+fn test_feat_aggregation_idle_timeout_in_source_reader() -> Result<()> {
+    setup_test_logger();
+
+    let source_input = gen_source_input();
+
+    let test_source = ForeignSource::new().unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = ddls_avg_all(
+        "FIXED WINDOW DURATION_MILLIS(500), DURATION_SECS(0)",
+        ",
+            IDLE_TIMEOUT_MSEC '300'",
+        &test_source,
+        &test_sink,
+    );
+
+    let sink_received = run_and_drain(
+        &ddls,
+        ForeignSourceInput::new_fifo_batch(source_input),
+        test_source,
+        &test_sink,
+    );
+    assert_all_panes_closed(&sink_received);
+
+    Ok(())
+}
+
+#[test]
+fn test_feat_aggregation_without_window_changelog() -> Result<()> {
+    setup_test_logger();
+
+    let source_input = gen_source_input();
+
+    let test_source = ForeignSource::new().unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_trade (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          ticker TEXT NOT NULL,
+          amount INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_avg_by_ticker (
+          op TEXT NOT NULL,
+          ticker TEXT NOT NULL,
+          avg_amount FLOAT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP avg_by_ticker AS
+        INSERT INTO sink_avg_by_ticker (op, ticker, avg_amount)
+        SELECT STREAM
+            CHANGELOG_OP,
+            source_trade.ticker AS ticker,
+            AVG(source_trade.amount) AS avg_amount
+        FROM source_trade
+        GROUP BY ticker;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_trade FOR sink_avg_by_ticker
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_trade FOR source_trade
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let sink_received = run_and_drain(
+        &ddls,
+        ForeignSourceInput::new_fifo_batch(source_input),
+        test_source,
+        &test_sink,
+    );
+
+    let sink_received = sink_received
+        .iter()
+        .map(|r| {
+            (
+                r["op"].as_str().unwrap().to_string(),
+                r["ticker"].as_str().unwrap().to_string(),
+                r["avg_amount"].as_f64().unwrap().round() as i32,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        sink_received,
+        vec![
+            ("INSERT".to_string(), "ORCL".to_string(), 10),
+            ("INSERT".to_string(), "GOOGL".to_string(), 30),
+            ("INSERT".to_string(), "IBM".to_string(), 50),
+            ("UPDATE".to_string(), "IBM".to_string(), 60),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_feat_aggregation_resample_fill_previous() -> Result<()> {
+    setup_test_logger();
+
+    let source_input = gen_source_input();
+
+    let test_source = ForeignSource::new().unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_trade (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          ticker TEXT NOT NULL,
+          amount INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_resampled (
+          ts TIMESTAMP NOT NULL,
+          ticker TEXT NOT NULL,
+          avg_amount FLOAT
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP resample_by_ticker AS
+        INSERT INTO sink_resampled (ts, ticker, avg_amount)
+        SELECT STREAM
+            RESAMPLE_TIME,
+            source_trade.ticker AS ticker,
+            AVG(source_trade.amount) AS avg_amount
+        FROM source_trade
+        GROUP BY ticker
+        RESAMPLE EVERY DURATION_SECS(10), DURATION_SECS(0) FILL PREVIOUS;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_trade FOR sink_resampled
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_trade FOR source_trade
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let sink_received = run_and_drain(
+        &ddls,
+        ForeignSourceInput::new_fifo_batch(source_input),
+        test_source,
+        &test_sink,
+    );
+
+    let mut sink_received = sink_received
+        .iter()
+        .map(|r| {
+            (
+                r["ts"].as_str().unwrap().to_string(),
+                r["ticker"].as_str().unwrap().to_string(),
+                r["avg_amount"].as_f64().unwrap().round() as i32,
+            )
+        })
+        .collect::<Vec<_>>();
+    sink_received.sort();
+
+    // [:20, :30) is not closed yet.
+    // ORCL and GOOGL do not have any tuple in [:10, :20) but filled with their previous averages.
+    assert_eq!(
+        sink_received,
+        vec![
+            (
+                "2020-01-01 00:00:00.000000000".to_string(),
+                "GOOGL".to_string(),
+                30
+            ),
+            (
+                "2020-01-01 00:00:00.000000000".to_string(),
+                "ORCL".to_string(),
+                10
+            ),
+            (
+                "2020-01-01 00:00:10.000000000".to_string(),
+                "GOOGL".to_string(),
+                30
+            ),
+            (
+                "2020-01-01 00:00:10.000000000".to_string(),
+                "IBM".to_string(),
+                50
+            ),
+            (
+                "2020-01-01 00:00:10.000000000".to_string(),
+                "ORCL".to_string(),
+                10
+            ),
+        ]
+    );
+
+    Ok(())
+}
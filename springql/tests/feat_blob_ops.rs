@@ -0,0 +1,87 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use std::str::FromStr;
+
+use pretty_assertions::assert_eq;
+use springql::{SpringConfig, SpringError, SpringSourceRowBuilder, SpringTimestamp};
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::apply_ddls;
+
+#[test]
+fn test_feat_decode_can_signals() -> Result<(), SpringError> {
+    setup_test_logger();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_can (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          can_data BLOB NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_signal (
+          speed INTEGER NOT NULL,
+          temperature INTEGER NOT NULL,
+          last_byte INTEGER NOT NULL,
+          upper_nibble INTEGER NOT NULL,
+          data_length INTEGER NOT NULL,
+          data_hex TEXT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_signal AS
+          INSERT INTO sink_signal (speed, temperature, last_byte, upper_nibble, data_length, data_hex)
+          SELECT STREAM
+            GET_BITS(source_can.can_data, 0, 16, 'LE', FALSE),
+            GET_BITS(source_can.can_data, 23, 8, 'BE', TRUE),
+            GET_BYTE(source_can.can_data, 3),
+            (GET_BYTE(source_can.can_data, 3) >> 4) & 15,
+            BLOB_LENGTH(source_can.can_data),
+            HEX(source_can.can_data)
+          FROM source_can;
+        "
+        .to_string(),
+        "
+        CREATE SINK WRITER q_sink_signal FOR sink_signal
+          TYPE IN_MEMORY_QUEUE OPTIONS (
+            NAME 'q_sink_signal'
+        );
+        "
+        .to_string(),
+        "
+        CREATE SOURCE READER q_source_can FOR source_can
+          TYPE IN_MEMORY_QUEUE OPTIONS (
+            NAME 'q_source_can'
+          );
+        "
+        .to_string(),
+    ];
+    let pipeline = apply_ddls(&ddls, SpringConfig::default());
+
+    let row = SpringSourceRowBuilder::default()
+        .add_column(
+            "ts",
+            SpringTimestamp::from_str("2020-01-01 00:00:00.000000000").unwrap(),
+        )?
+        .add_column("can_data", vec![0x34u8, 0x12, 0xF6, 0xA5])?
+        .build();
+    pipeline.push("q_source_can", row)?;
+
+    let sink_row = pipeline.pop("q_sink_signal")?;
+    assert_eq!(sink_row.get_not_null_by_index::<i32>(0)?, 0x1234);
+    assert_eq!(sink_row.get_not_null_by_index::<i32>(1)?, -10);
+    assert_eq!(sink_row.get_not_null_by_index::<i32>(2)?, 0xA5);
+    assert_eq!(sink_row.get_not_null_by_index::<i32>(3)?, 0xA);
+    assert_eq!(sink_row.get_not_null_by_index::<i32>(4)?, 4);
+    assert_eq!(
+        sink_row.get_not_null_by_index::<String>(5)?,
+        "3412F6A5".to_string()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use springql::*;
+use springql_foreign_service::{
+    sink::ForeignSink,
+    source::{ForeignSource, ForeignSourceInput},
+};
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::{apply_ddls, drain_from_sink};
+
+#[test]
+fn test_feat_column_default_and_generated() {
+    setup_test_logger();
+
+    let source_input = vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "device": "d1", "temp": 20}),
+        json!({"ts": "2020-01-01 00:00:01.000000000", "device": "d2", "firmware": "v2", "temp": 25, "battery": 80, "temp_x10": 0}),
+        json!({"ts": "2020-01-01 00:00:02.000000000", "device": "d3", "firmware": null, "temp": -10, "battery": null}),
+    ];
+
+    let test_source = ForeignSource::new().unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_sensor (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          device TEXT NOT NULL,
+          firmware TEXT NOT NULL DEFAULT 'v1',
+          temp INTEGER NOT NULL,
+          battery INTEGER DEFAULT -1,
+          temp_x10 INTEGER NOT NULL GENERATED ALWAYS AS (source_sensor.temp * 10)
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_sensor (
+          device TEXT NOT NULL,
+          firmware TEXT NOT NULL,
+          battery INTEGER,
+          temp_x10 INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_sensor AS
+          INSERT INTO sink_sensor (device, firmware, battery, temp_x10)
+          SELECT STREAM
+            source_sensor.device,
+            source_sensor.firmware,
+            source_sensor.battery,
+            source_sensor.temp_x10
+          FROM source_sensor;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_sensor FOR sink_sensor
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_source_sensor FOR source_sensor
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let _pipeline = apply_ddls(&ddls, SpringConfig::default());
+    test_source.start(ForeignSourceInput::new_fifo_batch(source_input));
+    let sink_received = drain_from_sink(&test_sink);
+
+    assert_eq!(
+        sink_received,
+        vec![
+            json!({"device": "d1", "firmware": "v1", "battery": -1, "temp_x10": 200}),
+            json!({"device": "d2", "firmware": "v2", "battery": 80, "temp_x10": 250}),
+            json!({"device": "d3", "firmware": "v1", "battery": -1, "temp_x10": -100}),
+        ]
+    );
+}
+
+#[test]
+fn test_feat_column_default_type_mismatch() {
+    setup_test_logger();
+
+    let pipeline = SpringPipeline::new(&SpringConfig::default()).unwrap();
+    let res = pipeline.command(
+        "
+        CREATE SOURCE STREAM source_sensor (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          battery INTEGER DEFAULT 'full'
+        );
+        ",
+    );
+    assert!(matches!(res, Err(SpringError::Sql(_))));
+}
@@ -0,0 +1,96 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use springql::*;
+use springql_foreign_service::{
+    sink::ForeignSink,
+    source::{ForeignSource, ForeignSourceInput},
+};
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::{apply_ddls, drain_from_sink};
+
+#[test]
+fn test_feat_decimal_billing() {
+    setup_test_logger();
+
+    let source_input = vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "price": 19.99, "quantity": 3, "discount": "0.97"}),
+        json!({"ts": "2020-01-01 00:00:01.000000000", "price": "0.10", "quantity": 7, "discount": 0.2}),
+    ];
+
+    let test_source = ForeignSource::new().unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_order (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          price DECIMAL(10, 2) NOT NULL,
+          quantity INTEGER NOT NULL,
+          discount DECIMAL(10, 2) NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_bill (
+          total DECIMAL(12, 2) NOT NULL,
+          per_unit DECIMAL(12, 4) NOT NULL,
+          taxed DECIMAL(12, 3) NOT NULL,
+          rounded_price DECIMAL(10, 1) NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_bill AS
+          INSERT INTO sink_bill (total, per_unit, taxed, rounded_price)
+          SELECT STREAM
+            (source_order.price * source_order.quantity) - source_order.discount,
+            ((source_order.price * source_order.quantity) - source_order.discount) / source_order.quantity,
+            source_order.price * 1.1,
+            ROUND(source_order.price, 1)
+          FROM source_order;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_bill FOR sink_bill
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}',
+            DECIMAL_FORMAT 'STRING'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_source_order FOR source_order
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let _pipeline = apply_ddls(&ddls, SpringConfig::default());
+    test_source.start(ForeignSourceInput::new_fifo_batch(source_input));
+    let sink_received = drain_from_sink(&test_sink);
+
+    assert_eq!(
+        sink_received,
+        vec![
+            json!({"total": "59.00", "per_unit": "19.6667", "taxed": "21.989", "rounded_price": "20.0"}),
+            json!({"total": "0.50", "per_unit": "0.0714", "taxed": "0.110", "rounded_price": "0.1"}),
+        ]
+    );
+}
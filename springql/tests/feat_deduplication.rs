@@ -0,0 +1,196 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use springql_core::api::*;
+use springql_foreign_service::{
+    sink::ForeignSink,
+    source::{ForeignSource, ForeignSourceInput},
+};
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::*;
+
+/// Frames resent by gateways.
+fn gen_source_frame() -> Vec<serde_json::Value> {
+    vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "gateway_id": "g1", "seq": 1}),
+        json!({"ts": "2020-01-01 00:00:01.000000000", "gateway_id": "g1", "seq": 1}),
+        json!({"ts": "2020-01-01 00:00:02.000000000", "gateway_id": "g2", "seq": 1}),
+        json!({"ts": "2020-01-01 00:00:05.000000000", "gateway_id": "g1", "seq": 2}),
+        json!({"ts": "2020-01-01 00:00:12.000000000", "gateway_id": "g1", "seq": 1}),
+        json!({"ts": "2020-01-01 00:00:25.000000000", "gateway_id": "g1", "seq": 3}),
+    ]
+}
+
+fn ddls(select: &str, test_source: &ForeignSource, test_sink: &ForeignSink) -> Vec<String> {
+    vec![
+        "
+        CREATE SOURCE STREAM source_frame (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          gateway_id TEXT NOT NULL,
+          seq INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_frame (
+          gateway_id TEXT NOT NULL,
+          seq INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE PUMP pu_frame AS
+          INSERT INTO sink_frame (gateway_id, seq)
+          {select};
+        ",
+            select = select
+        ),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_frame FOR sink_frame
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_source_frame FOR source_frame
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ]
+}
+
+fn run_and_drain(select: &str) -> Vec<(String, i64)> {
+    let test_source = ForeignSource::new().unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = ddls(select, &test_source, &test_sink);
+    let _pipeline = apply_ddls(&ddls, SpringConfig::default());
+    test_source.start(ForeignSourceInput::new_fifo_batch(gen_source_frame()));
+
+    drain_from_sink(&test_sink)
+        .into_iter()
+        .map(|r| {
+            (
+                r["gateway_id"].as_str().unwrap().to_string(),
+                r["seq"].as_i64().unwrap(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_feat_select_distinct_with_window() {
+    setup_test_logger();
+
+    let mut sink_received = run_and_drain(
+        "
+          SELECT DISTINCT STREAM source_frame.gateway_id, source_frame.seq
+          FROM source_frame
+          FIXED WINDOW DURATION_SECS(10), DURATION_SECS(0)
+        ",
+    );
+    sink_received.sort();
+
+    // [:00, :10): (g1, 1), (g2, 1), (g1, 2)
+    // [:10, :20): (g1, 1)
+    assert_eq!(
+        sink_received,
+        vec![
+            ("g1".to_string(), 1),
+            ("g1".to_string(), 1),
+            ("g1".to_string(), 2),
+            ("g2".to_string(), 1),
+        ]
+    );
+}
+
+#[test]
+fn test_feat_deduplicate_within() {
+    setup_test_logger();
+
+    let mut sink_received = run_and_drain(
+        "
+          SELECT STREAM source_frame.gateway_id, source_frame.seq
+          FROM source_frame
+          DEDUPLICATE ON (source_frame.gateway_id, source_frame.seq) WITHIN DURATION_SECS(10)
+        ",
+    );
+    sink_received.sort();
+
+    // (g1, 1) at :01 is a duplicate of :00 but the one at :12 is not.
+    assert_eq!(
+        sink_received,
+        vec![
+            ("g1".to_string(), 1),
+            ("g1".to_string(), 1),
+            ("g1".to_string(), 2),
+            ("g1".to_string(), 3),
+            ("g2".to_string(), 1),
+        ]
+    );
+}
+
+#[test]
+fn test_feat_changes_only_partition_by() {
+    setup_test_logger();
+
+    let mut sink_received = run_and_drain(
+        "
+          SELECT STREAM source_frame.gateway_id, source_frame.seq
+          FROM source_frame
+          CHANGES ONLY ON (source_frame.seq) PARTITION BY source_frame.gateway_id
+        ",
+    );
+    sink_received.sort();
+
+    // (g1, 1) at :01 repeats the last value of g1 but the one at :12 does not (last is 2).
+    assert_eq!(
+        sink_received,
+        vec![
+            ("g1".to_string(), 1),
+            ("g1".to_string(), 1),
+            ("g1".to_string(), 2),
+            ("g1".to_string(), 3),
+            ("g2".to_string(), 1),
+        ]
+    );
+}
+
+#[test]
+fn test_feat_where_before_changes_only() {
+    setup_test_logger();
+
+    let mut sink_received = run_and_drain(
+        "
+          SELECT STREAM source_frame.gateway_id, source_frame.seq
+          FROM source_frame
+          WHERE source_frame.seq = 1
+          CHANGES ONLY ON (source_frame.seq) PARTITION BY source_frame.gateway_id
+        ",
+    );
+    sink_received.sort();
+
+    // (g1, 2) at :05 is dropped by WHERE, so (g1, 1) at :12 repeats the last value of g1.
+    assert_eq!(
+        sink_received,
+        vec![("g1".to_string(), 1), ("g2".to_string(), 1)]
+    );
+}
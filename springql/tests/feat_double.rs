@@ -0,0 +1,161 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use std::str::FromStr;
+
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use springql::*;
+use springql_foreign_service::{
+    sink::ForeignSink,
+    source::{ForeignSource, ForeignSourceInput},
+};
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::{apply_ddls, drain_from_sink};
+
+#[test]
+fn test_feat_double_precision_via_json() {
+    setup_test_logger();
+
+    let source_input = vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "lat": 35.681236123456, "energy_wh": 123456789.125}),
+        json!({"ts": "2020-01-01 00:00:01.000000000", "lat": -33.868820987654, "energy_wh": 0.000001}),
+    ];
+
+    let test_source = ForeignSource::new().unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_energy (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          lat DOUBLE PRECISION NOT NULL,
+          energy_wh DOUBLE NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_energy (
+          lat DOUBLE NOT NULL,
+          energy_kwh DOUBLE NOT NULL,
+          lat_float FLOAT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_energy AS
+          INSERT INTO sink_energy (lat, energy_kwh, lat_float)
+          SELECT STREAM
+            source_energy.lat,
+            source_energy.energy_wh * 0.5 * 0.5 * 0.5,
+            source_energy.lat
+          FROM source_energy;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_energy FOR sink_energy
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_source_energy FOR source_energy
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let _pipeline = apply_ddls(&ddls, SpringConfig::default());
+    test_source.start(ForeignSourceInput::new_fifo_batch(source_input));
+    let sink_received = drain_from_sink(&test_sink);
+
+    assert_eq!(sink_received.len(), 2);
+
+    assert_eq!(sink_received[0]["lat"].as_f64().unwrap(), 35.681236123456);
+    assert_eq!(
+        sink_received[0]["energy_kwh"].as_f64().unwrap(),
+        15432098.640625
+    );
+    assert_eq!(
+        sink_received[0]["lat_float"].as_f64().unwrap(),
+        35.681236123456f32 as f64
+    );
+
+    assert_eq!(sink_received[1]["lat"].as_f64().unwrap(), -33.868820987654);
+    assert_eq!(
+        sink_received[1]["energy_kwh"].as_f64().unwrap(),
+        0.000001 * 0.125
+    );
+}
+
+#[test]
+fn test_feat_double_in_memory_queue() -> Result<()> {
+    setup_test_logger();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_position (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          lat DOUBLE NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_position (
+          lat DOUBLE NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_position AS
+          INSERT INTO sink_position (lat)
+          SELECT STREAM source_position.lat
+          FROM source_position;
+        "
+        .to_string(),
+        "
+        CREATE SINK WRITER q_sink_position FOR sink_position
+          TYPE IN_MEMORY_QUEUE OPTIONS (
+            NAME 'q_sink_position'
+        );
+        "
+        .to_string(),
+        "
+        CREATE SOURCE READER q_source_position FOR source_position
+          TYPE IN_MEMORY_QUEUE OPTIONS (
+            NAME 'q_source_position'
+          );
+        "
+        .to_string(),
+    ];
+    let pipeline = apply_ddls(&ddls, SpringConfig::default());
+
+    let row = SpringSourceRowBuilder::default()
+        .add_column(
+            "ts",
+            SpringTimestamp::from_str("2020-01-01 00:00:00.000000000").unwrap(),
+        )?
+        .add_column("lat", 35.681236123456f64)?
+        .build();
+    pipeline.push("q_source_position", row)?;
+
+    let sink_row = pipeline.pop("q_sink_position")?;
+    assert_eq!(sink_row.get_not_null_by_index::<f64>(0)?, 35.681236123456);
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use float_cmp::approx_eq;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use springql::*;
+use springql_foreign_service::{
+    sink::ForeignSink,
+    source::{ForeignSource, ForeignSourceInput},
+};
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::{apply_ddls, drain_from_sink};
+
+#[test]
+fn test_feat_geofence() {
+    setup_test_logger();
+
+    let source_input = vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "vehicle_id": "v1", "lat": 35.0, "lon": 139.01}),
+        json!({"ts": "2020-01-01 00:00:01.000000000", "vehicle_id": "v2", "lat": 36.0, "lon": 140.0}),
+        json!({"ts": "2020-01-01 00:00:02.000000000", "vehicle_id": "v3", "lat": 35.05, "lon": 139.0}),
+    ];
+
+    let test_source = ForeignSource::new().unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_position (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          vehicle_id TEXT NOT NULL,
+          lat FLOAT NOT NULL,
+          lon FLOAT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_geofence (
+          vehicle_id TEXT NOT NULL,
+          distance FLOAT NOT NULL,
+          bearing FLOAT NOT NULL,
+          cell TEXT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_geofence AS
+          INSERT INTO sink_geofence (vehicle_id, distance, bearing, cell)
+          SELECT STREAM
+            source_position.vehicle_id,
+            ST_DISTANCE(source_position.lat, source_position.lon, 35.0, 139.0),
+            ST_BEARING(source_position.lat, source_position.lon, 35.0, 139.0),
+            GEOHASH(source_position.lat, source_position.lon, 5)
+          FROM source_position
+          WHERE ST_WITHIN(
+            source_position.lat,
+            source_position.lon,
+            'POLYGON((138.9 34.9, 139.1 34.9, 139.1 35.1, 138.9 35.1, 138.9 34.9))'
+          );
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_geofence FOR sink_geofence
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_source_position FOR source_position
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let _pipeline = apply_ddls(&ddls, SpringConfig::default());
+    test_source.start(ForeignSourceInput::new_fifo_batch(source_input));
+    let mut sink_received = drain_from_sink(&test_sink);
+    sink_received.sort_by_key(|r| r["vehicle_id"].as_str().unwrap().to_string());
+
+    assert_eq!(sink_received.len(), 2);
+
+    let v1 = &sink_received[0];
+    assert_eq!(v1["vehicle_id"], "v1");
+    assert!(approx_eq!(
+        f64,
+        v1["distance"].as_f64().unwrap(),
+        910.36,
+        epsilon = 0.1
+    ));
+    assert!(approx_eq!(
+        f64,
+        v1["bearing"].as_f64().unwrap(),
+        270.0,
+        epsilon = 0.01
+    ));
+    assert_eq!(v1["cell"], "xn4z5");
+
+    let v3 = &sink_received[1];
+    assert_eq!(v3["vehicle_id"], "v3");
+    assert!(approx_eq!(
+        f64,
+        v3["distance"].as_f64().unwrap(),
+        5559.75,
+        epsilon = 0.1
+    ));
+    assert!(approx_eq!(
+        f64,
+        v3["bearing"].as_f64().unwrap(),
+        180.0,
+        epsilon = 0.01
+    ));
+    assert_eq!(v3["cell"], "xn4z7");
+}
@@ -183,3 +183,122 @@ fn test_feat_left_outer_join() {
     assert_eq!(r2["amount"].as_i64().unwrap(), 50);
     assert!(r2["temperature"].is_null());
 }
+
+#[test]
+fn test_feat_interval_join() {
+    setup_test_logger();
+
+    let source_brake = vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "vehicle_id": "v1"}),
+        json!({"ts": "2020-01-01 00:00:10.000000000", "vehicle_id": "v2"}),
+        json!({"ts": "2020-01-01 00:00:20.000000000", "vehicle_id": "v1"}),
+    ];
+    let source_speed = vec![
+        json!({"ts": "2020-01-01 00:00:03.000000000", "vehicle_id": "v1", "speed": 40}),
+        json!({"ts": "2020-01-01 00:00:04.000000000", "vehicle_id": "v2", "speed": 50}),
+        json!({"ts": "2020-01-01 00:00:12.000000000", "vehicle_id": "v2", "speed": 60}),
+        // only to advance watermark
+        json!({"ts": "2020-01-01 00:00:40.000000000", "vehicle_id": "v1", "speed": 70}),
+    ];
+
+    let test_source_brake = ForeignSource::new().unwrap();
+    let test_source_speed = ForeignSource::new().unwrap();
+
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_brake (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          vehicle_id TEXT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SOURCE STREAM source_speed (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          vehicle_id TEXT NOT NULL,
+          speed INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_joined (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          speed INTEGER
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_join AS
+          INSERT INTO sink_joined (ts, speed)
+          SELECT STREAM
+            source_brake.ts,
+            source_speed.speed
+          FROM source_brake
+          LEFT OUTER JOIN source_speed
+            ON source_brake.vehicle_id = source_speed.vehicle_id
+              AND source_speed.ts BETWEEN source_brake.ts - DURATION_SECS(5) AND source_brake.ts + DURATION_SECS(5);
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_joined FOR sink_joined
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_source_brake FOR source_brake
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source_brake.host_ip(),
+            remote_port = test_source_brake.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_source_speed FOR source_speed
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+      ",
+            remote_host = test_source_speed.host_ip(),
+            remote_port = test_source_speed.port()
+        ),
+    ];
+
+    let sink_received = run_and_drain(
+        &ddls,
+        ForeignSourceInput::new_fifo_batch(source_brake),
+        test_source_brake,
+        ForeignSourceInput::new_fifo_batch(source_speed),
+        test_source_speed,
+        &test_sink,
+    );
+
+    assert_eq!(sink_received.len(), 3);
+
+    let r0 = sink_received[0].clone();
+    assert_eq!(r0["ts"].as_str().unwrap(), "2020-01-01 00:00:00.000000000");
+    assert_eq!(r0["speed"].as_i64().unwrap(), 40);
+
+    let r1 = sink_received[1].clone();
+    assert_eq!(r1["ts"].as_str().unwrap(), "2020-01-01 00:00:10.000000000");
+    assert_eq!(r1["speed"].as_i64().unwrap(), 60);
+
+    let r2 = sink_received[2].clone();
+    assert_eq!(r2["ts"].as_str().unwrap(), "2020-01-01 00:00:20.000000000");
+    assert!(r2["speed"].is_null());
+}
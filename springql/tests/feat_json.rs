@@ -0,0 +1,247 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use std::str::FromStr;
+
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use springql::*;
+use springql_foreign_service::{
+    sink::ForeignSink,
+    source::{ForeignSource, ForeignSourceInput},
+};
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::{apply_ddls, drain_from_sink};
+
+#[test]
+fn test_feat_json_path_functions() {
+    setup_test_logger();
+
+    let source_input = vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "device": "d1", "payload": {"engine": {"rpm": 2400, "temp": 88.5}, "tags": ["a", "b"]}}),
+        json!({"ts": "2020-01-01 00:00:01.000000000", "device": "d2", "payload": {"engine": {"rpm": 800}, "note": "idle"}}),
+    ];
+
+    let test_source = ForeignSource::new().unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_telemetry (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          device TEXT NOT NULL,
+          payload JSON NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_telemetry (
+          rpm INTEGER,
+          temp DOUBLE,
+          has_tags BOOLEAN NOT NULL,
+          engine JSON,
+          summary JSON NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_telemetry AS
+          INSERT INTO sink_telemetry (rpm, temp, has_tags, engine, summary)
+          SELECT STREAM
+            JSON_VALUE(source_telemetry.payload, '$.engine.rpm'),
+            JSON_VALUE(source_telemetry.payload, '$.engine.temp'),
+            JSON_EXISTS(source_telemetry.payload, '$.tags'),
+            JSON_QUERY(source_telemetry.payload, '$.engine'),
+            JSON_OBJECT(
+              'device' VALUE source_telemetry.device,
+              'first_tag' VALUE JSON_VALUE(source_telemetry.payload, '$.tags[0]'),
+              'tags' VALUE JSON_QUERY(source_telemetry.payload, '$.tags')
+            )
+          FROM source_telemetry;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_telemetry FOR sink_telemetry
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_source_telemetry FOR source_telemetry
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let _pipeline = apply_ddls(&ddls, SpringConfig::default());
+    test_source.start(ForeignSourceInput::new_fifo_batch(source_input));
+    let sink_received = drain_from_sink(&test_sink);
+
+    assert_eq!(
+        sink_received,
+        vec![
+            json!({"rpm": 2400, "temp": 88.5, "has_tags": true, "engine": {"rpm": 2400, "temp": 88.5}, "summary": {"device": "d1", "first_tag": "a", "tags": ["a", "b"]}}),
+            json!({"rpm": 800, "temp": null, "has_tags": false, "engine": {"rpm": 800}, "summary": {"device": "d2", "first_tag": null, "tags": null}}),
+        ]
+    );
+}
+
+#[test]
+fn test_feat_json_column_paths() {
+    setup_test_logger();
+
+    let source_input = vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "vehicle": {"id": "v1", "speed": 42.5, "tires": [230, 240]}}),
+        json!({"ts": "2020-01-01 00:00:01.000000000", "vehicle": {"id": "v2", "tires": []}, "speed": 99}),
+    ];
+
+    let test_source = ForeignSource::new().unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_vehicle (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          vehicle_id TEXT NOT NULL,
+          speed DOUBLE,
+          rear_tire INTEGER
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_vehicle (
+          vehicle_id TEXT NOT NULL,
+          speed DOUBLE,
+          rear_tire INTEGER
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_vehicle AS
+          INSERT INTO sink_vehicle (vehicle_id, speed, rear_tire)
+          SELECT STREAM
+            source_vehicle.vehicle_id,
+            source_vehicle.speed,
+            source_vehicle.rear_tire
+          FROM source_vehicle;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_vehicle FOR sink_vehicle
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_source_vehicle FOR source_vehicle
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}',
+            COLUMN_PATH_vehicle_id '$.vehicle.id',
+            COLUMN_PATH_speed '$.vehicle.speed',
+            COLUMN_PATH_rear_tire '/vehicle/tires/1'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let _pipeline = apply_ddls(&ddls, SpringConfig::default());
+    test_source.start(ForeignSourceInput::new_fifo_batch(source_input));
+    let sink_received = drain_from_sink(&test_sink);
+
+    assert_eq!(
+        sink_received,
+        vec![
+            json!({"vehicle_id": "v1", "speed": 42.5, "rear_tire": 240}),
+            json!({"vehicle_id": "v2", "speed": null, "rear_tire": null}),
+        ]
+    );
+}
+
+#[test]
+fn test_feat_json_in_memory_queue() -> Result<()> {
+    setup_test_logger();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_event (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          payload JSON NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_event (
+          payload JSON NOT NULL,
+          kind TEXT
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_event AS
+          INSERT INTO sink_event (payload, kind)
+          SELECT STREAM source_event.payload, JSON_VALUE(source_event.payload, '$.kind')
+          FROM source_event;
+        "
+        .to_string(),
+        "
+        CREATE SINK WRITER q_sink_event FOR sink_event
+          TYPE IN_MEMORY_QUEUE OPTIONS (
+            NAME 'q_sink_event'
+        );
+        "
+        .to_string(),
+        "
+        CREATE SOURCE READER q_source_event FOR source_event
+          TYPE IN_MEMORY_QUEUE OPTIONS (
+            NAME 'q_source_event'
+          );
+        "
+        .to_string(),
+    ];
+    let pipeline = apply_ddls(&ddls, SpringConfig::default());
+
+    let payload = json!({"kind": "door", "open": true, "sensors": [1, 2.5]});
+    let row = SpringSourceRowBuilder::default()
+        .add_column(
+            "ts",
+            SpringTimestamp::from_str("2020-01-01 00:00:00.000000000").unwrap(),
+        )?
+        .add_column("payload", payload.clone())?
+        .build();
+    pipeline.push("q_source_event", row)?;
+
+    let sink_row = pipeline.pop("q_sink_event")?;
+    assert_eq!(
+        sink_row.get_not_null_by_index::<serde_json::Value>(0)?,
+        payload
+    );
+    assert_eq!(sink_row.get_not_null_by_index::<String>(1)?, "door");
+
+    Ok(())
+}
@@ -0,0 +1,198 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use springql_core::api::*;
+use springql_foreign_service::{
+    sink::ForeignSink,
+    source::{ForeignSource, ForeignSourceInput},
+};
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::*;
+
+fn gen_source_vehicle_event() -> Vec<serde_json::Value> {
+    vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "vehicle_id": "v1", "event": "hard_brake"}),
+        json!({"ts": "2020-01-01 00:00:00.500000000", "vehicle_id": "v2", "event": "hard_brake"}),
+        json!({"ts": "2020-01-01 00:00:01.000000000", "vehicle_id": "v1", "event": "airbag"}),
+        // too late for v2's hard brake
+        json!({"ts": "2020-01-01 00:00:03.000000000", "vehicle_id": "v2", "event": "airbag"}),
+        json!({"ts": "2020-01-01 00:00:06.000000000", "vehicle_id": "v1", "event": "brake"}),
+        json!({"ts": "2020-01-01 00:00:07.000000000", "vehicle_id": "v1", "event": "airbag"}),
+        // not contiguous
+        json!({"ts": "2020-01-01 00:00:08.000000000", "vehicle_id": "v2", "event": "hard_brake"}),
+        json!({"ts": "2020-01-01 00:00:09.000000000", "vehicle_id": "v2", "event": "drive"}),
+        json!({"ts": "2020-01-01 00:00:09.500000000", "vehicle_id": "v2", "event": "airbag"}),
+        json!({"ts": "2020-01-01 00:00:10.000000000", "vehicle_id": "v2", "event": "hard_brake"}),
+        json!({"ts": "2020-01-01 00:00:11.000000000", "vehicle_id": "v2", "event": "airbag"}),
+    ]
+}
+
+#[test]
+fn test_feat_match_recognize_hard_brake_then_airbag() {
+    setup_test_logger();
+
+    let test_source = ForeignSource::new().unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_vehicle_event (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          vehicle_id TEXT NOT NULL,
+          event TEXT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_crash (
+          vehicle_id TEXT NOT NULL,
+          brake_at TIMESTAMP NOT NULL,
+          airbag_at TIMESTAMP NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_crash AS
+          INSERT INTO sink_crash (vehicle_id, brake_at, airbag_at)
+          SELECT STREAM
+            source_vehicle_event.vehicle_id,
+            source_vehicle_event.brake_at,
+            source_vehicle_event.airbag_at
+          FROM source_vehicle_event
+          MATCH_RECOGNIZE (
+            PARTITION BY source_vehicle_event.vehicle_id
+            ORDER BY source_vehicle_event.ts
+            MEASURES A.ts AS brake_at, B.ts AS airbag_at
+            PATTERN (A B)
+            WITHIN DURATION_SECS(2)
+            DEFINE A AS A.event = 'hard_brake', B AS B.event = 'airbag'
+          );
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_crash FOR sink_crash
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_source_vehicle_event FOR source_vehicle_event
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let _pipeline = apply_ddls(&ddls, SpringConfig::default());
+    test_source.start(ForeignSourceInput::new_fifo_batch(
+        gen_source_vehicle_event(),
+    ));
+
+    let mut sink_received = drain_from_sink(&test_sink)
+        .into_iter()
+        .map(|r| {
+            (
+                r["vehicle_id"].as_str().unwrap().to_string(),
+                r["brake_at"].as_str().unwrap().to_string(),
+                r["airbag_at"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect::<Vec<_>>();
+    sink_received.sort();
+
+    assert_eq!(
+        sink_received,
+        vec![
+            (
+                "v1".to_string(),
+                "2020-01-01 00:00:00.000000000".to_string(),
+                "2020-01-01 00:00:01.000000000".to_string(),
+            ),
+            (
+                "v2".to_string(),
+                "2020-01-01 00:00:10.000000000".to_string(),
+                "2020-01-01 00:00:11.000000000".to_string(),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_feat_match_recognize_order_by_not_rowtime() {
+    setup_test_logger();
+
+    let pipeline = SpringPipeline::new(&SpringConfig::default()).unwrap();
+    pipeline
+        .command(
+            "
+        CREATE SOURCE STREAM source_vehicle_event (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          received_at TIMESTAMP NOT NULL,
+          vehicle_id TEXT NOT NULL,
+          event TEXT NOT NULL
+        );
+        ",
+        )
+        .unwrap();
+    pipeline
+        .command(
+            "
+        CREATE SINK STREAM sink_crash (
+          vehicle_id TEXT NOT NULL,
+          brake_at TIMESTAMP NOT NULL
+        );
+        ",
+        )
+        .unwrap();
+
+    let pump = |order_by: &str| {
+        format!(
+            "
+        CREATE PUMP pu_crash AS
+          INSERT INTO sink_crash (vehicle_id, brake_at)
+          SELECT STREAM
+            source_vehicle_event.vehicle_id,
+            source_vehicle_event.brake_at
+          FROM source_vehicle_event
+          MATCH_RECOGNIZE (
+            ORDER BY {}
+            MEASURES A.ts AS brake_at
+            PATTERN (A)
+            WITHIN DURATION_SECS(2)
+            DEFINE A AS A.event = 'hard_brake'
+          );
+        ",
+            order_by
+        )
+    };
+
+    // ORDER BY other than the ROWTIME column is rejected
+    for order_by in [
+        "source_vehicle_event.received_at",
+        "source_vehicle_event.vehicle_id",
+        "source_vehicle_event.ptime",
+    ] {
+        assert!(matches!(
+            pipeline.command(pump(order_by)).unwrap_err(),
+            SpringError::Sql(_)
+        ));
+    }
+
+    pipeline.command(pump("source_vehicle_event.ts")).unwrap();
+}
@@ -0,0 +1,326 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use springql_core::api::*;
+use springql_foreign_service::{
+    sink::ForeignSink,
+    source::{ForeignSource, ForeignSourceInput},
+};
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::*;
+
+fn gen_source_battery() -> Vec<serde_json::Value> {
+    vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "vehicle_id": "v1", "level": 100}),
+        json!({"ts": "2020-01-01 00:00:01.000000000", "vehicle_id": "v2", "level": 80}),
+        json!({"ts": "2020-01-01 00:00:02.000000000", "vehicle_id": "v1", "level": 97}),
+        json!({"ts": "2020-01-01 00:00:03.000000000", "vehicle_id": "v1", "level": 95}),
+        json!({"ts": "2020-01-01 00:00:04.000000000", "vehicle_id": "v2", "level": 71}),
+    ]
+}
+
+fn ddls(
+    select: &str,
+    delta_type: &str,
+    test_source: &ForeignSource,
+    test_sink: &ForeignSink,
+) -> Vec<String> {
+    vec![
+        "
+        CREATE SOURCE STREAM source_battery (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          vehicle_id TEXT NOT NULL,
+          level INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK STREAM sink_battery (
+          vehicle_id TEXT NOT NULL,
+          level INTEGER NOT NULL,
+          delta {delta_type}
+        );
+        ",
+            delta_type = delta_type
+        ),
+        format!(
+            "
+        CREATE PUMP pu_battery AS
+          INSERT INTO sink_battery (vehicle_id, level, delta)
+          {select};
+        ",
+            select = select
+        ),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_battery FOR sink_battery
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_source_battery FOR source_battery
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ]
+}
+
+fn drain(select: &str, delta_type: &str) -> Vec<serde_json::Value> {
+    let test_source = ForeignSource::new().unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = ddls(select, delta_type, &test_source, &test_sink);
+    let _pipeline = apply_ddls(&ddls, SpringConfig::default());
+    test_source.start(ForeignSourceInput::new_fifo_batch(gen_source_battery()));
+
+    drain_from_sink(&test_sink)
+}
+
+fn run_and_drain(select: &str) -> Vec<(String, i64, Option<i64>)> {
+    drain(select, "INTEGER")
+        .into_iter()
+        .map(|r| {
+            (
+                r["vehicle_id"].as_str().unwrap().to_string(),
+                r["level"].as_i64().unwrap(),
+                r["delta"].as_i64(),
+            )
+        })
+        .collect()
+}
+
+/// Sorted by (vehicle_id, level)
+fn run_and_drain_double(select: &str) -> Vec<(String, i64, Option<f64>)> {
+    let mut sink_received = drain(select, "DOUBLE")
+        .into_iter()
+        .map(|r| {
+            (
+                r["vehicle_id"].as_str().unwrap().to_string(),
+                r["level"].as_i64().unwrap(),
+                r["delta"].as_f64(),
+            )
+        })
+        .collect::<Vec<_>>();
+    sink_received.sort_by(|a, b| (&a.0, a.1).cmp(&(&b.0, b.1)));
+    sink_received
+}
+
+#[test]
+fn test_feat_lag_delta_per_vehicle() {
+    setup_test_logger();
+
+    let mut sink_received = run_and_drain(
+        "
+          SELECT STREAM
+            source_battery.vehicle_id,
+            source_battery.level,
+            source_battery.level + -LAG(source_battery.level) OVER (PARTITION BY source_battery.vehicle_id)
+          FROM source_battery
+        ",
+    );
+    sink_received.sort();
+
+    assert_eq!(
+        sink_received,
+        vec![
+            ("v1".to_string(), 95, Some(-2)),
+            ("v1".to_string(), 97, Some(-3)),
+            ("v1".to_string(), 100, None),
+            ("v2".to_string(), 71, Some(-9)),
+            ("v2".to_string(), 80, None),
+        ]
+    );
+}
+
+#[test]
+fn test_feat_lead_per_vehicle() {
+    setup_test_logger();
+
+    let mut sink_received = run_and_drain(
+        "
+          SELECT STREAM
+            source_battery.vehicle_id,
+            source_battery.level,
+            LEAD(source_battery.level, 2) OVER (PARTITION BY source_battery.vehicle_id)
+          FROM source_battery
+        ",
+    );
+    sink_received.sort();
+
+    // Tuples without 2 following tuples in the same vehicle are not emitted yet.
+    assert_eq!(sink_received, vec![("v1".to_string(), 100, Some(95))]);
+}
+
+#[test]
+fn test_feat_lead_window_per_vehicle() {
+    setup_test_logger();
+
+    let mut sink_received = run_and_drain(
+        "
+          SELECT STREAM
+            source_battery.vehicle_id,
+            source_battery.level,
+            LEAD(source_battery.level, 2) OVER (PARTITION BY source_battery.vehicle_id WINDOW DURATION_SECS(2))
+          FROM source_battery
+        ",
+    );
+    sink_received.sort();
+
+    // v2 at 00:00:01 waits only until 00:00:03 and gets NULL.
+    assert_eq!(
+        sink_received,
+        vec![
+            ("v1".to_string(), 100, Some(95)),
+            ("v2".to_string(), 80, None),
+        ]
+    );
+}
+
+#[test]
+fn test_feat_ewma_per_vehicle() {
+    setup_test_logger();
+
+    let sink_received = run_and_drain_double(
+        "
+          SELECT STREAM
+            source_battery.vehicle_id,
+            source_battery.level,
+            EWMA(source_battery.level, 0.5) OVER (PARTITION BY source_battery.vehicle_id)
+          FROM source_battery
+        ",
+    );
+
+    assert_eq!(
+        sink_received,
+        vec![
+            ("v1".to_string(), 95, Some(96.75)),
+            ("v1".to_string(), 97, Some(98.5)),
+            ("v1".to_string(), 100, Some(100.0)),
+            ("v2".to_string(), 71, Some(75.5)),
+            ("v2".to_string(), 80, Some(80.0)),
+        ]
+    );
+}
+
+#[test]
+fn test_feat_derivative_and_integral_per_vehicle() {
+    setup_test_logger();
+
+    let sink_received = run_and_drain_double(
+        "
+          SELECT STREAM
+            source_battery.vehicle_id,
+            source_battery.level,
+            DERIVATIVE(source_battery.level) OVER (PARTITION BY source_battery.vehicle_id)
+          FROM source_battery
+        ",
+    );
+    assert_eq!(
+        sink_received,
+        vec![
+            ("v1".to_string(), 95, Some(-2.0)),
+            ("v1".to_string(), 97, Some(-1.5)),
+            ("v1".to_string(), 100, None),
+            ("v2".to_string(), 71, Some(-3.0)),
+            ("v2".to_string(), 80, None),
+        ]
+    );
+
+    // trapezoidal rule over seconds
+    let sink_received = run_and_drain_double(
+        "
+          SELECT STREAM
+            source_battery.vehicle_id,
+            source_battery.level,
+            INTEGRAL(source_battery.level) OVER (PARTITION BY source_battery.vehicle_id)
+          FROM source_battery
+        ",
+    );
+    assert_eq!(
+        sink_received,
+        vec![
+            ("v1".to_string(), 95, Some(293.0)),
+            ("v1".to_string(), 97, Some(197.0)),
+            ("v1".to_string(), 100, Some(0.0)),
+            ("v2".to_string(), 71, Some(226.5)),
+            ("v2".to_string(), 80, Some(0.0)),
+        ]
+    );
+}
+
+#[test]
+fn test_feat_zscore_outliers_per_vehicle() {
+    setup_test_logger();
+
+    let sink_received = run_and_drain_double(
+        "
+          SELECT STREAM
+            source_battery.vehicle_id,
+            source_battery.level,
+            ZSCORE(source_battery.level) OVER (PARTITION BY source_battery.vehicle_id WINDOW DURATION_SECS(60))
+          FROM source_battery
+          WHERE IS_OUTLIER(source_battery.level, 1.5) OVER (PARTITION BY source_battery.vehicle_id)
+        ",
+    );
+    let sink_received = sink_received
+        .into_iter()
+        .map(|(vehicle_id, level, zscore)| {
+            (
+                vehicle_id,
+                level,
+                zscore.map(|z| (z * 100.0).round() / 100.0),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    // mean and standard deviation of [100, 97] are 98.5 and 2.12
+    assert_eq!(sink_received, vec![("v1".to_string(), 95, Some(-1.65))]);
+}
+
+#[test]
+fn test_feat_zscore_outliers_after_where() {
+    setup_test_logger();
+
+    let sink_received = run_and_drain_double(
+        "
+          SELECT STREAM
+            source_battery.vehicle_id,
+            source_battery.level,
+            ZSCORE(source_battery.level) OVER ()
+          FROM source_battery
+          WHERE source_battery.vehicle_id = 'v1'
+            AND IS_OUTLIER(source_battery.level, 1.5) OVER ()
+        ",
+    );
+    let sink_received = sink_received
+        .into_iter()
+        .map(|(vehicle_id, level, zscore)| {
+            (
+                vehicle_id,
+                level,
+                zscore.map(|z| (z * 100.0).round() / 100.0),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    // v2 is dropped before navigation, so ZSCORE and IS_OUTLIER only see [100, 97] before 95.
+    assert_eq!(sink_received, vec![("v1".to_string(), 95, Some(-1.65))]);
+}
@@ -134,3 +134,85 @@ fn test_feat_processing_time_ptime() -> Result<()> {
 
     Ok(())
 }
+
+/// Panes keyed by processing time are closed by wall clock even if no more rows come.
+#[test]
+fn test_feat_processing_time_window_on_ptime() -> Result<()> {
+    setup_test_logger();
+
+    let source_input = (0..4)
+        .map(|_| {
+            json!({
+                "ticker": "ORCL",
+                "amount": 10,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let test_source = ForeignSource::new().unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_trade (
+          ticker TEXT NOT NULL,
+          amount INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_avg_all (
+          avg_amount FLOAT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP avg_all AS
+        INSERT INTO sink_avg_all (avg_amount)
+        SELECT STREAM
+            AVG(source_trade.amount) AS avg_amount
+        FROM source_trade
+        FIXED WINDOW DURATION_MILLIS(500) ON PTIME;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_avg_all FOR sink_avg_all
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_trade FOR source_trade
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let sink_received = run_and_drain(
+        &ddls,
+        ForeignSourceInput::new_fifo_batch(source_input),
+        test_source,
+        &test_sink,
+    );
+
+    // 4 rows may be split into multiple panes depending on their arrival time.
+    assert!(!sink_received.is_empty());
+    for r in sink_received {
+        assert_eq!(r["avg_amount"].as_f64().unwrap().round() as i32, 10);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,149 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use springql_core::api::*;
+use springql_foreign_service::{
+    sink::ForeignSink,
+    source::{ForeignSource, ForeignSourceInput},
+};
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::*;
+
+/// ECU e2 goes dead after :01.
+fn gen_source_heartbeat() -> Vec<serde_json::Value> {
+    vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "ecu_id": "e1"}),
+        json!({"ts": "2020-01-01 00:00:00.500000000", "ecu_id": "e2"}),
+        json!({"ts": "2020-01-01 00:00:01.000000000", "ecu_id": "e1"}),
+        json!({"ts": "2020-01-01 00:00:01.000000000", "ecu_id": "e2"}),
+        json!({"ts": "2020-01-01 00:00:02.000000000", "ecu_id": "e1"}),
+        json!({"ts": "2020-01-01 00:00:03.000000000", "ecu_id": "e1"}),
+        json!({"ts": "2020-01-01 00:00:04.000000000", "ecu_id": "e1"}),
+        json!({"ts": "2020-01-01 00:00:05.000000000", "ecu_id": "e1"}),
+    ]
+}
+
+fn ddls(select: &str, test_source: &ForeignSource, test_sink: &ForeignSink) -> Vec<String> {
+    vec![
+        "
+        CREATE SOURCE STREAM source_heartbeat (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          ecu_id TEXT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_dead_ecu (
+          ecu_id TEXT NOT NULL,
+          last_seen TIMESTAMP NOT NULL
+        );
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE PUMP pu_dead_ecu AS
+          INSERT INTO sink_dead_ecu (ecu_id, last_seen)
+          {select};
+        ",
+            select = select
+        ),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_dead_ecu FOR sink_dead_ecu
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_source_heartbeat FOR source_heartbeat
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ]
+}
+
+fn run_and_drain(select: &str) -> Vec<(String, String)> {
+    let test_source = ForeignSource::new().unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = ddls(select, &test_source, &test_sink);
+    let _pipeline = apply_ddls(&ddls, SpringConfig::default());
+    test_source.start(ForeignSourceInput::new_fifo_batch(gen_source_heartbeat()));
+
+    drain_from_sink(&test_sink)
+        .into_iter()
+        .map(|r| {
+            (
+                r["ecu_id"].as_str().unwrap().to_string(),
+                r["last_seen"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_feat_alert_if_silent_by_rowtime() {
+    setup_test_logger();
+
+    let sink_received = run_and_drain(
+        "
+          SELECT STREAM source_heartbeat.ecu_id, source_heartbeat.ts
+          FROM source_heartbeat
+          ALERT IF SILENT FOR DURATION_SECS(3) PARTITION BY source_heartbeat.ecu_id
+        ",
+    );
+
+    // e1 keeps beating so only e2 is alerted when the watermark passes :04.
+    assert_eq!(
+        sink_received,
+        vec![(
+            "e2".to_string(),
+            "2020-01-01 00:00:01.000000000".to_string()
+        )]
+    );
+}
+
+#[test]
+fn test_feat_alert_if_silent_on_ptime() {
+    setup_test_logger();
+
+    let mut sink_received = run_and_drain(
+        "
+          SELECT STREAM source_heartbeat.ecu_id, source_heartbeat.ts
+          FROM source_heartbeat
+          ALERT IF SILENT FOR DURATION_MILLIS(500) ON PTIME PARTITION BY source_heartbeat.ecu_id
+        ",
+    );
+    sink_received.sort();
+
+    // The whole source gets silent by wall clock after sending all rows.
+    assert_eq!(
+        sink_received,
+        vec![
+            (
+                "e1".to_string(),
+                "2020-01-01 00:00:05.000000000".to_string()
+            ),
+            (
+                "e2".to_string(),
+                "2020-01-01 00:00:01.000000000".to_string()
+            ),
+        ]
+    );
+}
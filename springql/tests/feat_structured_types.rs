@@ -0,0 +1,100 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use springql::*;
+use springql_foreign_service::{
+    sink::ForeignSink,
+    source::{ForeignSource, ForeignSourceInput},
+};
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::{apply_ddls, drain_from_sink};
+
+#[test]
+fn test_feat_structured_types_via_json() {
+    setup_test_logger();
+
+    let source_input = vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "tags": ["indoor", "floor1"], "attrs": {"unit": "C", "site": "A"}, "pos": {"lat": 35.5, "lon": 139.25}, "readings": [21.0, 22, 23]}),
+        json!({"ts": "2020-01-01 00:00:01.000000000", "tags": [], "attrs": {}, "pos": {"lat": -33.75}, "readings": [5, null]}),
+    ];
+
+    let test_source = ForeignSource::new().unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_sensor (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          tags ARRAY<TEXT> NOT NULL,
+          attrs MAP<TEXT, TEXT> NOT NULL,
+          pos ROW(lat DOUBLE, lon DOUBLE) NOT NULL,
+          readings ARRAY<INTEGER> NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_sensor (
+          first_tag TEXT,
+          unit TEXT,
+          lat DOUBLE NOT NULL,
+          n_readings INTEGER NOT NULL,
+          pos ROW(lat DOUBLE, lon DOUBLE) NOT NULL,
+          readings ARRAY<INTEGER> NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_sensor AS
+          INSERT INTO sink_sensor (first_tag, unit, lat, n_readings, pos, readings)
+          SELECT STREAM
+            source_sensor.tags[1],
+            source_sensor.attrs['unit'],
+            source_sensor.pos.lat,
+            CARDINALITY(source_sensor.readings),
+            source_sensor.pos,
+            source_sensor.readings
+          FROM source_sensor;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_sensor FOR sink_sensor
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_source_sensor FOR source_sensor
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let _pipeline = apply_ddls(&ddls, SpringConfig::default());
+    test_source.start(ForeignSourceInput::new_fifo_batch(source_input));
+    let sink_received = drain_from_sink(&test_sink);
+
+    assert_eq!(
+        sink_received,
+        vec![
+            json!({"first_tag": "indoor", "unit": "C", "lat": 35.5, "n_readings": 3, "pos": {"lat": 35.5, "lon": 139.25}, "readings": [21, 22, 23]}),
+            json!({"first_tag": null, "unit": null, "lat": -33.75, "n_readings": 2, "pos": {"lat": -33.75, "lon": null}, "readings": [5, null]}),
+        ]
+    );
+}
@@ -83,3 +83,315 @@ fn test_feat_floor_time() {
 
     assert_eq!(r["ts"], "2020-01-01 23:59:59.000000000");
 }
+
+#[test]
+fn test_feat_timestamp_time_zone() {
+    setup_test_logger();
+
+    let source_input = vec![
+        json!({"ts": "2020-01-01T23:30:00+09:00", "reading": "2020/01/02 08:30"}),
+        json!({"ts": "2020-01-02T00:30:00+09:00", "reading": "2020/01/02 09:45"}),
+    ];
+
+    let test_source = ForeignSource::new().unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_meter (
+          ts TIMESTAMP WITH TIME ZONE NOT NULL ROWTIME,
+          reading TEXT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_meter (
+          ts TIMESTAMP WITH TIME ZONE NOT NULL,
+          local_day TIMESTAMP NOT NULL,
+          local_hour INTEGER NOT NULL,
+          local_dow INTEGER NOT NULL,
+          local_time TEXT NOT NULL,
+          read_at TIMESTAMP WITH TIME ZONE NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_meter AS
+          INSERT INTO sink_meter (ts, local_day, local_hour, local_dow, local_time, read_at)
+          SELECT STREAM
+            source_meter.ts,
+            DATE_TRUNC('day', source_meter.ts AT TIME ZONE '+09:00'),
+            EXTRACT(HOUR FROM source_meter.ts AT TIME ZONE '+09:00'),
+            EXTRACT(DOW FROM source_meter.ts AT TIME ZONE '+09:00'),
+            FORMAT_TIMESTAMP(source_meter.ts AT TIME ZONE '+09:00', '[month]/[day] [hour]:[minute]'),
+            TO_TIMESTAMP(source_meter.reading, '[year]/[month]/[day] [hour]:[minute]') AT TIME ZONE 'UTC' AT TIME ZONE '+09:00'
+          FROM source_meter;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_meter FOR sink_meter
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_meter FOR source_meter
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let _pipeline = apply_ddls(&ddls, SpringConfig::default());
+    test_source.start(ForeignSourceInput::new_fifo_batch(source_input));
+    let sink_received = drain_from_sink(&test_sink);
+
+    assert_eq!(
+        sink_received,
+        vec![
+            json!({
+                "ts": "2020-01-01T14:30:00.000000000Z",
+                "local_day": "2020-01-01 00:00:00.000000000",
+                "local_hour": 23,
+                "local_dow": 3,
+                "local_time": "01/01 23:30",
+                "read_at": "2020-01-01T23:30:00.000000000Z"
+            }),
+            json!({
+                "ts": "2020-01-01T15:30:00.000000000Z",
+                "local_day": "2020-01-02 00:00:00.000000000",
+                "local_hour": 0,
+                "local_dow": 4,
+                "local_time": "01/02 00:30",
+                "read_at": "2020-01-02T00:45:00.000000000Z"
+            }),
+        ]
+    );
+}
+
+#[test]
+fn test_feat_source_timestamp_formats() {
+    setup_test_logger();
+
+    let source_input = vec![
+        json!({"ts": 1577836800000_i64, "logged_at": "01/Jan/2020:09:00:00 +0900", "received_at": "2020-01-01T09:00:00.5+09:00"}),
+        json!({"ts": 1577836801500.0, "logged_at": "01/Jan/2020:00:00:01 +0000", "received_at": null}),
+    ];
+
+    let test_source = ForeignSource::new().unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_device (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          logged_at TIMESTAMP NOT NULL,
+          received_at TIMESTAMP
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_device (
+          ts TIMESTAMP NOT NULL,
+          logged_at TIMESTAMP NOT NULL,
+          received_at TIMESTAMP
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_device AS
+          INSERT INTO sink_device (ts, logged_at, received_at)
+          SELECT STREAM source_device.ts, source_device.logged_at, source_device.received_at
+          FROM source_device;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_device FOR sink_device
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_device FOR source_device
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}',
+            TIMESTAMP_FORMAT_ts 'EPOCH_MILLIS',
+            TIMESTAMP_FORMAT_logged_at '%d/%b/%Y:%H:%M:%S %z',
+            TIMESTAMP_FORMAT_received_at 'ISO8601'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let _pipeline = apply_ddls(&ddls, SpringConfig::default());
+    test_source.start(ForeignSourceInput::new_fifo_batch(source_input));
+    let sink_received = drain_from_sink(&test_sink);
+
+    assert_eq!(
+        sink_received,
+        vec![
+            json!({
+                "ts": "2020-01-01 00:00:00.000000000",
+                "logged_at": "2020-01-01 00:00:00.000000000",
+                "received_at": "2020-01-01 00:00:00.500000000"
+            }),
+            json!({
+                "ts": "2020-01-01 00:00:01.500000000",
+                "logged_at": "2020-01-01 00:00:01.000000000",
+                "received_at": null
+            }),
+        ]
+    );
+}
+
+#[test]
+fn test_feat_source_timestamp_format_invalid() {
+    setup_test_logger();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_device (
+          ts TIMESTAMP NOT NULL ROWTIME
+        );
+        "
+        .to_string(),
+        "
+        CREATE SOURCE READER tcp_device FOR source_device
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '127.0.0.1',
+            REMOTE_PORT '65535',
+            TIMESTAMP_FORMAT_ts '%Y-%m-%d %Q'
+          );
+        "
+        .to_string(),
+    ];
+
+    let pipeline = SpringPipeline::new(&SpringConfig::default()).unwrap();
+    pipeline.command(&ddls[0]).unwrap();
+    assert!(matches!(
+        pipeline.command(&ddls[1]).unwrap_err(),
+        SpringError::InvalidOption { .. }
+    ));
+}
+
+#[test]
+fn test_feat_timestamp_duration_arithmetic() {
+    setup_test_logger();
+
+    let source_input = vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "sent_at": "2020-01-01 00:00:00.100000000", "received_at": "2020-01-01 00:00:02.350000000"}),
+        json!({"ts": "2020-01-01 00:00:01.000000000", "sent_at": "2020-01-01 00:00:01.000000000", "received_at": "2020-01-01 00:00:03.000000000"}),
+    ];
+
+    let test_source = ForeignSource::new().unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let ddls = vec![
+        "
+        CREATE SOURCE STREAM source_event (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          sent_at TIMESTAMP NOT NULL,
+          received_at TIMESTAMP NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE SINK STREAM sink_event (
+          latency_ms BIGINT NOT NULL,
+          doubled_latency_ms BIGINT NOT NULL,
+          is_2s_late BOOLEAN NOT NULL,
+          deadline TIMESTAMP NOT NULL,
+          expires_at TIMESTAMP NOT NULL,
+          retry_at TIMESTAMP NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_event AS
+          INSERT INTO sink_event (latency_ms, doubled_latency_ms, is_2s_late, deadline, expires_at, retry_at)
+          SELECT STREAM
+            DURATION_TO_MILLIS(source_event.received_at - source_event.sent_at),
+            DURATION_TO_MILLIS((source_event.received_at - source_event.sent_at) * 2),
+            (source_event.received_at - source_event.sent_at) = DURATION_SECS(2),
+            source_event.ts + DURATION_MINUTES(5),
+            source_event.ts + DURATION_HOURS(1),
+            source_event.received_at - DURATION_MICROS(1500) * 2
+          FROM source_event;
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SINK WRITER tcp_sink_event FOR sink_event
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+            remote_host = test_sink.host_ip(),
+            remote_port = test_sink.port()
+        ),
+        format!(
+            "
+        CREATE SOURCE READER tcp_event FOR source_event
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ];
+
+    let _pipeline = apply_ddls(&ddls, SpringConfig::default());
+    test_source.start(ForeignSourceInput::new_fifo_batch(source_input));
+    let sink_received = drain_from_sink(&test_sink);
+
+    assert_eq!(
+        sink_received,
+        vec![
+            json!({
+                "latency_ms": 2250,
+                "doubled_latency_ms": 4500,
+                "is_2s_late": false,
+                "deadline": "2020-01-01 00:05:00.000000000",
+                "expires_at": "2020-01-01 01:00:00.000000000",
+                "retry_at": "2020-01-01 00:00:02.347000000"
+            }),
+            json!({
+                "latency_ms": 2000,
+                "doubled_latency_ms": 4000,
+                "is_2s_late": true,
+                "deadline": "2020-01-01 00:05:01.000000000",
+                "expires_at": "2020-01-01 01:00:01.000000000",
+                "retry_at": "2020-01-01 00:00:02.997000000"
+            }),
+        ]
+    );
+}
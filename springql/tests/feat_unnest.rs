@@ -0,0 +1,152 @@
+// This file is part of https://github.com/SpringQL/SpringQL which is licensed under MIT OR Apache-2.0. See file LICENSE-MIT or LICENSE-APACHE for full license details.
+
+mod test_support;
+
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use springql::*;
+use springql_foreign_service::{
+    sink::ForeignSink,
+    source::{ForeignSource, ForeignSourceInput},
+};
+use springql_test_logger::setup_test_logger;
+
+use crate::test_support::{apply_ddls, drain_from_sink};
+
+fn gen_source_input() -> Vec<serde_json::Value> {
+    vec![
+        json!({"ts": "2020-01-01 00:00:00.000000000", "device": "d1", "samples": [10, 20, 30]}),
+        json!({"ts": "2020-01-01 00:00:01.000000000", "device": "d2", "samples": []}),
+        json!({"ts": "2020-01-01 00:00:02.000000000", "device": "d2", "samples": null}),
+        json!({"ts": "2020-01-01 00:00:03.000000000", "device": "d2", "samples": [-4, 40]}),
+        json!({"ts": "2020-01-01 00:00:10.000000000", "device": "d1", "samples": [0]}),
+    ]
+}
+
+fn source_ddls(test_source: &ForeignSource) -> Vec<String> {
+    vec![
+        "
+        CREATE SOURCE STREAM source_batch (
+          ts TIMESTAMP NOT NULL ROWTIME,
+          device TEXT NOT NULL,
+          samples ARRAY<INTEGER>
+        );
+        "
+        .to_string(),
+        format!(
+            "
+        CREATE SOURCE READER tcp_source_batch FOR source_batch
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+          );
+        ",
+            remote_host = test_source.host_ip(),
+            remote_port = test_source.port()
+        ),
+    ]
+}
+
+fn sink_writer_ddl(sink_stream: &str, test_sink: &ForeignSink) -> String {
+    format!(
+        "
+        CREATE SINK WRITER tcp_{sink_stream} FOR {sink_stream}
+          TYPE NET_CLIENT OPTIONS (
+            PROTOCOL 'TCP',
+            REMOTE_HOST '{remote_host}',
+            REMOTE_PORT '{remote_port}'
+        );
+        ",
+        sink_stream = sink_stream,
+        remote_host = test_sink.host_ip(),
+        remote_port = test_sink.port()
+    )
+}
+
+#[test]
+fn test_feat_unnest_projection() {
+    setup_test_logger();
+
+    let test_source = ForeignSource::new().unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let mut ddls = source_ddls(&test_source);
+    ddls.extend(vec![
+        "
+        CREATE SINK STREAM sink_sample (
+          device TEXT NOT NULL,
+          sample INTEGER NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_sample AS
+          INSERT INTO sink_sample (device, sample)
+          SELECT STREAM source_batch.device, t.sample
+          FROM source_batch
+          CROSS JOIN UNNEST(source_batch.samples) AS t(sample);
+        "
+        .to_string(),
+        sink_writer_ddl("sink_sample", &test_sink),
+    ]);
+
+    let _pipeline = apply_ddls(&ddls, SpringConfig::default());
+    test_source.start(ForeignSourceInput::new_fifo_batch(gen_source_input()));
+    let sink_received = drain_from_sink(&test_sink);
+
+    assert_eq!(
+        sink_received,
+        vec![
+            json!({"device": "d1", "sample": 10}),
+            json!({"device": "d1", "sample": 20}),
+            json!({"device": "d1", "sample": 30}),
+            json!({"device": "d2", "sample": -4}),
+            json!({"device": "d2", "sample": 40}),
+            json!({"device": "d1", "sample": 0}),
+        ]
+    );
+}
+
+#[test]
+fn test_feat_unnest_before_window() {
+    setup_test_logger();
+
+    let test_source = ForeignSource::new().unwrap();
+    let test_sink = ForeignSink::start().unwrap();
+
+    let mut ddls = source_ddls(&test_source);
+    ddls.extend(vec![
+        "
+        CREATE SINK STREAM sink_avg (
+          device TEXT NOT NULL,
+          avg_sample FLOAT NOT NULL
+        );
+        "
+        .to_string(),
+        "
+        CREATE PUMP pu_avg AS
+          INSERT INTO sink_avg (device, avg_sample)
+          SELECT STREAM source_batch.device AS device, AVG(t.sample) AS avg_sample
+          FROM source_batch
+          CROSS JOIN UNNEST(source_batch.samples) AS t(sample)
+          GROUP BY device
+          FIXED WINDOW DURATION_SECS(10), DURATION_SECS(0);
+        "
+        .to_string(),
+        sink_writer_ddl("sink_avg", &test_sink),
+    ]);
+
+    let _pipeline = apply_ddls(&ddls, SpringConfig::default());
+    test_source.start(ForeignSourceInput::new_fifo_batch(gen_source_input()));
+    let mut sink_received = drain_from_sink(&test_sink);
+    sink_received.sort_by_key(|row| row["device"].as_str().unwrap().to_string());
+
+    assert_eq!(
+        sink_received,
+        vec![
+            json!({"device": "d1", "avg_sample": 20.0}),
+            json!({"device": "d2", "avg_sample": 18.0}),
+        ]
+    );
+}